@@ -19,28 +19,54 @@
 //! CORD chain configurations.
 
 pub mod bootstrap;
+pub mod snapshot;
 
-pub use cord_primitives::{AccountId, Balance, NodeId, Signature};
+pub use cord_primitives::{AccountId, Balance, Hash, NodeId, Signature};
 pub use cord_runtime::RuntimeGenesisConfig;
 use cord_runtime::{Block, SessionKeys};
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use sc_chain_spec::ChainSpecExtension;
 use sc_consensus_grandpa::AuthorityId as GrandpaId;
+use sc_network::config::MultiaddrWithPeerId;
 pub use sc_service::{ChainType, Properties};
 use sc_telemetry::TelemetryEndpoints;
 use serde::{Deserialize, Serialize};
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
-use sp_consensus_babe::AuthorityId as BabeId;
-use sp_core::{sr25519, Pair, Public};
+use sp_consensus_babe::{AuthorityId as BabeId, BabeEpochConfiguration};
+use sp_core::{
+	crypto::{
+		Ss58AddressFormat, Ss58AddressFormatRegistry, Ss58Codec, UncheckedFrom, UncheckedInto,
+	},
+	ed25519, sr25519, Pair, Public,
+};
 use sp_runtime::traits::{IdentifyAccount, Verify};
 use sp_std::collections::btree_map::BTreeMap;
+use std::path::Path;
 
 type AccountPublic = <Signature as Verify>::Signer;
 
 pub use cord_runtime_constants::{currency::*, time::*};
 
 const CORD_TELEMETRY_URL: &str = "wss://telemetry.cord.network/submit/";
+/// Environment variable overriding the staging telemetry endpoints, as
+/// comma-separated `url=level` pairs.
+const TELEMETRY_ENDPOINTS_ENV: &str = "CORD_TELEMETRY_ENDPOINTS";
+/// Environment variable holding the staging authority discovery keys, as
+/// newline-separated hex encoded sr25519 public keys.
+const AUTHORITY_DISCOVERY_KEYS_ENV: &str = "CORD_AUTHORITY_DISCOVERY_KEYS";
+/// Environment variable holding the staging bootnodes, as newline-separated
+/// multiaddrs ending in `/p2p/<peer id>`.
+const BOOTNODES_ENV: &str = "CORD_BOOTNODES";
+/// Environment variable holding the path of the staging council snapshot, a
+/// JSON array of SS58 encoded council member accounts.
+const COUNCIL_MEMBERS_FILE_ENV: &str = "CORD_COUNCIL_MEMBERS_FILE";
+/// Chain spec property holding prioritised bootnodes, as described by
+/// `res/bootnodes-weighted.schema.json`.
+const WEIGHTED_BOOTNODES_PROPERTY: &str = "bootnodes_weighted";
 const DEFAULT_PROTOCOL_ID: &str = "cord";
+/// EVM chain id of development chains, following the EVM convention for
+/// local networks.
+pub const DEV_CHAIN_ID: u64 = 1337;
 
 /// Node `ChainSpec` extensions.
 ///
@@ -55,6 +81,58 @@ pub struct Extensions {
 	pub bad_blocks: sc_client_api::BadBlocks<Block>,
 	/// The light sync state extension used by the sync-state rpc.
 	pub light_sync_state: sc_sync_state_rpc::LightSyncStateExtension,
+	/// The parachain id, once CORD is assigned a parachain slot.
+	#[serde(default)]
+	pub para_id: Option<u32>,
+	/// The numeric chain id expected by EVM tooling. Derived from the genesis
+	/// hash when not set.
+	#[serde(default)]
+	pub chain_id: Option<u64>,
+	/// The genesis hash recorded when the genesis runtime code was patched,
+	/// see [`patch_genesis_wasm`].
+	#[serde(default)]
+	pub genesis_hash: Option<Hash>,
+}
+
+impl Extensions {
+	/// The extensions of `chain_spec`, if it was built with these extensions.
+	pub fn try_get(chain_spec: &dyn sc_service::ChainSpec) -> Option<&Self> {
+		sc_chain_spec::get_extension(chain_spec.extensions())
+	}
+
+	/// Whether the chain spec can be used for a parachain migration: a
+	/// `para_id` is set and the runtime exports the parachain validation
+	/// function.
+	pub fn is_parachain_ready(&self) -> bool {
+		self.para_id.is_some() && cord_runtime::WASM_BINARY.map_or(false, exports_validate_block)
+	}
+}
+
+/// Derives a deterministic chain id from the first four bytes of the genesis
+/// hash.
+pub fn derive_chain_id(genesis_hash: &Hash) -> u64 {
+	let bytes = genesis_hash.as_fixed_bytes();
+	u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]).into()
+}
+
+/// The chain id of `chain_spec`: its `chainId` extension when set, derived
+/// from the genesis hash otherwise.
+pub fn chain_id(chain_spec: &dyn sc_service::ChainSpec, genesis_hash: &Hash) -> u64 {
+	Extensions::try_get(chain_spec)
+		.and_then(|extensions| extensions.chain_id)
+		.unwrap_or_else(|| derive_chain_id(genesis_hash))
+}
+
+/// Name of the parachain validation function exported by parachain runtimes.
+const VALIDATE_BLOCK_EXPORT: &[u8] = b"validate_block";
+
+/// Whether the, possibly compressed, wasm `code` contains the parachain
+/// validation function.
+fn exports_validate_block(code: &[u8]) -> bool {
+	sp_maybe_compressed_blob::decompress(code, sp_maybe_compressed_blob::CODE_BLOB_BOMB_LIMIT)
+		.map_or(false, |code| {
+			code.windows(VALIDATE_BLOCK_EXPORT.len()).any(|name| name == VALIDATE_BLOCK_EXPORT)
+		})
 }
 
 /// Specialized `ChainSpec`.
@@ -69,6 +147,19 @@ fn session_keys(
 	SessionKeys { babe, grandpa, im_online, authority_discovery }
 }
 
+/// Session keys with a BABE key backed by an Ed25519 public key, for
+/// validators whose signing hardware only exposes Ed25519 keys. The key is
+/// stored as a regular `BabeId`, so the session genesis config keeps its
+/// shape.
+pub fn session_keys_ed25519(
+	babe: ed25519::Public,
+	grandpa: GrandpaId,
+	im_online: ImOnlineId,
+	authority_discovery: AuthorityDiscoveryId,
+) -> SessionKeys {
+	session_keys(babe.0.unchecked_into(), grandpa, im_online, authority_discovery)
+}
+
 /// Helper function to generate a crypto pair from seed
 pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
 	TPublic::Pair::from_string(&format!("//{}", seed), None)
@@ -76,14 +167,31 @@ pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Pu
 		.public()
 }
 
-/// Helper function to set properties
-pub fn get_properties(symbol: &str, decimals: u32, ss58format: u32) -> Properties {
+/// Helper function to generate an Ed25519 public key from seed, typed as
+/// `TPublic`
+pub fn get_from_seed_ed25519<TPublic: Public + UncheckedFrom<[u8; 32]>>(seed: &str) -> TPublic {
+	TPublic::unchecked_from(get_from_seed::<ed25519::Public>(seed).0)
+}
+
+/// Helper function to set properties. The SS58 format must be a prefix
+/// known to the SS58 registry.
+pub fn get_properties(
+	symbol: &str,
+	decimals: u32,
+	ss58format: u32,
+) -> Result<Properties, String> {
+	u16::try_from(ss58format)
+		.ok()
+		.map(Ss58AddressFormat::custom)
+		.and_then(|format| Ss58AddressFormatRegistry::try_from(format).ok())
+		.ok_or_else(|| format!("Unsupported SS58 format `{}`", ss58format))?;
+
 	let mut properties = Properties::new();
 	properties.insert("tokenSymbol".into(), symbol.into());
 	properties.insert("tokenDecimals".into(), decimals.into());
 	properties.insert("ss58Format".into(), ss58format.into());
 
-	properties
+	Ok(properties)
 }
 
 /// Helper function to generate an account ID from seed
@@ -98,8 +206,58 @@ where
 pub fn get_authority_keys_from_seed(
 	seed: &str,
 ) -> (AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId) {
-	let keys = get_authority_keys(seed);
-	(keys.0, keys.1, keys.2, keys.3, keys.4)
+	let (_stash, controller, babe, grandpa, im_online, authority_discovery) =
+		get_authority_keys_at_depth(seed, 0);
+	(controller, babe, grandpa, im_online, authority_discovery)
+}
+
+/// Helper function to generate stash, controller and session key from seed
+/// at derivation depth `depth`, i.e. from `//seed//depth` with the stash at
+/// `//seed//depth//stash`. Depth 0 is `//seed` itself, so that its keys are
+/// the ones of `get_authority_keys_from_seed`.
+pub fn get_authority_keys_at_depth(
+	seed: &str,
+	depth: u32,
+) -> (AccountId, AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId) {
+	let seed = match depth {
+		0 => seed.to_string(),
+		depth => format!("{}//{}", seed, depth),
+	};
+	let keys = get_authority_keys(&seed);
+	(
+		get_account_id_from_seed::<sr25519::Public>(&format!("{}//stash", seed)),
+		keys.0,
+		keys.1,
+		keys.2,
+		keys.3,
+		keys.4,
+	)
+}
+
+/// Helper function to generate a crypto pair from a BIP-39 mnemonic
+pub fn get_from_mnemonic<TPublic: Public>(
+	mnemonic: &str,
+	password: Option<&str>,
+) -> Result<<TPublic::Pair as Pair>::Public, String> {
+	TPublic::Pair::from_phrase(mnemonic, password)
+		.map(|(pair, _)| pair.public())
+		.map_err(|e| format!("Invalid mnemonic: {:?}", e))
+}
+
+/// Helper function to generate controller and session key from a BIP-39
+/// mnemonic
+pub fn get_authority_keys_from_mnemonic(
+	mnemonic: &str,
+	password: Option<&str>,
+) -> Result<(AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId), String> {
+	Ok((
+		AccountPublic::from(get_from_mnemonic::<sr25519::Public>(mnemonic, password)?)
+			.into_account(),
+		get_from_mnemonic::<BabeId>(mnemonic, password)?,
+		get_from_mnemonic::<GrandpaId>(mnemonic, password)?,
+		get_from_mnemonic::<ImOnlineId>(mnemonic, password)?,
+		get_from_mnemonic::<AuthorityDiscoveryId>(mnemonic, password)?,
+	))
 }
 
 /// Helper function to generate  controller and session key from seed
@@ -115,6 +273,52 @@ pub fn get_authority_keys(
 	)
 }
 
+/// Balance endowed to every network member at genesis.
+const ENDOWMENT: Balance = 10_000 * WAY;
+/// Balance endowed to every initial authority account at genesis.
+const STASH: Balance = 1_000 * WAY;
+/// Balance credited to the sudo account at genesis.
+const CREDIT_ENDOWMENT: Balance = 100_000 * WAY;
+
+/// Balances endowed by the genesis builders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenesisBalanceConfig {
+	/// Balance of every network member.
+	pub endowment: Balance,
+	/// Balance of every initial authority account.
+	pub stash: Balance,
+	/// Balance of the sudo account.
+	pub credit_endowment: Balance,
+}
+
+impl Default for GenesisBalanceConfig {
+	fn default() -> Self {
+		Self { endowment: ENDOWMENT, stash: STASH, credit_endowment: CREDIT_ENDOWMENT }
+	}
+}
+
+impl GenesisBalanceConfig {
+	/// Genesis balances of the given accounts. Accounts present in more than
+	/// one role are endowed once with the sum of their role balances.
+	fn endowed_accounts(
+		&self,
+		network_members: &[AccountId],
+		authorities: &[AccountId],
+		root_key: &AccountId,
+	) -> Vec<(AccountId, Balance)> {
+		let mut balances = BTreeMap::<AccountId, Balance>::new();
+		let mut endow = |who: &AccountId, amount: Balance| {
+			let balance = balances.entry(who.clone()).or_default();
+			*balance = balance.saturating_add(amount);
+		};
+		network_members.iter().for_each(|who| endow(who, self.endowment));
+		authorities.iter().for_each(|who| endow(who, self.stash));
+		endow(root_key, self.credit_endowment);
+
+		balances.into_iter().filter(|(_, balance)| *balance > 0).collect()
+	}
+}
+
 fn member_accounts() -> Vec<AccountId> {
 	vec![
 		(get_account_id_from_seed::<sr25519::Public>("Alice")),
@@ -123,25 +327,111 @@ fn member_accounts() -> Vec<AccountId> {
 	]
 }
 
-/// Development config.
-fn cord_development_config_genesis() -> serde_json::Value {
-	cord_local_genesis(
+/// Parse a `--dev-alice-balance` value, given in WAY, into a balance.
+pub fn parse_dev_balance(value: &str) -> Result<Balance, String> {
+	let ways: Balance =
+		value.parse().map_err(|e| format!("`{}` is not a valid WAY amount: {}", value, e))?;
+	if ways < 1 {
+		return Err("the development balance must be at least 1 WAY".into())
+	}
+	ways.checked_mul(WAY).ok_or_else(|| format!("`{}` WAY does not fit in a balance", value))
+}
+
+/// Replace the genesis balance of each of `accounts` with `balance`.
+fn override_genesis_balances(
+	genesis: &mut serde_json::Value,
+	accounts: &[AccountId],
+	balance: Balance,
+) {
+	let accounts =
+		accounts.iter().map(|who| serde_json::json!(who)).collect::<Vec<serde_json::Value>>();
+	if let Some(entries) = genesis["balances"]["balances"].as_array_mut() {
+		entries
+			.iter_mut()
+			.filter(|entry| accounts.contains(&entry[0]))
+			.for_each(|entry| entry[1] = serde_json::json!(balance));
+	}
+}
+
+/// Check that the sudo key of a genesis config patch, if any, is endowed
+/// with a non-zero balance, so that it can pay transaction fees.
+fn validate_genesis_config(genesis: &serde_json::Value) -> Result<(), String> {
+	let sudo_key = &genesis["sudo"]["key"];
+	if sudo_key.is_null() {
+		return Ok(())
+	}
+
+	let endowed = genesis["balances"]["balances"].as_array().map_or(false, |balances| {
+		balances.iter().any(|entry| entry[0] == *sudo_key && entry[1] != serde_json::json!(0))
+	});
+	if endowed {
+		Ok(())
+	} else {
+		Err(format!("The sudo key {} has no genesis balance", sudo_key))
+	}
+}
+
+/// A minimal W3C DID document for the given development account.
+fn development_did_document(who: &AccountId) -> Vec<u8> {
+	let did = format!("did:cord:{}", who.to_ss58check_with_version(Ss58AddressFormat::custom(29)));
+	serde_json::json!({
+		"@context": ["https://www.w3.org/ns/did/v1"],
+		"id": did,
+		"controller": did,
+	})
+	.to_string()
+	.into_bytes()
+}
+
+/// The Credit Treasury account, derived from the `py/crdit` pallet id.
+const CREDIT_TREASURY: &str = "6d6f646c70792f63726469740000000000000000000000000000000000000000";
+
+/// Credits the Credit Treasury holds on development chains.
+const CREDIT_TREASURY_ISSUANCE: Balance = 1_000_000 * WAY;
+
+fn credit_treasury_account() -> AccountId {
+	let mut raw = [0u8; 32];
+	hex::decode_to_slice(CREDIT_TREASURY, &mut raw)
+		.expect("the credit treasury is a 32 byte hex string");
+	raw.into()
+}
+
+/// Development config. `alice_balance` overrides the initial balance of the
+/// Alice and Bob accounts.
+fn cord_development_config_genesis(alice_balance: Option<Balance>) -> serde_json::Value {
+	let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+	let mut genesis = cord_local_genesis(
 		vec![get_authority_keys_from_seed("Alice")],
-		vec![(
-			b"12D3KooWBmAwcd4PJNJvfV89HwE48nwkRmAgo8Vy3uQEyNNHBox2".to_vec(),
-			get_account_id_from_seed::<sr25519::Public>("Alice"),
-		)],
-		get_account_id_from_seed::<sr25519::Public>("Alice"),
-	)
+		vec![(b"12D3KooWBmAwcd4PJNJvfV89HwE48nwkRmAgo8Vy3uQEyNNHBox2".to_vec(), alice.clone())],
+		member_accounts(),
+		alice.clone(),
+		GenesisBalanceConfig::default(),
+		None,
+	);
+	genesis["didName"] = serde_json::json!({
+		"names": [(alice.clone(), b"alice@cord".to_vec())],
+	});
+	genesis["didDocument"] = serde_json::json!({
+		"documents": [(alice.clone(), alice.clone(), development_did_document(&alice))],
+	});
+	let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+	genesis["indices"] = serde_json::json!({
+		"indices": [(0, alice.clone()), (1, bob.clone())],
+	});
+	genesis["credit"] = serde_json::json!({
+		"balances": [(credit_treasury_account(), CREDIT_TREASURY_ISSUANCE)],
+	});
+	if let Some(balance) = alice_balance {
+		override_genesis_balances(&mut genesis, &[alice, bob], balance);
+	}
+	genesis
 }
 
-fn cord_local_config_genesis() -> serde_json::Value {
+/// Local testnet genesis with the authorities derived from `authority_seeds`,
+/// which also make up the council.
+fn cord_local_config_genesis(authority_seeds: &[&str]) -> serde_json::Value {
 	cord_local_genesis(
-		vec![
-			get_authority_keys_from_seed("Alice"),
-			get_authority_keys_from_seed("Bob"),
-			get_authority_keys_from_seed("Charlie"),
-		],
+		authority_seeds.iter().map(|seed| get_authority_keys_from_seed(seed)).collect(),
 		vec![
 			(
 				b"12D3KooWBmAwcd4PJNJvfV89HwE48nwkRmAgo8Vy3uQEyNNHBox2".to_vec(),
@@ -160,20 +450,26 @@ fn cord_local_config_genesis() -> serde_json::Value {
 				get_account_id_from_seed::<sr25519::Public>("Dave"),
 			),
 		],
+		member_accounts(),
 		get_account_id_from_seed::<sr25519::Public>("Alice"),
+		GenesisBalanceConfig::default(),
+		None,
 	)
 }
 
-pub fn cord_dev_config() -> Result<CordChainSpec, String> {
-	let properties = get_properties("WAY", 12, 29);
+pub fn cord_dev_config(alice_balance: Option<Balance>) -> Result<CordChainSpec, String> {
+	let genesis = cord_development_config_genesis(alice_balance);
+	validate_genesis_config(&genesis)?;
+
+	let properties = get_properties("WAY", 12, 29)?;
 	Ok(CordChainSpec::builder(
 		cord_runtime::WASM_BINARY.ok_or("Cord development wasm not available")?,
-		Default::default(),
+		Extensions { chain_id: Some(DEV_CHAIN_ID), ..Default::default() },
 	)
 	.with_name("Cord Ignite")
 	.with_id("dev")
 	.with_chain_type(ChainType::Development)
-	.with_genesis_config_patch(cord_development_config_genesis())
+	.with_genesis_config_patch(genesis)
 	.with_telemetry_endpoints(
 		TelemetryEndpoints::new(vec![(CORD_TELEMETRY_URL.to_string(), 0)])
 			.expect("Cord telemetry url is valid; qed"),
@@ -184,15 +480,169 @@ pub fn cord_dev_config() -> Result<CordChainSpec, String> {
 }
 
 pub fn cord_local_config() -> Result<CordChainSpec, String> {
-	let properties = get_properties("WAY", 12, 29);
+	local_config("Cord Spin", "local", &["Alice", "Bob", "Charlie"])
+}
+
+/// Local testnet with Alice, Bob, Charlie and Dave as authorities. GRANDPA
+/// needs more than two thirds of the authorities, so 3 of the 4 finalize and
+/// one authority can be stopped without stalling finality.
+pub fn cord_local_testnet_3_config() -> Result<CordChainSpec, String> {
+	local_config("Cord Spin 3", "local3", &["Alice", "Bob", "Charlie", "Dave"])
+}
+
+fn local_config(name: &str, id: &str, authority_seeds: &[&str]) -> Result<CordChainSpec, String> {
+	let genesis = cord_local_config_genesis(authority_seeds);
+	validate_genesis_config(&genesis)?;
+
+	let properties = get_properties("WAY", 12, 29)?;
 	Ok(CordChainSpec::builder(
 		cord_runtime::WASM_BINARY.ok_or("Cord development wasm not available")?,
 		Default::default(),
 	)
-	.with_name("Cord Spin")
-	.with_id("local")
+	.with_name(name)
+	.with_id(id)
 	.with_chain_type(ChainType::Local)
-	.with_genesis_config_patch(cord_local_config_genesis())
+	.with_genesis_config_patch(genesis)
+	.with_telemetry_endpoints(
+		TelemetryEndpoints::new(vec![(CORD_TELEMETRY_URL.to_string(), 0)])
+			.expect("Cord telemetry url is valid; qed"),
+	)
+	.with_protocol_id(DEFAULT_PROTOCOL_ID)
+	.with_properties(properties)
+	.build())
+}
+
+/// An authority entry of a development genesis file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DevGenesisAuthority {
+	/// Hex encoded sr25519 public key, used as the account, babe, im-online
+	/// and authority-discovery key.
+	pub account: String,
+	/// Hex encoded ed25519 public key used for grandpa.
+	pub grandpa: String,
+}
+
+/// A well known node entry of a development genesis file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DevGenesisNode {
+	/// The libp2p peer id of the node.
+	pub node_id: String,
+	/// Hex encoded account owning the node.
+	pub owner: String,
+}
+
+/// Genesis parameters of a development chain, loaded from a JSON file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DevGenesisParams {
+	/// Initial set of authorities.
+	pub authorities: Vec<DevGenesisAuthority>,
+	/// Initial set of well known nodes.
+	pub well_known_nodes: Vec<DevGenesisNode>,
+	/// Hex encoded sudo account.
+	pub sudo_key: String,
+	/// Hex encoded network members. Defaults to the authorities and the sudo
+	/// account when absent.
+	#[serde(default)]
+	pub network_members: Option<Vec<String>>,
+	/// BABE slot probability and allowed slots. Defaults to
+	/// `cord_runtime::BABE_GENESIS_EPOCH_CONFIG` when absent.
+	#[serde(default)]
+	pub babe_epoch_config: Option<BabeEpochConfiguration>,
+}
+
+fn parse_public_key(value: &str, field: &str) -> Result<[u8; 32], String> {
+	array_bytes::hex2array(value)
+		.map_err(|e| format!("`{}` is not a valid hex encoded public key: {:?}", field, e))
+}
+
+/// Development config genesis built from externally supplied parameters.
+fn cord_development_config_genesis_from_params(
+	params: DevGenesisParams,
+) -> Result<serde_json::Value, String> {
+	if params.authorities.is_empty() || params.well_known_nodes.is_empty() {
+		return Err("`authorities` and `wellKnownNodes` cannot be empty".into());
+	}
+
+	let initial_authorities = params
+		.authorities
+		.iter()
+		.map(|authority| {
+			let sr25519_key = parse_public_key(&authority.account, "authorities.account")?;
+			let ed25519_key = parse_public_key(&authority.grandpa, "authorities.grandpa")?;
+			Ok((
+				AccountId::from(sr25519_key),
+				sr25519_key.unchecked_into(),
+				ed25519_key.unchecked_into(),
+				sr25519_key.unchecked_into(),
+				sr25519_key.unchecked_into(),
+			))
+		})
+		.collect::<Result<Vec<_>, String>>()?;
+
+	let initial_well_known_nodes = params
+		.well_known_nodes
+		.iter()
+		.map(|node| {
+			let owner = parse_public_key(&node.owner, "wellKnownNodes.owner")?;
+			Ok((node.node_id.as_bytes().to_vec(), AccountId::from(owner)))
+		})
+		.collect::<Result<Vec<_>, String>>()?;
+
+	let root_key = AccountId::from(parse_public_key(&params.sudo_key, "sudoKey")?);
+
+	if let Some(BabeEpochConfiguration { c: (numerator, denominator), .. }) =
+		params.babe_epoch_config
+	{
+		if denominator == 0 || numerator > denominator {
+			return Err("`babeEpochConfig.c` must be `[n, d]` with `n <= d` and `d > 0`".into())
+		}
+	}
+
+	let network_members = match params.network_members {
+		Some(members) => members
+			.iter()
+			.map(|member| parse_public_key(member, "networkMembers").map(AccountId::from))
+			.collect::<Result<Vec<_>, String>>()?,
+		None => initial_authorities
+			.iter()
+			.map(|x| x.0.clone())
+			.chain(sp_std::iter::once(root_key.clone()))
+			.collect(),
+	};
+
+	Ok(cord_local_genesis(
+		initial_authorities,
+		initial_well_known_nodes,
+		network_members,
+		root_key,
+		GenesisBalanceConfig::default(),
+		params.babe_epoch_config,
+	))
+}
+
+/// Development config with the genesis parameters read from a JSON file.
+pub fn cord_development_config_from_file(path: &Path) -> Result<CordChainSpec, String> {
+	let file = std::fs::File::open(path)
+		.map_err(|e| format!("Error opening genesis file `{}`: {}", path.display(), e))?;
+	let params: DevGenesisParams = serde_json::from_reader(std::io::BufReader::new(file))
+		.map_err(|e| format!("Error parsing genesis file `{}`: {}", path.display(), e))?;
+	let genesis = cord_development_config_genesis_from_params(params)
+		.map_err(|e| format!("Invalid genesis file `{}`: {}", path.display(), e))?;
+	validate_genesis_config(&genesis)
+		.map_err(|e| format!("Invalid genesis file `{}`: {}", path.display(), e))?;
+
+	let properties = get_properties("WAY", 12, 29)?;
+	Ok(CordChainSpec::builder(
+		cord_runtime::WASM_BINARY.ok_or("Cord development wasm not available")?,
+		Default::default(),
+	)
+	.with_name("Cord Ignite")
+	.with_id("dev")
+	.with_chain_type(ChainType::Development)
+	.with_genesis_config_patch(genesis)
 	.with_telemetry_endpoints(
 		TelemetryEndpoints::new(vec![(CORD_TELEMETRY_URL.to_string(), 0)])
 			.expect("Cord telemetry url is valid; qed"),
@@ -202,12 +652,612 @@ pub fn cord_local_config() -> Result<CordChainSpec, String> {
 	.build())
 }
 
-// pub fn cord_config() -> Result<CordChainSpec, String> {
-// CordChainSpec::from_json_bytes(&include_bytes!("../chain-specs/cord.json")[..
-// ]) }
+/// Storage key of the runtime code (`:code`) in a raw chain spec.
+const CODE_STORAGE_KEY: &str = "0x3a636f6465";
+
+/// Ensure the runtime code in the genesis of a raw chain spec is the wasm
+/// binary this node was built with.
+fn ensure_genesis_wasm_matches_runtime(spec: &serde_json::Value) -> Result<(), String> {
+	let wasm = cord_runtime::WASM_BINARY.ok_or("Cord wasm not available")?;
+	let code = spec["genesis"]["raw"]["top"][CODE_STORAGE_KEY]
+		.as_str()
+		.ok_or("Chain spec genesis is not raw or does not contain the runtime code")?;
+	let code = array_bytes::hex2bytes(code)
+		.map_err(|e| format!("Invalid runtime code in chain spec genesis: {:?}", e))?;
+
+	let expected = sp_core::hashing::blake2_256(wasm);
+	let found = sp_core::hashing::blake2_256(&code);
+	if expected != found {
+		return Err(format!(
+			"Genesis runtime code hash {} does not match the node wasm binary hash {}",
+			array_bytes::bytes2hex("0x", found),
+			array_bytes::bytes2hex("0x", expected),
+		));
+	}
+	Ok(())
+}
+
+/// Mainnet config from the JSON of a raw chain spec. The chain type and
+/// telemetry endpoints are forced to their production values, and the spec is
+/// rejected if its genesis runtime code differs from the node wasm binary.
+pub fn cord_mainnet_config_from_json_bytes(bytes: &[u8]) -> Result<CordChainSpec, String> {
+	let mut spec: serde_json::Value = serde_json::from_slice(bytes)
+		.map_err(|e| format!("Error parsing mainnet chain spec: {}", e))?;
+
+	ensure_genesis_wasm_matches_runtime(&spec)?;
+
+	spec["chainType"] = serde_json::json!(ChainType::Live);
+	spec["telemetryEndpoints"] = serde_json::json!([[CORD_TELEMETRY_URL, 0]]);
+
+	let bytes = serde_json::to_vec(&spec).map_err(|e| e.to_string())?;
+	CordChainSpec::from_json_bytes(bytes)
+}
+
+/// Replace the runtime code in the genesis of `spec` with `new_wasm`.
+///
+/// The spec is converted to its raw form, the genesis state root is
+/// recomputed over the patched storage and the resulting genesis hash is
+/// recorded in the `genesisHash` extension. Live chain specs are only patched
+/// when `force` is set, as the new genesis no longer matches the running
+/// network.
+pub fn patch_genesis_wasm(
+	spec: &mut CordChainSpec,
+	new_wasm: &[u8],
+	force: bool,
+) -> Result<(), String> {
+	if sc_service::ChainSpec::chain_type(spec) == ChainType::Live && !force {
+		return Err("Refusing to patch the genesis wasm of a live chain spec without force".into())
+	}
+
+	let json = sc_service::ChainSpec::as_json(spec, true)?;
+	let mut value: serde_json::Value =
+		serde_json::from_str(&json).map_err(|e| format!("Error parsing raw chain spec: {}", e))?;
+	value["genesis"]["raw"]["top"][CODE_STORAGE_KEY] =
+		serde_json::Value::String(array_bytes::bytes2hex("0x", new_wasm));
+
+	let bytes = serde_json::to_vec(&value).map_err(|e| e.to_string())?;
+	let patched = CordChainSpec::from_json_bytes(bytes)?;
+	value["genesisHash"] = serde_json::json!(genesis_hash(&patched)?);
+
+	let bytes = serde_json::to_vec(&value).map_err(|e| e.to_string())?;
+	*spec = CordChainSpec::from_json_bytes(bytes)?;
+	Ok(())
+}
+
+/// The genesis block hash of `spec`, computed from its genesis storage.
+fn genesis_hash(spec: &CordChainSpec) -> Result<Hash, String> {
+	use sp_runtime::{traits::Block as BlockT, BuildStorage};
+
+	let state_version = cord_runtime::VERSION.state_version();
+	let state_root = snapshot::storage_root(&spec.build_storage()?, state_version);
+	Ok(sc_chain_spec::construct_genesis_block::<Block>(state_root, state_version).hash())
+}
+
+/// Top-level chain spec fields compared by `diff`, besides the properties.
+const COMPARED_SPEC_FIELDS: [&str; 4] = ["name", "id", "chainType", "protocolId"];
+
+/// A genesis balance differing between two chain specs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceChange {
+	/// SS58 address of the endowed account.
+	pub account: String,
+	/// Balance in the first spec, `None` if the account is not endowed there.
+	pub before: Option<Balance>,
+	/// Balance in the second spec, `None` if the account is not endowed there.
+	pub after: Option<Balance>,
+}
+
+/// A chain spec field or property differing between two chain specs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyChange {
+	/// Name of the spec field, with properties prefixed by `properties.`.
+	pub key: String,
+	/// Value in the first spec, if set.
+	pub before: Option<serde_json::Value>,
+	/// Value in the second spec, if set.
+	pub after: Option<serde_json::Value>,
+}
+
+/// Differences between the genesis and properties of two chain specs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChainSpecDiff {
+	/// Initial authorities of the second spec missing from the first.
+	pub added_authorities: Vec<String>,
+	/// Initial authorities of the first spec missing from the second.
+	pub removed_authorities: Vec<String>,
+	/// Genesis balances differing between the specs.
+	pub balance_changes: Vec<BalanceChange>,
+	/// Spec fields and properties differing between the specs.
+	pub property_changes: Vec<PropertyChange>,
+}
+
+impl ChainSpecDiff {
+	/// Whether both specs match on every compared field.
+	pub fn is_empty(&self) -> bool {
+		self.added_authorities.is_empty() &&
+			self.removed_authorities.is_empty() &&
+			self.balance_changes.is_empty() &&
+			self.property_changes.is_empty()
+	}
+}
+
+impl std::fmt::Display for ChainSpecDiff {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		fn or_none<T: ToString>(value: &Option<T>) -> String {
+			value.as_ref().map_or_else(|| "none".into(), ToString::to_string)
+		}
+
+		for who in &self.added_authorities {
+			writeln!(f, "+ authority {}", who)?;
+		}
+		for who in &self.removed_authorities {
+			writeln!(f, "- authority {}", who)?;
+		}
+		for change in &self.balance_changes {
+			writeln!(
+				f,
+				"~ balance {}: {} -> {}",
+				change.account,
+				or_none(&change.before),
+				or_none(&change.after)
+			)?;
+		}
+		for change in &self.property_changes {
+			writeln!(
+				f,
+				"~ {}: {} -> {}",
+				change.key,
+				or_none(&change.before),
+				or_none(&change.after)
+			)?;
+		}
+		Ok(())
+	}
+}
+
+/// The plain JSON of `spec`.
+fn plain_spec_json(spec: &CordChainSpec) -> Result<serde_json::Value, String> {
+	let json = sc_service::ChainSpec::as_json(spec, false)?;
+	serde_json::from_str(&json).map_err(|e| format!("Error parsing chain spec: {}", e))
+}
+
+/// The genesis config, or genesis config patch, of a plain chain spec.
+fn plain_genesis_config(spec: &serde_json::Value) -> Result<&serde_json::Value, String> {
+	let genesis = &spec["genesis"];
+	let runtime_genesis = &genesis["runtimeGenesis"];
+	[&runtime_genesis["patch"], &runtime_genesis["config"], &genesis["runtime"]]
+		.into_iter()
+		.find(|config| config.is_object())
+		.ok_or_else(|| "Raw chain specs can not be compared, compare the plain chain specs".into())
+}
+
+fn genesis_authorities(genesis: &serde_json::Value) -> Vec<String> {
+	genesis["authorityMembership"]["initialAuthorities"]
+		.as_array()
+		.into_iter()
+		.flatten()
+		.filter_map(|authority| authority.as_str().map(Into::into))
+		.collect()
+}
+
+fn genesis_balances(genesis: &serde_json::Value) -> Result<BTreeMap<String, Balance>, String> {
+	genesis["balances"]["balances"]
+		.as_array()
+		.into_iter()
+		.flatten()
+		.map(|entry| {
+			let account = entry[0].as_str().ok_or("Genesis balance without an account")?;
+			let balance = serde_json::from_value::<Balance>(entry[1].clone())
+				.map_err(|e| format!("Invalid genesis balance of {}: {}", account, e))?;
+			Ok((account.to_string(), balance))
+		})
+		.collect()
+}
+
+fn spec_properties(spec: &serde_json::Value) -> BTreeMap<String, serde_json::Value> {
+	let fields = COMPARED_SPEC_FIELDS
+		.iter()
+		.filter(|field| !spec[**field].is_null())
+		.map(|field| (field.to_string(), spec[*field].clone()));
+	let properties = spec["properties"]
+		.as_object()
+		.into_iter()
+		.flatten()
+		.map(|(key, value)| (format!("properties.{}", key), value.clone()));
+	fields.chain(properties).collect()
+}
+
+/// Keys whose value differs between `a` and `b`, with both values.
+fn changed_entries<V: Clone + PartialEq>(
+	a: &BTreeMap<String, V>,
+	b: &BTreeMap<String, V>,
+) -> Vec<(String, Option<V>, Option<V>)> {
+	a.keys()
+		.chain(b.keys())
+		.collect::<std::collections::BTreeSet<_>>()
+		.into_iter()
+		.filter(|key| a.get(*key) != b.get(*key))
+		.map(|key| (key.clone(), a.get(key).cloned(), b.get(key).cloned()))
+		.collect()
+}
+
+/// Compare two plain chain specs field by field: the initial authorities and
+/// the balances of their genesis, and their name, id, chain type, protocol id
+/// and properties. Raw chain specs are rejected.
+pub fn diff(a: &CordChainSpec, b: &CordChainSpec) -> Result<ChainSpecDiff, String> {
+	let (spec_a, spec_b) = (plain_spec_json(a)?, plain_spec_json(b)?);
+	let (genesis_a, genesis_b) = (plain_genesis_config(&spec_a)?, plain_genesis_config(&spec_b)?);
+
+	let (authorities_a, authorities_b) =
+		(genesis_authorities(genesis_a), genesis_authorities(genesis_b));
+	let added_authorities =
+		authorities_b.iter().filter(|who| !authorities_a.contains(who)).cloned().collect();
+	let removed_authorities =
+		authorities_a.iter().filter(|who| !authorities_b.contains(who)).cloned().collect();
+
+	let balance_changes =
+		changed_entries(&genesis_balances(genesis_a)?, &genesis_balances(genesis_b)?)
+			.into_iter()
+			.map(|(account, before, after)| BalanceChange { account, before, after })
+			.collect();
+	let property_changes = changed_entries(&spec_properties(&spec_a), &spec_properties(&spec_b))
+		.into_iter()
+		.map(|(key, before, after)| PropertyChange { key, before, after })
+		.collect();
+
+	Ok(ChainSpecDiff { added_authorities, removed_authorities, balance_changes, property_changes })
+}
+
+/// The keys of a genesis authority.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorityKeySet {
+	pub stash: AccountId,
+	pub controller: AccountId,
+	pub babe: BabeId,
+	pub grandpa: GrandpaId,
+	pub im_online: ImOnlineId,
+	pub authority_discovery: AuthorityDiscoveryId,
+}
+
+/// Export of the authority keys of a chain spec, for migrating validators
+/// between environments.
+pub trait ExportAuthorityKeys {
+	/// The keys of every genesis authority, in session genesis order. Raw
+	/// chain specs are rejected.
+	fn export_authority_keys(&self) -> Result<Vec<AuthorityKeySet>, String>;
+}
+
+impl ExportAuthorityKeys for CordChainSpec {
+	fn export_authority_keys(&self) -> Result<Vec<AuthorityKeySet>, String> {
+		let spec = plain_spec_json(self)?;
+		let genesis = plain_genesis_config(&spec).map_err(|_| {
+			"Raw chain specs are not supported, export the keys of the plain chain spec".to_string()
+		})?;
+
+		genesis["session"]["keys"]
+			.as_array()
+			.ok_or("Chain spec genesis does not contain any session keys")?
+			.iter()
+			.enumerate()
+			.map(|(index, entry)| {
+				let invalid =
+					|e: serde_json::Error| format!("Invalid keys of authority #{}: {}", index, e);
+				let stash = serde_json::from_value(entry[0].clone()).map_err(invalid)?;
+				let controller = serde_json::from_value(entry[1].clone()).map_err(invalid)?;
+				let SessionKeys { babe, grandpa, im_online, authority_discovery } =
+					serde_json::from_value(entry[2].clone()).map_err(invalid)?;
+				Ok(AuthorityKeySet {
+					stash,
+					controller,
+					babe,
+					grandpa,
+					im_online,
+					authority_discovery,
+				})
+			})
+			.collect()
+	}
+}
+
+/// Parse comma-separated `url=level` telemetry endpoint pairs.
+fn parse_telemetry_endpoints(value: &str) -> Result<Vec<(String, u8)>, String> {
+	value
+		.split(',')
+		.map(str::trim)
+		.filter(|endpoint| !endpoint.is_empty())
+		.map(|endpoint| {
+			let (url, level) = endpoint.rsplit_once('=').ok_or_else(|| {
+				format!("Telemetry endpoint `{}` is not of the form `url=level`", endpoint)
+			})?;
+			let level = level
+				.parse::<u8>()
+				.map_err(|e| format!("Invalid telemetry level in `{}`: {}", endpoint, e))?;
+			Ok((url.to_string(), level))
+		})
+		.collect()
+}
+
+/// Telemetry endpoints read from `CORD_TELEMETRY_ENDPOINTS`, or `default` at
+/// level 0 when the variable is absent.
+fn telemetry_endpoints_from_env(default: &str) -> Result<TelemetryEndpoints, String> {
+	telemetry_endpoints(std::env::var(TELEMETRY_ENDPOINTS_ENV).ok().as_deref(), default)
+}
+
+/// Telemetry endpoints parsed from `value`, or `default` at level 0 when no
+/// value is given.
+fn telemetry_endpoints(value: Option<&str>, default: &str) -> Result<TelemetryEndpoints, String> {
+	let endpoints = match value {
+		Some(value) => parse_telemetry_endpoints(value)?,
+		None => vec![(default.to_string(), 0)],
+	};
+	TelemetryEndpoints::new(endpoints)
+		.map_err(|e| format!("Invalid {}: {}", TELEMETRY_ENDPOINTS_ENV, e))
+}
+
+/// Parse newline-separated hex encoded authority discovery keys.
+fn parse_authority_discovery_keys(value: &str) -> Result<Vec<AuthorityDiscoveryId>, String> {
+	value
+		.lines()
+		.map(str::trim)
+		.filter(|key| !key.is_empty())
+		.map(|key| parse_public_key(key, AUTHORITY_DISCOVERY_KEYS_ENV).map(|k| k.unchecked_into()))
+		.collect()
+}
+
+/// Authority discovery keys read from `CORD_AUTHORITY_DISCOVERY_KEYS`, or no
+/// keys when the variable is absent.
+pub fn authority_discovery_keys_from_env() -> Result<Vec<AuthorityDiscoveryId>, String> {
+	match std::env::var(AUTHORITY_DISCOVERY_KEYS_ENV) {
+		Ok(value) => parse_authority_discovery_keys(&value),
+		Err(_) => Ok(Vec::new()),
+	}
+}
+
+/// Parse newline-separated bootnode multiaddrs.
+fn parse_bootnodes(value: &str) -> Result<Vec<MultiaddrWithPeerId>, String> {
+	value
+		.lines()
+		.map(str::trim)
+		.filter(|bootnode| !bootnode.is_empty())
+		.map(|bootnode| {
+			bootnode
+				.parse()
+				.map_err(|e| format!("Invalid {} entry `{}`: {}", BOOTNODES_ENV, bootnode, e))
+		})
+		.collect()
+}
+
+/// Bootnodes read from `CORD_BOOTNODES`, or no bootnodes when the variable is
+/// absent.
+pub fn bootnodes_from_env() -> Result<Vec<MultiaddrWithPeerId>, String> {
+	match std::env::var(BOOTNODES_ENV) {
+		Ok(value) => parse_bootnodes(&value),
+		Err(_) => Ok(Vec::new()),
+	}
+}
+
+/// A bootnode listed in the `bootnodes_weighted` chain spec property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedBootnode {
+	/// Address of the bootnode.
+	pub addr: sc_network::Multiaddr,
+	/// Priority of the bootnode, higher priorities are dialled first.
+	pub priority: u8,
+}
+
+/// Parse a `bootnodes_weighted` property value into bootnodes, highest
+/// priority first. Bootnodes of equal priority keep their order.
+fn parse_weighted_bootnodes(value: &serde_json::Value) -> Result<Vec<WeightedBootnode>, String> {
+	#[derive(Deserialize)]
+	#[serde(deny_unknown_fields)]
+	struct Entry {
+		addr: String,
+		priority: u8,
+	}
+
+	let entries: Vec<Entry> = serde_json::from_value(value.clone())
+		.map_err(|e| format!("Invalid `{}` property: {}", WEIGHTED_BOOTNODES_PROPERTY, e))?;
+	let mut bootnodes = entries
+		.into_iter()
+		.map(|Entry { addr, priority }| {
+			let addr = addr.parse().map_err(|e| {
+				format!("Invalid `{}` address `{}`: {}", WEIGHTED_BOOTNODES_PROPERTY, addr, e)
+			})?;
+			Ok(WeightedBootnode { addr, priority })
+		})
+		.collect::<Result<Vec<_>, String>>()?;
+	bootnodes.sort_by_key(|bootnode| sp_std::cmp::Reverse(bootnode.priority));
+
+	Ok(bootnodes)
+}
+
+/// The addresses of the `bootnodes_weighted` property of `spec`, highest
+/// priority first, or no addresses when the property is absent.
+pub fn weighted_bootnodes_from_spec(
+	spec: &dyn sc_service::ChainSpec,
+) -> Result<Vec<sc_network::Multiaddr>, String> {
+	match spec.properties().get(WEIGHTED_BOOTNODES_PROPERTY) {
+		Some(value) => Ok(parse_weighted_bootnodes(value)?
+			.into_iter()
+			.map(|bootnode| bootnode.addr)
+			.collect()),
+		None => Ok(Vec::new()),
+	}
+}
+
+/// Staging authority keys: the sr25519 account key, also used as the babe,
+/// im-online and authority discovery key, and the ed25519 grandpa key.
+const STAGING_AUTHORITIES: [(&str, &str); 3] = [
+	(
+		"0x7a2f95ca0d3dceab4d56546f77e2f07bd7f17bc335761bfdcaae4a11c27f5756",
+		"0xd810f5c241626a2ad37fd31a113e223a6a1db066198eecdaa25bc1d69b8ba194",
+	),
+	(
+		"0x5ca299d8e3bc988309e1728ab1940031004c756c68b5b5e69159f66397a1c653",
+		"0xdb873322b792495fdd928aa115918b51e2d2b13bf512bdeb4f28968188ed8b82",
+	),
+	(
+		"0x6e90cfc4e139cdd69b87c4b54a7c057aec0cc1892db467048308154cea04dc7d",
+		"0x667c1435b23d754b0ec7e5c29a36235ab91f0893844ab7b0ab2db2ae2d1684c0",
+	),
+];
+const STAGING_SUDO_KEY: &str = "0x4cdb17993bb530cbf642c9d4c954c31dbf5a15681e28758b66ebeaea78b88a15";
+
+/// A staging authority, as `(stash, controller, babe, grandpa, im_online,
+/// authority_discovery)`.
+pub type StagingAuthority =
+	(AccountId, AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId);
+
+/// The authorities of the staging network.
+pub fn staging_initial_authorities() -> Vec<StagingAuthority> {
+	STAGING_AUTHORITIES
+		.iter()
+		.map(|(account, grandpa)| {
+			let account = array_bytes::hex2array_unchecked::<_, 32>(account);
+			(
+				account.into(),
+				account.into(),
+				account.unchecked_into(),
+				array_bytes::hex2array_unchecked::<_, 32>(grandpa).unchecked_into(),
+				account.unchecked_into(),
+				account.unchecked_into(),
+			)
+		})
+		.collect()
+}
+
+/// Council members read from a governance snapshot, a JSON array of SS58
+/// encoded accounts.
+pub fn council_members_from_file(path: &Path) -> Result<Vec<AccountId>, String> {
+	let file = std::fs::File::open(path)
+		.map_err(|e| format!("Error opening council snapshot `{}`: {}", path.display(), e))?;
+	serde_json::from_reader(std::io::BufReader::new(file))
+		.map_err(|e| format!("Error parsing council snapshot `{}`: {}", path.display(), e))
+}
+
+/// Council members of the snapshot at `path`, or `None` when no path is
+/// given or it names no file.
+fn staging_council_members(path: Option<&Path>) -> Result<Option<Vec<AccountId>>, String> {
+	match path {
+		Some(path) if path.exists() => council_members_from_file(path).map(Some),
+		_ => Ok(None),
+	}
+}
+
+/// Staging genesis for the given authorities. The endowed accounts are
+/// funded and admitted as network members. The council is seeded from
+/// `council_members` when given, and from the authorities otherwise.
+///
+/// Non-empty `authority_discovery_keys` replace the authority discovery keys
+/// of the authorities, one key per authority. The authority discovery pallet
+/// takes its genesis keys from the session keys, so they are not repeated in
+/// the `authorityDiscovery` section.
+fn cord_staging_config_genesis(
+	mut initial_authorities: Vec<StagingAuthority>,
+	endowed_accounts: Vec<AccountId>,
+	council_members: Option<Vec<AccountId>>,
+	authority_discovery_keys: Vec<AuthorityDiscoveryId>,
+) -> Result<serde_json::Value, String> {
+	if !authority_discovery_keys.is_empty() {
+		if authority_discovery_keys.len() != initial_authorities.len() {
+			return Err(format!(
+				"{} lists {} keys for {} staging authorities",
+				AUTHORITY_DISCOVERY_KEYS_ENV,
+				authority_discovery_keys.len(),
+				initial_authorities.len(),
+			))
+		}
+		for (authority, key) in initial_authorities.iter_mut().zip(authority_discovery_keys) {
+			authority.5 = key;
+		}
+	}
+
+	let mut genesis = cord_local_genesis(
+		initial_authorities
+			.into_iter()
+			.map(|(_stash, controller, babe, grandpa, im_online, authority_discovery)| {
+				(controller, babe, grandpa, im_online, authority_discovery)
+			})
+			.collect(),
+		vec![],
+		endowed_accounts,
+		array_bytes::hex2array_unchecked::<_, 32>(STAGING_SUDO_KEY).into(),
+		GenesisBalanceConfig::default(),
+		None,
+	);
+	if let Some(members) = council_members {
+		genesis["councilMembership"]["members"] = serde_json::json!(members);
+	}
+	Ok(genesis)
+}
+
+/// Staging testnet config built from `staging_initial_authorities`, for
+/// standing up fresh staging environments. The live staging network keeps
+/// using the bundled `sprint.json`.
+pub fn cord_staging_testnet_config() -> Result<CordChainSpec, String> {
+	staging_testnet_config(
+		telemetry_endpoints_from_env(CORD_TELEMETRY_URL)?,
+		bootnodes_from_env()?,
+		staging_council_members(
+			std::env::var_os(COUNCIL_MEMBERS_FILE_ENV).as_ref().map(Path::new),
+		)?,
+		authority_discovery_keys_from_env()?,
+	)
+}
+
+/// Staging testnet config with the given deployment settings, see
+/// `cord_staging_testnet_config`.
+fn staging_testnet_config(
+	telemetry_endpoints: TelemetryEndpoints,
+	bootnodes: Vec<MultiaddrWithPeerId>,
+	council_members: Option<Vec<AccountId>>,
+	authority_discovery_keys: Vec<AuthorityDiscoveryId>,
+) -> Result<CordChainSpec, String> {
+	let properties = get_properties("WAY", 12, 29)?;
+	let endowed_accounts =
+		staging_initial_authorities().into_iter().map(|authority| authority.0).collect();
+	let genesis = cord_staging_config_genesis(
+		staging_initial_authorities(),
+		endowed_accounts,
+		council_members,
+		authority_discovery_keys,
+	)?;
+	validate_genesis_config(&genesis)?;
+	Ok(CordChainSpec::builder(
+		cord_runtime::WASM_BINARY.ok_or("Cord development wasm not available")?,
+		Default::default(),
+	)
+	.with_name("Cord Sprint Testnet")
+	.with_id("staging_testnet")
+	.with_chain_type(ChainType::Live)
+	.with_genesis_config_patch(genesis)
+	.with_boot_nodes(bootnodes)
+	.with_telemetry_endpoints(telemetry_endpoints)
+	.with_protocol_id(DEFAULT_PROTOCOL_ID)
+	.with_properties(properties)
+	.build())
+}
 
 pub fn cord_staging_config() -> Result<CordChainSpec, String> {
-	CordChainSpec::from_json_bytes(&include_bytes!("../res/sprint.json")[..])
+	staging_config(telemetry_endpoints_from_env(CORD_TELEMETRY_URL)?, bootnodes_from_env()?)
+}
+
+/// The bundled staging spec with the given telemetry endpoints, and the
+/// given bootnodes when there are any.
+fn staging_config(
+	telemetry_endpoints: TelemetryEndpoints,
+	bootnodes: Vec<MultiaddrWithPeerId>,
+) -> Result<CordChainSpec, String> {
+	let mut spec: serde_json::Value =
+		serde_json::from_slice(&include_bytes!("../res/sprint.json")[..])
+			.map_err(|e| format!("Error parsing staging chain spec: {}", e))?;
+	spec["telemetryEndpoints"] = serde_json::json!(telemetry_endpoints);
+	if !bootnodes.is_empty() {
+		spec["bootNodes"] = serde_json::json!(bootnodes);
+	}
+
+	let bytes = serde_json::to_vec(&spec).map_err(|e| e.to_string())?;
+	CordChainSpec::from_json_bytes(bytes)
 }
 
 pub fn cord_builder_config() -> Result<CordChainSpec, String> {
@@ -217,14 +1267,35 @@ pub fn cord_builder_config() -> Result<CordChainSpec, String> {
 fn cord_local_genesis(
 	initial_authorities: Vec<(AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId)>,
 	initial_well_known_nodes: Vec<(NodeId, AccountId)>,
+	network_members: Vec<AccountId>,
 	root_key: AccountId,
+	balances: GenesisBalanceConfig,
+	babe_epoch_config: Option<BabeEpochConfiguration>,
 ) -> serde_json::Value {
+	let endowed_accounts = balances.endowed_accounts(
+		&network_members,
+		&initial_authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
+		&root_key,
+	);
+
 	serde_json::json!( {
+		"balances":  {
+			"balances": endowed_accounts,
+		},
+		"treasuryReward":  {
+			// no inflation until governance sets a rate
+			"inflationRate": 0,
+		},
 		"nodeAuthorization":  {
 			"nodes": initial_well_known_nodes.iter().map(|x| (x.0.clone(), x.1.clone())).collect::<Vec<_>>(),
 		},
 		"networkMembership":  {
-			"members": member_accounts().into_iter().map(|member| (member, false)).collect::<BTreeMap<_, _>>(),
+			// authorities need a membership to submit their own transactions
+			"members": network_members
+				.into_iter()
+				.chain(initial_authorities.iter().map(|x| x.0.clone()))
+				.map(|member| (member, false))
+				.collect::<BTreeMap<_, _>>(),
 		},
 		"authorityMembership":  {
 			"initialAuthorities": initial_authorities
@@ -250,7 +1321,7 @@ fn cord_local_genesis(
 				.collect::<Vec<_>>(),
 		},
 		"babe":  {
-			"epochConfig": Some(cord_runtime::BABE_GENESIS_EPOCH_CONFIG),
+			"epochConfig": Some(babe_epoch_config.unwrap_or(cord_runtime::BABE_GENESIS_EPOCH_CONFIG)),
 		},
 		"councilMembership":  {
 			"members": initial_authorities
@@ -267,3 +1338,649 @@ fn cord_local_genesis(
 		"sudo": { "key": Some(root_key) },
 	})
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{crypto::DEV_PHRASE, ByteArray};
+
+	/// Mini secret key derived from `DEV_PHRASE`.
+	const DEV_SEED: &str = "fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e";
+	/// sr25519 public key derived from `DEV_PHRASE`.
+	const DEV_SR25519_PUBLIC: &str =
+		"46ebddef8cd9bb167dc30878d7113b7e168e6f0646beffd77d69d39bad76b47a";
+
+	fn raw_spec_with_code(code: &[u8]) -> serde_json::Value {
+		serde_json::json!({
+			"name": "Cord",
+			"id": "cord",
+			"chainType": "Development",
+			"bootNodes": [],
+			"telemetryEndpoints": null,
+			"protocolId": "cord",
+			"properties": null,
+			"codeSubstitutes": {},
+			"genesis": {
+				"raw": {
+					"top": { CODE_STORAGE_KEY: array_bytes::bytes2hex("0x", code) },
+					"childrenDefault": {},
+				}
+			},
+		})
+	}
+
+	#[test]
+	fn mainnet_config_accepts_matching_wasm() {
+		let wasm = cord_runtime::WASM_BINARY.expect("Cord wasm is built for tests; qed");
+		let json = serde_json::to_vec(&raw_spec_with_code(wasm)).unwrap();
+
+		let spec = cord_mainnet_config_from_json_bytes(&json).unwrap();
+		assert_eq!(sc_service::ChainSpec::chain_type(&spec), ChainType::Live);
+		assert!(sc_service::ChainSpec::telemetry_endpoints(&spec).is_some());
+	}
+
+	#[test]
+	fn mainnet_config_rejects_mismatching_wasm() {
+		let json = serde_json::to_vec(&raw_spec_with_code(b"not the cord runtime")).unwrap();
+
+		let err = cord_mainnet_config_from_json_bytes(&json).unwrap_err();
+		assert!(err.contains("does not match"));
+	}
+
+	#[test]
+	fn patch_genesis_wasm_replaces_the_code_and_records_the_genesis_hash() {
+		let wasm = cord_runtime::WASM_BINARY.expect("Cord wasm is built for tests; qed");
+		let json = serde_json::to_vec(&raw_spec_with_code(b"outdated runtime")).unwrap();
+		let mut spec = CordChainSpec::from_json_bytes(json).unwrap();
+
+		patch_genesis_wasm(&mut spec, wasm, false).unwrap();
+
+		let patched: serde_json::Value =
+			serde_json::from_str(&sc_service::ChainSpec::as_json(&spec, true).unwrap()).unwrap();
+		assert!(ensure_genesis_wasm_matches_runtime(&patched).is_ok());
+		let recorded = Extensions::try_get(&spec).and_then(|extensions| extensions.genesis_hash);
+		assert_eq!(recorded, Some(genesis_hash(&spec).unwrap()));
+	}
+
+	#[test]
+	fn patch_genesis_wasm_requires_force_for_live_specs() {
+		let wasm = cord_runtime::WASM_BINARY.expect("Cord wasm is built for tests; qed");
+		let mut raw = raw_spec_with_code(b"outdated runtime");
+		raw["chainType"] = serde_json::json!(ChainType::Live);
+		let mut spec = CordChainSpec::from_json_bytes(serde_json::to_vec(&raw).unwrap()).unwrap();
+
+		assert!(patch_genesis_wasm(&mut spec, wasm, false).unwrap_err().contains("live"));
+		assert_eq!(patch_genesis_wasm(&mut spec, wasm, true), Ok(()));
+	}
+
+	#[test]
+	fn session_keys_ed25519_keep_the_ed25519_babe_key() {
+		let babe = get_from_seed::<ed25519::Public>("Alice");
+		let keys = session_keys_ed25519(
+			babe,
+			get_from_seed::<GrandpaId>("Alice"),
+			get_from_seed::<ImOnlineId>("Alice"),
+			get_from_seed::<AuthorityDiscoveryId>("Alice"),
+		);
+
+		assert_eq!(keys.babe.as_slice(), babe.as_slice());
+		assert_eq!(get_from_seed_ed25519::<BabeId>("Alice"), keys.babe);
+	}
+
+	#[test]
+	fn telemetry_endpoints_are_parsed() {
+		assert_eq!(
+			parse_telemetry_endpoints("wss://a.example/submit/=0, wss://b.example/submit/=1"),
+			Ok(vec![
+				("wss://a.example/submit/".to_string(), 0),
+				("wss://b.example/submit/".to_string(), 1)
+			])
+		);
+		assert!(parse_telemetry_endpoints("wss://a.example/submit/").is_err());
+		assert!(parse_telemetry_endpoints("wss://a.example/submit/=high").is_err());
+	}
+
+	/// Staging testnet config with the default deployment settings, without
+	/// reading the environment.
+	fn default_staging_testnet_config() -> CordChainSpec {
+		staging_testnet_config(default_telemetry_endpoints(), vec![], None, vec![]).unwrap()
+	}
+
+	fn default_telemetry_endpoints() -> TelemetryEndpoints {
+		telemetry_endpoints(None, CORD_TELEMETRY_URL).unwrap()
+	}
+
+	#[test]
+	fn staging_specs_have_the_configured_telemetry_endpoints() {
+		let endpoints = telemetry_endpoints(
+			Some("wss://telemetry.cord.network/submit/=0,wss://telemetry.example.com/submit/=1"),
+			CORD_TELEMETRY_URL,
+		)
+		.unwrap();
+		let specs = [
+			staging_config(endpoints.clone(), vec![]).unwrap(),
+			staging_testnet_config(endpoints, vec![], None, vec![]).unwrap(),
+		];
+		for spec in specs {
+			let endpoints = serde_json::json!(sc_service::ChainSpec::telemetry_endpoints(&spec));
+			assert_eq!(endpoints.as_array().map(Vec::len), Some(2));
+		}
+
+		let endpoints = serde_json::json!(default_telemetry_endpoints());
+		assert_eq!(endpoints.as_array().map(Vec::len), Some(1));
+	}
+
+	#[test]
+	fn bootnodes_are_parsed() {
+		let bootnode = "/dns/bootnode.example/tcp/30333/p2p/\
+			12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp";
+
+		let bootnodes = parse_bootnodes(&format!("{}\n\n{}", bootnode, bootnode)).unwrap();
+		assert_eq!(bootnodes.len(), 2);
+		assert_eq!(bootnodes[0].to_string(), bootnode);
+		assert_eq!(parse_bootnodes(""), Ok(Vec::new()));
+		assert!(parse_bootnodes(&format!("{}\n/dns/bootnode.example/tcp/30333", bootnode)).is_err());
+
+		let spec = staging_config(default_telemetry_endpoints(), bootnodes).unwrap();
+		assert_eq!(sc_service::ChainSpec::boot_nodes(&spec).len(), 2);
+	}
+
+	#[test]
+	fn weighted_bootnodes_are_sorted_by_priority() {
+		let value = serde_json::json!([
+			{ "addr": "/dns/low.example/tcp/30333", "priority": 1 },
+			{ "addr": "/dns/high.example/tcp/30333", "priority": 10 },
+			{ "addr": "/dns/other.example/tcp/30333", "priority": 1 },
+		]);
+		let addrs = parse_weighted_bootnodes(&value)
+			.unwrap()
+			.into_iter()
+			.map(|bootnode| bootnode.addr.to_string())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			addrs,
+			vec![
+				"/dns/high.example/tcp/30333",
+				"/dns/low.example/tcp/30333",
+				"/dns/other.example/tcp/30333"
+			]
+		);
+
+		let invalid = serde_json::json!([{ "addr": "bootnode.example", "priority": 1 }]);
+		assert!(parse_weighted_bootnodes(&invalid).is_err());
+		let out_of_range = serde_json::json!([{ "addr": "/dns/a.example/tcp/1", "priority": 256 }]);
+		assert!(parse_weighted_bootnodes(&out_of_range).is_err());
+	}
+
+	#[test]
+	fn weighted_bootnodes_are_read_from_the_spec_properties() {
+		let mut raw = raw_spec_with_code(b"code");
+		let spec = CordChainSpec::from_json_bytes(serde_json::to_vec(&raw).unwrap()).unwrap();
+		assert_eq!(weighted_bootnodes_from_spec(&spec), Ok(Vec::new()));
+
+		raw["properties"] = serde_json::json!({
+			WEIGHTED_BOOTNODES_PROPERTY: [{ "addr": "/dns/a.example/tcp/30333", "priority": 5 }],
+		});
+		let spec = CordChainSpec::from_json_bytes(serde_json::to_vec(&raw).unwrap()).unwrap();
+		assert_eq!(
+			weighted_bootnodes_from_spec(&spec).unwrap(),
+			vec!["/dns/a.example/tcp/30333".parse::<sc_network::Multiaddr>().unwrap()]
+		);
+	}
+
+	#[test]
+	fn authority_discovery_keys_are_parsed() {
+		let keys = parse_authority_discovery_keys(&format!(
+			"0x{}\n\n  {}  \n",
+			DEV_SR25519_PUBLIC, DEV_SR25519_PUBLIC
+		))
+		.unwrap();
+		assert_eq!(keys.len(), 2);
+		assert_eq!(keys[0].as_slice(), array_bytes::hex2bytes_unchecked(DEV_SR25519_PUBLIC));
+		assert_eq!(parse_authority_discovery_keys(""), Ok(vec![]));
+		assert!(parse_authority_discovery_keys("0x1234").is_err());
+	}
+
+	#[test]
+	fn staging_authority_discovery_keys_are_set_in_the_session_keys() {
+		let key: AuthorityDiscoveryId =
+			array_bytes::hex2array_unchecked::<_, 32>(DEV_SR25519_PUBLIC).unchecked_into();
+		let keys = vec![key.clone(); STAGING_AUTHORITIES.len()];
+
+		let genesis =
+			cord_staging_config_genesis(staging_initial_authorities(), vec![], None, keys).unwrap();
+		let session_keys = genesis["session"]["keys"].as_array().unwrap();
+		assert_eq!(session_keys.len(), STAGING_AUTHORITIES.len());
+		assert!(session_keys
+			.iter()
+			.all(|keys| keys[2]["authority_discovery"] == serde_json::json!(key)));
+		assert!(genesis.get("authorityDiscovery").is_none());
+
+		let missing_keys =
+			cord_staging_config_genesis(staging_initial_authorities(), vec![], None, vec![key]);
+		assert!(missing_keys.is_err());
+	}
+
+	#[test]
+	fn genesis_balances_are_merged_per_account() {
+		let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+		let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+		let config = GenesisBalanceConfig { endowment: 10, stash: 5, credit_endowment: 1 };
+
+		let balances =
+			config.endowed_accounts(&[alice.clone(), bob.clone()], &[alice.clone()], &bob);
+		assert_eq!(balances.len(), 2);
+		assert!(balances.contains(&(alice, 15)));
+		assert!(balances.contains(&(bob, 11)));
+	}
+
+	#[test]
+	fn authority_keys_from_mnemonic_match_known_public_keys() {
+		let (account, babe, grandpa, im_online, authority_discovery) =
+			get_authority_keys_from_mnemonic(DEV_PHRASE, None).unwrap();
+
+		let sr25519_public: [u8; 32] = array_bytes::hex2array_unchecked(DEV_SR25519_PUBLIC);
+		let ed25519_public =
+			ed25519::Pair::from_seed(&array_bytes::hex2array_unchecked(DEV_SEED)).public();
+
+		assert_eq!(account, AccountId::from(sr25519_public));
+		assert_eq!(babe.as_slice(), &sr25519_public[..]);
+		assert_eq!(im_online.as_slice(), &sr25519_public[..]);
+		assert_eq!(authority_discovery.as_slice(), &sr25519_public[..]);
+		assert_eq!(grandpa.as_slice(), ed25519_public.as_slice());
+	}
+
+	#[test]
+	fn authority_keys_from_mnemonic_honour_password() {
+		let with_password = get_authority_keys_from_mnemonic(DEV_PHRASE, Some("password")).unwrap();
+		let without_password = get_authority_keys_from_mnemonic(DEV_PHRASE, None).unwrap();
+
+		assert_ne!(with_password.0, without_password.0);
+		assert_ne!(with_password.2, without_password.2);
+	}
+
+	#[test]
+	fn authority_keys_at_depth_zero_match_keys_from_seed() {
+		let (stash, controller, babe, grandpa, im_online, authority_discovery) =
+			get_authority_keys_at_depth("Alice", 0);
+
+		assert_eq!(
+			(controller, babe, grandpa, im_online, authority_discovery),
+			get_authority_keys_from_seed("Alice")
+		);
+		assert_eq!(stash, get_account_id_from_seed::<sr25519::Public>("Alice//stash"));
+	}
+
+	#[test]
+	fn authority_keys_at_depth_derive_from_the_depth() {
+		let (stash, controller, babe, ..) = get_authority_keys_at_depth("Alice", 1);
+
+		assert_eq!(controller, get_account_id_from_seed::<sr25519::Public>("Alice//1"));
+		assert_eq!(stash, get_account_id_from_seed::<sr25519::Public>("Alice//1//stash"));
+		assert_eq!(babe, get_from_seed::<BabeId>("Alice//1"));
+		assert_ne!(controller, get_authority_keys_at_depth("Alice", 0).1);
+	}
+
+	#[test]
+	fn authority_keys_from_invalid_mnemonic_fails() {
+		assert!(get_authority_keys_from_mnemonic("not a valid mnemonic", None).is_err());
+	}
+
+	#[test]
+	fn local_testnet_3_genesis_has_four_authorities() {
+		let genesis = cord_local_config_genesis(&["Alice", "Bob", "Charlie", "Dave"]);
+		let expected = ["Alice", "Bob", "Charlie", "Dave"]
+			.iter()
+			.map(|seed| serde_json::json!(get_account_id_from_seed::<sr25519::Public>(seed)))
+			.collect::<Vec<_>>();
+
+		assert_eq!(
+			genesis["authorityMembership"]["initialAuthorities"],
+			serde_json::json!(expected)
+		);
+		assert_eq!(genesis["session"]["keys"].as_array().map(Vec::len), Some(4));
+		assert_eq!(genesis["councilMembership"]["members"], serde_json::json!(expected));
+		assert_eq!(genesis["nodeAuthorization"]["nodes"].as_array().map(Vec::len), Some(4));
+	}
+
+	#[test]
+	fn extensions_para_id_defaults_to_none() {
+		let extensions: Extensions = serde_json::from_value(serde_json::json!({
+			"forkBlocks": null,
+			"badBlocks": null,
+			"lightSyncState": null,
+		}))
+		.unwrap();
+		assert_eq!(extensions.para_id, None);
+		assert!(!extensions.is_parachain_ready());
+
+		let extensions: Extensions =
+			serde_json::from_value(serde_json::json!({ "paraId": 2000 })).unwrap();
+		assert_eq!(extensions.para_id, Some(2000));
+	}
+
+	#[test]
+	fn chain_id_is_derived_from_genesis_hash_unless_set() {
+		let mut genesis_hash = Hash::repeat_byte(0xff);
+		genesis_hash.as_fixed_bytes_mut()[..4].copy_from_slice(&[0, 0, 0x05, 0x39]);
+		assert_eq!(derive_chain_id(&genesis_hash), DEV_CHAIN_ID);
+
+		let dev = cord_dev_config(None).unwrap();
+		assert_eq!(chain_id(&dev, &Hash::zero()), DEV_CHAIN_ID);
+
+		let staging = default_staging_testnet_config();
+		assert_eq!(chain_id(&staging, &genesis_hash), derive_chain_id(&genesis_hash));
+	}
+
+	#[test]
+	fn validate_block_export_is_detected() {
+		assert!(exports_validate_block(b"\0asm\x01\0\0\0validate_block"));
+		assert!(!exports_validate_block(b"\0asm\x01\0\0\0execute_block"));
+	}
+
+	#[test]
+	fn get_properties_rejects_unknown_ss58_formats() {
+		let properties = get_properties("WAY", 12, 29).unwrap();
+		assert_eq!(properties["ss58Format"], serde_json::json!(29));
+
+		assert!(get_properties("WAY", 12, 16_000).is_err());
+		assert!(get_properties("WAY", 12, u32::from(u16::MAX) + 1).is_err());
+	}
+
+	#[test]
+	fn development_genesis_names_alice() {
+		let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+		assert_eq!(
+			cord_development_config_genesis(None)["didName"]["names"],
+			serde_json::json!([(alice, b"alice@cord".to_vec())])
+		);
+	}
+
+	#[test]
+	fn development_genesis_anchors_alice_did_document() {
+		let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+		let genesis = cord_development_config_genesis(None);
+		let documents = &genesis["didDocument"]["documents"];
+		assert_eq!(documents.as_array().map(Vec::len), Some(1));
+		assert_eq!(documents[0][0], serde_json::json!(alice));
+
+		let document: Vec<u8> = serde_json::from_value(documents[0][2].clone()).unwrap();
+		let document: serde_json::Value = serde_json::from_slice(&document).unwrap();
+		assert!(document["id"].as_str().unwrap().starts_with("did:cord:"));
+	}
+
+	#[test]
+	fn staging_initial_authorities_match_the_staging_spec() {
+		let authorities = staging_initial_authorities();
+		assert_eq!(authorities.len(), 3);
+		for ((stash, controller, babe, grandpa, ..), (account, grandpa_hex)) in
+			authorities.iter().zip(STAGING_AUTHORITIES)
+		{
+			assert_eq!(stash, controller);
+			assert_eq!(babe.as_slice(), array_bytes::hex2bytes_unchecked(account));
+			assert_eq!(grandpa.as_slice(), array_bytes::hex2bytes_unchecked(grandpa_hex));
+		}
+	}
+
+	#[test]
+	fn authority_keys_are_exported_from_the_staging_config() {
+		let expected = staging_initial_authorities()
+			.into_iter()
+			.map(|(stash, controller, babe, grandpa, im_online, authority_discovery)| {
+				AuthorityKeySet { stash, controller, babe, grandpa, im_online, authority_discovery }
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(default_staging_testnet_config().export_authority_keys(), Ok(expected));
+
+		// the bundled staging spec is raw
+		let staging = staging_config(default_telemetry_endpoints(), vec![]).unwrap();
+		assert!(staging.export_authority_keys().is_err());
+	}
+
+	#[test]
+	fn staging_genesis_accepts_any_authority_count() {
+		let mut authorities = staging_initial_authorities();
+		let (account, babe, grandpa, im_online, authority_discovery) =
+			get_authority_keys_from_seed("Alice");
+		authorities.push((
+			account.clone(),
+			account.clone(),
+			babe,
+			grandpa,
+			im_online,
+			authority_discovery,
+		));
+
+		let genesis = cord_staging_config_genesis(authorities, vec![account], None, vec![]).unwrap();
+		assert_eq!(genesis["session"]["keys"].as_array().map(Vec::len), Some(4));
+		assert_eq!(
+			genesis["authorityMembership"]["initialAuthorities"].as_array().map(Vec::len),
+			Some(4)
+		);
+		// Every authority is a network member.
+		assert_eq!(genesis["networkMembership"]["members"].as_object().map(|m| m.len()), Some(4));
+	}
+
+	#[test]
+	fn diff_compares_dev_and_local_testnet_configs() {
+		let dev = cord_dev_config(None).unwrap();
+		let local = cord_local_config().unwrap();
+		let account = |seed: &str| get_account_id_from_seed::<sr25519::Public>(seed).to_string();
+
+		assert!(diff(&dev, &dev).unwrap().is_empty());
+
+		let diff = diff(&dev, &local).unwrap();
+		assert_eq!(diff.added_authorities, vec![account("Bob"), account("Charlie")]);
+		assert!(diff.removed_authorities.is_empty());
+		// The new authorities are endowed with a stash on top of their
+		// membership endowment.
+		let mut expected = ["Bob", "Charlie"]
+			.map(|seed| BalanceChange {
+				account: account(seed),
+				before: Some(ENDOWMENT),
+				after: Some(ENDOWMENT + STASH),
+			})
+			.to_vec();
+		expected.sort_by(|a, b| a.account.cmp(&b.account));
+		assert_eq!(diff.balance_changes, expected);
+		assert_eq!(
+			diff.property_changes.iter().map(|change| change.key.as_str()).collect::<Vec<_>>(),
+			vec!["chainType", "id", "name"]
+		);
+		assert!(diff.to_string().contains(&format!("+ authority {}", account("Charlie"))));
+	}
+
+	#[test]
+	fn staging_council_is_seeded_from_the_snapshot() {
+		let path =
+			Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/council_members.json");
+		let members = council_members_from_file(&path).unwrap();
+		assert_eq!(
+			members,
+			vec![
+				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				get_account_id_from_seed::<sr25519::Public>("Bob"),
+			]
+		);
+		assert_eq!(staging_council_members(Some(&path)), Ok(Some(members.clone())));
+
+		let genesis = cord_staging_config_genesis(
+			staging_initial_authorities(),
+			vec![],
+			Some(members),
+			vec![],
+		)
+		.unwrap();
+		assert_eq!(genesis["councilMembership"]["members"].as_array().map(Vec::len), Some(2));
+
+		// Without a snapshot the council is made of the authorities.
+		let council_members =
+			staging_council_members(Some(&path.with_file_name("missing.json"))).unwrap();
+		assert_eq!(council_members, None);
+		assert_eq!(staging_council_members(None), Ok(None));
+		let genesis = cord_staging_config_genesis(
+			staging_initial_authorities(),
+			vec![],
+			council_members,
+			vec![],
+		)
+		.unwrap();
+		assert_eq!(genesis["councilMembership"]["members"].as_array().map(Vec::len), Some(3));
+
+		assert!(council_members_from_file(&path.with_file_name("did_snapshot.json")).is_err());
+	}
+
+	fn dev_genesis_params() -> serde_json::Value {
+		serde_json::json!({
+			"authorities": [{
+				"account": "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d",
+				"grandpa": "0x88dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee",
+			}],
+			"wellKnownNodes": [{
+				"nodeId": "12D3KooWBmAwcd4PJNJvfV89HwE48nwkRmAgo8Vy3uQEyNNHBox2",
+				"owner": "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d",
+			}],
+			"sudoKey": "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d",
+		})
+	}
+
+	#[test]
+	fn dev_genesis_params_from_json_works() {
+		let params: DevGenesisParams = serde_json::from_value(dev_genesis_params()).unwrap();
+		let genesis = cord_development_config_genesis_from_params(params).unwrap();
+
+		assert_eq!(
+			genesis["sudo"]["key"],
+			serde_json::json!(get_account_id_from_seed::<sr25519::Public>("Alice"))
+		);
+		assert_eq!(genesis["session"]["keys"].as_array().unwrap().len(), 1);
+		assert_eq!(genesis["networkMembership"]["members"].as_object().unwrap().len(), 1);
+	}
+
+	#[test]
+	fn genesis_config_requires_an_endowed_sudo_key() {
+		let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+		let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+		let genesis = |root_key: &AccountId, credit_endowment| {
+			cord_local_genesis(
+				vec![get_authority_keys_from_seed("Alice")],
+				vec![],
+				vec![alice.clone()],
+				root_key.clone(),
+				GenesisBalanceConfig { credit_endowment, ..Default::default() },
+				None,
+			)
+		};
+
+		assert_eq!(validate_genesis_config(&genesis(&alice, 0)), Ok(()));
+		assert_eq!(validate_genesis_config(&genesis(&bob, CREDIT_ENDOWMENT)), Ok(()));
+		assert!(validate_genesis_config(&genesis(&bob, 0)).is_err());
+		assert_eq!(validate_genesis_config(&cord_development_config_genesis(None)), Ok(()));
+	}
+
+	#[test]
+	fn dev_genesis_params_babe_epoch_config() {
+		let params: DevGenesisParams = serde_json::from_value(dev_genesis_params()).unwrap();
+		let genesis = cord_development_config_genesis_from_params(params).unwrap();
+		assert_eq!(
+			genesis["babe"]["epochConfig"],
+			serde_json::json!(cord_runtime::BABE_GENESIS_EPOCH_CONFIG)
+		);
+
+		let mut json = dev_genesis_params();
+		json["babeEpochConfig"] =
+			serde_json::json!({ "c": [1, 2], "allowed_slots": "PrimarySlots" });
+		let params: DevGenesisParams = serde_json::from_value(json.clone()).unwrap();
+		let genesis = cord_development_config_genesis_from_params(params).unwrap();
+		assert_eq!(genesis["babe"]["epochConfig"], json["babeEpochConfig"]);
+
+		json["babeEpochConfig"]["c"] = serde_json::json!([1, 0]);
+		let params: DevGenesisParams = serde_json::from_value(json).unwrap();
+		assert!(cord_development_config_genesis_from_params(params).is_err());
+	}
+
+	#[test]
+	fn dev_genesis_params_missing_field_fails() {
+		let mut json = dev_genesis_params();
+		json.as_object_mut().unwrap().remove("sudoKey");
+
+		let err = serde_json::from_value::<DevGenesisParams>(json).unwrap_err();
+		assert!(err.to_string().contains("sudoKey"));
+	}
+
+	#[test]
+	fn dev_genesis_params_invalid_key_fails() {
+		let mut json = dev_genesis_params();
+		json["sudoKey"] = serde_json::json!("0x1234");
+
+		let params: DevGenesisParams = serde_json::from_value(json).unwrap();
+		let err = cord_development_config_genesis_from_params(params).unwrap_err();
+		assert!(err.contains("sudoKey"));
+	}
+
+	#[test]
+	fn parse_dev_balance_works() {
+		assert_eq!(parse_dev_balance("1"), Ok(WAY));
+		assert_eq!(parse_dev_balance("50000"), Ok(50_000 * WAY));
+		assert!(parse_dev_balance("0").is_err());
+		assert!(parse_dev_balance("-1").is_err());
+		assert!(parse_dev_balance(&Balance::MAX.to_string()).is_err());
+	}
+
+	#[test]
+	fn dev_genesis_endows_the_credit_treasury() {
+		use frame_support::PalletId;
+		use sp_runtime::traits::AccountIdConversion;
+
+		let treasury: AccountId = PalletId(*b"py/crdit").into_account_truncating();
+		assert_eq!(credit_treasury_account(), treasury);
+
+		let genesis = cord_development_config_genesis(None);
+		assert_eq!(
+			genesis["credit"]["balances"],
+			serde_json::json!([(treasury, CREDIT_TREASURY_ISSUANCE)])
+		);
+	}
+
+	/// Set to rewrite the pinned development genesis state root.
+	const BLESS_GENESIS_STATE_ROOT: &str = "CORD_BLESS_GENESIS_STATE_ROOT";
+
+	/// Pins the root of the development genesis storage, runtime code
+	/// excluded so that runtime-only changes do not need a new fixture.
+	///
+	/// Regenerate the fixture after an intended genesis change with
+	/// `CORD_BLESS_GENESIS_STATE_ROOT=1 cargo test -p cord-node-cli
+	/// dev_genesis_state_root_is_stable` and commit the updated
+	/// `tests/fixtures/dev_genesis_state_root.json`.
+	#[test]
+	fn dev_genesis_state_root_is_stable() {
+		use sp_runtime::{traits::BlakeTwo256, BuildStorage};
+
+		let mut storage = cord_dev_config(None).unwrap().build_storage().unwrap();
+		storage.top.remove(sp_core::storage::well_known_keys::CODE);
+		let backend = sp_state_machine::InMemoryBackend::<BlakeTwo256>::from((
+			storage,
+			cord_runtime::VERSION.state_version(),
+		));
+		let state_root = *backend.root();
+
+		let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+			.join("tests/fixtures/dev_genesis_state_root.json");
+		if std::env::var_os(BLESS_GENESIS_STATE_ROOT).is_some() {
+			let pinned = serde_json::json!({ "stateRoot": state_root });
+			std::fs::write(&fixture, format!("{:#}\n", pinned)).unwrap();
+			return
+		}
+
+		let pinned: serde_json::Value =
+			serde_json::from_slice(&std::fs::read(&fixture).unwrap()).unwrap();
+		let pinned: Option<Hash> = serde_json::from_value(pinned["stateRoot"].clone()).unwrap();
+		assert_eq!(
+			Some(state_root),
+			pinned,
+			"The development genesis state changed. If this is intended, rerun this test with \
+			 {}=1 and commit the updated fixture.",
+			BLESS_GENESIS_STATE_ROOT,
+		);
+	}
+}
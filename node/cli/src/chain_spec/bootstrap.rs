@@ -120,7 +120,7 @@ fn cord_custom_config_genesis(config: ChainParams) -> serde_json::Value {
 }
 
 pub fn cord_custom_config(config: ChainParams) -> Result<CordChainSpec, String> {
-	let properties = get_properties("WAY", 12, 29);
+	let properties = get_properties("WAY", 12, 29)?;
 	let chain_name = String::from(config.chain_name());
 	let chain_type = config.chain_type();
 	Ok(CordChainSpec::builder(
@@ -0,0 +1,196 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Genesis configuration seeded from on-chain snapshots.
+
+use crate::chain_spec::{AccountId, CordChainSpec};
+use codec::Encode;
+use cord_primitives::{BlockNumber, Hash};
+use cord_runtime::NetworkMembershipConfig;
+use sp_core::storage::{StateVersion, Storage};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash as HashT},
+	BuildStorage,
+};
+use std::{
+	collections::BTreeMap,
+	path::{Path, PathBuf},
+};
+
+/// A state snapshot to seed a chain spec from.
+#[derive(Debug, Clone)]
+pub struct SnapshotConfig {
+	/// Path to the snapshot, a raw chain spec as written by `export-state`.
+	pub snapshot_path: PathBuf,
+	/// Number of the block the snapshot was taken at.
+	pub block_number: BlockNumber,
+	/// Expected state root of the snapshot, the state root of the header of
+	/// `block_number`.
+	pub state_root: Hash,
+}
+
+/// The state root of the given storage, child trie roots included.
+pub(crate) fn storage_root(storage: &Storage, state_version: StateVersion) -> Hash {
+	let mut top = storage.top.clone().into_iter().collect::<Vec<_>>();
+	top.extend(storage.children_default.values().filter(|child| !child.data.is_empty()).map(
+		|child| {
+			let child_root = <BlakeTwo256 as HashT>::trie_root(
+				child.data.clone().into_iter().collect(),
+				state_version,
+			);
+			(child.child_info.prefixed_storage_key().into_inner(), child_root.encode())
+		},
+	));
+
+	<BlakeTwo256 as HashT>::trie_root(top, state_version)
+}
+
+/// Live chain spec whose genesis is the state of a staging block.
+///
+/// The snapshot state root must match `state_root`. The resulting spec starts
+/// from that state without executing the blocks before it, and records the
+/// snapshot height in the `snapshotBlockNumber` property.
+pub fn cord_staging_config_from_snapshot(cfg: SnapshotConfig) -> Result<CordChainSpec, String> {
+	let path = cfg.snapshot_path.display();
+	let bytes = std::fs::read(&cfg.snapshot_path)
+		.map_err(|e| format!("Error reading state snapshot `{}`: {}", path, e))?;
+	let mut spec: serde_json::Value = serde_json::from_slice(&bytes)
+		.map_err(|e| format!("Error parsing state snapshot `{}`: {}", path, e))?;
+	if !spec["genesis"]["raw"].is_object() {
+		return Err(format!("State snapshot `{}` is not a raw chain spec", path))
+	}
+
+	let storage = CordChainSpec::from_json_bytes(bytes)?.build_storage()?;
+	let state_root = storage_root(&storage, cord_runtime::VERSION.state_version());
+	if state_root != cfg.state_root {
+		return Err(format!(
+			"State snapshot `{}` has state root {:?}, expected {:?}",
+			path, state_root, cfg.state_root
+		))
+	}
+
+	spec["chainType"] = serde_json::json!("Live");
+	spec["properties"]["snapshotBlockNumber"] = serde_json::json!(cfg.block_number);
+
+	let bytes = serde_json::to_vec(&spec).map_err(|e| e.to_string())?;
+	CordChainSpec::from_json_bytes(bytes)
+}
+
+/// Network membership genesis seeded from a DID registry snapshot.
+///
+/// The snapshot is a JSON array of `[did_identifier, account_id]` pairs, with
+/// SS58 encoded accounts. Accounts linked to more than one DID are added once,
+/// and memberships are created without expiry.
+pub fn network_membership_from_did_snapshot(
+	snapshot_path: &Path,
+) -> Result<NetworkMembershipConfig, String> {
+	let file = std::fs::File::open(snapshot_path).map_err(|e| {
+		format!("Error opening DID snapshot `{}`: {}", snapshot_path.display(), e)
+	})?;
+	let entries: Vec<(String, AccountId)> =
+		serde_json::from_reader(std::io::BufReader::new(file)).map_err(|e| {
+			format!("Error parsing DID snapshot `{}`: {}", snapshot_path.display(), e)
+		})?;
+
+	let members = entries
+		.into_iter()
+		.map(|(_did, account)| (account, false))
+		.collect::<BTreeMap<_, _>>();
+
+	Ok(NetworkMembershipConfig { members })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chain_spec::{get_account_id_from_seed, ChainType};
+	use sc_chain_spec::ChainSpec;
+	use sp_core::sr25519;
+
+	fn fixture(name: &str) -> std::path::PathBuf {
+		Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+	}
+
+	#[test]
+	fn network_membership_from_did_snapshot_deduplicates_accounts() {
+		let config = network_membership_from_did_snapshot(&fixture("did_snapshot.json")).unwrap();
+
+		assert_eq!(
+			config.members.into_iter().collect::<Vec<_>>(),
+			vec![
+				(get_account_id_from_seed::<sr25519::Public>("Bob"), false),
+				(get_account_id_from_seed::<sr25519::Public>("Alice"), false),
+			]
+		);
+	}
+
+	#[test]
+	fn network_membership_from_missing_did_snapshot_fails() {
+		assert!(network_membership_from_did_snapshot(&fixture("missing.json")).is_err());
+	}
+
+	fn write_raw_snapshot(dir: &Path, top: &[(&[u8], &[u8])]) -> PathBuf {
+		let top = top
+			.iter()
+			.map(|(key, value)| {
+				(array_bytes::bytes2hex("0x", key), array_bytes::bytes2hex("0x", value))
+			})
+			.collect::<BTreeMap<_, _>>();
+		let spec = serde_json::json!({
+			"name": "Cord Sprint",
+			"id": "sprint",
+			"chainType": "Local",
+			"bootNodes": [],
+			"telemetryEndpoints": null,
+			"protocolId": "cord",
+			"properties": null,
+			"codeSubstitutes": {},
+			"genesis": { "raw": { "top": top, "childrenDefault": {} } },
+		});
+		let path = dir.join("snapshot.json");
+		std::fs::write(&path, serde_json::to_vec(&spec).unwrap()).unwrap();
+		path
+	}
+
+	#[test]
+	fn staging_config_from_snapshot_verifies_the_state_root() {
+		let dir = tempfile::tempdir().unwrap();
+		let top: &[(&[u8], &[u8])] = &[(b":code", &[0, 97, 115, 109]), (b"key", b"value")];
+		let snapshot_path = write_raw_snapshot(dir.path(), top);
+		let state_root = <BlakeTwo256 as HashT>::trie_root(
+			top.iter().map(|(key, value)| (key.to_vec(), value.to_vec())).collect(),
+			StateVersion::V1,
+		);
+
+		let spec = cord_staging_config_from_snapshot(SnapshotConfig {
+			snapshot_path: snapshot_path.clone(),
+			block_number: 42,
+			state_root,
+		})
+		.unwrap();
+		assert_eq!(spec.chain_type(), ChainType::Live);
+		assert_eq!(spec.properties()["snapshotBlockNumber"], serde_json::json!(42));
+
+		assert!(cord_staging_config_from_snapshot(SnapshotConfig {
+			snapshot_path,
+			block_number: 42,
+			state_root: Hash::repeat_byte(1),
+		})
+		.is_err());
+	}
+}
@@ -17,7 +17,11 @@
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
 #![allow(missing_docs)]
-use crate::command::{chain_setup::BootstrapChainCmd, gen_key::KeySubcommand};
+use crate::command::{
+	chain_setup::BootstrapChainCmd, diff_spec::DiffChainSpecCmd,
+	export_keys::ExportAuthorityKeysCmd, gen_key::KeySubcommand, inject_key::InjectedKey,
+	patch_wasm::PatchGenesisWasmCmd, verify_spec::VerifyChainSpecCmd,
+};
 
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -43,6 +47,58 @@ pub struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// Calibrate the block execution weight to the CPU score of the machine.
+	///
+	/// Runs the hardware benchmarks at startup, unless they already run, and
+	/// logs the block execution weight scaled to the measured CPU score. The
+	/// node refuses to start if it deviates from the weight of the runtime by
+	/// more than 50%.
+	#[arg(long)]
+	pub calibrate_weights: bool,
+
+	/// Initial balance, in WAY, of the Alice and Bob accounts of the
+	/// development chain.
+	///
+	/// Only applies to the `dev` chain specification. Must be at least 1 WAY.
+	#[arg(long, value_name = "WAY", value_parser = crate::chain_spec::parse_dev_balance)]
+	pub dev_alice_balance: Option<cord_primitives::Balance>,
+
+	/// Insert a session key into the keystore before block production
+	/// starts, as `<scheme>:<seed>` with the scheme one of `babe`, `gran`,
+	/// `imon` or `audi`.
+	///
+	/// Meant for development nodes. Can be given multiple times.
+	#[arg(
+		long,
+		value_name = "SCHEME:SEED",
+		value_parser = crate::command::inject_key::parse_inject_key
+	)]
+	pub inject_key: Vec<InjectedKey>,
+
+	/// Port of the HTTP endpoint serving the node health as JSON at
+	/// `/health`, for liveness and readiness probes.
+	#[arg(long, value_name = "PORT", default_value_t = 9955)]
+	pub health_port: u16,
+
+	/// Expose the health endpoint on all interfaces.
+	///
+	/// By default it only listens on localhost.
+	#[arg(long)]
+	pub health_external: bool,
+
+	/// Do not serve the health endpoint.
+	#[arg(long, conflicts_with_all = ["health_port", "health_external"])]
+	pub no_health: bool,
+
+	/// Sync with warp sync: download the GRANDPA finality proofs and the
+	/// state of the latest finalized block, then the older blocks in the
+	/// background.
+	///
+	/// Takes precedence over `--sync`. Without it the node keeps the sync
+	/// mode of `--sync`, a full sync by default.
+	#[arg(long)]
+	pub warp_sync: bool,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub storage_monitor: sc_storage_monitor::StorageMonitorParams,
@@ -93,6 +149,19 @@ pub enum Subcommand {
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
 
+	/// Verify that authority keys of a chain specification are unique.
+	VerifyChainSpec(VerifyChainSpecCmd),
+
+	/// Replace the genesis runtime wasm of an existing chain specification.
+	PatchGenesisWasm(PatchGenesisWasmCmd),
+
+	/// Print the authority, balance and property differences between two
+	/// chain specifications.
+	DiffSpec(DiffChainSpecCmd),
+
+	/// Export the authority keys of a chain specification as JSON.
+	ExportAuthorityKeys(ExportAuthorityKeysCmd),
+
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
@@ -17,7 +17,12 @@
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod chain_setup;
+pub mod diff_spec;
+pub mod export_keys;
 pub mod gen_key;
+pub mod inject_key;
+pub mod patch_wasm;
+pub mod verify_spec;
 
 use crate::{
 	benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferKeepAliveBuilder},
@@ -63,12 +68,31 @@ impl SubstrateCli for Cli {
 	fn load_spec(&self, id: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, String> {
 		let spec = match id {
 			"cord" | "" => Box::new(chain_spec::cord_staging_config()?),
-			"cord_dev" | "dev" | "ignite" => Box::new(chain_spec::cord_dev_config()?),
+			"cord_dev" | "dev" | "ignite" =>
+				Box::new(chain_spec::cord_dev_config(self.dev_alice_balance)?),
 			"local" | "spin" => Box::new(chain_spec::cord_local_config()?),
+			"local3" => Box::new(chain_spec::cord_local_testnet_3_config()?),
 			"staging" | "sprint" => Box::new(chain_spec::cord_staging_config()?),
+			"staging-testnet" => Box::new(chain_spec::cord_staging_testnet_config()?),
 			"builder" | "spark" => Box::new(chain_spec::cord_builder_config()?),
-			path =>
-				Box::new(chain_spec::CordChainSpec::from_json_file(std::path::PathBuf::from(path))?),
+			path => {
+				let path = std::path::PathBuf::from(path);
+				match chain_spec::CordChainSpec::from_json_file(path.clone()) {
+					Ok(spec) => Box::new(spec),
+					// Not a chain spec, try to read it as development genesis parameters.
+					Err(spec_err) => match chain_spec::cord_development_config_from_file(&path) {
+						Ok(spec) => Box::new(spec),
+						Err(genesis_err) =>
+							return Err(format!(
+								"`{}` is neither a chain spec ({}) nor development genesis \
+								 parameters ({})",
+								path.display(),
+								spec_err,
+								genesis_err,
+							)),
+					},
+				}
+			},
 		};
 		Ok(spec)
 	}
@@ -99,6 +123,10 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run(config.chain_spec, config.network))
 		},
+		Some(Subcommand::VerifyChainSpec(cmd)) => cmd.run(),
+		Some(Subcommand::PatchGenesisWasm(cmd)) => cmd.run(),
+		Some(Subcommand::DiffSpec(cmd)) => cmd.run(),
+		Some(Subcommand::ExportAuthorityKeys(cmd)) => cmd.run(),
 		Some(Subcommand::CheckBlock(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
@@ -0,0 +1,49 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+use std::path::PathBuf;
+
+use sc_cli::{
+	clap::{self, Args},
+	Error,
+};
+
+use crate::chain_spec::{diff, CordChainSpec};
+
+#[derive(Debug, Args)]
+pub struct DiffChainSpecCmd {
+	/// Path to the first (non-raw) chain spec JSON file.
+	spec_a: PathBuf,
+
+	/// Path to the second (non-raw) chain spec JSON file.
+	spec_b: PathBuf,
+}
+
+impl DiffChainSpecCmd {
+	pub fn run(&self) -> Result<(), Error> {
+		let spec_a = CordChainSpec::from_json_file(self.spec_a.clone())?;
+		let spec_b = CordChainSpec::from_json_file(self.spec_b.clone())?;
+
+		let diff = diff(&spec_a, &spec_b)?;
+		if diff.is_empty() {
+			println!("No differences found");
+		} else {
+			print!("{}", diff);
+		}
+		Ok(())
+	}
+}
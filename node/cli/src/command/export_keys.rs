@@ -0,0 +1,50 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{fs, path::PathBuf};
+
+use sc_cli::{
+	clap::{self, Args},
+	Error,
+};
+
+use crate::chain_spec::{CordChainSpec, ExportAuthorityKeys};
+
+#[derive(Debug, Args)]
+pub struct ExportAuthorityKeysCmd {
+	/// Path to the (non-raw) chain spec JSON file.
+	#[arg(long)]
+	spec: PathBuf,
+
+	/// Path of the JSON file the authority keys are written to.
+	#[arg(long)]
+	output: PathBuf,
+}
+
+impl ExportAuthorityKeysCmd {
+	pub fn run(&self) -> Result<(), Error> {
+		let spec = CordChainSpec::from_json_file(self.spec.clone())?;
+		let keys = spec.export_authority_keys()?;
+
+		let json =
+			serde_json::to_string_pretty(&keys).map_err(|e| Error::Application(Box::new(e)))?;
+		fs::write(&self.output, json)?;
+		println!("Exported the keys of {} authorities", keys.len());
+		Ok(())
+	}
+}
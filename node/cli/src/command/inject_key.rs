@@ -0,0 +1,111 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keys seeded into the keystore before the consensus engines start.
+
+use sp_core::{
+	crypto::{key_types, KeyTypeId, SecretStringError},
+	ed25519, sr25519, ByteArray, Pair,
+};
+use sp_keystore::Keystore;
+
+/// A session key given as `<scheme>:<seed>` on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InjectedKey {
+	/// The key type the key is inserted under.
+	pub key_type: KeyTypeId,
+	/// The secret URI the key pair is derived from.
+	pub suri: String,
+}
+
+/// Parse a `<scheme>:<seed>` key, the scheme being one of `babe`, `gran`,
+/// `imon` or `audi`.
+pub fn parse_inject_key(s: &str) -> Result<InjectedKey, String> {
+	let (scheme, suri) = s
+		.split_once(':')
+		.ok_or_else(|| format!("Expected `<scheme>:<seed>`, got `{}`", s))?;
+	let key_type = match scheme {
+		"babe" => key_types::BABE,
+		"gran" => key_types::GRANDPA,
+		"imon" => key_types::IM_ONLINE,
+		"audi" => key_types::AUTHORITY_DISCOVERY,
+		_ =>
+			return Err(format!(
+				"Unknown key scheme `{}`, expected one of babe, gran, imon or audi",
+				scheme
+			)),
+	};
+	if suri.is_empty() {
+		return Err(format!("Missing seed for the `{}` key", scheme))
+	}
+
+	Ok(InjectedKey { key_type, suri: suri.to_string() })
+}
+
+impl InjectedKey {
+	/// Derive the public key from the seed and insert the key into
+	/// `keystore`. GRANDPA keys are ed25519, all others sr25519.
+	pub fn insert(&self, keystore: &dyn Keystore) -> Result<(), String> {
+		let scheme = String::from_utf8_lossy(&self.key_type.0);
+		let invalid_seed =
+			|e: SecretStringError| format!("Invalid seed for the `{}` key: {:?}", scheme, e);
+		let public = if self.key_type == key_types::GRANDPA {
+			ed25519::Pair::from_string(&self.suri, None)
+				.map_err(invalid_seed)?
+				.public()
+				.to_raw_vec()
+		} else {
+			sr25519::Pair::from_string(&self.suri, None)
+				.map_err(invalid_seed)?
+				.public()
+				.to_raw_vec()
+		};
+
+		keystore
+			.insert(self.key_type, &self.suri, &public)
+			.map_err(|_| format!("Failed to insert the `{}` key into the keystore", scheme))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_keystore::testing::MemoryKeystore;
+
+	#[test]
+	fn parse_inject_key_works() {
+		let key = parse_inject_key("gran://Alice").unwrap();
+		assert_eq!(key, InjectedKey { key_type: key_types::GRANDPA, suri: "//Alice".into() });
+		assert!(parse_inject_key("babe").is_err());
+		assert!(parse_inject_key("babe:").is_err());
+		assert!(parse_inject_key("beef://Alice").unwrap_err().contains("beef"));
+	}
+
+	#[test]
+	fn insert_uses_the_scheme_of_the_key_type() {
+		let keystore = MemoryKeystore::new();
+
+		parse_inject_key("babe://Dave").unwrap().insert(&keystore).unwrap();
+		parse_inject_key("gran://Dave").unwrap().insert(&keystore).unwrap();
+
+		let babe = sr25519::Pair::from_string("//Dave", None).unwrap().public();
+		let grandpa = ed25519::Pair::from_string("//Dave", None).unwrap().public();
+		assert_eq!(keystore.sr25519_public_keys(key_types::BABE), vec![babe]);
+		assert_eq!(keystore.ed25519_public_keys(key_types::GRANDPA), vec![grandpa]);
+	}
+}
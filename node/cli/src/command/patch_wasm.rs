@@ -0,0 +1,55 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{fs, path::PathBuf};
+
+use sc_cli::{
+	clap::{self, Args},
+	Error,
+};
+
+use crate::chain_spec::{patch_genesis_wasm, CordChainSpec};
+
+#[derive(Debug, Args)]
+pub struct PatchGenesisWasmCmd {
+	/// Path to the chain spec JSON file to patch. The file is rewritten as a
+	/// raw chain spec.
+	#[arg(long, short = 'c')]
+	chain_spec: PathBuf,
+
+	/// Path to the new runtime wasm blob.
+	#[arg(long)]
+	wasm: PathBuf,
+
+	/// Patch the chain spec even if its chain type is `Live`.
+	#[arg(long)]
+	force: bool,
+}
+
+impl PatchGenesisWasmCmd {
+	pub fn run(&self) -> Result<(), Error> {
+		let wasm = fs::read(&self.wasm)?;
+		let mut spec = CordChainSpec::from_json_file(self.chain_spec.clone())?;
+
+		patch_genesis_wasm(&mut spec, &wasm, self.force)?;
+
+		let json = sc_service::ChainSpec::as_json(&spec, true)?;
+		fs::write(&self.chain_spec, json)?;
+		Ok(())
+	}
+}
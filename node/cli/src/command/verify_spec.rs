@@ -0,0 +1,149 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use sc_cli::{
+	clap::{self, Args},
+	Error,
+};
+
+/// Session key types checked for uniqueness, as named in the session genesis
+/// config.
+const SESSION_KEY_TYPES: [&str; 4] = ["babe", "grandpa", "im_online", "authority_discovery"];
+
+/// A session key shared by more than one authority.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKey {
+	/// Session key type, e.g. `babe`.
+	pub key_type: &'static str,
+	/// The duplicated public key.
+	pub key: String,
+	/// Index and account of every authority using the key.
+	pub authorities: Vec<(usize, String)>,
+}
+
+#[derive(Debug, Args)]
+pub struct VerifyChainSpecCmd {
+	/// Path to a (non-raw) chain spec JSON file.
+	#[arg(long, short = 'c')]
+	chain_spec: PathBuf,
+}
+
+impl VerifyChainSpecCmd {
+	pub fn run(&self) -> Result<(), Error> {
+		let json = fs::read(&self.chain_spec)?;
+		let spec: serde_json::Value =
+			serde_json::from_slice(&json).map_err(|e| Error::Application(Box::new(e)))?;
+
+		let duplicates = find_duplicate_session_keys(&spec).map_err(Error::Input)?;
+		if duplicates.is_empty() {
+			println!("No duplicate authority keys found");
+			return Ok(())
+		}
+
+		for duplicate in &duplicates {
+			println!("- {} {}", duplicate.key_type, duplicate.key);
+			for (index, account) in &duplicate.authorities {
+				println!("    + authority #{} {}", index, account);
+			}
+		}
+
+		Err(Error::Input(format!("Found {} duplicate authority key(s)", duplicates.len())))
+	}
+}
+
+/// Reads the session keys from the genesis of a non-raw chain spec.
+fn session_keys(spec: &serde_json::Value) -> Result<&Vec<serde_json::Value>, String> {
+	let genesis = &spec["genesis"];
+	if !genesis["raw"].is_null() {
+		return Err("Raw chain specs are not supported, verify the plain chain spec instead".into())
+	}
+
+	let runtime_genesis = &genesis["runtimeGenesis"];
+	[&runtime_genesis["patch"], &runtime_genesis["config"], &genesis["runtime"]]
+		.into_iter()
+		.find_map(|config| config["session"]["keys"].as_array())
+		.ok_or_else(|| "Chain spec genesis does not contain any session keys".into())
+}
+
+/// Returns every session key used by more than one authority.
+pub fn find_duplicate_session_keys(spec: &serde_json::Value) -> Result<Vec<DuplicateKey>, String> {
+	let mut seen = BTreeMap::<(&'static str, String), Vec<(usize, String)>>::new();
+
+	for (index, entry) in session_keys(spec)?.iter().enumerate() {
+		let account = entry[0].as_str().unwrap_or_default().to_string();
+		for key_type in SESSION_KEY_TYPES {
+			let key = entry[2][key_type].as_str().ok_or_else(|| {
+				format!("Authority #{} has no `{}` session key", index, key_type)
+			})?;
+			seen.entry((key_type, key.to_string())).or_default().push((index, account.clone()));
+		}
+	}
+
+	Ok(seen
+		.into_iter()
+		.filter(|(_, authorities)| authorities.len() > 1)
+		.map(|((key_type, key), authorities)| DuplicateKey { key_type, key, authorities })
+		.collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn spec_with_keys(keys: serde_json::Value) -> serde_json::Value {
+		serde_json::json!({
+			"genesis": { "runtimeGenesis": { "patch": { "session": { "keys": keys } } } }
+		})
+	}
+
+	fn keys(account: &str, babe: &str, grandpa: &str) -> serde_json::Value {
+		serde_json::json!([account, account, {
+			"babe": babe,
+			"grandpa": grandpa,
+			"im_online": babe,
+			"authority_discovery": babe,
+		}])
+	}
+
+	#[test]
+	fn unique_keys_pass() {
+		let spec = spec_with_keys(serde_json::json!([keys("a", "b1", "g1"), keys("b", "b2", "g2")]));
+		assert_eq!(find_duplicate_session_keys(&spec), Ok(vec![]));
+	}
+
+	#[test]
+	fn duplicate_keys_are_reported() {
+		let spec = spec_with_keys(serde_json::json!([keys("a", "b1", "g1"), keys("b", "b2", "g1")]));
+		assert_eq!(
+			find_duplicate_session_keys(&spec),
+			Ok(vec![DuplicateKey {
+				key_type: "grandpa",
+				key: "g1".into(),
+				authorities: vec![(0, "a".into()), (1, "b".into())],
+			}])
+		);
+	}
+
+	#[test]
+	fn raw_specs_are_rejected() {
+		let spec = serde_json::json!({ "genesis": { "raw": { "top": {} } } });
+		assert!(find_duplicate_session_keys(&spec).is_err());
+	}
+}
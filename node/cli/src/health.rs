@@ -0,0 +1,199 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! HTTP endpoint reporting the health of the node as JSON.
+//!
+//! `GET /health` answers with the node status, its number of connected peers
+//! and its best and finalized block numbers. The response is `200 OK` while
+//! the node is `ok`, and `503 Service Unavailable` while it is `syncing` or
+//! `stalled`, so it can back a readiness probe as is.
+
+use futures::{future, FutureExt, StreamExt};
+use hyper::{
+	header::CONTENT_TYPE,
+	service::{make_service_fn, service_fn},
+	Body, Method, Request, Response, Server, StatusCode,
+};
+use sc_client_api::BlockchainEvents;
+use sc_network_sync::SyncingService;
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use sp_consensus::SyncOracle;
+use sp_runtime::{traits::Block as BlockT, SaturatedConversion};
+use std::{
+	convert::Infallible,
+	net::SocketAddr,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+const LOG_TARGET: &str = "cord-health";
+
+/// Time without a block import after which a node that is not major syncing
+/// is considered stalled.
+pub const STALL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Coarse state of the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+	/// The node is following the chain.
+	Ok,
+	/// The node is catching up with the network.
+	Syncing,
+	/// The node did not import a block for `STALL_TIMEOUT`.
+	Stalled,
+}
+
+impl Status {
+	/// The status of a node that last imported a block `since_last_import`
+	/// ago.
+	pub fn new(is_major_syncing: bool, since_last_import: Duration) -> Self {
+		if is_major_syncing {
+			Status::Syncing
+		} else if since_last_import >= STALL_TIMEOUT {
+			Status::Stalled
+		} else {
+			Status::Ok
+		}
+	}
+}
+
+/// Body of a `/health` response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NodeHealth {
+	pub status: Status,
+	pub peers: u32,
+	pub best_block: u64,
+	pub finalized_block: u64,
+}
+
+struct HealthSource<B: BlockT, C> {
+	client: Arc<C>,
+	sync: Arc<SyncingService<B>>,
+	last_import: Arc<Mutex<Instant>>,
+}
+
+impl<B: BlockT, C: HeaderBackend<B>> HealthSource<B, C> {
+	fn health(&self) -> NodeHealth {
+		let info = self.client.info();
+		let since_last_import =
+			self.last_import.lock().expect("lock is never poisoned; qed").elapsed();
+
+		NodeHealth {
+			status: Status::new(self.sync.is_major_syncing(), since_last_import),
+			peers: self.sync.num_connected_peers().saturated_into(),
+			best_block: info.best_number.saturated_into(),
+			finalized_block: info.finalized_number.saturated_into(),
+		}
+	}
+
+	fn respond(&self, request: Request<Body>) -> Response<Body> {
+		if request.method() != Method::GET || request.uri().path() != "/health" {
+			return Response::builder()
+				.status(StatusCode::NOT_FOUND)
+				.body(Body::empty())
+				.expect("response is well formed; qed")
+		}
+
+		let health = self.health();
+		let status = match health.status {
+			Status::Ok => StatusCode::OK,
+			Status::Syncing | Status::Stalled => StatusCode::SERVICE_UNAVAILABLE,
+		};
+		let body = serde_json::to_vec(&health).expect("health serializes to JSON; qed");
+
+		Response::builder()
+			.status(status)
+			.header(CONTENT_TYPE, "application/json")
+			.body(Body::from(body))
+			.expect("response is well formed; qed")
+	}
+}
+
+/// Serve the `/health` endpoint on `addr` until the node shuts down.
+///
+/// Failing to bind `addr` only logs a warning, so that several nodes can run
+/// on one host with the default port.
+pub async fn run<B, C>(addr: SocketAddr, client: Arc<C>, sync: Arc<SyncingService<B>>)
+where
+	B: BlockT,
+	C: HeaderBackend<B> + BlockchainEvents<B> + Send + Sync + 'static,
+{
+	let last_import = Arc::new(Mutex::new(Instant::now()));
+	let track_imports = {
+		let last_import = last_import.clone();
+		client.import_notification_stream().for_each(move |_| {
+			*last_import.lock().expect("lock is never poisoned; qed") = Instant::now();
+			future::ready(())
+		})
+	};
+
+	let source = Arc::new(HealthSource { client, sync, last_import });
+	let make_service = make_service_fn(move |_| {
+		let source = source.clone();
+		async move {
+			Ok::<_, Infallible>(service_fn(move |request| {
+				let response = source.respond(request);
+				async move { Ok::<_, Infallible>(response) }
+			}))
+		}
+	});
+
+	let server = match Server::try_bind(&addr) {
+		Ok(builder) => builder.serve(make_service),
+		Err(e) => {
+			log::warn!(target: LOG_TARGET, "Health endpoint disabled, can not bind {}: {}", addr, e);
+			return
+		},
+	};
+	log::info!(target: LOG_TARGET, "Health endpoint listening on http://{}/health", addr);
+
+	let stopped = future::select(Box::pin(server), track_imports.boxed()).await;
+	if let future::Either::Left((Err(e), _)) = stopped {
+		log::warn!(target: LOG_TARGET, "Health endpoint stopped: {}", e);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn status_follows_sync_and_imports() {
+		assert_eq!(Status::new(true, Duration::ZERO), Status::Syncing);
+		assert_eq!(Status::new(true, STALL_TIMEOUT), Status::Syncing);
+		assert_eq!(Status::new(false, STALL_TIMEOUT - Duration::from_secs(1)), Status::Ok);
+		assert_eq!(Status::new(false, STALL_TIMEOUT), Status::Stalled);
+	}
+
+	#[test]
+	fn health_serializes_to_the_probe_schema() {
+		let health =
+			NodeHealth { status: Status::Syncing, peers: 3, best_block: 120, finalized_block: 118 };
+		assert_eq!(
+			serde_json::to_value(health).unwrap(),
+			serde_json::json!({
+				"status": "syncing",
+				"peers": 3,
+				"best_block": 120,
+				"finalized_block": 118,
+			})
+		);
+	}
+}
@@ -19,6 +19,8 @@
 #[cfg(feature = "cli")]
 mod benchmarking;
 pub mod chain_spec;
+pub mod health;
+pub mod metrics;
 
 #[cfg(feature = "cli")]
 mod cli;
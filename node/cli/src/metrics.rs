@@ -0,0 +1,255 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prometheus metrics of the runtime checks of the node.
+//!
+//! The author check of `CheckNetworkMembership` runs in the runtime, so the
+//! node counts its outcome from the transaction validity that the runtime
+//! returns to the transaction pool. `MeteredChainApi` wraps the chain API of
+//! the pool and hands every validation result to `MetricsHandle`, which
+//! counts it on the node registry next to the Substrate metrics.
+
+use cord_primitives::CordTransactionError;
+use futures::{future::BoxFuture, FutureExt};
+use prometheus_endpoint::{register, Counter, PrometheusError, Registry, U64};
+use sc_transaction_pool::ChainApi;
+use sc_transaction_pool_api::{
+	LocalTransactionFor, LocalTransactionPool, TransactionPool, TransactionSource, TxHash,
+};
+use sp_blockchain::TreeRoute;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, NumberFor},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError},
+};
+use std::sync::Arc;
+
+const LOG_TARGET: &str = "cord-metrics";
+
+/// Counters of the extrinsic author checks.
+#[derive(Clone)]
+pub struct MetricsHandle {
+	author_check_hits: Counter<U64>,
+	author_check_misses: Counter<U64>,
+}
+
+impl MetricsHandle {
+	/// Register the counters on `registry`.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			author_check_hits: register(
+				Counter::new(
+					"cord_extrinsic_author_check_hits_total",
+					"Number of extrinsics whose signer passed the network membership check",
+				)?,
+				registry,
+			)?,
+			author_check_misses: register(
+				Counter::new(
+					"cord_extrinsic_author_check_misses_total",
+					"Number of extrinsics whose signer failed the network membership check",
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	/// Count the author check behind the validity of one transaction.
+	///
+	/// A valid transaction passed the check and a transaction rejected with
+	/// `NotAuthorized` or `MembershipExpired` failed it. Any other rejection
+	/// is not counted, as the check may not have run.
+	pub fn note_validity(&self, validity: &TransactionValidity) {
+		match validity {
+			Ok(_) => self.author_check_hits.inc(),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Custom(code))) => {
+				if let Some(
+					CordTransactionError::NotAuthorized | CordTransactionError::MembershipExpired,
+				) = CordTransactionError::from_code(*code)
+				{
+					self.author_check_misses.inc()
+				}
+			},
+			Err(_) => {},
+		}
+	}
+
+	/// The number of author checks passed so far.
+	pub fn author_check_hits(&self) -> u64 {
+		self.author_check_hits.get()
+	}
+
+	/// The number of author checks failed so far.
+	pub fn author_check_misses(&self) -> u64 {
+		self.author_check_misses.get()
+	}
+}
+
+/// A transaction pool chain API counting the author checks of the
+/// transactions it validates.
+///
+/// Every validation of the pool is counted, revalidations included.
+pub struct MeteredChainApi<A> {
+	inner: A,
+	metrics: Option<MetricsHandle>,
+}
+
+impl<A> MeteredChainApi<A> {
+	/// Wrap `inner`, counting on `registry` if there is one.
+	pub fn new(inner: A, registry: Option<&Registry>) -> Self {
+		let metrics = registry.and_then(|registry| match MetricsHandle::register(registry) {
+			Ok(metrics) => Some(metrics),
+			Err(e) => {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to register author check metrics: {:?}",
+					e,
+				);
+				None
+			},
+		});
+		Self { inner, metrics }
+	}
+}
+
+impl<A> ChainApi for MeteredChainApi<A>
+where
+	A: ChainApi,
+	A::ValidationFuture: 'static,
+{
+	type Block = A::Block;
+	type Error = A::Error;
+	type ValidationFuture = BoxFuture<'static, Result<TransactionValidity, Self::Error>>;
+	type BodyFuture = A::BodyFuture;
+
+	fn validate_transaction(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		source: TransactionSource,
+		uxt: <Self::Block as BlockT>::Extrinsic,
+	) -> Self::ValidationFuture {
+		let metrics = self.metrics.clone();
+		self.inner
+			.validate_transaction(at, source, uxt)
+			.map(move |result| {
+				if let (Some(metrics), Ok(validity)) = (&metrics, &result) {
+					metrics.note_validity(validity);
+				}
+				result
+			})
+			.boxed()
+	}
+
+	fn block_id_to_number(
+		&self,
+		at: &BlockId<Self::Block>,
+	) -> Result<Option<NumberFor<Self::Block>>, Self::Error> {
+		self.inner.block_id_to_number(at)
+	}
+
+	fn block_id_to_hash(
+		&self,
+		at: &BlockId<Self::Block>,
+	) -> Result<Option<<Self::Block as BlockT>::Hash>, Self::Error> {
+		self.inner.block_id_to_hash(at)
+	}
+
+	fn hash_and_length(
+		&self,
+		uxt: &<Self::Block as BlockT>::Extrinsic,
+	) -> (<Self::Block as BlockT>::Hash, usize) {
+		self.inner.hash_and_length(uxt)
+	}
+
+	fn block_body(&self, at: <Self::Block as BlockT>::Hash) -> Self::BodyFuture {
+		self.inner.block_body(at)
+	}
+
+	fn block_header(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+	) -> Result<Option<<Self::Block as BlockT>::Header>, Self::Error> {
+		self.inner.block_header(at)
+	}
+
+	fn tree_route(
+		&self,
+		from: <Self::Block as BlockT>::Hash,
+		to: <Self::Block as BlockT>::Hash,
+	) -> Result<TreeRoute<Self::Block>, Self::Error> {
+		self.inner.tree_route(from, to)
+	}
+}
+
+/// Local submission of offchain transactions into a pool that is not built
+/// on the full chain API.
+///
+/// The submission waits for the validation of the pool, which runs on its
+/// own threads.
+pub struct LocalPool<P>(pub Arc<P>);
+
+impl<P> LocalTransactionPool for LocalPool<P>
+where
+	P: TransactionPool + 'static,
+{
+	type Block = P::Block;
+	type Hash = TxHash<P>;
+	type Error = P::Error;
+
+	fn submit_local(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		xt: LocalTransactionFor<Self>,
+	) -> Result<Self::Hash, Self::Error> {
+		futures::executor::block_on(self.0.submit_one(at, TransactionSource::Local, xt))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::transaction_validity::ValidTransaction;
+
+	#[test]
+	fn author_checks_are_counted() {
+		let registry = Registry::new();
+		let metrics = MetricsHandle::register(&registry).unwrap();
+		let not_authorized = InvalidTransaction::Custom(CordTransactionError::NotAuthorized.code());
+		let expired = InvalidTransaction::Custom(CordTransactionError::MembershipExpired.code());
+		let rate_limited =
+			InvalidTransaction::Custom(CordTransactionError::RateLimitExceeded.code());
+
+		metrics.note_validity(&Ok(ValidTransaction::default()));
+		metrics.note_validity(&Err(not_authorized.into()));
+		metrics.note_validity(&Err(expired.into()));
+		metrics.note_validity(&Err(rate_limited.into()));
+		metrics.note_validity(&Err(InvalidTransaction::BadProof.into()));
+		metrics.note_validity(&Ok(ValidTransaction::default()));
+
+		assert_eq!(metrics.author_check_hits(), 2);
+		assert_eq!(metrics.author_check_misses(), 2);
+
+		let names: Vec<_> = registry
+			.gather()
+			.into_iter()
+			.map(|family| family.get_name().to_owned())
+			.collect();
+		assert!(names.contains(&"cord_extrinsic_author_check_hits_total".to_owned()));
+		assert!(names.contains(&"cord_extrinsic_author_check_misses_total".to_owned()));
+	}
+}
@@ -21,7 +21,11 @@
 
 #![deny(unused_results)]
 
-use crate::cli::Cli;
+use crate::{
+	cli::Cli,
+	command::inject_key::InjectedKey,
+	metrics::{LocalPool, MeteredChainApi},
+};
 use codec::Encode;
 pub use cord_primitives::Block;
 pub use cord_runtime::RuntimeApi;
@@ -111,7 +115,10 @@ type FullGrandpaBlockImport =
 	sc_consensus_grandpa::GrandpaBlockImport<FullBackend, Block, FullClient, FullSelectChain>;
 
 /// The transaction pool type defintion.
-pub type TransactionPool = sc_transaction_pool::FullPool<Block, FullClient>;
+pub type TransactionPool = sc_transaction_pool::BasicPool<
+	MeteredChainApi<sc_transaction_pool::FullChainApi<FullClient, Block>>,
+	Block,
+>;
 
 /// The minimum period of blocks on which justifications will be
 /// imported and generated.
@@ -217,9 +224,10 @@ exists; qed",
 		.checked_next_power_of_two()
 		.map(|c| c / 2)
 		.unwrap_or(2) as u64;
-	let _tip = 0;
+	let tip = 0;
 	let extra: cord_runtime::SignedExtra = (
 		pallet_network_membership::CheckNetworkMembership::<cord_runtime::Runtime>::new(),
+		pallet_network_membership::RateLimiter::<cord_runtime::Runtime>::new(),
 		frame_system::CheckNonZeroSender::<cord_runtime::Runtime>::new(),
 		frame_system::CheckSpecVersion::<cord_runtime::Runtime>::new(),
 		frame_system::CheckTxVersion::<cord_runtime::Runtime>::new(),
@@ -230,12 +238,15 @@ exists; qed",
 		)),
 		frame_system::CheckNonce::<cord_runtime::Runtime>::from(nonce),
 		frame_system::CheckWeight::<cord_runtime::Runtime>::new(),
+		pallet_cord_fee_proxy::FeeProxyExtension::<cord_runtime::Runtime>::from(tip),
+		pallet_cord_fee_rebate::RebateFee::<cord_runtime::Runtime>::new(),
 	);
 
 	let raw_payload = cord_runtime::SignedPayload::from_raw(
 		function.clone(),
 		extra.clone(),
 		(
+			(),
 			(),
 			(),
 			cord_runtime::VERSION.spec_version,
@@ -244,6 +255,8 @@ exists; qed",
 			best_hash,
 			(),
 			(),
+			(),
+			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
@@ -267,11 +280,12 @@ pub fn new_partial(
 		FullBackend,
 		FullSelectChain,
 		sc_consensus::DefaultImportQueue<Block>,
-		sc_transaction_pool::FullPool<Block, FullClient>,
+		TransactionPool,
 		(
 			impl Fn(
 				cord_rpc::DenyUnsafe,
 				sc_rpc::SubscriptionTaskExecutor,
+				Arc<dyn cord_rpc::network::NetworkStatusProvider>,
 			) -> Result<jsonrpsee::RpcModule<()>, sc_service::Error>,
 			(
 				sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
@@ -312,13 +326,28 @@ pub fn new_partial(
 
 	let select_chain = sc_consensus::LongestChain::new(backend.clone());
 
-	let transaction_pool = sc_transaction_pool::BasicPool::new_full(
+	// Same as `BasicPool::new_full`, with the author checks of the validated
+	// transactions counted on the node registry.
+	let pool_api = MeteredChainApi::new(
+		sc_transaction_pool::FullChainApi::new(
+			client.clone(),
+			config.prometheus_registry(),
+			&task_manager.spawn_essential_handle(),
+		),
+		config.prometheus_registry(),
+	);
+	let chain_info = client.info();
+	let transaction_pool = Arc::new(sc_transaction_pool::BasicPool::with_revalidation_type(
 		config.transaction_pool.clone(),
 		config.role.is_authority().into(),
+		Arc::new(pool_api),
 		config.prometheus_registry(),
+		sc_transaction_pool::RevalidationType::Full,
 		task_manager.spawn_essential_handle(),
-		client.clone(),
-	);
+		chain_info.best_number,
+		chain_info.best_hash,
+		chain_info.finalized_hash,
+	));
 
 	#[allow(clippy::redundant_clone)]
 	let (grandpa_block_import, grandpa_link) = sc_consensus_grandpa::block_import(
@@ -356,7 +385,9 @@ pub fn new_partial(
 			spawner: &task_manager.spawn_essential_handle(),
 			registry: config.prometheus_registry(),
 			telemetry: telemetry.as_ref().map(|x| x.handle()),
-			offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool.clone()),
+			offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(LocalPool(
+				transaction_pool.clone(),
+			)),
 		})?;
 
 	let import_setup = (block_import, grandpa_link, babe_link);
@@ -379,9 +410,11 @@ pub fn new_partial(
 		let select_chain = select_chain.clone();
 		let keystore = keystore_container.keystore();
 		let chain_spec = config.chain_spec.cloned_box();
+		let genesis_hash = client.block_hash(0).ok().flatten().expect("Genesis block exists; qed");
+		let chain_id = crate::chain_spec::chain_id(&*chain_spec, &genesis_hash);
 
 		let rpc_backend = backend.clone();
-		let rpc_extensions_builder = move |deny_unsafe, subscription_executor| {
+		let rpc_extensions_builder = move |deny_unsafe, subscription_executor, network_status| {
 			let deps = cord_rpc::FullDeps {
 				client: client.clone(),
 				pool: pool.clone(),
@@ -400,6 +433,8 @@ pub fn new_partial(
 					finality_provider: finality_proof_provider.clone(),
 				},
 				backend: rpc_backend.clone(),
+				chain_id,
+				network_status,
 			};
 
 			cord_rpc::create_full(deps).map_err(Into::into)
@@ -440,6 +475,8 @@ pub struct NewFullBase {
 pub fn new_full_base(
 	config: Configuration,
 	disable_hardware_benchmarks: bool,
+	calibrate_weights: bool,
+	inject_keys: &[InjectedKey],
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
 		&sc_consensus_babe::BabeLink<Block>,
@@ -461,6 +498,22 @@ pub fn new_full_base(
 		}))
 		.flatten();
 
+	if calibrate_weights {
+		let hardware_score = hwbench
+			.as_ref()
+			.map(|hwbench| hwbench.cpu_hashrate_score)
+			.unwrap_or_else(|| sc_sysinfo::gather_hwbench(None).cpu_hashrate_score)
+			.as_kibs() as u64;
+		let weight = cord_runtime::calibrate_block_execution_weight(hardware_score);
+		log::info!(
+			"⚖️  Calibrated block execution weight: {} ps for a CPU score of {} KiB/s \
+			 (reference {} KiB/s)",
+			weight.ref_time(),
+			hardware_score,
+			cord_runtime::REFERENCE_HARDWARE_SCORE,
+		);
+	}
+
 	let sc_service::PartialComponents {
 		client,
 		backend,
@@ -472,6 +525,10 @@ pub fn new_full_base(
 		other: (rpc_builder, import_setup, rpc_setup, mut telemetry),
 	} = new_partial(&config)?;
 
+	for key in inject_keys {
+		key.insert(&*keystore_container.keystore()).map_err(ServiceError::Other)?;
+	}
+
 	let shared_voter_state = rpc_setup;
 	let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
 	let mut net_config = sc_network::config::FullNetworkConfiguration::new(&config.network);
@@ -502,6 +559,13 @@ pub fn new_full_base(
 			block_relay: None,
 		})?;
 
+	// The network state RPC reads from the syncing service, which only exists
+	// once the network is built.
+	let network_status: Arc<dyn cord_rpc::network::NetworkStatusProvider> = sync_service.clone();
+	let rpc_builder = move |deny_unsafe, subscription_executor| {
+		rpc_builder(deny_unsafe, subscription_executor, network_status.clone())
+	};
+
 	let rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
 		config,
 		backend: backend.clone(),
@@ -662,7 +726,9 @@ pub fn new_full_base(
 			voting_rule: sc_consensus_grandpa::VotingRulesBuilder::default().build(),
 			prometheus_registry,
 			shared_voter_state,
-			offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool.clone()),
+			offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(LocalPool(
+				transaction_pool.clone(),
+			)),
 		};
 
 		// the GRANDPA voter task is considered infallible, i.e.
@@ -682,9 +748,9 @@ pub fn new_full_base(
 				runtime_api_provider: client.clone(),
 				keystore: Some(keystore_container.keystore()),
 				offchain_db: backend.offchain_storage(),
-				transaction_pool: Some(OffchainTransactionPoolFactory::new(
+				transaction_pool: Some(OffchainTransactionPoolFactory::new(LocalPool(
 					transaction_pool.clone(),
-				)),
+				))),
 				network_provider: network.clone(),
 				is_validator: role.is_authority(),
 				enable_http_requests: true,
@@ -706,11 +772,56 @@ pub fn new_full_base(
 	})
 }
 
+/// Dial the `bootnodes_weighted` bootnodes of the chain spec first, highest
+/// priority first, followed by the remaining configured bootnodes.
+fn prioritise_weighted_bootnodes(config: &mut Configuration) -> Result<(), ServiceError> {
+	let mut boot_nodes = crate::chain_spec::weighted_bootnodes_from_spec(&*config.chain_spec)
+		.map_err(ServiceError::Other)?
+		.into_iter()
+		.map(|addr| {
+			addr.to_string().parse::<sc_network::config::MultiaddrWithPeerId>().map_err(|e| {
+				ServiceError::Other(format!("Weighted bootnode `{}` is invalid: {}", addr, e))
+			})
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	for bootnode in std::mem::take(&mut config.network.boot_nodes) {
+		if !boot_nodes.contains(&bootnode) {
+			boot_nodes.push(bootnode);
+		}
+	}
+	config.network.boot_nodes = boot_nodes;
+	Ok(())
+}
+
 /// Builds a new service for a full client.
-pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
+pub fn new_full(mut config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
+	prioritise_weighted_bootnodes(&mut config)?;
+	if cli.warp_sync {
+		config.network.sync_mode = sc_network::config::SyncMode::Warp;
+	}
 	let database_path = config.database.path().map(Path::to_path_buf);
-	let task_manager = new_full_base(config, cli.no_hardware_benchmarks, |_, _| ())
-		.map(|NewFullBase { task_manager, .. }| task_manager)?;
+	let NewFullBase { task_manager, client, sync, .. } =
+		new_full_base(
+		config,
+		cli.no_hardware_benchmarks,
+		cli.calibrate_weights,
+		&cli.inject_key,
+		|_, _| (),
+	)?;
+
+	if !cli.no_health {
+		let interface = if cli.health_external { [0, 0, 0, 0] } else { [127, 0, 0, 1] };
+		task_manager.spawn_handle().spawn(
+			"health-endpoint",
+			None,
+			crate::health::run(
+				std::net::SocketAddr::from((interface, cli.health_port)),
+				client,
+				sync,
+			),
+		);
+	}
 
 	if let Some(database_path) = database_path {
 		sc_storage_monitor::StorageMonitorService::try_spawn(
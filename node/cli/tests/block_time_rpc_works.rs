@@ -0,0 +1,55 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use std::time::Duration;
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use cord_cli_test_utils as common;
+use cord_runtime_constants::time::MILLISECS_PER_BLOCK;
+
+#[tokio::test]
+async fn block_time_rpc_reports_the_target_and_measured_block_time() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(common::start_node());
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		common::wait_n_finalized_blocks(3, &ws_url).await;
+
+		let rpc = ws_client(&ws_url).await.unwrap();
+		let target: u64 = rpc
+			.request("cord_targetBlockTimeMs", rpc_params![])
+			.await
+			.expect("cord_targetBlockTimeMs is exposed by the node");
+		assert_eq!(target, 6000);
+
+		// Timestamps of consecutive blocks are at least half a slot apart.
+		let measured: u64 = rpc
+			.request("cord_measuredBlockTimeMs", rpc_params![2])
+			.await
+			.expect("cord_measuredBlockTimeMs is exposed by the node");
+		assert!(measured >= MILLISECS_PER_BLOCK / 2, "measured block time {measured}ms");
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
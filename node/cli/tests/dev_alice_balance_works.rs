@@ -0,0 +1,55 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use assert_cmd::cargo::cargo_bin;
+use std::process::Command;
+use tempfile::tempdir;
+
+#[test]
+fn dev_alice_balance_overrides_alice_and_bob() {
+	let base_path = tempdir().expect("could not create a temp dir");
+
+	let output = Command::new(cargo_bin("cord"))
+		.args(["--dev-alice-balance", "5", "build-spec", "--dev", "-d"])
+		.arg(base_path.path())
+		.output()
+		.unwrap();
+	assert!(output.status.success());
+
+	let spec: serde_json::Value = serde_json::from_slice(output.stdout.as_slice()).unwrap();
+	let balances = spec["genesis"]["runtimeGenesis"]["patch"]["balances"]["balances"]
+		.as_array()
+		.expect("dev genesis endows accounts");
+	let overridden = balances
+		.iter()
+		.filter(|entry| entry[1] == serde_json::json!(5_000_000_000_000u128))
+		.count();
+	assert_eq!(overridden, 2);
+}
+
+#[test]
+fn dev_alice_balance_below_one_way_is_rejected() {
+	let base_path = tempdir().expect("could not create a temp dir");
+
+	let output = Command::new(cargo_bin("cord"))
+		.args(["--dev-alice-balance", "0", "build-spec", "--dev", "-d"])
+		.arg(base_path.path())
+		.output()
+		.unwrap();
+	assert!(!output.status.success());
+}
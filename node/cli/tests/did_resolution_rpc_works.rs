@@ -0,0 +1,128 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use serde_json::Value;
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use sp_keyring::AccountKeyring;
+use std::time::Duration;
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use cord_cli_test_utils as common;
+
+/// The development genesis anchors the DID document of Alice, who is also
+/// its creator.
+fn alice() -> String {
+	AccountKeyring::Alice.to_account_id().to_ss58check()
+}
+
+fn stranger() -> String {
+	AccountId32::new([0u8; 32]).to_ss58check()
+}
+
+#[tokio::test]
+async fn resolve_did_rpc_returns_the_anchored_document() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(common::start_node());
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+
+		let rpc = ws_client(&ws_url).await.unwrap();
+		let document: Option<Value> = rpc
+			.request("cord_resolveDid", rpc_params![alice()])
+			.await
+			.expect("cord_resolveDid is exposed by the node");
+		let document = document.expect("Alice anchored a DID document at genesis");
+		assert_eq!(document["active"], Value::Bool(true));
+		assert_eq!(document["creator"], Value::String(alice()));
+		assert_eq!(document["createdAt"], Value::from(0));
+
+		let document: Option<Value> = rpc
+			.request("cord_resolveDid", rpc_params![stranger()])
+			.await
+			.expect("cord_resolveDid is exposed by the node");
+		assert!(document.is_none());
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
+
+#[tokio::test]
+async fn did_exists_rpc_reports_anchored_dids() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(common::start_node());
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+		let block_hash = common::block_hash(1, &ws_url).await.unwrap();
+
+		let rpc = ws_client(&ws_url).await.unwrap();
+		let exists: bool = rpc
+			.request("cord_didExists", rpc_params![alice(), block_hash])
+			.await
+			.expect("cord_didExists is exposed by the node");
+		assert!(exists);
+
+		let exists: bool = rpc
+			.request("cord_didExists", rpc_params![stranger(), block_hash])
+			.await
+			.expect("cord_didExists is exposed by the node");
+		assert!(!exists);
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
+
+#[tokio::test]
+async fn list_dids_for_account_rpc_lists_the_dids_of_the_creator() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(common::start_node());
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+
+		let rpc = ws_client(&ws_url).await.unwrap();
+		let dids: Vec<String> = rpc
+			.request("cord_listDidsForAccount", rpc_params![alice()])
+			.await
+			.expect("cord_listDidsForAccount is exposed by the node");
+		assert_eq!(dids, vec![alice()]);
+
+		let dids: Vec<String> = rpc
+			.request("cord_listDidsForAccount", rpc_params![stranger()])
+			.await
+			.expect("cord_listDidsForAccount is exposed by the node");
+		assert!(dids.is_empty());
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
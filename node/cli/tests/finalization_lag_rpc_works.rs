@@ -0,0 +1,62 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use std::time::Duration;
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use cord_cli_test_utils as common;
+
+#[tokio::test]
+async fn finalization_lag_rpc_reports_no_lag_on_a_dev_chain() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(common::start_node());
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		common::wait_n_finalized_blocks(2, &ws_url).await;
+
+		// The only authority of the dev chain finalizes every block it
+		// authors, the best block may be ahead for a moment only.
+		let rpc = ws_client(&ws_url).await.unwrap();
+		let mut lag = u32::MAX;
+		for _ in 0..10 {
+			lag = rpc
+				.request("cord_finalizationLag", rpc_params![])
+				.await
+				.expect("cord_finalizationLag is exposed by the node");
+			if lag == 0 {
+				break
+			}
+			tokio::time::sleep(Duration::from_millis(500)).await;
+		}
+		assert_eq!(lag, 0);
+
+		let estimate: u64 = rpc
+			.request("cord_estimatedTimeToFinalize", rpc_params![2])
+			.await
+			.expect("cord_estimatedTimeToFinalize is exposed by the node");
+		assert_eq!(estimate, 2 * cord_runtime_constants::time::MILLISECS_PER_BLOCK);
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
@@ -0,0 +1,51 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use cord_primitives::Hash;
+use std::time::Duration;
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use cord_cli_test_utils as common;
+
+#[tokio::test]
+async fn genesis_hash_rpc_matches_block_zero() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(common::start_node());
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		// The runtime only learns the genesis hash once block 1 is imported.
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+
+		let rpc = ws_client(&ws_url).await.unwrap();
+		let runtime_genesis: Hash = rpc
+			.request("cord_genesisHash", rpc_params![])
+			.await
+			.expect("cord_genesisHash is exposed by the node");
+
+		let block_zero = common::block_hash(0, &ws_url).await.unwrap();
+		assert_eq!(runtime_genesis, block_zero);
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
@@ -0,0 +1,82 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use assert_cmd::cargo::cargo_bin;
+use std::{
+	io::{Read, Write},
+	net::TcpStream,
+	process::{self, Command},
+	time::Duration,
+};
+
+use cord_cli_test_utils as common;
+
+const HEALTH_PORT: u16 = 39955;
+
+/// Fetch `/health` and return the status line and the body of the response.
+fn get_health() -> (String, String) {
+	let mut stream = TcpStream::connect(("127.0.0.1", HEALTH_PORT)).unwrap();
+	stream
+		.write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+		.unwrap();
+	let mut response = String::new();
+	stream.read_to_string(&mut response).unwrap();
+
+	let (head, body) = response.split_once("\r\n\r\n").expect("response has a body");
+	(head.lines().next().unwrap().to_string(), body.to_string())
+}
+
+#[tokio::test]
+async fn health_endpoint_reports_the_node_status() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(
+			Command::new(cargo_bin("cord"))
+				.stdout(process::Stdio::piped())
+				.stderr(process::Stdio::piped())
+				.args(["--dev", "--tmp", "--no-hardware-benchmarks"])
+				.arg(format!("--health-port={}", HEALTH_PORT))
+				.spawn()
+				.unwrap(),
+		);
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+
+		let (status_line, body) = tokio::task::spawn_blocking(get_health).await.unwrap();
+		let health: serde_json::Value = serde_json::from_str(&body).unwrap();
+		let health = health.as_object().expect("health is a JSON object");
+
+		let mut keys = health.keys().map(String::as_str).collect::<Vec<_>>();
+		keys.sort();
+		assert_eq!(keys, ["best_block", "finalized_block", "peers", "status"]);
+		assert!(health["peers"].is_u64());
+		assert!(health["best_block"].as_u64().unwrap() >= 1);
+		assert!(health["finalized_block"].as_u64().unwrap() >= 1);
+
+		// The development node is alone, it is never syncing.
+		assert_eq!(health["status"], "ok");
+		assert!(status_line.contains("200"), "unexpected status line: {}", status_line);
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
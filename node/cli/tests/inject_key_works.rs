@@ -0,0 +1,74 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use assert_cmd::cargo::cargo_bin;
+use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+use std::{
+	process::{self, Command},
+	time::Duration,
+};
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use cord_cli_test_utils as common;
+
+#[tokio::test]
+async fn inject_key_seeds_the_keystore() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(
+			Command::new(cargo_bin("cord"))
+				.stdout(process::Stdio::piped())
+				.stderr(process::Stdio::piped())
+				.args(["--dev", "--tmp", "--no-hardware-benchmarks"])
+				.args(["--inject-key", "babe://Dave", "--inject-key", "gran://Dave"])
+				.spawn()
+				.unwrap(),
+		);
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		let rpc = ws_client(&ws_url).await.unwrap();
+		for (public, key_type) in [
+			(array_bytes::bytes2hex("0x", Sr25519Keyring::Dave.public()), "babe"),
+			(array_bytes::bytes2hex("0x", Ed25519Keyring::Dave.public()), "gran"),
+		] {
+			let has_key: bool = rpc
+				.request("author_hasKey", rpc_params![public, key_type])
+				.await
+				.expect("author_hasKey is exposed by the dev node");
+			assert!(has_key, "the `{}` key was not injected", key_type);
+		}
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
+
+#[test]
+fn inject_key_rejects_unknown_schemes() {
+	let output = Command::new(cargo_bin("cord"))
+		.args(["--dev", "--tmp", "--inject-key", "beef://Dave"])
+		.output()
+		.unwrap();
+
+	assert!(!output.status.success());
+	assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown key scheme"));
+}
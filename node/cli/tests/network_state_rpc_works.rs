@@ -0,0 +1,94 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use assert_cmd::cargo::cargo_bin;
+use serde_json::Value;
+use std::{
+	process::{Command, Stdio},
+	time::Duration,
+};
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use cord_cli_test_utils as common;
+
+/// Peer id derived from the node key passed to Alice.
+const ALICE_PEER_ID: &str = "12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp";
+
+fn start_local_node(args: &[&str]) -> common::KillChildOnDrop {
+	common::KillChildOnDrop(
+		Command::new(cargo_bin("cord"))
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.args(["--chain=local", "--tmp", "--validator", "--no-hardware-benchmarks"])
+			.args(args)
+			.spawn()
+			.unwrap(),
+	)
+}
+
+async fn network_state(url: &str) -> Value {
+	let rpc = ws_client(url).await.unwrap();
+	rpc.request("cord_networkState", rpc_params![])
+		.await
+		.expect("cord_networkState is exposed by the node")
+}
+
+#[tokio::test]
+async fn network_state_rpc_reports_the_connected_peers() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut alice = start_local_node(&[
+			"--alice",
+			"--port=30433",
+			"--rpc-port=45791",
+			"--node-key=0000000000000000000000000000000000000000000000000000000000000001",
+		]);
+		let alice_stderr = alice.stderr.take().unwrap();
+		let alice_url = common::extract_info_from_output(alice_stderr).0.ws_url;
+
+		let bootnode = format!("--bootnodes=/ip4/127.0.0.1/tcp/30433/p2p/{}", ALICE_PEER_ID);
+		let mut bob =
+			start_local_node(&["--bob", "--port=30434", "--rpc-port=45792", bootnode.as_str()]);
+		let bob_stderr = bob.stderr.take().unwrap();
+		let bob_url = common::extract_info_from_output(bob_stderr).0.ws_url;
+
+		let state = loop {
+			let state = network_state(&bob_url).await;
+			if state["peerCount"] == 1 {
+				break state;
+			}
+			tokio::time::sleep(Duration::from_secs(1)).await;
+		};
+
+		let peers = state["connectedPeers"].as_array().unwrap();
+		assert_eq!(peers.len(), 1);
+		assert_eq!(peers[0]["peerId"], ALICE_PEER_ID);
+		assert_eq!(peers[0]["roles"], "authority");
+		assert!(state["bestBlock"].is_u64());
+		assert!(state["isMajorSyncing"].is_boolean());
+
+		assert_eq!(network_state(&alice_url).await["peerCount"], 1);
+
+		alice.assert_still_running();
+		bob.assert_still_running();
+		bob.stop();
+		alice.stop();
+	})
+	.await;
+}
@@ -0,0 +1,57 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use serde_json::Value;
+use std::time::Duration;
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use cord_cli_test_utils as common;
+
+#[tokio::test]
+async fn pallet_list_rpc_lists_the_cord_pallets() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(common::start_node());
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+
+		let rpc = ws_client(&ws_url).await.unwrap();
+		let pallets: Vec<Value> = rpc
+			.request("cord_palletList", rpc_params![])
+			.await
+			.expect("cord_palletList is exposed by the node");
+
+		let network_membership = pallets
+			.iter()
+			.find(|pallet| pallet["name"] == "NetworkMembership")
+			.expect("the network membership pallet is listed");
+		assert_eq!(
+			network_membership["storagePrefix"],
+			array_bytes::bytes2hex("0x", sp_core::twox_128(b"NetworkMembership"))
+		);
+		assert!(pallets.iter().any(|pallet| pallet["name"] == "System"));
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
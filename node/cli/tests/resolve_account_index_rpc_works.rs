@@ -0,0 +1,61 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use sp_core::crypto::Ss58Codec;
+use sp_keyring::AccountKeyring;
+use std::time::Duration;
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use cord_cli_test_utils as common;
+
+#[tokio::test]
+async fn resolve_account_index_rpc_returns_the_assigned_account() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(common::start_node());
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+
+		let rpc = ws_client(&ws_url).await.unwrap();
+		let resolve = |index: u32| {
+			let rpc = &rpc;
+			async move {
+				rpc.request::<Option<String>, _>("cord_resolveAccountIndex", rpc_params![index])
+					.await
+					.expect("cord_resolveAccountIndex is exposed by the node")
+			}
+		};
+
+		// The development genesis assigns index 0 to Alice and 1 to Bob.
+		for (index, keyring) in [(0, AccountKeyring::Alice), (1, AccountKeyring::Bob)] {
+			let address = resolve(index).await.expect("the index is assigned at genesis");
+			let (account, _) = sp_core::crypto::AccountId32::from_ss58check_with_version(&address)
+				.expect("the RPC returns an SS58 address");
+			assert_eq!(account, keyring.to_account_id());
+		}
+		assert_eq!(resolve(42).await, None);
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
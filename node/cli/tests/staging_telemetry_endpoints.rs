@@ -0,0 +1,40 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use assert_cmd::cargo::cargo_bin;
+use std::process::Command;
+use tempfile::tempdir;
+
+#[test]
+fn staging_telemetry_endpoints_from_env() {
+	let base_path = tempdir().expect("could not create a temp dir");
+
+	let output = Command::new(cargo_bin("cord"))
+		.env(
+			"CORD_TELEMETRY_ENDPOINTS",
+			"wss://telemetry.cord.network/submit/=0,wss://telemetry.example.com/submit/=1",
+		)
+		.args(["build-spec", "--chain", "staging", "-d"])
+		.arg(base_path.path())
+		.output()
+		.unwrap();
+	assert!(output.status.success());
+
+	let spec: serde_json::Value = serde_json::from_slice(output.stdout.as_slice()).unwrap();
+	assert_eq!(spec["telemetryEndpoints"].as_array().map(|e| e.len()), Some(2));
+}
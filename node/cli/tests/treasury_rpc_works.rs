@@ -0,0 +1,98 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+
+use codec::Encode;
+use cord_node_testing::keyring::{alice, bob, sign, signed_extra};
+use cord_runtime::{CheckedExtrinsic, RuntimeCall, VERSION};
+use cord_runtime_constants::currency::WAY;
+use sp_core::{
+	crypto::{AccountId32, Ss58Codec},
+	Bytes,
+};
+use std::time::Duration;
+use substrate_rpc_client::{rpc_params, ws_client, ClientT, WsClient};
+
+use cord_cli_test_utils as common;
+
+async fn submit(rpc: &WsClient, nonce: u32, function: RuntimeCall, genesis_hash: [u8; 32]) {
+	let xt = sign(
+		CheckedExtrinsic { signed: Some((alice(), signed_extra(nonce, 0))), function },
+		VERSION.spec_version,
+		VERSION.transaction_version,
+		genesis_hash,
+	);
+	let _: serde_json::Value = rpc
+		.request("author_submitExtrinsic", rpc_params![Bytes(xt.encode())])
+		.await
+		.expect("the extrinsic is accepted by the pool");
+}
+
+async fn pending_proposals_count(rpc: &WsClient) -> u32 {
+	rpc.request("cord_pendingProposalsCount", rpc_params![])
+		.await
+		.expect("cord_pendingProposalsCount is exposed by the node")
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn treasury_rpc_reports_treasury_spend_proposals() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let mut node = common::KillChildOnDrop(common::start_node());
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+		let rpc = ws_client(&ws_url).await.unwrap();
+		assert_eq!(pending_proposals_count(&rpc).await, 0);
+
+		let genesis_hash = common::block_hash(0, &ws_url).await.unwrap().into();
+		let propose =
+			pallet_treasury::Call::propose_spend { value: 10 * WAY, beneficiary: bob().into() };
+		submit(&rpc, 0, RuntimeCall::Treasury(propose), genesis_hash).await;
+		common::wait_n_finalized_blocks(3, &ws_url).await;
+		assert_eq!(pending_proposals_count(&rpc).await, 1);
+
+		// Alice is the sudo key of the dev chain.
+		let approve = pallet_treasury::Call::approve_proposal { proposal_id: 0 };
+		let sudo = pallet_sudo::Call::sudo { call: Box::new(RuntimeCall::Treasury(approve)) };
+		submit(&rpc, 1, RuntimeCall::Sudo(sudo), genesis_hash).await;
+		common::wait_n_finalized_blocks(3, &ws_url).await;
+
+		let approved: Vec<serde_json::Value> = rpc
+			.request("cord_approvedProposals", rpc_params![])
+			.await
+			.expect("cord_approvedProposals is exposed by the node");
+		assert_eq!(approved.len(), 1);
+		assert_eq!(approved[0]["index"], serde_json::json!(0));
+		let proposer = approved[0]["proposer"].as_str().expect("the proposer is SS58 encoded");
+		assert_eq!(AccountId32::from_ss58check_with_version(proposer).unwrap().0, alice());
+		assert_eq!(pending_proposals_count(&rpc).await, 0);
+
+		let _: serde_json::Value = rpc
+			.request("cord_treasuryBalance", rpc_params![])
+			.await
+			.expect("cord_treasuryBalance is exposed by the node");
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
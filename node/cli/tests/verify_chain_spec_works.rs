@@ -0,0 +1,56 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use assert_cmd::cargo::cargo_bin;
+use std::process::Command;
+use tempfile::tempdir;
+
+#[test]
+fn verify_chain_spec_works() {
+	let base_path = tempdir().expect("could not create a temp dir");
+	let spec_path = base_path.path().join("local.json");
+
+	let output = Command::new(cargo_bin("cord"))
+		.args(["build-spec", "--chain", "local", "-d"])
+		.arg(base_path.path())
+		.output()
+		.unwrap();
+	assert!(output.status.success());
+	std::fs::write(&spec_path, &output.stdout).unwrap();
+
+	let status = Command::new(cargo_bin("cord"))
+		.args(["verify-chain-spec", "-c"])
+		.arg(&spec_path)
+		.status()
+		.unwrap();
+	assert!(status.success());
+
+	// Reuse the first authority keys for the second authority.
+	let mut spec: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+	let keys = &mut spec["genesis"]["runtimeGenesis"]["patch"]["session"]["keys"];
+	keys[1][2] = keys[0][2].clone();
+	std::fs::write(&spec_path, serde_json::to_vec(&spec).unwrap()).unwrap();
+
+	let output = Command::new(cargo_bin("cord"))
+		.args(["verify-chain-spec", "-c"])
+		.arg(&spec_path)
+		.output()
+		.unwrap();
+	assert!(!output.status.success());
+	assert!(String::from_utf8_lossy(&output.stdout).contains("babe"));
+}
@@ -0,0 +1,302 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC methods exposing the CORD runtime APIs.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use cord_runtime_api::{
+	CordAuthorityApi, CordBlockTimeApi, CordMetadataApi, CordRuntimeApi, CordSyncStateApi,
+};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use serde::{Deserialize, Serialize};
+use sp_api::{Core, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use sp_version::RuntimeVersion;
+
+/// Error code of runtime API call failures.
+const RUNTIME_ERROR: i32 = 1;
+
+/// The runtime version together with the CORD specific features the runtime
+/// was built with.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeVersionInfo {
+	/// The version reported by the runtime.
+	#[serde(flatten)]
+	pub version: RuntimeVersion,
+	/// Names of the CORD specific features enabled in the runtime.
+	pub features: Vec<String>,
+}
+
+/// A pallet of the runtime, with its storage prefix hex encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PalletMeta {
+	/// Name of the pallet in the runtime.
+	pub name: String,
+	/// Storage version of the pallet, 0 when none is recorded.
+	pub version: u32,
+	/// Prefix of the storage keys of the pallet, as `0x` prefixed hex.
+	pub storage_prefix: String,
+}
+
+impl From<cord_runtime_api::PalletMeta> for PalletMeta {
+	fn from(meta: cord_runtime_api::PalletMeta) -> Self {
+		let storage_prefix =
+			meta.storage_prefix.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+		Self {
+			name: String::from_utf8_lossy(&meta.name).into_owned(),
+			version: meta.version,
+			storage_prefix: format!("0x{}", storage_prefix),
+		}
+	}
+}
+
+/// CORD chain RPC methods.
+#[rpc(client, server)]
+pub trait CordApi<BlockHash, AccountIndex, AccountId> {
+	/// The genesis hash of the chain, as recorded by the runtime at the given
+	/// block, or at the best block when omitted.
+	#[method(name = "cord_genesisHash")]
+	fn genesis_hash(&self, at: Option<BlockHash>) -> RpcResult<BlockHash>;
+
+	/// The runtime version and CORD features at the given block, or at the
+	/// best block when omitted.
+	#[method(name = "cord_runtimeVersion")]
+	fn runtime_version(&self, at: Option<BlockHash>) -> RpcResult<RuntimeVersionInfo>;
+
+	/// The numeric chain id expected by EVM tooling.
+	#[method(name = "cord_chainId")]
+	fn chain_id(&self) -> RpcResult<u64>;
+
+	/// The account an account index is assigned to at the given block, or at
+	/// the best block when omitted. `None` when the index is unassigned.
+	#[method(name = "cord_resolveAccountIndex")]
+	fn resolve_account_index(
+		&self,
+		index: AccountIndex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AccountId>>;
+
+	/// The pallets of the runtime at the given block, or at the best block
+	/// when omitted, in the order of their index.
+	#[method(name = "cord_palletList")]
+	fn pallet_list(&self, at: Option<BlockHash>) -> RpcResult<Vec<PalletMeta>>;
+
+	/// Whether an account could author extrinsics at the given block, or at
+	/// the best block when omitted.
+	#[method(name = "cord_isAuthor")]
+	fn is_author(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+	/// The number of blocks the given block, or the best block when omitted,
+	/// is ahead of the last finalized block.
+	#[method(name = "cord_finalizationLag")]
+	fn finalization_lag(&self, at: Option<BlockHash>) -> RpcResult<u32>;
+
+	/// The estimated time, in milliseconds, until a finalization lag of `lag`
+	/// blocks is caught up, at the given block, or at the best block when
+	/// omitted.
+	#[method(name = "cord_estimatedTimeToFinalize")]
+	fn estimated_time_to_finalize(&self, lag: u32, at: Option<BlockHash>) -> RpcResult<u64>;
+
+	/// The time, in milliseconds, the runtime at the given block, or at the
+	/// best block when omitted, targets between two blocks.
+	#[method(name = "cord_targetBlockTimeMs")]
+	fn target_block_time_ms(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+
+	/// The average time, in milliseconds, between the last `window` blocks
+	/// up to the given block, or the best block when omitted. Zero if the
+	/// runtime keeps no timestamps for the window.
+	#[method(name = "cord_measuredBlockTimeMs")]
+	fn measured_block_time_ms(&self, window: u32, at: Option<BlockHash>) -> RpcResult<u64>;
+}
+
+/// Implementation of the CORD chain RPC methods.
+pub struct Cord<C, Block> {
+	client: Arc<C>,
+	chain_id: u64,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Cord<C, Block> {
+	/// Create a new instance of the CORD chain RPC methods.
+	pub fn new(client: Arc<C>, chain_id: u64) -> Self {
+		Self { client, chain_id, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountIndex, AccountId>
+	CordApiServer<<Block as BlockT>::Hash, AccountIndex, AccountId> for Cord<C, Block>
+where
+	Block: BlockT,
+	AccountIndex: Codec + Send + Sync + for<'de> Deserialize<'de> + 'static,
+	AccountId: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CordRuntimeApi<Block, AccountId, AccountIndex>
+		+ CordMetadataApi<Block>
+		+ CordAuthorityApi<Block, AccountId>
+		+ CordSyncStateApi<Block, NumberFor<Block>>
+		+ CordBlockTimeApi<Block>
+		+ Core<Block>,
+{
+	fn genesis_hash(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Block::Hash> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().genesis_hash(at).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query the genesis hash.",
+				Some(e.to_string()),
+			)
+		})
+	}
+
+	fn runtime_version(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RuntimeVersionInfo> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		let runtime_error = |e: sp_api::ApiError| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query the runtime version.",
+				Some(e.to_string()),
+			)
+		};
+
+		let version = api.version(at).map_err(runtime_error)?;
+		let features = api
+			.features(at)
+			.map_err(runtime_error)?
+			.into_iter()
+			.map(|feature| String::from_utf8_lossy(&feature).into_owned())
+			.collect();
+
+		Ok(RuntimeVersionInfo { version, features })
+	}
+
+	fn chain_id(&self) -> RpcResult<u64> {
+		Ok(self.chain_id)
+	}
+
+	fn resolve_account_index(
+		&self,
+		index: AccountIndex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AccountId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().resolve_account_index(at, index).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to resolve the account index.",
+				Some(e.to_string()),
+			)
+		})
+	}
+
+	fn pallet_list(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<PalletMeta>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let pallets = self.client.runtime_api().cord_pallet_list(at).map_err(|e| {
+			ErrorObject::owned(RUNTIME_ERROR, "Unable to list the pallets.", Some(e.to_string()))
+		})?;
+
+		Ok(pallets.into_iter().map(Into::into).collect())
+	}
+
+	fn is_author(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().is_author(at, account).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query the authorship of the account.",
+				Some(e.to_string()),
+			)
+		})
+	}
+
+	fn finalization_lag(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+		let info = self.client.info();
+		let at = at.unwrap_or(info.best_hash);
+
+		self.client
+			.runtime_api()
+			.finalization_lag(at, info.finalized_number)
+			.map_err(|e| {
+				ErrorObject::owned(
+					RUNTIME_ERROR,
+					"Unable to query the finalization lag.",
+					Some(e.to_string()),
+				)
+			})
+	}
+
+	fn estimated_time_to_finalize(
+		&self,
+		lag: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<u64> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().estimated_time_to_finalize(at, lag).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to estimate the time to finalize.",
+				Some(e.to_string()),
+			)
+		})
+	}
+
+	fn target_block_time_ms(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u64> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().target_block_time_ms(at).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query the target block time.",
+				Some(e.to_string()),
+			)
+		})
+	}
+
+	fn measured_block_time_ms(
+		&self,
+		window: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<u64> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().measured_block_time_ms(at, window).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query the measured block time.",
+				Some(e.to_string()),
+			)
+		})
+	}
+}
@@ -0,0 +1,162 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC methods resolving the DID documents anchored on a CORD chain.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use cord_runtime_api::CordIdentityApi as CordIdentityRuntimeApi;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code of runtime API call failures.
+const RUNTIME_ERROR: i32 = 1;
+
+/// The on-chain anchor of a DID document.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentInfo<AccountId, Hash, BlockNumber> {
+	/// Hash of the DID document.
+	pub digest: Hash,
+	/// Whether the document can still be updated, `false` once it was
+	/// deactivated.
+	pub active: bool,
+	/// The account that anchored the document.
+	pub creator: AccountId,
+	/// The block the document was first anchored in.
+	pub created_at: BlockNumber,
+	/// The block of the last update or deactivation of the document.
+	pub updated_at: BlockNumber,
+}
+
+impl<AccountId, Hash, BlockNumber>
+	From<cord_runtime_api::DidDocumentInfo<AccountId, Hash, BlockNumber>>
+	for DidDocumentInfo<AccountId, Hash, BlockNumber>
+{
+	fn from(info: cord_runtime_api::DidDocumentInfo<AccountId, Hash, BlockNumber>) -> Self {
+		Self {
+			digest: info.digest,
+			active: info.active,
+			creator: info.creator,
+			created_at: info.created_at,
+			updated_at: info.updated_at,
+		}
+	}
+}
+
+/// CORD DID resolution RPC methods.
+#[rpc(client, server)]
+pub trait CordIdentityApi<BlockHash, DidIdentifier, AccountId, BlockNumber> {
+	/// The document anchored by a DID at the given block, or at the best
+	/// block when omitted. `None` when the DID anchored no document.
+	#[method(name = "cord_resolveDid")]
+	fn resolve_did(
+		&self,
+		did: DidIdentifier,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<DidDocumentInfo<AccountId, BlockHash, BlockNumber>>>;
+
+	/// Whether a DID anchored a document at the given block, or at the best
+	/// block when omitted.
+	#[method(name = "cord_didExists")]
+	fn did_exists(&self, did: DidIdentifier, at: Option<BlockHash>) -> RpcResult<bool>;
+
+	/// The DIDs whose documents an account anchored, at the given block, or
+	/// at the best block when omitted.
+	#[method(name = "cord_listDidsForAccount")]
+	fn list_dids_for_account(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DidIdentifier>>;
+}
+
+/// Implementation of the CORD DID resolution RPC methods.
+pub struct CordIdentity<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> CordIdentity<C, Block> {
+	/// Create a new instance of the CORD DID resolution RPC methods.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, DidIdentifier, AccountId, BlockNumber>
+	CordIdentityApiServer<<Block as BlockT>::Hash, DidIdentifier, AccountId, BlockNumber>
+	for CordIdentity<C, Block>
+where
+	Block: BlockT,
+	DidIdentifier: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+	AccountId: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+	BlockNumber: Codec + Send + Sync + Serialize + 'static,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CordIdentityRuntimeApi<Block, DidIdentifier, AccountId, BlockNumber>,
+{
+	fn resolve_did(
+		&self,
+		did: DidIdentifier,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<DidDocumentInfo<AccountId, Block::Hash, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let document = self.client.runtime_api().resolve_did(at, did).map_err(|e| {
+			ErrorObject::owned(RUNTIME_ERROR, "Unable to resolve the DID.", Some(e.to_string()))
+		})?;
+
+		Ok(document.map(Into::into))
+	}
+
+	fn did_exists(
+		&self,
+		did: DidIdentifier,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().did_exists(at, did).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query the existence of the DID.",
+				Some(e.to_string()),
+			)
+		})
+	}
+
+	fn list_dids_for_account(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DidIdentifier>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().list_dids_for_account(at, account).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to list the DIDs of the account.",
+				Some(e.to_string()),
+			)
+		})
+	}
+}
@@ -20,7 +20,9 @@
 
 use std::sync::Arc;
 
-use cord_primitives::{AccountId, Block, BlockNumber, Hash, Nonce};
+use cord_primitives::{
+	AccountId, AccountIndex, Balance, Block, BlockNumber, DidIdentifier, Hash, Nonce,
+};
 use jsonrpsee::RpcModule;
 use sc_client_api::AuxStore;
 use sc_consensus_babe::BabeWorkerHandle;
@@ -35,6 +37,12 @@ use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
 use sp_keystore::KeystorePtr;
 
+pub mod cord;
+pub mod identity;
+pub mod network;
+pub mod snapshot;
+pub mod treasury;
+
 /// A type representing all RPC extensions.
 pub type RpcExtension = RpcModule<()>;
 
@@ -78,6 +86,10 @@ pub struct FullDeps<C, P, SC, B> {
 	pub grandpa: GrandpaDeps<B>,
 	/// The backend used by the node.
 	pub backend: Arc<B>,
+	/// The numeric chain id reported to EVM tooling.
+	pub chain_id: u64,
+	/// Source of the live network state of the node.
+	pub network_status: Arc<dyn network::NetworkStatusProvider>,
 }
 
 /// Instantiate all Full RPC extensions.
@@ -91,6 +103,8 @@ pub fn create_full<C, P, SC, B>(
 		babe,
 		grandpa,
 		backend,
+		chain_id,
+		network_status,
 	}: FullDeps<C, P, SC, B>,
 ) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
@@ -103,14 +117,31 @@ where
 		+ Sync
 		+ 'static,
 	C::Api: frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: cord_runtime_api::CordRuntimeApi<Block, AccountId, AccountIndex>,
+	C::Api: cord_runtime_api::CordMetadataApi<Block>,
+	C::Api: cord_runtime_api::CordAuthorityApi<Block, AccountId>,
+	C::Api: cord_runtime_api::CordSyncStateApi<Block, BlockNumber>,
+	C::Api: cord_runtime_api::CordTreasuryApi<Block, AccountId, Balance>,
+	C::Api: cord_runtime_api::CordBlockTimeApi<Block>,
+	C::Api: cord_runtime_api::CordIdentityApi<Block, DidIdentifier, AccountId, BlockNumber>,
+	C::Api: cord_runtime_api::CordSnapshotApi<Block, BlockNumber>,
 	P: TransactionPool + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use crate::{
+		cord::{Cord, CordApiServer},
+		identity::{CordIdentity, CordIdentityApiServer},
+		network::{CordNetwork, CordNetworkApiServer},
+		snapshot::{CordSnapshot, CordSnapshotApiServer},
+		treasury::{CordTreasury, CordTreasuryApiServer},
+	};
 	use frame_rpc_system::{System, SystemApiServer};
+	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
 	use sc_rpc::dev::{Dev, DevApiServer};
@@ -135,6 +166,7 @@ where
 	io.merge(ChainSpec::new(chain_name, genesis_hash, properties).into_rpc())?;
 
 	io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
+	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
 	io.merge(
 		Babe::new(client.clone(), babe_worker_handle.clone(), keystore, select_chain, deny_unsafe)
@@ -156,6 +188,11 @@ where
 			.into_rpc(),
 	)?;
 	io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
+	io.merge(Cord::new(client.clone(), chain_id).into_rpc())?;
+	io.merge(CordNetwork::new(client.clone(), network_status, deny_unsafe).into_rpc())?;
+	io.merge(CordTreasury::<_, _, Balance>::new(client.clone()).into_rpc())?;
+	io.merge(CordIdentity::new(client.clone()).into_rpc())?;
+	io.merge(CordSnapshot::new(client.clone()).into_rpc())?;
 	io.merge(Dev::new(client, deny_unsafe).into_rpc())?;
 
 	Ok(io)
@@ -0,0 +1,148 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC methods exposing the live network state of the node.
+
+use std::sync::Arc;
+
+use cord_primitives::{Block, BlockNumber, Hash};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+};
+use sc_network_sync::SyncingService;
+use sc_rpc_api::DenyUnsafe;
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_consensus::SyncOracle;
+
+/// A peer the node is connected to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerInfo {
+	/// Base58 encoded identity of the peer.
+	pub peer_id: String,
+	/// Role the peer announced, one of `authority`, `full` or `light`.
+	pub roles: String,
+	/// Hash of the best block announced by the peer.
+	pub best_hash: Hash,
+	/// Number of the best block announced by the peer.
+	pub best_number: BlockNumber,
+}
+
+/// The network state of the node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkStateInfo {
+	/// Number of peers the node is connected to.
+	pub peer_count: u32,
+	/// Number of the best block of the node.
+	pub best_block: u64,
+	/// Whether the node is far behind the chain and catching up.
+	pub is_major_syncing: bool,
+	/// The peers the node is connected to.
+	pub connected_peers: Vec<PeerInfo>,
+}
+
+/// Source of the network state reported by [`CordNetwork`].
+///
+/// The RPC extensions are built before the network is started, so the
+/// service hands the provider in once the network exists.
+#[async_trait]
+pub trait NetworkStatusProvider: Send + Sync {
+	/// The peers the node is currently connected to.
+	async fn connected_peers(&self) -> Vec<PeerInfo>;
+
+	/// Whether the node is performing a major sync.
+	fn is_major_syncing(&self) -> bool;
+}
+
+#[async_trait]
+impl NetworkStatusProvider for SyncingService<Block> {
+	async fn connected_peers(&self) -> Vec<PeerInfo> {
+		// The request only fails when the syncing engine is shutting down.
+		let peers = self.peers_info().await.unwrap_or_default();
+		peers
+			.into_iter()
+			.map(|(peer_id, info)| {
+				let roles = if info.roles.is_authority() {
+					"authority"
+				} else if info.roles.is_full() {
+					"full"
+				} else {
+					"light"
+				};
+				PeerInfo {
+					peer_id: peer_id.to_base58(),
+					roles: roles.into(),
+					best_hash: info.best_hash,
+					best_number: info.best_number,
+				}
+			})
+			.collect()
+	}
+
+	fn is_major_syncing(&self) -> bool {
+		SyncOracle::is_major_syncing(self)
+	}
+}
+
+/// CORD network RPC methods.
+#[rpc(client, server)]
+pub trait CordNetworkApi {
+	/// The peers, best block and sync status of the node.
+	#[method(name = "cord_networkState")]
+	async fn network_state(&self) -> RpcResult<NetworkStateInfo>;
+}
+
+/// Provides the network state of the node over RPC.
+pub struct CordNetwork<C> {
+	client: Arc<C>,
+	network_status: Arc<dyn NetworkStatusProvider>,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl<C> CordNetwork<C> {
+	/// Creates a new instance of the CORD network RPC handler.
+	pub fn new(
+		client: Arc<C>,
+		network_status: Arc<dyn NetworkStatusProvider>,
+		deny_unsafe: DenyUnsafe,
+	) -> Self {
+		Self { client, network_status, deny_unsafe }
+	}
+}
+
+#[async_trait]
+impl<C> CordNetworkApiServer for CordNetwork<C>
+where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+{
+	async fn network_state(&self) -> RpcResult<NetworkStateInfo> {
+		// Like `system_peers`, the peer list reveals the topology of the network.
+		self.deny_unsafe.check_if_safe()?;
+
+		let connected_peers = self.network_status.connected_peers().await;
+		Ok(NetworkStateInfo {
+			peer_count: connected_peers.len() as u32,
+			best_block: self.client.info().best_number.into(),
+			is_major_syncing: self.network_status.is_major_syncing(),
+			connected_peers,
+		})
+	}
+}
@@ -0,0 +1,107 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC methods returning the state snapshots published on a CORD chain.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use cord_runtime_api::CordSnapshotApi as CordSnapshotRuntimeApi;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code of runtime API call failures.
+const RUNTIME_ERROR: i32 = 1;
+
+/// A state snapshot published on-chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotInfo<Hash, BlockNumber> {
+	/// The block whose state the snapshot holds.
+	pub block: BlockNumber,
+	/// The state root of the block, to verify the snapshot against.
+	pub state_root: Hash,
+	/// The IPFS CID the snapshot can be fetched under.
+	pub ipfs_cid: String,
+}
+
+impl<Hash, BlockNumber> From<cord_runtime_api::SnapshotInfo<Hash, BlockNumber>>
+	for SnapshotInfo<Hash, BlockNumber>
+{
+	fn from(info: cord_runtime_api::SnapshotInfo<Hash, BlockNumber>) -> Self {
+		Self {
+			block: info.block,
+			state_root: info.state_root,
+			ipfs_cid: String::from_utf8_lossy(&info.ipfs_cid).into_owned(),
+		}
+	}
+}
+
+/// CORD state snapshot RPC methods.
+#[rpc(client, server)]
+pub trait CordSnapshotApi<BlockHash, BlockNumber> {
+	/// The latest state snapshot published at the given block, or at the
+	/// best block when omitted. `None` when no snapshot was published yet.
+	#[method(name = "cord_getLatestSnapshot")]
+	fn get_latest_snapshot(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<SnapshotInfo<BlockHash, BlockNumber>>>;
+}
+
+/// Implementation of the CORD state snapshot RPC methods.
+pub struct CordSnapshot<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> CordSnapshot<C, Block> {
+	/// Create a new instance of the CORD state snapshot RPC methods.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, BlockNumber> CordSnapshotApiServer<<Block as BlockT>::Hash, BlockNumber>
+	for CordSnapshot<C, Block>
+where
+	Block: BlockT,
+	BlockNumber: Codec + Send + Sync + Serialize + 'static,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CordSnapshotRuntimeApi<Block, BlockNumber>,
+{
+	fn get_latest_snapshot(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<SnapshotInfo<Block::Hash, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let snapshot = self.client.runtime_api().get_latest_snapshot(at).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query the latest snapshot.",
+				Some(e.to_string()),
+			)
+		})?;
+
+		Ok(snapshot.map(Into::into))
+	}
+}
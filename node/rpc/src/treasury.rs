@@ -0,0 +1,153 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC methods exposing the treasury of the CORD runtime.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use cord_runtime_api::CordTreasuryApi as CordTreasuryRuntimeApi;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code of runtime API call failures.
+const RUNTIME_ERROR: i32 = 1;
+
+/// An approved treasury spend proposal.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreasuryProposalInfo<AccountId> {
+	/// Index of the proposal in the treasury.
+	pub index: u32,
+	/// The account that made the proposal.
+	pub proposer: AccountId,
+	/// The account the treasury pays.
+	pub beneficiary: AccountId,
+	/// The amount the treasury pays.
+	pub value: NumberOrHex,
+	/// The deposit reserved from the proposer.
+	pub bond: NumberOrHex,
+}
+
+impl<AccountId, Balance: Into<NumberOrHex>>
+	From<cord_runtime_api::TreasuryProposalInfo<AccountId, Balance>>
+	for TreasuryProposalInfo<AccountId>
+{
+	fn from(info: cord_runtime_api::TreasuryProposalInfo<AccountId, Balance>) -> Self {
+		Self {
+			index: info.index,
+			proposer: info.proposer,
+			beneficiary: info.beneficiary,
+			value: info.value.into(),
+			bond: info.bond.into(),
+		}
+	}
+}
+
+/// CORD treasury RPC methods.
+#[rpc(client, server)]
+pub trait CordTreasuryApi<BlockHash, AccountId> {
+	/// The free balance of the treasury at the given block, or at the best
+	/// block when omitted.
+	#[method(name = "cord_treasuryBalance")]
+	fn treasury_balance(&self, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+	/// The approved treasury spend proposals awaiting payout at the given
+	/// block, or at the best block when omitted.
+	#[method(name = "cord_approvedProposals")]
+	fn approved_proposals(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TreasuryProposalInfo<AccountId>>>;
+
+	/// The number of treasury spend proposals not approved yet at the given
+	/// block, or at the best block when omitted. The count is capped at
+	/// `MAX_TREASURY_PROPOSALS_COUNTED`.
+	#[method(name = "cord_pendingProposalsCount")]
+	fn pending_proposals_count(&self, at: Option<BlockHash>) -> RpcResult<u32>;
+}
+
+/// Implementation of the CORD treasury RPC methods.
+pub struct CordTreasury<C, Block, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(Block, Balance)>,
+}
+
+impl<C, Block, Balance> CordTreasury<C, Block, Balance> {
+	/// Create a new instance of the CORD treasury RPC methods.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Balance> CordTreasuryApiServer<<Block as BlockT>::Hash, AccountId>
+	for CordTreasury<C, Block, Balance>
+where
+	Block: BlockT,
+	AccountId: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+	Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CordTreasuryRuntimeApi<Block, AccountId, Balance>,
+{
+	fn treasury_balance(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let balance = self.client.runtime_api().treasury_balance(at).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query the treasury balance.",
+				Some(e.to_string()),
+			)
+		})?;
+
+		Ok(balance.into())
+	}
+
+	fn approved_proposals(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<TreasuryProposalInfo<AccountId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let proposals = self.client.runtime_api().approved_proposals(at).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to list the approved treasury proposals.",
+				Some(e.to_string()),
+			)
+		})?;
+
+		Ok(proposals.into_iter().map(Into::into).collect())
+	}
+
+	fn pending_proposals_count(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().pending_proposals_count(at).map_err(|e| {
+			ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to count the pending treasury proposals.",
+				Some(e.to_string()),
+			)
+		})
+	}
+}
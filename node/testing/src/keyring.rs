@@ -68,9 +68,10 @@ pub fn to_session_keys(
 }
 
 /// Returns transaction extra.
-pub fn signed_extra(nonce: Nonce, _extra_fee: Balance) -> SignedExtra {
+pub fn signed_extra(nonce: Nonce, extra_fee: Balance) -> SignedExtra {
 	(
 		pallet_network_membership::CheckNetworkMembership::new(),
+		pallet_network_membership::RateLimiter::new(),
 		frame_system::CheckNonZeroSender::new(),
 		frame_system::CheckSpecVersion::new(),
 		frame_system::CheckTxVersion::new(),
@@ -78,6 +79,8 @@ pub fn signed_extra(nonce: Nonce, _extra_fee: Balance) -> SignedExtra {
 		frame_system::CheckEra::from(Era::mortal(256, 0)),
 		frame_system::CheckNonce::from(nonce),
 		frame_system::CheckWeight::new(),
+		pallet_cord_fee_proxy::FeeProxyExtension::from(extra_fee),
+		pallet_cord_fee_rebate::RebateFee::new(),
 	)
 }
 
@@ -0,0 +1,93 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::traits::{EnsureOrigin, Get};
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// A permission distinct for every `index`.
+fn permission(index: u32) -> PermissionId {
+	let mut permission = [0u8; 32];
+	permission[..4].copy_from_slice(&index.to_le_bytes());
+	permission
+}
+
+/// Grant `count` permissions to `who`.
+fn grant_permissions<T: Config>(who: &T::AccountId, count: u32) {
+	Permissions::<T>::mutate(who, |permissions| {
+		for index in 0..count {
+			permissions
+				.try_insert(permission(index))
+				.expect("The permissions should not exceed `MaxPermissions`.");
+		}
+	});
+}
+
+benchmarks! {
+	grant_permission {
+		let who: T::AccountId = account("who", 0, SEED);
+		grant_permissions::<T>(&who, T::MaxPermissions::get() - 1);
+		let granted = permission(T::MaxPermissions::get());
+		let origin =
+			T::PermissionOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, who.clone(), granted)
+	verify {
+		assert!(Pallet::<T>::has_permission(&who, &granted));
+		assert_last_event::<T>(Event::PermissionGranted { who, permission: granted }.into());
+	}
+
+	revoke_permission {
+		let who: T::AccountId = account("who", 0, SEED);
+		grant_permissions::<T>(&who, T::MaxPermissions::get());
+		let revoked = permission(T::MaxPermissions::get() - 1);
+		let origin =
+			T::PermissionOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, who.clone(), revoked)
+	verify {
+		assert!(!Pallet::<T>::has_permission(&who, &revoked));
+		assert_last_event::<T>(Event::PermissionRevoked { who, permission: revoked }.into());
+	}
+
+	has_permission {
+		// The account holds `MaxPermissions` permissions, and the last one is
+		// checked.
+		let who: T::AccountId = account("who", 0, SEED);
+		grant_permissions::<T>(&who, T::MaxPermissions::get());
+		let checked = permission(T::MaxPermissions::get() - 1);
+	}: {
+		assert!(Pallet::<T>::has_permission(&who, &checked));
+	}
+	verify {
+		assert_eq!(Permissions::<T>::get(&who).len() as u32, T::MaxPermissions::get());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
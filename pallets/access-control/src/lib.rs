@@ -0,0 +1,184 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Access Control Pallet
+//!
+//! The Access Control Pallet grants accounts fine-grained permissions, where
+//! the authorship of extrinsics only tells whether an account may author
+//! extrinsics at all. Every account holds a set of at most `MaxPermissions`
+//! permissions, managed by the `PermissionOrigin`. Other pallets check a
+//! permission with `has_permission`, or through the `HasPermission` trait
+//! to stay decoupled from this pallet.
+//!
+//! A permission is identified by 32 bytes, such as the blake2-256 hash of
+//! its name.
+//!
+//! ### Storage
+//!
+//! - `Permissions`: The permissions granted to every account.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `grant_permission` - Grant a permission to an account.
+//! - `revoke_permission` - Revoke a permission from an account.
+//!
+//! ### Public Functions
+//!
+//! - `has_permission` - Whether an account was granted a permission.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Identifier of a permission.
+pub type PermissionId = [u8; 32];
+
+/// Permissions granted to accounts.
+pub trait HasPermission<AccountId> {
+	/// Whether `who` was granted `permission`.
+	fn has_permission(who: &AccountId, permission: &PermissionId) -> bool;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, storage::bounded_btree_set::BoundedBTreeSet};
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of the permissions of an account.
+	pub type PermissionsOf<T> = BoundedBTreeSet<PermissionId, <T as Config>::MaxPermissions>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin that grants and revokes permissions.
+		type PermissionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The most permissions of an account.
+		#[pallet::constant]
+		type MaxPermissions: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// permissions granted to the accounts
+	#[pallet::storage]
+	#[pallet::getter(fn permissions)]
+	pub type Permissions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, PermissionsOf<T>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A permission was granted to an account.
+		PermissionGranted { who: T::AccountId, permission: PermissionId },
+		/// A permission was revoked from an account.
+		PermissionRevoked { who: T::AccountId, permission: PermissionId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account was already granted the permission.
+		AlreadyGranted,
+		/// The account was not granted the permission.
+		NotGranted,
+		/// The account already holds `MaxPermissions` permissions.
+		TooManyPermissions,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Grant `permission` to `who`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::grant_permission())]
+		pub fn grant_permission(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			permission: PermissionId,
+		) -> DispatchResult {
+			T::PermissionOrigin::ensure_origin(origin)?;
+
+			Permissions::<T>::try_mutate(&who, |permissions| {
+				match permissions.try_insert(permission) {
+					Ok(true) => Ok(()),
+					Ok(false) => Err(Error::<T>::AlreadyGranted),
+					Err(_) => Err(Error::<T>::TooManyPermissions),
+				}
+			})?;
+
+			Self::deposit_event(Event::PermissionGranted { who, permission });
+			Ok(())
+		}
+
+		/// Revoke `permission` from `who`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_permission())]
+		pub fn revoke_permission(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			permission: PermissionId,
+		) -> DispatchResult {
+			T::PermissionOrigin::ensure_origin(origin)?;
+
+			Permissions::<T>::try_mutate_exists(&who, |permissions| {
+				let held = permissions.as_mut().ok_or(Error::<T>::NotGranted)?;
+				ensure!(held.remove(&permission), Error::<T>::NotGranted);
+				if held.is_empty() {
+					*permissions = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::PermissionRevoked { who, permission });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `who` was granted `permission`.
+	pub fn has_permission(who: &T::AccountId, permission: &PermissionId) -> bool {
+		Permissions::<T>::get(who).contains(permission)
+	}
+}
+
+impl<T: Config> HasPermission<T::AccountId> for Pallet<T> {
+	fn has_permission(who: &T::AccountId, permission: &PermissionId) -> bool {
+		Self::has_permission(who, permission)
+	}
+}
@@ -0,0 +1,76 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+const ISSUE_CREDENTIAL: PermissionId = [1u8; 32];
+const REVOKE_CREDENTIAL: PermissionId = [2u8; 32];
+
+#[test]
+fn granted_permissions_are_held_until_revoked() {
+	new_test_ext().execute_with(|| {
+		assert!(!AccessControl::has_permission(&1, &ISSUE_CREDENTIAL));
+
+		assert_noop!(
+			AccessControl::grant_permission(RuntimeOrigin::signed(1), 1, ISSUE_CREDENTIAL),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(AccessControl::grant_permission(RuntimeOrigin::root(), 1, ISSUE_CREDENTIAL));
+		System::assert_last_event(
+			Event::PermissionGranted { who: 1, permission: ISSUE_CREDENTIAL }.into(),
+		);
+		assert!(AccessControl::has_permission(&1, &ISSUE_CREDENTIAL));
+		assert!(!AccessControl::has_permission(&1, &REVOKE_CREDENTIAL));
+		assert!(!AccessControl::has_permission(&2, &ISSUE_CREDENTIAL));
+		assert_noop!(
+			AccessControl::grant_permission(RuntimeOrigin::root(), 1, ISSUE_CREDENTIAL),
+			Error::<Test>::AlreadyGranted
+		);
+
+		assert_ok!(AccessControl::revoke_permission(RuntimeOrigin::root(), 1, ISSUE_CREDENTIAL));
+		System::assert_last_event(
+			Event::PermissionRevoked { who: 1, permission: ISSUE_CREDENTIAL }.into(),
+		);
+		assert!(!AccessControl::has_permission(&1, &ISSUE_CREDENTIAL));
+		// The entry of an account is removed with its last permission.
+		assert!(!Permissions::<Test>::contains_key(1));
+		assert_noop!(
+			AccessControl::revoke_permission(RuntimeOrigin::root(), 1, ISSUE_CREDENTIAL),
+			Error::<Test>::NotGranted
+		);
+	});
+}
+
+#[test]
+fn grant_permission_is_bounded_by_max_permissions() {
+	new_test_ext().execute_with(|| {
+		for i in 0..MAX_PERMISSIONS {
+			assert_ok!(AccessControl::grant_permission(RuntimeOrigin::root(), 1, [i as u8; 32]));
+		}
+		assert_noop!(
+			AccessControl::grant_permission(RuntimeOrigin::root(), 1, [u8::MAX; 32]),
+			Error::<Test>::TooManyPermissions
+		);
+		// Other accounts hold permissions of their own.
+		assert_ok!(AccessControl::grant_permission(RuntimeOrigin::root(), 2, [u8::MAX; 32]));
+		assert_eq!(AccessControl::permissions(1).len() as u32, MAX_PERMISSIONS);
+	});
+}
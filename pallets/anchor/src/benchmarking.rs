@@ -0,0 +1,89 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use codec::{Encode, MaxEncodedLen};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// Number of anchors stored before each benchmark runs.
+const PREPOPULATED: u32 = 1_000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn data_hash(index: u32) -> H256 {
+	<sp_runtime::traits::BlakeTwo256 as Hash>::hash(&index.encode())
+}
+
+fn fund<T: Config>(who: &T::AccountId) {
+	T::StorageDeposit::fund(who, AnchorEntryOf::<T>::max_encoded_len() as u32);
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	fund::<T>(&who);
+	who
+}
+
+/// Fill the anchors map with `count` anchors by distinct authors.
+fn add_anchors<T: Config>(count: u32) {
+	for i in 0..count {
+		let author = funded_account::<T>("author", i);
+		Pallet::<T>::anchor(RawOrigin::Signed(author).into(), data_hash(i), BoundedVec::new())
+			.expect("data hashes are unique");
+	}
+}
+
+benchmarks! {
+	anchor {
+		let l in 0 .. T::MaxMetaLen::get();
+
+		add_anchors::<T>(PREPOPULATED);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let data = data_hash(PREPOPULATED);
+		let meta = BoundedVec::truncate_from(vec![0u8; l as usize]);
+	}: _(RawOrigin::Signed(caller.clone()), data, meta)
+	verify {
+		assert!(Anchors::<T>::contains_key(data));
+		assert_last_event::<T>(
+			Event::Anchored {
+				author: caller,
+				data_hash: data,
+				block: frame_system::Pallet::<T>::block_number(),
+			}
+			.into()
+		);
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,142 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Anchor Pallet
+//!
+//! The Anchor Pallet lets an account anchor the hash of off-chain data on
+//! chain, so that the existence of the data at a given block can be audited
+//! later. An anchor keeps its author, the block it was created at and
+//! metadata of up to `MaxMetaLen` bytes. Anchors are immutable: a hash can be
+//! anchored once, and the anchor is never updated or removed.
+//!
+//! Creating an anchor holds the storage deposit of the anchor from the
+//! author, through `StorageDeposit`. As anchors are never removed, the
+//! deposit is never released.
+//!
+//! ### Storage
+//!
+//! - `Anchors`: The anchor of every anchored data hash.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `anchor` - Anchor a data hash.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::Encode;
+use cord_utilities::traits::StorageDeposit;
+pub use pallet::*;
+use sp_core::H256;
+pub use types::AnchorEntry;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Metadata of an anchor.
+	pub type AnchorMetaOf<T> = BoundedVec<u8, <T as Config>::MaxMetaLen>;
+	/// Type of an anchor.
+	pub type AnchorEntryOf<T> =
+		AnchorEntry<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, AnchorMetaOf<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Holds the storage deposit of an anchor.
+		type StorageDeposit: StorageDeposit<Self::AccountId>;
+		/// The maximum length of the metadata of an anchor.
+		#[pallet::constant]
+		type MaxMetaLen: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// anchors by data hash
+	#[pallet::storage]
+	#[pallet::getter(fn anchors)]
+	pub type Anchors<T: Config> =
+		StorageMap<_, Blake2_128Concat, H256, AnchorEntryOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A data hash was anchored.
+		Anchored { author: T::AccountId, data_hash: H256, block: BlockNumberFor<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The data hash is already anchored.
+		AnchorAlreadyExists,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Anchor `data_hash` with `meta`, as of the current block. The
+		/// storage deposit of the anchor is held from the caller.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::anchor(meta.len() as u32))]
+		pub fn anchor(
+			origin: OriginFor<T>,
+			data_hash: H256,
+			meta: AnchorMetaOf<T>,
+		) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+			ensure!(!Anchors::<T>::contains_key(data_hash), Error::<T>::AnchorAlreadyExists);
+
+			let block = frame_system::Pallet::<T>::block_number();
+			let entry = AnchorEntry { author: author.clone(), block, meta };
+			T::StorageDeposit::hold(&author, &data_hash, entry.encoded_size() as u32)?;
+			Anchors::<T>::insert(data_hash, entry);
+
+			Self::deposit_event(Event::Anchored { author, data_hash, block });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The anchor of `data_hash`, if any.
+	pub fn get_anchor(data_hash: &H256) -> Option<AnchorEntryOf<T>> {
+		Anchors::<T>::get(data_hash)
+	}
+}
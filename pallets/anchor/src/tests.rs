@@ -0,0 +1,93 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency, BoundedVec};
+use sp_runtime::traits::BadOrigin;
+
+const DATA: H256 = H256::repeat_byte(1);
+
+fn meta(bytes: &[u8]) -> AnchorMetaOf<Test> {
+	BoundedVec::truncate_from(bytes.to_vec())
+}
+
+#[test]
+fn anchor_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Anchor::anchor(RuntimeOrigin::root(), DATA, meta(b"")), BadOrigin);
+
+		System::set_block_number(5);
+		assert_ok!(Anchor::anchor(RuntimeOrigin::signed(ALICE), DATA, meta(b"report.pdf")));
+		assert_eq!(
+			Anchor::get_anchor(&DATA),
+			Some(AnchorEntry { author: ALICE, block: 5, meta: meta(b"report.pdf") })
+		);
+		System::assert_last_event(
+			Event::Anchored { author: ALICE, data_hash: DATA, block: 5 }.into(),
+		);
+	});
+}
+
+#[test]
+fn anchor_holds_the_storage_deposit_of_the_entry() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Anchor::anchor(RuntimeOrigin::signed(ALICE), DATA, meta(b"meta")));
+		let entry = Anchor::get_anchor(&DATA).unwrap();
+		let first = DEPOSIT_PER_BYTE * entry.encoded_size() as Balance;
+		assert_eq!(Balances::reserved_balance(ALICE), first);
+
+		// the deposit of a second anchor adds up
+		let data = H256::repeat_byte(2);
+		assert_ok!(Anchor::anchor(RuntimeOrigin::signed(ALICE), data, meta(b"")));
+		let second =
+			DEPOSIT_PER_BYTE * Anchor::get_anchor(&data).unwrap().encoded_size() as Balance;
+		assert!(second < first);
+		assert_eq!(Balances::reserved_balance(ALICE), first + second);
+	});
+}
+
+#[test]
+fn anchor_requires_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::reserve(&BOB, INITIAL_BALANCE - 5));
+
+		assert_noop!(
+			Anchor::anchor(RuntimeOrigin::signed(BOB), DATA, meta(b"")),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert!(Anchor::get_anchor(&DATA).is_none());
+	});
+}
+
+#[test]
+fn anchors_are_immutable() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Anchor::anchor(RuntimeOrigin::signed(ALICE), DATA, meta(b"first")));
+
+		assert_noop!(
+			Anchor::anchor(RuntimeOrigin::signed(ALICE), DATA, meta(b"second")),
+			Error::<Test>::AnchorAlreadyExists
+		);
+		assert_noop!(
+			Anchor::anchor(RuntimeOrigin::signed(BOB), DATA, meta(b"first")),
+			Error::<Test>::AnchorAlreadyExists
+		);
+		assert_eq!(Anchor::get_anchor(&DATA).map(|entry| entry.author), Some(ALICE));
+	});
+}
@@ -0,0 +1,114 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Encode;
+use cord_utilities::traits::GenerateBenchmarkOrigin;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::sp_runtime::traits::Hash;
+use identifier::{IdentifierType, Ss58Identifier};
+use pallet_asset::{AssetStatusOf, VCAssetEntryOf};
+use pallet_cord_credentials::CredentialEntryOf;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn generate_identifier<T: Config>(seed: u8, id_type: IdentifierType) -> Ss58Identifier {
+	let digest = <T as frame_system::Config>::Hashing::hash(&[seed; 32][..]);
+	Ss58Identifier::create_identifier(&digest.encode()[..], id_type).unwrap()
+}
+
+/// Stores an active VC asset issued by `did` and a credential issued by
+/// `did` to `holder`. A VC asset is the worst case, as the regular assets
+/// are looked up first.
+fn setup<T: Config>(
+	did: &T::SpaceCreatorId,
+	holder: T::AccountId,
+) -> (AssetIdOf, CredentialIdOf<T>) {
+	let asset_id = generate_identifier::<T>(1, IdentifierType::Asset);
+	pallet_asset::VCAssets::<T>::insert(
+		&asset_id,
+		VCAssetEntryOf::<T> {
+			digest: <T as frame_system::Config>::Hashing::hash(&[2u8; 32][..]),
+			asset_issuance: 0,
+			asset_status: AssetStatusOf::ACTIVE,
+			asset_issuer: did.clone(),
+			asset_qty: 10,
+			created_at: frame_system::Pallet::<T>::block_number(),
+		},
+	);
+
+	let credential_id = <T as frame_system::Config>::Hashing::hash(&[3u8; 32][..]);
+	pallet_cord_credentials::Credentials::<T>::insert(
+		credential_id,
+		CredentialEntryOf::<T> {
+			schema: generate_identifier::<T>(4, IdentifierType::Schema),
+			statement: generate_identifier::<T>(5, IdentifierType::Statement),
+			digest: <T as frame_system::Config>::Hashing::hash(&[6u8; 32][..]),
+			issuer: did.clone(),
+			holder,
+			created_at: frame_system::Pallet::<T>::block_number(),
+		},
+	);
+
+	(asset_id, credential_id)
+}
+
+benchmarks! {
+	where_clause {
+		where
+		<T as pallet_asset::Config>::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::SpaceCreatorId>,
+	}
+	link_asset_to_credential {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let holder: T::AccountId = account("holder", 0, SEED);
+
+		let (asset_id, credential_id) = setup::<T>(&did, holder);
+		let origin = <T as pallet_asset::Config>::EnsureOrigin::generate_origin(caller, did.clone());
+	}: _<T::RuntimeOrigin>(origin, asset_id.clone(), credential_id)
+	verify {
+		assert_eq!(AssetCredentialLink::<T>::get(&asset_id), Some(credential_id));
+		assert_last_event::<T>(Event::AssetLinked { asset_id, credential_id, issuer: did }.into());
+	}
+
+	unlink_asset {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let holder: T::AccountId = account("holder", 0, SEED);
+
+		let (asset_id, credential_id) = setup::<T>(&did, holder);
+		let origin = <T as pallet_asset::Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		Pallet::<T>::link_asset_to_credential(origin.clone(), asset_id.clone(), credential_id)?;
+	}: _<T::RuntimeOrigin>(origin, asset_id.clone())
+	verify {
+		assert!(!AssetCredentialLink::<T>::contains_key(&asset_id));
+		assert_last_event::<T>(Event::AssetUnlinked { asset_id, credential_id, issuer: did }.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
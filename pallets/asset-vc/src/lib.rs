@@ -0,0 +1,212 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Asset VC Pallet
+//!
+//! Assets and credentials are otherwise independent. This pallet links an
+//! asset to the credential that backs it, so that the holder of an asset
+//! can be checked against a verifiable credential. The asset issuer links
+//! an active asset to a credential whose statement has not been revoked,
+//! and can remove the link again.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use crate::{pallet::*, weights::WeightInfo};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use cord_utilities::traits::CallSources;
+	use frame_support::{pallet_prelude::*, traits::StorageVersion};
+	use frame_system::pallet_prelude::*;
+	use pallet_asset::{AssetCreatorOf, AssetStatusOf};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of an asset identifier.
+	pub type AssetIdOf = pallet_asset::AssetIdOf;
+	/// Type of a credential identifier.
+	pub type CredentialIdOf<T> = pallet_cord_credentials::CredentialIdOf<T>;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_asset::Config + pallet_cord_credentials::Config
+	{
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Credentials backing assets, keyed by asset identifier.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_credential_link)]
+	pub type AssetCredentialLink<T> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf, CredentialIdOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An asset has been linked to a credential.
+		/// \[asset identifier, credential identifier, asset issuer\]
+		AssetLinked {
+			asset_id: AssetIdOf,
+			credential_id: CredentialIdOf<T>,
+			issuer: AssetCreatorOf<T>,
+		},
+		/// The link of an asset to its credential has been removed.
+		/// \[asset identifier, credential identifier, asset issuer\]
+		AssetUnlinked {
+			asset_id: AssetIdOf,
+			credential_id: CredentialIdOf<T>,
+			issuer: AssetCreatorOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The asset does not exist.
+		AssetNotFound,
+		/// The asset is not active.
+		AssetNotActive,
+		/// The credential does not exist.
+		CredentialNotFound,
+		/// The statement anchoring the credential has been revoked.
+		CredentialRevoked,
+		/// Only the asset issuer can link or unlink the asset.
+		UnauthorizedOperation,
+		/// The asset is already linked to a credential.
+		AssetAlreadyLinked,
+		/// The asset is not linked to a credential.
+		AssetNotLinked,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Link the asset `asset_id` to the credential `credential_id`.
+		///
+		/// The caller must be the issuer of the asset, which must be
+		/// active. The credential must exist and its statement must not be
+		/// revoked. An asset is linked to at most one credential.
+		///
+		/// # Events
+		/// - `AssetLinked`
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::link_asset_to_credential())]
+		pub fn link_asset_to_credential(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf,
+			credential_id: CredentialIdOf<T>,
+		) -> DispatchResult {
+			let issuer =
+				<T as pallet_asset::Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let (asset_issuer, asset_status) =
+				Self::asset_issuer_and_status(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+			ensure!(asset_issuer == issuer, Error::<T>::UnauthorizedOperation);
+			ensure!(asset_status == AssetStatusOf::ACTIVE, Error::<T>::AssetNotActive);
+
+			let credential = pallet_cord_credentials::Credentials::<T>::get(credential_id)
+				.ok_or(Error::<T>::CredentialNotFound)?;
+			ensure!(
+				!pallet_statement::RevocationList::<T>::contains_key(
+					&credential.statement,
+					credential.digest
+				),
+				Error::<T>::CredentialRevoked
+			);
+
+			ensure!(
+				!<AssetCredentialLink<T>>::contains_key(&asset_id),
+				Error::<T>::AssetAlreadyLinked
+			);
+
+			<AssetCredentialLink<T>>::insert(&asset_id, credential_id);
+
+			Self::deposit_event(Event::AssetLinked { asset_id, credential_id, issuer });
+
+			Ok(())
+		}
+
+		/// Remove the link of the asset `asset_id` to its credential.
+		///
+		/// The caller must be the issuer of the asset. The link can be
+		/// removed whatever the status of the asset or credential.
+		///
+		/// # Events
+		/// - `AssetUnlinked`
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unlink_asset())]
+		pub fn unlink_asset(origin: OriginFor<T>, asset_id: AssetIdOf) -> DispatchResult {
+			let issuer =
+				<T as pallet_asset::Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let (asset_issuer, _) =
+				Self::asset_issuer_and_status(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+			ensure!(asset_issuer == issuer, Error::<T>::UnauthorizedOperation);
+
+			let credential_id =
+				<AssetCredentialLink<T>>::take(&asset_id).ok_or(Error::<T>::AssetNotLinked)?;
+
+			Self::deposit_event(Event::AssetUnlinked { asset_id, credential_id, issuer });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The issuer and status of an asset, created either as a regular or as
+	/// a VC asset.
+	fn asset_issuer_and_status(
+		asset_id: &AssetIdOf,
+	) -> Option<(pallet_asset::AssetCreatorOf<T>, pallet_asset::AssetStatusOf)> {
+		pallet_asset::Assets::<T>::get(asset_id)
+			.map(|asset| (asset.asset_issuer, asset.asset_status))
+			.or_else(|| {
+				pallet_asset::VCAssets::<T>::get(asset_id)
+					.map(|asset| (asset.asset_issuer, asset.asset_status))
+			})
+	}
+
+	/// The credential an asset is linked to.
+	///
+	/// Arguments:
+	///
+	/// * `asset_id`: The identifier of the asset.
+	///
+	/// Returns:
+	///
+	/// The credential identifier, or `None` if the asset is not linked.
+	pub fn get_credential_for_asset(asset_id: &AssetIdOf) -> Option<CredentialIdOf<T>> {
+		<AssetCredentialLink<T>>::get(asset_id)
+	}
+}
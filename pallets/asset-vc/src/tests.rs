@@ -0,0 +1,186 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use codec::Encode;
+use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
+use frame_support::{assert_err, assert_ok};
+use identifier::{IdentifierType, Ss58Identifier};
+use pallet_asset::{AssetStatusOf, VCAssetEntryOf};
+use pallet_cord_credentials::CredentialEntryOf;
+use pallet_statement::StatementEntryStatusOf;
+use sp_runtime::{traits::Hash, AccountId32};
+
+pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
+pub(crate) const DID_01: SubjectId = SubjectId(AccountId32::new([5u8; 32]));
+pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
+pub(crate) const HOLDER_00: AccountId = AccountId::new([9u8; 32]);
+
+fn identifier(seed: u8, id_type: IdentifierType) -> Ss58Identifier {
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[seed; 32][..]);
+	Ss58Identifier::create_identifier(&digest.encode()[..], id_type).unwrap()
+}
+
+/// Stores a VC asset issued by `DID_00` with the given status.
+fn insert_asset(status: AssetStatusOf) -> AssetIdOf {
+	let asset_id = identifier(1, IdentifierType::Asset);
+	pallet_asset::VCAssets::<Test>::insert(
+		&asset_id,
+		VCAssetEntryOf::<Test> {
+			digest: <Test as frame_system::Config>::Hashing::hash(&[2u8; 32][..]),
+			asset_issuance: 0,
+			asset_status: status,
+			asset_issuer: DID_00,
+			asset_qty: 10,
+			created_at: 1,
+		},
+	);
+	asset_id
+}
+
+/// Stores a credential issued by `DID_00` to `HOLDER_00`.
+fn insert_credential() -> (CredentialIdOf<Test>, CredentialEntryOf<Test>) {
+	let credential_id = <Test as frame_system::Config>::Hashing::hash(&[3u8; 32][..]);
+	let credential = CredentialEntryOf::<Test> {
+		schema: identifier(4, IdentifierType::Schema),
+		statement: identifier(5, IdentifierType::Statement),
+		digest: <Test as frame_system::Config>::Hashing::hash(&[6u8; 32][..]),
+		issuer: DID_00,
+		holder: HOLDER_00,
+		created_at: 1,
+	};
+	pallet_cord_credentials::Credentials::<Test>::insert(credential_id, credential.clone());
+	(credential_id, credential)
+}
+
+#[test]
+fn link_asset_to_credential_works() {
+	new_test_ext().execute_with(|| {
+		let asset_id = insert_asset(AssetStatusOf::ACTIVE);
+		let (credential_id, _) = insert_credential();
+
+		assert_ok!(AssetVc::link_asset_to_credential(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			asset_id.clone(),
+			credential_id,
+		));
+		assert_eq!(AssetVc::get_credential_for_asset(&asset_id), Some(credential_id));
+		System::assert_last_event(
+			Event::AssetLinked { asset_id: asset_id.clone(), credential_id, issuer: DID_00 }.into(),
+		);
+
+		assert_err!(
+			AssetVc::link_asset_to_credential(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				asset_id,
+				credential_id,
+			),
+			Error::<Test>::AssetAlreadyLinked
+		);
+	});
+}
+
+#[test]
+fn link_asset_to_credential_requires_active_entries() {
+	new_test_ext().execute_with(|| {
+		let (credential_id, credential) = insert_credential();
+		let unknown_asset = identifier(7, IdentifierType::Asset);
+		assert_err!(
+			AssetVc::link_asset_to_credential(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				unknown_asset,
+				credential_id,
+			),
+			Error::<Test>::AssetNotFound
+		);
+
+		let asset_id = insert_asset(AssetStatusOf::INACTIVE);
+		assert_err!(
+			AssetVc::link_asset_to_credential(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				asset_id.clone(),
+				credential_id,
+			),
+			Error::<Test>::AssetNotActive
+		);
+
+		let asset_id = insert_asset(AssetStatusOf::ACTIVE);
+		assert_err!(
+			AssetVc::link_asset_to_credential(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				asset_id.clone(),
+				<Test as frame_system::Config>::Hashing::hash(&[8u8; 32][..]),
+			),
+			Error::<Test>::CredentialNotFound
+		);
+		assert_err!(
+			AssetVc::link_asset_to_credential(
+				DoubleOrigin(ACCOUNT_00, DID_01).into(),
+				asset_id.clone(),
+				credential_id,
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		pallet_statement::RevocationList::<Test>::insert(
+			&credential.statement,
+			credential.digest,
+			StatementEntryStatusOf::<Test> { creator: DID_00, revoked: true },
+		);
+		assert_err!(
+			AssetVc::link_asset_to_credential(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				asset_id,
+				credential_id,
+			),
+			Error::<Test>::CredentialRevoked
+		);
+	});
+}
+
+#[test]
+fn unlink_asset_works() {
+	new_test_ext().execute_with(|| {
+		let asset_id = insert_asset(AssetStatusOf::ACTIVE);
+		let (credential_id, _) = insert_credential();
+		assert_err!(
+			AssetVc::unlink_asset(DoubleOrigin(ACCOUNT_00, DID_00).into(), asset_id.clone()),
+			Error::<Test>::AssetNotLinked
+		);
+
+		assert_ok!(AssetVc::link_asset_to_credential(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			asset_id.clone(),
+			credential_id,
+		));
+		assert_err!(
+			AssetVc::unlink_asset(DoubleOrigin(ACCOUNT_00, DID_01).into(), asset_id.clone()),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		assert_ok!(AssetVc::unlink_asset(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			asset_id.clone()
+		));
+		assert_eq!(AssetVc::get_credential_for_asset(&asset_id), None);
+		System::assert_last_event(
+			Event::AssetUnlinked { asset_id, credential_id, issuer: DID_00 }.into(),
+		);
+	});
+}
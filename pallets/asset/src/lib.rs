@@ -721,6 +721,14 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// The status of an asset, whether it was created with `create` or
+	/// `vc_create`. Returns `None` for unknown asset identifiers.
+	pub fn query_asset_status(asset_id: &AssetIdOf) -> Option<AssetStatusOf> {
+		<Assets<T>>::get(asset_id)
+			.map(|asset| asset.asset_status)
+			.or_else(|| <VCAssets<T>>::get(asset_id).map(|asset| asset.asset_status))
+	}
+
 	pub fn get_distributed_qty(asset_id: &AssetIdOf) -> u32 {
 		<Distribution<T>>::get(asset_id)
 			.map(|bounded_vec| bounded_vec.len() as u32)
@@ -495,3 +495,65 @@ fn asset_vc_status_change_should_succeed() {
 		));
 	});
 }
+
+#[test]
+fn asset_status_should_follow_status_changes() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_qty: 10,
+		asset_type: AssetTypeOf::MF,
+		asset_value: 10,
+		asset_tag: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_meta: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+	};
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+
+	let asset_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let asset_id: Ss58Identifier = generate_asset_id::<Test>(&asset_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(Asset::query_asset_status(&asset_id), None);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Asset::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id
+		));
+		assert_eq!(Asset::query_asset_status(&asset_id), Some(AssetStatusOf::ACTIVE));
+
+		assert_ok!(Asset::status_change(
+			DoubleOrigin(author, creator).into(),
+			asset_id.clone(),
+			None,
+			AssetStatusOf::EXPIRED
+		));
+		assert_eq!(Asset::query_asset_status(&asset_id), Some(AssetStatusOf::EXPIRED));
+	});
+}
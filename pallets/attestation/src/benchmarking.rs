@@ -0,0 +1,82 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+
+const SEED: u32 = 0;
+const SCHEMA: H256 = H256::repeat_byte(1);
+
+/// Number of attestations stored before each benchmark runs.
+const PREPOPULATED: u32 = 1_000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn claim_hash(index: u32) -> H256 {
+	<sp_runtime::traits::BlakeTwo256 as Hash>::hash(&index.encode())
+}
+
+/// Fill the attestations map with `count` attestations by distinct attesters.
+fn add_attestations<T: Config>(count: u32) {
+	for i in 0..count {
+		let attester: T::AccountId = account("attester", i, SEED);
+		Pallet::<T>::attest(RawOrigin::Signed(attester).into(), claim_hash(i), SCHEMA)
+			.expect("claim hashes are unique");
+	}
+}
+
+benchmarks! {
+	attest {
+		add_attestations::<T>(PREPOPULATED);
+		let caller: T::AccountId = whitelisted_caller();
+		let claim = claim_hash(PREPOPULATED);
+	}: _(RawOrigin::Signed(caller.clone()), claim, SCHEMA)
+	verify {
+		assert!(Attestations::<T>::contains_key(claim));
+		assert_last_event::<T>(
+			Event::Attested { claim_hash: claim, schema_id: SCHEMA, attester: caller }.into()
+		);
+	}
+
+	revoke_attestation {
+		add_attestations::<T>(PREPOPULATED);
+		let caller: T::AccountId = whitelisted_caller();
+		let claim = claim_hash(PREPOPULATED);
+		Pallet::<T>::attest(RawOrigin::Signed(caller.clone()).into(), claim, SCHEMA)?;
+	}: _(RawOrigin::Signed(caller.clone()), claim)
+	verify {
+		assert!(Pallet::<T>::get_attestation(&claim).map_or(false, |entry| entry.revoked));
+		assert_last_event::<T>(
+			Event::AttestationRevoked { claim_hash: claim, attester: caller }.into()
+		);
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
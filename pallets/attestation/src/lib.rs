@@ -0,0 +1,160 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Attestation Pallet
+//!
+//! The Attestation Pallet records that an account verified a claim at a
+//! given block. A claim is identified by its hash and checked against a
+//! schema; the attestation keeps the attester, the schema and the block it
+//! was made at. The attester can later revoke it, the record and its block
+//! are kept so that the attestation time remains provable.
+//!
+//! ### Storage
+//!
+//! - `Attestations`: The attestation of every attested claim hash.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `attest` - Attest a claim.
+//! - `revoke_attestation` - Revoke an attestation made by the caller.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+use sp_core::H256;
+pub use types::AttestationEntry;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of an attestation.
+	pub type AttestationEntryOf<T> =
+		AttestationEntry<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// attestations by claim hash
+	#[pallet::storage]
+	#[pallet::getter(fn attestations)]
+	pub type Attestations<T: Config> =
+		StorageMap<_, Blake2_128Concat, H256, AttestationEntryOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A claim was attested.
+		Attested { claim_hash: H256, schema_id: H256, attester: T::AccountId },
+		/// An attestation was revoked by its attester.
+		AttestationRevoked { claim_hash: H256, attester: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The claim is already attested.
+		AttestationAlreadyExists,
+		/// There is no attestation for the claim.
+		AttestationNotFound,
+		/// Only the attester can revoke an attestation.
+		NotAttester,
+		/// The attestation is already revoked.
+		AttestationAlreadyRevoked,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Attest that the caller verified the claim hashed to `claim_hash`
+		/// against the schema `schema_id`, as of the current block.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::attest())]
+		pub fn attest(origin: OriginFor<T>, claim_hash: H256, schema_id: H256) -> DispatchResult {
+			let attester = ensure_signed(origin)?;
+			ensure!(
+				!Attestations::<T>::contains_key(claim_hash),
+				Error::<T>::AttestationAlreadyExists
+			);
+
+			Attestations::<T>::insert(
+				claim_hash,
+				AttestationEntry {
+					attester: attester.clone(),
+					schema_id,
+					block: frame_system::Pallet::<T>::block_number(),
+					revoked: false,
+				},
+			);
+
+			Self::deposit_event(Event::Attested { claim_hash, schema_id, attester });
+			Ok(())
+		}
+
+		/// Revoke the attestation of `claim_hash`. Only the attester can
+		/// revoke it.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_attestation())]
+		pub fn revoke_attestation(origin: OriginFor<T>, claim_hash: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Attestations::<T>::try_mutate(claim_hash, |entry| {
+				let entry = entry.as_mut().ok_or(Error::<T>::AttestationNotFound)?;
+				ensure!(entry.attester == who, Error::<T>::NotAttester);
+				ensure!(!entry.revoked, Error::<T>::AttestationAlreadyRevoked);
+				entry.revoked = true;
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::AttestationRevoked { claim_hash, attester: who });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The attestation of `claim_hash`, revoked or not.
+	pub fn get_attestation(claim_hash: &H256) -> Option<AttestationEntryOf<T>> {
+		Attestations::<T>::get(claim_hash)
+	}
+}
@@ -0,0 +1,98 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+const CLAIM: H256 = H256::repeat_byte(1);
+const SCHEMA: H256 = H256::repeat_byte(2);
+
+#[test]
+fn attest_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Attestation::attest(RuntimeOrigin::root(), CLAIM, SCHEMA), BadOrigin);
+
+		System::set_block_number(5);
+		assert_ok!(Attestation::attest(RuntimeOrigin::signed(ALICE), CLAIM, SCHEMA));
+		assert_eq!(
+			Attestation::get_attestation(&CLAIM),
+			Some(AttestationEntry { attester: ALICE, schema_id: SCHEMA, block: 5, revoked: false })
+		);
+		System::assert_last_event(
+			Event::Attested { claim_hash: CLAIM, schema_id: SCHEMA, attester: ALICE }.into(),
+		);
+	});
+}
+
+#[test]
+fn attest_rejects_existing_claim() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Attestation::attest(RuntimeOrigin::signed(ALICE), CLAIM, SCHEMA));
+
+		assert_noop!(
+			Attestation::attest(RuntimeOrigin::signed(BOB), CLAIM, SCHEMA),
+			Error::<Test>::AttestationAlreadyExists
+		);
+
+		// A revoked attestation keeps its record, so the claim stays taken.
+		assert_ok!(Attestation::revoke_attestation(RuntimeOrigin::signed(ALICE), CLAIM));
+		assert_noop!(
+			Attestation::attest(RuntimeOrigin::signed(ALICE), CLAIM, SCHEMA),
+			Error::<Test>::AttestationAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn revoke_attestation_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Attestation::attest(RuntimeOrigin::signed(ALICE), CLAIM, SCHEMA));
+
+		System::set_block_number(10);
+		assert_ok!(Attestation::revoke_attestation(RuntimeOrigin::signed(ALICE), CLAIM));
+		let entry = Attestation::get_attestation(&CLAIM).unwrap();
+		assert!(entry.revoked);
+		assert_eq!(entry.block, 1);
+		System::assert_last_event(
+			Event::AttestationRevoked { claim_hash: CLAIM, attester: ALICE }.into(),
+		);
+
+		assert_noop!(
+			Attestation::revoke_attestation(RuntimeOrigin::signed(ALICE), CLAIM),
+			Error::<Test>::AttestationAlreadyRevoked
+		);
+	});
+}
+
+#[test]
+fn revoke_attestation_is_restricted_to_attester() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Attestation::revoke_attestation(RuntimeOrigin::signed(ALICE), CLAIM),
+			Error::<Test>::AttestationNotFound
+		);
+
+		assert_ok!(Attestation::attest(RuntimeOrigin::signed(ALICE), CLAIM, SCHEMA));
+		assert_noop!(
+			Attestation::revoke_attestation(RuntimeOrigin::signed(BOB), CLAIM),
+			Error::<Test>::NotAttester
+		);
+	});
+}
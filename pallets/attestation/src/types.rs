@@ -0,0 +1,38 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+
+/// An attestation that a claim was verified.
+///
+/// ## Fields
+///
+/// - `attester`: The account that verified the claim.
+/// - `schema_id`: The schema the claim was verified against.
+/// - `block`: The block the attestation was made at.
+/// - `revoked`: Whether the attester revoked the attestation.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct AttestationEntry<AccountId, BlockNumber> {
+	pub attester: AccountId,
+	pub schema_id: H256,
+	pub block: BlockNumber,
+	pub revoked: bool,
+}
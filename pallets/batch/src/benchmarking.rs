@@ -0,0 +1,54 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_std::{vec, vec::Vec};
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	batch_all {
+		let c in 0 .. T::MaxBatchSize::get();
+
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_weight = call.get_dispatch_info().weight;
+		let calls: BatchOf<T> = vec![Box::new(call); c as usize]
+			.try_into()
+			.expect("The batch should not exceed `MaxBatchSize`.");
+	}: _(RawOrigin::Root, calls)
+	verify {
+		let weight = (0..c).fold(<T as pallet::Config>::WeightInfo::batch_all(c), |weight, _| {
+			weight.saturating_add(call_weight)
+		});
+		assert_last_event::<T>(Event::BatchCompleted { calls: c, weight }.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
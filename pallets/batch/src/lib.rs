@@ -0,0 +1,202 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Batch Pallet
+//!
+//! The Batch Pallet dispatches a bounded list of calls as one atomic unit,
+//! for instance the schema, stream and statement calls that issue a
+//! credential. If any call of a batch fails, the changes of all the calls
+//! before it are reverted.
+//!
+//! Unlike `batch_all` of the utility pallet, every call is checked against
+//! `ExtrinsicAuthorship` before it is dispatched, and the batch emits a
+//! single `BatchCompleted` event with its cumulative weight instead of an
+//! event per call.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `batch_all` - Dispatch a batch of calls, reverting all of them if any
+//!   fails.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::{
+	dispatch::{extract_actual_weight, DispatchClass, GetDispatchInfo, PostDispatchInfo},
+	traits::UnfilteredDispatchable,
+	weights::Weight,
+};
+use sp_runtime::{traits::Dispatchable, DispatchErrorWithPostInfo};
+use sp_std::boxed::Box;
+
+/// Authorship of the calls of a batch.
+pub trait ExtrinsicAuthorship<Origin, Call> {
+	/// Whether `origin` may author `call` as part of a batch.
+	fn is_authorized(origin: &Origin, call: &Call) -> bool;
+}
+
+impl<Origin, Call> ExtrinsicAuthorship<Origin, Call> for () {
+	fn is_authorized(_origin: &Origin, _call: &Call) -> bool {
+		true
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of the calls of a batch.
+	pub type BatchOf<T> = BoundedVec<Box<<T as Config>::RuntimeCall>, <T as Config>::MaxBatchSize>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The runtime call type the calls of a batch are made of.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>
+			+ UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+		/// Decides whether the origin of a batch may author each of its
+		/// calls.
+		type ExtrinsicAuthorship: ExtrinsicAuthorship<
+			Self::RuntimeOrigin,
+			<Self as Config>::RuntimeCall,
+		>;
+		/// The most calls in a batch.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// All the calls of a batch were dispatched, with a total `weight`.
+		BatchCompleted { calls: u32, weight: Weight },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The origin may not author a call of the batch.
+		UnauthorizedCall,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dispatch `calls` in order with the origin of the batch. Every call
+		/// must be authorized by `ExtrinsicAuthorship`. If a call is not, or
+		/// fails, the whole batch is reverted and the error of that call is
+		/// returned.
+		///
+		/// A root origin dispatches the calls without the call filter.
+		#[pallet::call_index(0)]
+		#[pallet::weight({
+			let (weight, class) = Pallet::<T>::weight_and_dispatch_class(calls);
+			(
+				weight.saturating_add(
+					<T as pallet::Config>::WeightInfo::batch_all(calls.len() as u32),
+				),
+				class,
+			)
+		})]
+		pub fn batch_all(origin: OriginFor<T>, calls: BatchOf<T>) -> DispatchResultWithPostInfo {
+			let is_root = ensure_root(origin.clone()).is_ok();
+			let calls_len = calls.len() as u32;
+			let mut weight = Weight::zero();
+
+			for (index, call) in calls.into_iter().enumerate() {
+				let call = *call;
+				// The weight of the batch up to and including this call, for
+				// the post info of an error.
+				let weight_so_far = |weight: Weight| {
+					<T as pallet::Config>::WeightInfo::batch_all(index.saturating_add(1) as u32)
+						.saturating_add(weight)
+				};
+
+				if !T::ExtrinsicAuthorship::is_authorized(&origin, &call) {
+					return Err(DispatchErrorWithPostInfo {
+						post_info: Some(weight_so_far(weight)).into(),
+						error: Error::<T>::UnauthorizedCall.into(),
+					})
+				}
+
+				let info = call.get_dispatch_info();
+				let result = if is_root {
+					call.dispatch_bypass_filter(origin.clone())
+				} else {
+					call.dispatch(origin.clone())
+				};
+				weight = weight.saturating_add(extract_actual_weight(&result, &info));
+				result.map_err(|mut err| {
+					err.post_info = Some(weight_so_far(weight)).into();
+					err
+				})?;
+			}
+
+			let weight =
+				<T as pallet::Config>::WeightInfo::batch_all(calls_len).saturating_add(weight);
+			Self::deposit_event(Event::BatchCompleted { calls: calls_len, weight });
+			Ok(Some(weight).into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The summed weight of `calls`, and the class of the batch. A batch is
+	/// operational only if all of its calls are.
+	fn weight_and_dispatch_class(
+		calls: &[Box<<T as Config>::RuntimeCall>],
+	) -> (Weight, DispatchClass) {
+		calls.iter().map(|call| call.get_dispatch_info()).fold(
+			(Default::default(), DispatchClass::Operational),
+			|(weight, class), info| {
+				(
+					weight.saturating_add(info.weight),
+					if info.class == DispatchClass::Normal { info.class } else { class },
+				)
+			},
+		)
+	}
+}
@@ -0,0 +1,99 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_err_ignore_postinfo, assert_ok};
+use sp_runtime::DispatchError;
+
+fn remark(remark: &[u8]) -> RuntimeCall {
+	frame_system::Call::remark_with_event { remark: remark.to_vec() }.into()
+}
+
+fn batch(calls: Vec<RuntimeCall>) -> BatchOf<Test> {
+	calls.into_iter().map(Box::new).collect::<Vec<_>>().try_into().unwrap()
+}
+
+fn remarked() -> usize {
+	System::events()
+		.iter()
+		.filter(|record| {
+			matches!(record.event, RuntimeEvent::System(frame_system::Event::Remarked { .. }))
+		})
+		.count()
+}
+
+#[test]
+fn batch_all_dispatches_every_call() {
+	new_test_ext().execute_with(|| {
+		let calls = vec![remark(b"schema"), remark(b"statement")];
+		let call_weight = calls.iter().fold(Weight::zero(), |weight, call| {
+			weight.saturating_add(call.get_dispatch_info().weight)
+		});
+
+		assert_ok!(Batch::batch_all(RuntimeOrigin::signed(1), batch(calls)));
+		assert_eq!(remarked(), 2);
+		System::assert_last_event(
+			Event::BatchCompleted {
+				calls: 2,
+				weight: <() as WeightInfo>::batch_all(2).saturating_add(call_weight),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn batch_all_reverts_every_call_if_one_fails() {
+	new_test_ext().execute_with(|| {
+		// `set_heap_pages` needs root, so the batch fails after the first
+		// remark was dispatched.
+		let call: RuntimeCall = Call::<Test>::batch_all {
+			calls: batch(vec![
+				remark(b"schema"),
+				frame_system::Call::set_heap_pages { pages: 1 }.into(),
+			]),
+		}
+		.into();
+
+		assert_err_ignore_postinfo!(
+			call.dispatch(RuntimeOrigin::signed(1)),
+			DispatchError::BadOrigin
+		);
+		assert_eq!(remarked(), 0);
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn batch_all_checks_the_authorship_of_every_call() {
+	new_test_ext().execute_with(|| {
+		assert_err_ignore_postinfo!(
+			Batch::batch_all(RuntimeOrigin::signed(3), batch(vec![remark(b"schema")])),
+			Error::<Test>::UnauthorizedCall
+		);
+		assert!(System::events().is_empty());
+
+		// Root is authorized, and dispatches calls that need root.
+		assert_ok!(Batch::batch_all(
+			RuntimeOrigin::root(),
+			batch(vec![remark(b"schema"), frame_system::Call::set_heap_pages { pages: 1 }.into()])
+		));
+		assert_eq!(remarked(), 1);
+	});
+}
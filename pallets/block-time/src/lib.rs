@@ -0,0 +1,110 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Block Time Pallet
+//!
+//! The Block Time Pallet keeps the timestamps of the last `MaxWindow` blocks,
+//! so that the runtime can report the average time between recent blocks
+//! next to the block time it targets.
+//!
+//! ### Storage
+//!
+//! - `BlockTimestamps`: The timestamp of each recent block, written when the
+//!   block is finalized and pruned once it falls out of the window.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	SaturatedConversion,
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_timestamp::Config {
+		/// The most blocks a block time is measured over.
+		#[pallet::constant]
+		type MaxWindow: Get<u32>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// maps a block number to the timestamp of the block, for the last
+	/// `MaxWindow` blocks
+	#[pallet::storage]
+	pub type BlockTimestamps<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, T::Moment, OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// Accounts for `on_finalize`.
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			BlockTimestamps::<T>::insert(n, pallet_timestamp::Pallet::<T>::get());
+
+			let kept = BlockNumberFor::<T>::from(T::MaxWindow::get()).saturating_add(One::one());
+			if n >= kept {
+				BlockTimestamps::<T>::remove(n - kept);
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The average time between the last `window` blocks, up to the current
+	/// block.
+	///
+	/// The window is capped to `MaxWindow` and starts after the genesis
+	/// block, which has no timestamp. Zero if it spans no block or a
+	/// timestamp at either end of it is missing.
+	pub fn measured_block_time(window: u32) -> T::Moment {
+		let end = frame_system::Pallet::<T>::block_number();
+		let window = window
+			.min(T::MaxWindow::get())
+			.min(end.saturating_sub(One::one()).saturated_into());
+		if window.is_zero() {
+			return Zero::zero()
+		}
+
+		let start = end.saturating_sub(window.into());
+		match (BlockTimestamps::<T>::get(start), BlockTimestamps::<T>::get(end)) {
+			(Some(start), Some(end)) => end.saturating_sub(start) / T::Moment::from(window),
+			_ => Zero::zero(),
+		}
+	}
+}
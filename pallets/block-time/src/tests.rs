@@ -0,0 +1,57 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+
+#[test]
+fn timestamps_are_kept_for_the_window() {
+	new_test_ext().execute_with(|| {
+		run_blocks(&[3_000, 6_000, 9_000, 12_000, 15_000]);
+
+		assert_eq!(BlockTimestamps::<Test>::get(1), None);
+		assert_eq!(BlockTimestamps::<Test>::get(2), Some(6_000));
+		assert_eq!(BlockTimestamps::<Test>::get(5), Some(15_000));
+		assert_eq!(BlockTimestamps::<Test>::iter().count(), 4);
+	});
+}
+
+#[test]
+fn measured_block_time_averages_the_window() {
+	new_test_ext().execute_with(|| {
+		run_blocks(&[3_000, 6_000, 9_000, 15_000, 21_000]);
+
+		assert_eq!(BlockTime::measured_block_time(1), 6_000);
+		assert_eq!(BlockTime::measured_block_time(3), 5_000);
+		// Capped to the `MaxWindow` of three blocks.
+		assert_eq!(BlockTime::measured_block_time(10), 5_000);
+	});
+}
+
+#[test]
+fn measured_block_time_starts_after_genesis() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(BlockTime::measured_block_time(3), 0);
+
+		run_blocks(&[3_000]);
+		assert_eq!(BlockTime::measured_block_time(3), 0);
+
+		run_blocks(&[7_000, 9_000]);
+		assert_eq!(BlockTime::measured_block_time(3), 3_000);
+	});
+}
@@ -0,0 +1,126 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn funded_curator<T: Config>() -> T::AccountId {
+	let curator: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&curator, BalanceOf::<T>::max_value() / 2u32.into());
+	curator
+}
+
+/// Propose a bounty for `schema` by `curator` and assign it to `worker`.
+fn assigned_bounty<T: Config>(
+	curator: &T::AccountId,
+	worker: &T::AccountId,
+	schema: T::SchemaId,
+) -> BountyIndex {
+	let bounty_id = Pallet::<T>::bounty_count();
+	let description = BoundedVec::truncate_from(vec![0u8; T::MaxDescLen::get() as usize]);
+	Pallet::<T>::propose_bounty(
+		RawOrigin::Signed(curator.clone()).into(),
+		schema,
+		T::Currency::minimum_balance(),
+		description,
+	)
+	.expect("the curator is funded");
+	Pallet::<T>::assign_bounty(
+		RawOrigin::Signed(curator.clone()).into(),
+		bounty_id,
+		worker.clone(),
+	)
+	.expect("the caller is the curator");
+	bounty_id
+}
+
+benchmarks! {
+	propose_bounty {
+		let d in 0 .. T::MaxDescLen::get();
+
+		let curator = funded_curator::<T>();
+		let (_, schema) = T::Credentials::active_credential();
+		let reward = T::Currency::minimum_balance();
+		let description = BoundedVec::truncate_from(vec![0u8; d as usize]);
+	}: _(RawOrigin::Signed(curator.clone()), schema.clone(), reward, description)
+	verify {
+		assert_eq!(Pallet::<T>::bounty_count(), 1);
+		assert_last_event::<T>(
+			Event::BountyProposed { bounty_id: 0, curator, schema, reward }.into()
+		);
+	}
+
+	assign_bounty {
+		let curator = funded_curator::<T>();
+		let worker: T::AccountId = account("worker", 0, SEED);
+		let (_, schema) = T::Credentials::active_credential();
+		let bounty_id = assigned_bounty::<T>(&curator, &curator, schema);
+	}: _(RawOrigin::Signed(curator), bounty_id, worker.clone())
+	verify {
+		assert_last_event::<T>(Event::BountyAssigned { bounty_id, worker }.into());
+	}
+
+	claim_bounty {
+		let curator = funded_curator::<T>();
+		let worker: T::AccountId = account("worker", 0, SEED);
+		let (credential, schema) = T::Credentials::active_credential();
+		let bounty_id = assigned_bounty::<T>(&curator, &worker, schema);
+	}: _(RawOrigin::Signed(worker.clone()), bounty_id, credential)
+	verify {
+		assert_eq!(ClaimedCredentials::<T>::get(credential), Some(bounty_id));
+		assert_last_event::<T>(
+			Event::BountyClaimed {
+				bounty_id,
+				worker,
+				credential,
+				reward: T::Currency::minimum_balance(),
+			}
+			.into()
+		);
+	}
+
+	close_bounty {
+		let curator = funded_curator::<T>();
+		let worker: T::AccountId = account("worker", 0, SEED);
+		let (_, schema) = T::Credentials::active_credential();
+		let bounty_id = assigned_bounty::<T>(&curator, &worker, schema);
+	}: _(RawOrigin::Signed(curator), bounty_id)
+	verify {
+		assert!(!Bounties::<T>::contains_key(bounty_id));
+		assert_last_event::<T>(Event::BountyClosed { bounty_id }.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
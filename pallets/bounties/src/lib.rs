@@ -0,0 +1,292 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Bounties Pallet
+//!
+//! The Bounties Pallet pays out rewards for data-verification tasks. A
+//! bounty asks for a credential of a given schema: the account proposing it
+//! becomes its curator, reserves the reward and assigns a worker to the
+//! task. The worker claims the bounty with the identifier of a credential
+//! of the bounty's schema, and receives the reward once the credential is
+//! found active. A credential claims at most one bounty.
+//!
+//! The curator can close an unclaimed bounty, which releases the reward.
+//!
+//! ### Storage
+//!
+//! - `BountyCount`: The number of bounties ever proposed, and the
+//!   identifier of the next one.
+//! - `Bounties`: The open bounties.
+//! - `ClaimedCredentials`: The bounty every claimed credential paid out.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `propose_bounty` - Propose a bounty and reserve its reward.
+//! - `assign_bounty` - Assign a worker to a bounty.
+//! - `claim_bounty` - Claim a bounty with a credential.
+//! - `close_bounty` - Close an unclaimed bounty.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod traits;
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use frame_support::traits::{BalanceStatus, Currency, ReservableCurrency};
+pub use pallet::*;
+pub use traits::CredentialInspect;
+pub use types::{BountyDetails, BountyStatus};
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Identifier of a bounty.
+pub type BountyIndex = u32;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Description of a bounty.
+	pub type BountyDescriptionOf<T> = BoundedVec<u8, <T as Config>::MaxDescLen>;
+	/// Type of a bounty.
+	pub type BountyDetailsOf<T> = BountyDetails<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as Config>::SchemaId,
+		BountyDescriptionOf<T>,
+	>;
+	/// Identifier of a credential.
+	pub type CredentialIdOf<T> = <T as frame_system::Config>::Hash;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency bounty rewards are paid in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Identifier of a credential schema.
+		type SchemaId: Parameter + MaxEncodedLen;
+		/// The credentials bounties are claimed with.
+		type Credentials: CredentialInspect<CredentialIdOf<Self>, Self::SchemaId>;
+		/// The maximum length of the description of a bounty.
+		#[pallet::constant]
+		type MaxDescLen: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// number of bounties ever proposed
+	#[pallet::storage]
+	#[pallet::getter(fn bounty_count)]
+	pub type BountyCount<T> = StorageValue<_, BountyIndex, ValueQuery>;
+
+	/// open bounties by identifier
+	#[pallet::storage]
+	#[pallet::getter(fn bounties)]
+	pub type Bounties<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, BountyDetailsOf<T>, OptionQuery>;
+
+	/// bounties paid out by claimed credential
+	#[pallet::storage]
+	#[pallet::getter(fn claimed_credentials)]
+	pub type ClaimedCredentials<T: Config> =
+		StorageMap<_, Blake2_128Concat, CredentialIdOf<T>, BountyIndex, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A bounty was proposed.
+		BountyProposed {
+			bounty_id: BountyIndex,
+			curator: T::AccountId,
+			schema: T::SchemaId,
+			reward: BalanceOf<T>,
+		},
+		/// A worker was assigned to a bounty.
+		BountyAssigned { bounty_id: BountyIndex, worker: T::AccountId },
+		/// A bounty was claimed and its reward paid out.
+		BountyClaimed {
+			bounty_id: BountyIndex,
+			worker: T::AccountId,
+			credential: CredentialIdOf<T>,
+			reward: BalanceOf<T>,
+		},
+		/// An unclaimed bounty was closed.
+		BountyClosed { bounty_id: BountyIndex },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No open bounty has the identifier.
+		BountyNotFound,
+		/// The caller is not the curator of the bounty.
+		NotCurator,
+		/// The caller is not the worker assigned to the bounty.
+		NotWorker,
+		/// The credential is not an active credential of the bounty's schema.
+		InvalidCredential,
+		/// The credential already claimed a bounty.
+		CredentialAlreadyClaimed,
+		/// Bounty identifiers are exhausted.
+		TooManyBounties,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose a bounty for a credential of `schema_id`, paying `reward`.
+		/// The caller becomes the curator of the bounty, and `reward` is
+		/// reserved from them.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_bounty(description.len() as u32))]
+		pub fn propose_bounty(
+			origin: OriginFor<T>,
+			schema_id: T::SchemaId,
+			reward: BalanceOf<T>,
+			description: BountyDescriptionOf<T>,
+		) -> DispatchResult {
+			let curator = ensure_signed(origin)?;
+			let bounty_id = BountyCount::<T>::get();
+			let next = bounty_id.checked_add(1).ok_or(Error::<T>::TooManyBounties)?;
+
+			T::Currency::reserve(&curator, reward)?;
+
+			BountyCount::<T>::put(next);
+			Bounties::<T>::insert(
+				bounty_id,
+				BountyDetails {
+					curator: curator.clone(),
+					schema: schema_id.clone(),
+					reward,
+					description,
+					status: BountyStatus::Proposed,
+				},
+			);
+
+			Self::deposit_event(Event::BountyProposed {
+				bounty_id,
+				curator,
+				schema: schema_id,
+				reward,
+			});
+			Ok(())
+		}
+
+		/// Assign `worker` to bounty `bounty_id`, replacing any worker
+		/// assigned before. Only the curator of the bounty can assign it.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::assign_bounty())]
+		pub fn assign_bounty(
+			origin: OriginFor<T>,
+			bounty_id: BountyIndex,
+			worker: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Bounties::<T>::try_mutate(bounty_id, |bounty| {
+				let bounty = bounty.as_mut().ok_or(Error::<T>::BountyNotFound)?;
+				ensure!(bounty.curator == who, Error::<T>::NotCurator);
+				bounty.status = BountyStatus::Assigned { worker: worker.clone() };
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::BountyAssigned { bounty_id, worker });
+			Ok(())
+		}
+
+		/// Claim bounty `bounty_id` with `credential_id`, an active
+		/// credential of the bounty's schema, and receive its reward. Only the
+		/// assigned worker can claim the bounty.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_bounty())]
+		pub fn claim_bounty(
+			origin: OriginFor<T>,
+			bounty_id: BountyIndex,
+			credential_id: CredentialIdOf<T>,
+		) -> DispatchResult {
+			let worker = ensure_signed(origin)?;
+			let bounty = Bounties::<T>::get(bounty_id).ok_or(Error::<T>::BountyNotFound)?;
+			ensure!(
+				bounty.status == BountyStatus::Assigned { worker: worker.clone() },
+				Error::<T>::NotWorker
+			);
+			ensure!(
+				!ClaimedCredentials::<T>::contains_key(credential_id),
+				Error::<T>::CredentialAlreadyClaimed
+			);
+			ensure!(
+				T::Credentials::is_active(&credential_id, &bounty.schema),
+				Error::<T>::InvalidCredential
+			);
+
+			T::Currency::repatriate_reserved(
+				&bounty.curator,
+				&worker,
+				bounty.reward,
+				BalanceStatus::Free,
+			)?;
+
+			Bounties::<T>::remove(bounty_id);
+			ClaimedCredentials::<T>::insert(credential_id, bounty_id);
+
+			Self::deposit_event(Event::BountyClaimed {
+				bounty_id,
+				worker,
+				credential: credential_id,
+				reward: bounty.reward,
+			});
+			Ok(())
+		}
+
+		/// Close the unclaimed bounty `bounty_id` and release its reward to
+		/// the curator. Only the curator of the bounty can close it.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::close_bounty())]
+		pub fn close_bounty(origin: OriginFor<T>, bounty_id: BountyIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bounty = Bounties::<T>::get(bounty_id).ok_or(Error::<T>::BountyNotFound)?;
+			ensure!(bounty.curator == who, Error::<T>::NotCurator);
+
+			T::Currency::unreserve(&bounty.curator, bounty.reward);
+			Bounties::<T>::remove(bounty_id);
+
+			Self::deposit_event(Event::BountyClosed { bounty_id });
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,208 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, BountyIndex, BountyStatus, Error, Event};
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency, BoundedVec};
+use sp_core::H256;
+
+const SCHEMA: u32 = 7;
+const REWARD: Balance = 100;
+const CREDENTIAL: H256 = H256::repeat_byte(1);
+const CAROL: AccountId = 3;
+
+fn propose(curator: AccountId) -> BountyIndex {
+	let bounty_id = Bounties::bounty_count();
+	assert_ok!(Bounties::propose_bounty(
+		RuntimeOrigin::signed(curator),
+		SCHEMA,
+		REWARD,
+		BoundedVec::truncate_from(b"verify a degree".to_vec())
+	));
+	bounty_id
+}
+
+fn activate(credential: H256, schema: u32) {
+	ActiveCredentials::mutate(|credentials| credentials.insert((credential, schema)));
+}
+
+#[test]
+fn propose_bounty_reserves_the_reward() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(propose(ALICE), 0);
+		assert_eq!(propose(ALICE), 1);
+
+		assert_eq!(Bounties::bounty_count(), 2);
+		assert_eq!(Balances::reserved_balance(ALICE), 2 * REWARD);
+		let bounty = Bounties::bounties(0).expect("bounty was proposed");
+		assert_eq!(bounty.curator, ALICE);
+		assert_eq!(bounty.schema, SCHEMA);
+		assert_eq!(bounty.status, BountyStatus::Proposed);
+		System::assert_last_event(
+			Event::BountyProposed { bounty_id: 1, curator: ALICE, schema: SCHEMA, reward: REWARD }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn propose_bounty_requires_the_reward() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Bounties::propose_bounty(
+				RuntimeOrigin::signed(ALICE),
+				SCHEMA,
+				INITIAL_BALANCE + 1,
+				BoundedVec::new()
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(Bounties::bounty_count(), 0);
+	});
+}
+
+#[test]
+fn only_the_curator_assigns_a_bounty() {
+	new_test_ext().execute_with(|| {
+		let bounty_id = propose(ALICE);
+
+		assert_noop!(
+			Bounties::assign_bounty(RuntimeOrigin::signed(BOB), bounty_id, BOB),
+			Error::<Test>::NotCurator
+		);
+		assert_noop!(
+			Bounties::assign_bounty(RuntimeOrigin::signed(ALICE), bounty_id + 1, BOB),
+			Error::<Test>::BountyNotFound
+		);
+
+		assert_ok!(Bounties::assign_bounty(RuntimeOrigin::signed(ALICE), bounty_id, BOB));
+		System::assert_last_event(Event::BountyAssigned { bounty_id, worker: BOB }.into());
+
+		// the curator can hand the bounty to another worker
+		assert_ok!(Bounties::assign_bounty(RuntimeOrigin::signed(ALICE), bounty_id, CAROL));
+		assert_eq!(
+			Bounties::bounties(bounty_id).map(|bounty| bounty.status),
+			Some(BountyStatus::Assigned { worker: CAROL })
+		);
+	});
+}
+
+#[test]
+fn claim_bounty_pays_the_worker() {
+	new_test_ext().execute_with(|| {
+		let bounty_id = propose(ALICE);
+		assert_ok!(Bounties::assign_bounty(RuntimeOrigin::signed(ALICE), bounty_id, BOB));
+		activate(CREDENTIAL, SCHEMA);
+
+		assert_ok!(Bounties::claim_bounty(RuntimeOrigin::signed(BOB), bounty_id, CREDENTIAL));
+
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - REWARD);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + REWARD);
+		assert!(Bounties::bounties(bounty_id).is_none());
+		assert_eq!(Bounties::claimed_credentials(CREDENTIAL), Some(bounty_id));
+		System::assert_last_event(
+			Event::BountyClaimed { bounty_id, worker: BOB, credential: CREDENTIAL, reward: REWARD }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn only_the_assigned_worker_claims_a_bounty() {
+	new_test_ext().execute_with(|| {
+		let bounty_id = propose(ALICE);
+		activate(CREDENTIAL, SCHEMA);
+
+		// nobody is assigned yet
+		assert_noop!(
+			Bounties::claim_bounty(RuntimeOrigin::signed(BOB), bounty_id, CREDENTIAL),
+			Error::<Test>::NotWorker
+		);
+
+		assert_ok!(Bounties::assign_bounty(RuntimeOrigin::signed(ALICE), bounty_id, BOB));
+		assert_noop!(
+			Bounties::claim_bounty(RuntimeOrigin::signed(CAROL), bounty_id, CREDENTIAL),
+			Error::<Test>::NotWorker
+		);
+		assert_noop!(
+			Bounties::claim_bounty(RuntimeOrigin::signed(BOB), bounty_id + 1, CREDENTIAL),
+			Error::<Test>::BountyNotFound
+		);
+	});
+}
+
+#[test]
+fn claim_bounty_requires_an_active_credential_of_the_schema() {
+	new_test_ext().execute_with(|| {
+		let bounty_id = propose(ALICE);
+		assert_ok!(Bounties::assign_bounty(RuntimeOrigin::signed(ALICE), bounty_id, BOB));
+
+		assert_noop!(
+			Bounties::claim_bounty(RuntimeOrigin::signed(BOB), bounty_id, CREDENTIAL),
+			Error::<Test>::InvalidCredential
+		);
+
+		// a credential of another schema does not verify the bounty
+		activate(CREDENTIAL, SCHEMA + 1);
+		assert_noop!(
+			Bounties::claim_bounty(RuntimeOrigin::signed(BOB), bounty_id, CREDENTIAL),
+			Error::<Test>::InvalidCredential
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), REWARD);
+	});
+}
+
+#[test]
+fn a_credential_claims_one_bounty() {
+	new_test_ext().execute_with(|| {
+		let first = propose(ALICE);
+		let second = propose(ALICE);
+		assert_ok!(Bounties::assign_bounty(RuntimeOrigin::signed(ALICE), first, BOB));
+		assert_ok!(Bounties::assign_bounty(RuntimeOrigin::signed(ALICE), second, BOB));
+		activate(CREDENTIAL, SCHEMA);
+
+		assert_ok!(Bounties::claim_bounty(RuntimeOrigin::signed(BOB), first, CREDENTIAL));
+		assert_noop!(
+			Bounties::claim_bounty(RuntimeOrigin::signed(BOB), second, CREDENTIAL),
+			Error::<Test>::CredentialAlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn close_bounty_releases_the_reward() {
+	new_test_ext().execute_with(|| {
+		let bounty_id = propose(ALICE);
+		assert_ok!(Bounties::assign_bounty(RuntimeOrigin::signed(ALICE), bounty_id, BOB));
+
+		assert_noop!(
+			Bounties::close_bounty(RuntimeOrigin::signed(BOB), bounty_id),
+			Error::<Test>::NotCurator
+		);
+
+		assert_ok!(Bounties::close_bounty(RuntimeOrigin::signed(ALICE), bounty_id));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert!(Bounties::bounties(bounty_id).is_none());
+		System::assert_last_event(Event::BountyClosed { bounty_id }.into());
+
+		assert_noop!(
+			Bounties::close_bounty(RuntimeOrigin::signed(ALICE), bounty_id),
+			Error::<Test>::BountyNotFound
+		);
+	});
+}
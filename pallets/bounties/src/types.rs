@@ -0,0 +1,49 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The state of a bounty.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum BountyStatus<AccountId> {
+	/// The bounty waits for the curator to assign a worker.
+	Proposed,
+	/// The bounty is assigned to `worker`, who can claim it.
+	Assigned { worker: AccountId },
+}
+
+/// A data-verification bounty.
+///
+/// ## Fields
+///
+/// - `curator`: The account that proposed the bounty and assigns its worker.
+/// - `schema`: The schema of the credential the bounty is claimed with.
+/// - `reward`: The reward, reserved from the curator until the bounty is
+///   claimed or closed.
+/// - `description`: The verification task.
+/// - `status`: Whether a worker is assigned.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct BountyDetails<AccountId, Balance, SchemaId, Description> {
+	pub curator: AccountId,
+	pub schema: SchemaId,
+	pub reward: Balance,
+	pub description: Description,
+	pub status: BountyStatus<AccountId>,
+}
@@ -0,0 +1,105 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Chain Namespace Pallet
+//!
+//! Identifiers of schemas and statements are derived from digests of their
+//! content. Once CORD chains exchange data, the same content anchored on two
+//! chains must still get distinct identifiers. This pallet holds a chain id,
+//! set at genesis, and scopes a raw identifier digest to the chain as
+//! `blake2_256(chain_id ++ raw_id)`.
+//!
+//! Chain id 0 keeps the raw identifier. Chains launched before namespacing
+//! have that id, so the identifiers they already stored stay valid without a
+//! migration.
+//!
+//! Pallets deriving identifiers opt in by using the pallet as their
+//! `IdentifierNamespace`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(test)]
+pub mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+pub use crate::{pallet::*, weights::WeightInfo};
+use cord_utilities::traits::IdentifierNamespace;
+use sp_std::vec::Vec;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::StorageVersion};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// The id identifiers of this chain are scoped to.
+	#[pallet::storage]
+	#[pallet::getter(fn chain_id)]
+	pub type ChainId<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub chain_id: u32,
+		#[serde(skip)]
+		pub _config: sp_std::marker::PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			ChainId::<T>::put(self.chain_id);
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The id identifiers of this chain are scoped to.
+	pub fn current_chain_id() -> u32 {
+		ChainId::<T>::get()
+	}
+}
+
+impl<T: Config> IdentifierNamespace for Pallet<T> {
+	fn namespaced(raw_id: &[u8]) -> Vec<u8> {
+		match ChainId::<T>::get() {
+			0 => raw_id.to_vec(),
+			chain_id => {
+				let chain_id = chain_id.to_le_bytes();
+				sp_io::hashing::blake2_256(&[&chain_id[..], raw_id].concat()).to_vec()
+			},
+		}
+	}
+}
@@ -0,0 +1,60 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+
+#[test]
+fn chain_id_is_set_at_genesis() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ChainNamespace::current_chain_id(), CHAIN_ID);
+	});
+}
+
+#[test]
+fn namespaced_prefixes_the_chain_id() {
+	new_test_ext().execute_with(|| {
+		let raw_id = [7u8; 32];
+		let expected =
+			sp_io::hashing::blake2_256(&[&CHAIN_ID.to_le_bytes()[..], &raw_id[..]].concat());
+
+		assert_eq!(ChainNamespace::namespaced(&raw_id), expected.to_vec());
+	});
+}
+
+#[test]
+fn namespaced_differs_between_chains() {
+	new_test_ext().execute_with(|| {
+		let raw_id = [7u8; 32];
+		let on_this_chain = ChainNamespace::namespaced(&raw_id);
+
+		ChainId::<Test>::put(CHAIN_ID + 1);
+		assert_ne!(ChainNamespace::namespaced(&raw_id), on_this_chain);
+		assert_ne!(<() as IdentifierNamespace>::namespaced(&raw_id), on_this_chain);
+	});
+}
+
+#[test]
+fn chain_id_zero_keeps_the_raw_identifier() {
+	new_test_ext().execute_with(|| {
+		let raw_id = [7u8; 32];
+		ChainId::<Test>::put(0);
+
+		assert_eq!(ChainNamespace::namespaced(&raw_id), raw_id.to_vec());
+	});
+}
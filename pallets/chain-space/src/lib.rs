@@ -1251,10 +1251,10 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
-		Self::increment_usage(&d.space_id)?;
-
 		Self::validate_space_for_transaction(&d.space_id)?;
 
+		Self::increment_usage(&d.space_id)?;
+
 		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
 
 		Ok(d.space_id)
@@ -1269,10 +1269,10 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
-		Self::increment_usage(&d.space_id)?;
-
 		Self::validate_space_for_restore_transaction(&d.space_id)?;
 
+		Self::increment_usage(&d.space_id)?;
+
 		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
 
 		Ok(d.space_id)
@@ -1293,10 +1293,10 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
-		Self::increment_usage(&d.space_id)?;
-
 		Self::validate_space_for_transaction(&d.space_id)?;
 
+		Self::increment_usage(&d.space_id)?;
+
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
 
 		Ok(d.space_id)
@@ -1308,7 +1308,7 @@ impl<T: Config> Pallet<T> {
 	/// This function checks whether the provided `authorization_id` corresponds
 	/// to an existing authorization and whether the delegate associated with
 	/// that authorization is allowed to perform audit operations. It also
-	/// increments usage and validates the space for transactions.
+	/// validates the space for transactions and increments its usage.
 	pub fn ensure_authorization_delegator_origin(
 		authorization_id: &AuthorizationIdOf,
 		delegate: &SpaceCreatorOf<T>,
@@ -1318,10 +1318,10 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
-		Self::increment_usage(&d.space_id)?;
-
 		Self::validate_space_for_transaction(&d.space_id)?;
 
+		Self::increment_usage(&d.space_id)?;
+
 		ensure!(
 			d.permissions.contains(Permissions::DELEGATE | Permissions::ADMIN),
 			Error::<T>::UnauthorizedOperation
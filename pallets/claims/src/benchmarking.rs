@@ -0,0 +1,77 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::{
+	secp_utils::{eth, secret, sig},
+	Pallet,
+};
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::Hooks;
+use frame_system::RawOrigin;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	mint_claim {
+		let ethereum_address = eth(&secret(b"claimer"));
+		let amount = T::Currency::minimum_balance();
+		let expires_on = frame_system::Pallet::<T>::block_number() + T::ClaimExpiry::get();
+	}: _(RawOrigin::Root, ethereum_address, amount)
+	verify {
+		assert_last_event::<T>(Event::ClaimMinted { ethereum_address, amount, expires_on }.into());
+	}
+
+	claim {
+		let caller: T::AccountId = whitelisted_caller();
+		let key = secret(b"claimer");
+		let ethereum_address = eth(&key);
+		let amount = T::Currency::minimum_balance();
+		Pallet::<T>::mint_claim(RawOrigin::Root.into(), ethereum_address, amount)?;
+		let signature = sig::<T>(&key, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), ethereum_address, signature)
+	verify {
+		assert_last_event::<T>(Event::Claimed { who: caller, ethereum_address, amount }.into());
+	}
+
+	on_initialize {
+		let c in 0 .. T::MaxClaimsPerBlock::get();
+
+		let amount = T::Currency::minimum_balance();
+		for i in 0..c {
+			let ethereum_address = EthereumAddress::from_low_u64_be(i.into());
+			Pallet::<T>::mint_claim(RawOrigin::Root.into(), ethereum_address, amount)?;
+		}
+		let n = frame_system::Pallet::<T>::block_number() + T::ClaimExpiry::get();
+	}: {
+		Pallet::<T>::on_initialize(n);
+	}
+	verify {
+		assert_eq!(Claims::<T>::iter().count(), 0);
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
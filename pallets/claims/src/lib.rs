@@ -0,0 +1,286 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Claims Pallet
+//!
+//! The Claims Pallet lets recipients of a WAY pre-allocation that were not
+//! part of the genesis claim their tokens later, proving ownership of the
+//! Ethereum address they pre-registered with.
+//!
+//! Root mints a claim of an amount for an Ethereum address. The owner of the
+//! address signs the hex encoded CORD account the tokens should go to, with
+//! the Ethereum `personal_sign` scheme, and submits the signature with
+//! `claim` from that account. The claimed amount is minted into the account.
+//! A claim that is not made within `ClaimExpiry` blocks is dropped.
+//!
+//! ### Storage
+//!
+//! - `Claims`: The amount claimable by each Ethereum address.
+//! - `ExpiresOn`: The block at which each claim expires.
+//! - `ClaimsExpireOn`: The addresses whose claim expires at a block.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `mint_claim` - Make an amount claimable by an Ethereum address.
+//! - `claim` - Claim the amount of an Ethereum address into the caller's
+//!   account.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod secp_utils;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{Currency, Get, Imbalance};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use scale_info::TypeInfo;
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	RuntimeDebug,
+};
+use sp_std::prelude::*;
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// An Ethereum address.
+pub type EthereumAddress = sp_core::H160;
+
+/// A recoverable secp256k1 signature of an Ethereum address, `r`, `s` and
+/// the recovery id `v`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EthereumSignature(pub [u8; 65]);
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency the claims are minted in.
+		type Currency: Currency<Self::AccountId>;
+		/// The text prepended to the hex encoded account in the signed
+		/// message.
+		#[pallet::constant]
+		type Prefix: Get<&'static [u8]>;
+		/// The number of blocks a minted claim stays claimable.
+		#[pallet::constant]
+		type ClaimExpiry: Get<BlockNumberFor<Self>>;
+		/// The maximum number of claims minted in the same block.
+		#[pallet::constant]
+		type MaxClaimsPerBlock: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// amount claimable by an Ethereum address
+	#[pallet::storage]
+	#[pallet::getter(fn claims)]
+	pub type Claims<T: Config> =
+		StorageMap<_, Identity, EthereumAddress, BalanceOf<T>, OptionQuery>;
+
+	/// block at which the claim of an Ethereum address expires
+	#[pallet::storage]
+	#[pallet::getter(fn expires_on)]
+	pub type ExpiresOn<T: Config> =
+		StorageMap<_, Identity, EthereumAddress, BlockNumberFor<T>, OptionQuery>;
+
+	/// maps block number to the addresses whose claim expires at this block
+	#[pallet::storage]
+	pub type ClaimsExpireOn<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<EthereumAddress, T::MaxClaimsPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An amount was made claimable by an Ethereum address.
+		ClaimMinted {
+			ethereum_address: EthereumAddress,
+			amount: BalanceOf<T>,
+			expires_on: BlockNumberFor<T>,
+		},
+		/// The claim of an Ethereum address was minted into an account.
+		Claimed { who: T::AccountId, ethereum_address: EthereumAddress, amount: BalanceOf<T> },
+		/// The claim of an Ethereum address expired unclaimed.
+		ClaimExpired { ethereum_address: EthereumAddress, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The signature does not recover to the Ethereum address
+		InvalidEthereumSignature,
+		/// The Ethereum address has no claim
+		SignerHasNoClaim,
+		/// The Ethereum address already has a claim
+		ClaimAlreadyExists,
+		/// The claim is below the existential deposit
+		ClaimBelowExistentialDeposit,
+		/// Too many claims expire in the same block
+		TooManyExpiringClaims,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let expiring = ClaimsExpireOn::<T>::take(n);
+			let count = expiring.len() as u32;
+			for ethereum_address in expiring {
+				Self::expire_claim(ethereum_address, n);
+			}
+			T::WeightInfo::on_initialize(count)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Make `amount` claimable by `who` for the next `ClaimExpiry`
+		/// blocks.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::mint_claim())]
+		pub fn mint_claim(
+			origin: OriginFor<T>,
+			who: EthereumAddress,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(!Claims::<T>::contains_key(who), Error::<T>::ClaimAlreadyExists);
+			ensure!(
+				amount >= T::Currency::minimum_balance(),
+				Error::<T>::ClaimBelowExistentialDeposit
+			);
+
+			let expires_on =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ClaimExpiry::get());
+			ClaimsExpireOn::<T>::try_mutate(expires_on, |expiring| expiring.try_push(who))
+				.map_err(|_| Error::<T>::TooManyExpiringClaims)?;
+			Claims::<T>::insert(who, amount);
+			ExpiresOn::<T>::insert(who, expires_on);
+
+			Self::deposit_event(Event::ClaimMinted { ethereum_address: who, amount, expires_on });
+			Ok(())
+		}
+
+		/// Mint the claim of `eth_address` into the caller's account.
+		/// `ethereum_signature` is the `personal_sign` signature of `Prefix`
+		/// followed by the hex encoded caller account.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim())]
+		pub fn claim(
+			origin: OriginFor<T>,
+			eth_address: EthereumAddress,
+			ethereum_signature: EthereumSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let signer = Self::eth_recover(&ethereum_signature, &who.using_encoded(to_ascii_hex))
+				.ok_or(Error::<T>::InvalidEthereumSignature)?;
+			ensure!(signer == eth_address, Error::<T>::InvalidEthereumSignature);
+
+			let amount = Claims::<T>::take(eth_address).ok_or(Error::<T>::SignerHasNoClaim)?;
+			ExpiresOn::<T>::remove(eth_address);
+			let minted = T::Currency::deposit_creating(&who, amount);
+			debug_assert!(!minted.peek().is_zero(), "claims are at least the existential deposit");
+
+			Self::deposit_event(Event::Claimed { who, ethereum_address: eth_address, amount });
+			Ok(())
+		}
+	}
+}
+
+/// Converts `data` to lowercase hex, without the `0x` prefix.
+fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
+	let mut hex = Vec::with_capacity(data.len() * 2);
+	let mut push_nibble = |n| hex.push(if n < 10 { b'0' + n } else { b'a' - 10 + n });
+	for &byte in data {
+		push_nibble(byte / 16);
+		push_nibble(byte % 16);
+	}
+	hex
+}
+
+impl<T: Config> Pallet<T> {
+	/// The message signed with `personal_sign` for `what`: the Ethereum
+	/// prefix and the message length, followed by `Prefix` and `what`.
+	pub fn ethereum_signable_message(what: &[u8]) -> Vec<u8> {
+		let prefix = T::Prefix::get();
+		let mut length = prefix.len() + what.len();
+		let mut digits = Vec::new();
+		while length > 0 {
+			digits.push(b'0' + (length % 10) as u8);
+			length /= 10;
+		}
+
+		let mut message = b"\x19Ethereum Signed Message:\n".to_vec();
+		message.extend(digits.into_iter().rev());
+		message.extend_from_slice(prefix);
+		message.extend_from_slice(what);
+		message
+	}
+
+	/// The Ethereum address that signed `what`, if the signature is valid.
+	pub fn eth_recover(signature: &EthereumSignature, what: &[u8]) -> Option<EthereumAddress> {
+		let message = keccak_256(&Self::ethereum_signable_message(what));
+		let public = secp256k1_ecdsa_recover(&signature.0, &message).ok()?;
+		Some(EthereumAddress::from_slice(&keccak_256(&public)[12..]))
+	}
+
+	/// Drop the claim of `ethereum_address` if it is still due to expire at
+	/// block `n`.
+	fn expire_claim(ethereum_address: EthereumAddress, n: BlockNumberFor<T>) {
+		if ExpiresOn::<T>::get(ethereum_address) != Some(n) {
+			return
+		}
+
+		ExpiresOn::<T>::remove(ethereum_address);
+		if let Some(amount) = Claims::<T>::take(ethereum_address) {
+			Self::deposit_event(Event::ClaimExpired { ethereum_address, amount });
+		}
+	}
+}
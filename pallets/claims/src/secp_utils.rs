@@ -0,0 +1,46 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Ethereum keys and signatures for the tests and benchmarks.
+
+use crate::{Config, EthereumAddress, EthereumSignature, Pallet};
+use codec::Encode;
+use sp_io::hashing::keccak_256;
+
+/// The secret key derived from `seed`.
+pub fn secret(seed: &[u8]) -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&keccak_256(seed)).expect("keccak output is a valid key; qed")
+}
+
+/// The Ethereum address of `secret`.
+pub fn eth(secret: &libsecp256k1::SecretKey) -> EthereumAddress {
+	let public = libsecp256k1::PublicKey::from_secret_key(secret);
+	EthereumAddress::from_slice(&keccak_256(&public.serialize()[1..65])[12..])
+}
+
+/// The `personal_sign` signature of `who` by `secret`.
+pub fn sig<T: Config>(secret: &libsecp256k1::SecretKey, who: &T::AccountId) -> EthereumSignature {
+	let what = who.using_encoded(crate::to_ascii_hex);
+	let message = keccak_256(&Pallet::<T>::ethereum_signable_message(&what));
+	let (signature, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(&message), secret);
+	let mut raw = [0u8; 65];
+	raw[0..64].copy_from_slice(&signature.serialize()[..]);
+	raw[64] = recovery_id.serialize();
+	EthereumSignature(raw)
+}
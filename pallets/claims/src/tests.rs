@@ -0,0 +1,173 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	mock::{Claims, *},
+	secp_utils::{eth, secret, sig},
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::traits::BadOrigin;
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		Claims::on_initialize(next);
+	}
+}
+
+#[test]
+fn ethereum_signatures_recover_the_signer() {
+	new_test_ext().execute_with(|| {
+		let alice = secret(b"Alice");
+		let what = 42u64.using_encoded(to_ascii_hex);
+
+		assert_eq!(Claims::eth_recover(&sig::<Test>(&alice, &42), &what), Some(eth(&alice)));
+		assert_ne!(Claims::eth_recover(&sig::<Test>(&alice, &43), &what), Some(eth(&alice)));
+		assert_eq!(Claims::eth_recover(&EthereumSignature([0; 65]), &what), None);
+	});
+}
+
+#[test]
+fn signable_message_carries_the_length_and_prefix() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			Claims::ethereum_signable_message(b"00ff"),
+			b"\x19Ethereum Signed Message:\n33Pay WAYs to the CORD account:00ff".to_vec()
+		);
+		assert_eq!(to_ascii_hex(&[0x00, 0x9f, 0xa0]), b"009fa0".to_vec());
+	});
+}
+
+#[test]
+fn claim_mints_into_the_caller_account() {
+	new_test_ext().execute_with(|| {
+		let alice = secret(b"Alice");
+		let address = eth(&alice);
+
+		assert_noop!(Claims::mint_claim(RuntimeOrigin::signed(1), address, 100), BadOrigin);
+		assert_ok!(Claims::mint_claim(RuntimeOrigin::root(), address, 100));
+		System::assert_last_event(
+			Event::ClaimMinted {
+				ethereum_address: address,
+				amount: 100,
+				expires_on: 1 + CLAIM_EXPIRY,
+			}
+			.into(),
+		);
+		assert_eq!(Claims::claims(address), Some(100));
+
+		assert_ok!(Claims::claim(RuntimeOrigin::signed(7), address, sig::<Test>(&alice, &7)));
+		assert_eq!(Balances::free_balance(7), 100);
+		assert_eq!(Claims::claims(address), None);
+		assert_eq!(Claims::expires_on(address), None);
+		System::assert_last_event(
+			Event::Claimed { who: 7, ethereum_address: address, amount: 100 }.into(),
+		);
+
+		assert_noop!(
+			Claims::claim(RuntimeOrigin::signed(7), address, sig::<Test>(&alice, &7)),
+			Error::<Test>::SignerHasNoClaim
+		);
+	});
+}
+
+#[test]
+fn claim_rejects_foreign_signatures() {
+	new_test_ext().execute_with(|| {
+		let alice = secret(b"Alice");
+		let bob = secret(b"Bob");
+		assert_ok!(Claims::mint_claim(RuntimeOrigin::root(), eth(&alice), 100));
+
+		// Signed by someone else.
+		assert_noop!(
+			Claims::claim(RuntimeOrigin::signed(7), eth(&alice), sig::<Test>(&bob, &7)),
+			Error::<Test>::InvalidEthereumSignature
+		);
+		// Signed for another account.
+		assert_noop!(
+			Claims::claim(RuntimeOrigin::signed(8), eth(&alice), sig::<Test>(&alice, &7)),
+			Error::<Test>::InvalidEthereumSignature
+		);
+		assert_noop!(
+			Claims::claim(RuntimeOrigin::signed(7), eth(&bob), sig::<Test>(&bob, &7)),
+			Error::<Test>::SignerHasNoClaim
+		);
+	});
+}
+
+#[test]
+fn mint_claim_rejects_invalid_claims() {
+	new_test_ext().execute_with(|| {
+		let address = eth(&secret(b"Alice"));
+
+		assert_noop!(
+			Claims::mint_claim(RuntimeOrigin::root(), address, EXISTENTIAL_DEPOSIT - 1),
+			Error::<Test>::ClaimBelowExistentialDeposit
+		);
+		assert_ok!(Claims::mint_claim(RuntimeOrigin::root(), address, 100));
+		assert_noop!(
+			Claims::mint_claim(RuntimeOrigin::root(), address, 200),
+			Error::<Test>::ClaimAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn unclaimed_claims_expire() {
+	new_test_ext().execute_with(|| {
+		let alice = secret(b"Alice");
+		let address = eth(&alice);
+		assert_ok!(Claims::mint_claim(RuntimeOrigin::root(), address, 100));
+
+		run_to_block(CLAIM_EXPIRY);
+		assert_eq!(Claims::claims(address), Some(100));
+
+		run_to_block(CLAIM_EXPIRY + 1);
+		assert_eq!(Claims::claims(address), None);
+		assert_eq!(Claims::expires_on(address), None);
+		System::assert_last_event(
+			Event::ClaimExpired { ethereum_address: address, amount: 100 }.into(),
+		);
+		assert_noop!(
+			Claims::claim(RuntimeOrigin::signed(7), address, sig::<Test>(&alice, &7)),
+			Error::<Test>::SignerHasNoClaim
+		);
+	});
+}
+
+#[test]
+fn a_claim_minted_again_keeps_its_own_expiry() {
+	new_test_ext().execute_with(|| {
+		let alice = secret(b"Alice");
+		let address = eth(&alice);
+		assert_ok!(Claims::mint_claim(RuntimeOrigin::root(), address, 100));
+		assert_ok!(Claims::claim(RuntimeOrigin::signed(7), address, sig::<Test>(&alice, &7)));
+
+		run_to_block(10);
+		assert_ok!(Claims::mint_claim(RuntimeOrigin::root(), address, 50));
+
+		// The expiry of the first claim leaves the second one alone.
+		run_to_block(CLAIM_EXPIRY + 1);
+		assert_eq!(Claims::claims(address), Some(50));
+
+		run_to_block(CLAIM_EXPIRY + 10);
+		assert_eq!(Claims::claims(address), None);
+	});
+}
@@ -0,0 +1,138 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_claims`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_claims
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/claims/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_claims`.
+pub trait WeightInfo {
+	fn mint_claim() -> Weight;
+	fn claim() -> Weight;
+	fn on_initialize(c: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_cord_claims` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Claims::Claims` (r:1 w:1)
+	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::ClaimsExpireOn` (r:1 w:1)
+	/// Proof: `Claims::ClaimsExpireOn` (`max_values`: None, `max_size`: Some(20026), added: 22501, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::ExpiresOn` (r:0 w:1)
+	/// Proof: `Claims::ExpiresOn` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn mint_claim() -> Weight {
+		Weight::from_parts(21_980_000, 23491)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Claims::Claims` (r:1 w:1)
+	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::ExpiresOn` (r:0 w:1)
+	/// Proof: `Claims::ExpiresOn` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn claim() -> Weight {
+		Weight::from_parts(79_630_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Claims::ClaimsExpireOn` (r:1 w:1)
+	/// Proof: `Claims::ClaimsExpireOn` (`max_values`: None, `max_size`: Some(20026), added: 22501, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::ExpiresOn` (r:1000 w:1000)
+	/// Proof: `Claims::ExpiresOn` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::Claims` (r:1000 w:1000)
+	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 1000]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(3_340_000, 23491)
+			.saturating_add(Weight::from_parts(14_218, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(c.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Claims::Claims` (r:1 w:1)
+	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::ClaimsExpireOn` (r:1 w:1)
+	/// Proof: `Claims::ClaimsExpireOn` (`max_values`: None, `max_size`: Some(20026), added: 22501, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::ExpiresOn` (r:0 w:1)
+	/// Proof: `Claims::ExpiresOn` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn mint_claim() -> Weight {
+		Weight::from_parts(21_980_000, 23491)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Claims::Claims` (r:1 w:1)
+	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::ExpiresOn` (r:0 w:1)
+	/// Proof: `Claims::ExpiresOn` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn claim() -> Weight {
+		Weight::from_parts(79_630_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Claims::ClaimsExpireOn` (r:1 w:1)
+	/// Proof: `Claims::ClaimsExpireOn` (`max_values`: None, `max_size`: Some(20026), added: 22501, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::ExpiresOn` (r:1000 w:1000)
+	/// Proof: `Claims::ExpiresOn` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::Claims` (r:1000 w:1000)
+	/// Proof: `Claims::Claims` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 1000]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(3_340_000, 23491)
+			.saturating_add(Weight::from_parts(14_218, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(c.into()))
+	}
+}
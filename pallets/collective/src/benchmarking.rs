@@ -749,5 +749,31 @@ verify {
 	assert_last_event::<T, I>(Event::Disapproved { proposal_hash: last_hash }.into());
 }
 
+set_voting_mode {
+}: _(SystemOrigin::Root, VotingMode::StakeWeighted)
+verify {
+	assert_eq!(Collective::<T, I>::voting_mode(), VotingMode::StakeWeighted);
+	assert_last_event::<T, I>(Event::VotingModeSet { mode: VotingMode::StakeWeighted }.into());
+}
+
+tally_stake {
+	let m in 1 .. T::MaxMembers::get();
+
+	// Every member holds stake, and every member approves.
+	let mut members = vec![];
+	for i in 0 .. m {
+		let member = account::<T::AccountId>("member", i, SEED);
+		T::Currency::make_free_balance_be(&member, T::Currency::minimum_balance());
+		members.push(member);
+	}
+	Collective::<T, I>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
+	Collective::<T, I>::set_voting_mode(SystemOrigin::Root.into(), VotingMode::StakeWeighted)?;
+}: {
+	assert_eq!(Collective::<T, I>::count_votes(&members, &[], m), (m, 0));
+}
+verify {
+	assert_eq!(Collective::<T, I>::voting_mode(), VotingMode::StakeWeighted);
+}
+
 impl_benchmark_test_suite!(Collective, crate::tests::ExtBuilder::default().build(), crate::tests::Test);
 }
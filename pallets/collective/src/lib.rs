@@ -45,6 +45,12 @@
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without
 //! being executed.
+//!
+//! The `VotingMode` decides how votes are counted. With `EqualWeight`, the
+//! default, every member has one vote. With `StakeWeighted`, a vote counts
+//! with the voter's share of the free balance of all members, converted back
+//! into the equivalent number of members (rounded down), so thresholds and the
+//! `Members` origin keep their meaning.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
@@ -53,8 +59,9 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_io::storage;
 use sp_runtime::{
-	traits::{Dispatchable, Hash},
-	DispatchError, RuntimeDebug,
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{Dispatchable, Hash, UniqueSaturatedInto},
+	DispatchError, Rounding, RuntimeDebug,
 };
 use sp_std::{marker::PhantomData, prelude::*, result};
 
@@ -64,7 +71,7 @@ use frame_support::{
 	},
 	ensure, impl_ensure_origin_with_arg_ignoring_arg,
 	traits::{
-		Backing, ChangeMembers, EnsureOrigin, EnsureOriginWithArg, Get, GetBacking,
+		Backing, ChangeMembers, Currency, EnsureOrigin, EnsureOriginWithArg, Get, GetBacking,
 		InitializeMembers, StorageVersion,
 	},
 	weights::Weight,
@@ -142,6 +149,18 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 	}
 }
 
+/// How the votes of members are counted when a motion is closed.
+#[derive(
+	Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub enum VotingMode {
+	/// Every member has one vote.
+	#[default]
+	EqualWeight,
+	/// Every member votes with the free balance they hold.
+	StakeWeighted,
+}
+
 /// Origin for the collective module.
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(I))]
@@ -242,6 +261,10 @@ pub mod pallet {
 		/// executed.
 		#[pallet::constant]
 		type MaxProposalWeight: Get<Weight>;
+
+		/// The currency whose free balance weighs the votes of members in
+		/// `VotingMode::StakeWeighted`.
+		type Currency: Currency<Self::AccountId>;
 	}
 
 	#[pallet::genesis_config]
@@ -311,6 +334,12 @@ pub mod pallet {
 	#[pallet::getter(fn prime)]
 	pub type Prime<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// How the votes of members are counted.
+	#[pallet::storage]
+	#[pallet::getter(fn voting_mode)]
+	pub type CurrentVotingMode<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, VotingMode, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -345,6 +374,8 @@ pub mod pallet {
 		/// A proposal was closed because its threshold was reached or after its
 		/// duration was up.
 		Closed { proposal_hash: T::Hash, yes: MemberCount, no: MemberCount },
+		/// The way votes are counted was changed.
+		VotingModeSet { mode: VotingMode },
 	}
 
 	#[pallet::error]
@@ -666,6 +697,7 @@ pub mod pallet {
 					.max(<T as pallet::Config<I>>::WeightInfo::close_early_disapproved(m, p2))
 					.max(<T as pallet::Config<I>>::WeightInfo::close_approved(b, m, p2))
 					.max(<T as pallet::Config<I>>::WeightInfo::close_disapproved(m, p2))
+					.saturating_add(<T as pallet::Config<I>>::WeightInfo::tally_stake(m))
 					.saturating_add(p1)
 			},
 			DispatchClass::Operational
@@ -683,6 +715,22 @@ pub mod pallet {
 
 			Self::do_close(proposal_hash, index, proposal_weight_bound, length_bound)
 		}
+
+		/// Set how the votes of members are counted when a motion is closed.
+		/// Open motions are counted in the new mode from then on.
+		///
+		/// The dispatch origin of this call must be `NetworkMembershipOrigin`.
+		#[pallet::call_index(7)]
+		#[pallet::weight((
+			<T as pallet::Config<I>>::WeightInfo::set_voting_mode(),
+			DispatchClass::Operational
+		))]
+		pub fn set_voting_mode(origin: OriginFor<T>, mode: VotingMode) -> DispatchResult {
+			<T as pallet::Config<I>>::NetworkMembershipOrigin::ensure_origin(origin)?;
+			CurrentVotingMode::<T, I>::put(mode);
+			Self::deposit_event(Event::VotingModeSet { mode });
+			Ok(())
+		}
 	}
 }
 
@@ -824,9 +872,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let voting = Self::voting(proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
 		ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
-		let mut no_votes = voting.nays.len() as MemberCount;
-		let mut yes_votes = voting.ayes.len() as MemberCount;
 		let seats = Self::members().len() as MemberCount;
+		let (mut yes_votes, mut no_votes) = Self::count_votes(&voting.ayes, &voting.nays, seats);
+		let tally_weight = Self::tally_weight(seats);
 		let approved = yes_votes >= voting.threshold;
 		let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
 		// Allow (dis-)approving the proposal as soon as there are enough votes.
@@ -846,7 +894,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						seats,
 						proposal_count,
 					)
-					.saturating_add(proposal_weight),
+					.saturating_add(proposal_weight)
+					.saturating_add(tally_weight),
 				),
 				Pays::No,
 			)
@@ -855,10 +904,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 			return Ok((
-				Some(<T as pallet::Config<I>>::WeightInfo::close_early_disapproved(
-					seats,
-					proposal_count,
-				)),
+				Some(
+					<T as pallet::Config<I>>::WeightInfo::close_early_disapproved(
+						seats,
+						proposal_count,
+					)
+					.saturating_add(tally_weight),
+				),
 				Pays::No,
 			)
 				.into());
@@ -895,7 +947,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						seats,
 						proposal_count,
 					)
-					.saturating_add(proposal_weight),
+					.saturating_add(proposal_weight)
+					.saturating_add(tally_weight),
 				),
 				Pays::No,
 			)
@@ -904,16 +957,62 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 			Ok((
-				Some(<T as pallet::Config<I>>::WeightInfo::close_disapproved(
-					seats,
-					proposal_count,
-				)),
+				Some(
+					<T as pallet::Config<I>>::WeightInfo::close_disapproved(seats, proposal_count)
+						.saturating_add(tally_weight),
+				),
 				Pays::No,
 			)
 				.into())
 		}
 	}
 
+	/// Count the approvals and rejections of a motion as a number of members.
+	///
+	/// In `VotingMode::StakeWeighted` the share of the members' free balance
+	/// held by the voters is converted into the equivalent number of `seats`,
+	/// rounded down. If the members hold no balance at all, every member
+	/// counts once.
+	pub(crate) fn count_votes(
+		ayes: &[T::AccountId],
+		nays: &[T::AccountId],
+		seats: MemberCount,
+	) -> (MemberCount, MemberCount) {
+		let head_count = (ayes.len() as MemberCount, nays.len() as MemberCount);
+		if Self::voting_mode() == VotingMode::EqualWeight {
+			return head_count;
+		}
+
+		let stake_of = |voters: &[T::AccountId]| {
+			voters.iter().fold(0u128, |stake, who| {
+				stake.saturating_add(T::Currency::free_balance(who).unique_saturated_into())
+			})
+		};
+		let total_stake = stake_of(&Self::members());
+		if total_stake == 0 {
+			return head_count;
+		}
+		let seats_of = |voters: &[T::AccountId]| {
+			multiply_by_rational_with_rounding(
+				stake_of(voters),
+				seats.into(),
+				total_stake,
+				Rounding::Down,
+			)
+			.map_or(0, |count| count as MemberCount)
+		};
+		(seats_of(ayes), seats_of(nays))
+	}
+
+	/// The weight `count_votes` adds to closing a motion in a collective of
+	/// `seats` members.
+	fn tally_weight(seats: MemberCount) -> Weight {
+		match Self::voting_mode() {
+			VotingMode::EqualWeight => Weight::zero(),
+			VotingMode::StakeWeighted => <T as pallet::Config<I>>::WeightInfo::tally_stake(seats),
+		}
+	}
+
 	/// Ensure that the right proposal bounds were passed and get the proposal
 	/// from storage.
 	///
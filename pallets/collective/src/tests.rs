@@ -40,6 +40,7 @@ construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Collective: pallet_collective::<Instance1>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		CollectiveMajority: pallet_collective::<Instance2>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		DefaultCollective: pallet_collective::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
@@ -111,7 +112,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -130,6 +131,7 @@ impl Config<Instance1> for Test {
 	type WeightInfo = ();
 	type NetworkMembershipOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
+	type Currency = Balances;
 }
 impl Config<Instance2> for Test {
 	type RuntimeOrigin = RuntimeOrigin;
@@ -142,6 +144,22 @@ impl Config<Instance2> for Test {
 	type WeightInfo = ();
 	type NetworkMembershipOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
+	type Currency = Balances;
+}
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
 }
 impl mock_democracy::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -158,11 +176,13 @@ impl Config for Test {
 	type WeightInfo = ();
 	type NetworkMembershipOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
+	type Currency = Balances;
 }
 
 parameter_types! {
 	pub const MembershipPeriod: BlockNumberFor<Test> = 5;
 	pub const MaxMembersPerBlock: u32 = 5;
+	pub const MaxExtrinsicsPerBlock: u32 = 5;
 }
 
 impl pallet_network_membership::Config for Test {
@@ -170,6 +190,7 @@ impl pallet_network_membership::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MembershipPeriod = MembershipPeriod;
 	type MaxMembersPerBlock = MaxMembersPerBlock;
+	type MaxExtrinsicsPerBlock = MaxExtrinsicsPerBlock;
 	type WeightInfo = ();
 }
 
@@ -215,6 +236,7 @@ impl ExtBuilder {
 				members: network_membership.into_iter().map(|member| (member, false)).collect(),
 			},
 			default_collective: Default::default(),
+			balances: Default::default(),
 		}
 		.build_storage()
 		.unwrap()
@@ -1330,6 +1352,126 @@ fn disapprove_proposal_works() {
 	})
 }
 
+#[test]
+fn set_voting_mode_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(Collective::voting_mode(), VotingMode::EqualWeight);
+		assert_noop!(
+			Collective::set_voting_mode(RuntimeOrigin::signed(1), VotingMode::StakeWeighted),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Collective::set_voting_mode(RuntimeOrigin::root(), VotingMode::StakeWeighted));
+		assert_eq!(Collective::voting_mode(), VotingMode::StakeWeighted);
+		System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::VotingModeSet {
+			mode: VotingMode::StakeWeighted,
+		}));
+		// the other collectives keep counting heads
+		assert_eq!(CollectiveMajority::voting_mode(), VotingMode::EqualWeight);
+	});
+}
+
+/// Make 1, 2 and 3 the members of `Collective`, holding 10%, 10% and 80% of
+/// the stake, and propose `proposal` with a threshold of two members.
+fn propose_to_staked_members(proposal: RuntimeCall) -> H256 {
+	let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+	assert_ok!(Collective::set_members(
+		RuntimeOrigin::root(),
+		vec![1, 2, 3],
+		None,
+		MaxMembers::get()
+	));
+	Balances::make_free_balance_be(&1, 10);
+	Balances::make_free_balance_be(&2, 10);
+	Balances::make_free_balance_be(&3, 80);
+	let hash = BlakeTwo256::hash_of(&proposal);
+	assert_ok!(Collective::propose(RuntimeOrigin::signed(1), 2, Box::new(proposal), proposal_len));
+	hash
+}
+
+#[test]
+fn stake_weighted_votes_count_the_free_balance() {
+	ExtBuilder::default().build_and_execute(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = propose_to_staked_members(proposal);
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(3), hash, 0, true));
+
+		// one of three members does not reach the threshold
+		assert_noop!(
+			Collective::close(RuntimeOrigin::signed(3), hash, 0, proposal_weight, proposal_len),
+			Error::<Test, Instance1>::TooEarly
+		);
+
+		// 80% of the stake is worth two of the three seats
+		assert_ok!(Collective::set_voting_mode(RuntimeOrigin::root(), VotingMode::StakeWeighted));
+		assert_ok!(Collective::close(
+			RuntimeOrigin::signed(3),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+		System::assert_has_event(RuntimeEvent::Collective(CollectiveEvent::Closed {
+			proposal_hash: hash,
+			yes: 2,
+			no: 0,
+		}));
+		System::assert_has_event(RuntimeEvent::Collective(CollectiveEvent::Approved {
+			proposal_hash: hash,
+		}));
+		assert!(Collective::proposals().is_empty());
+	});
+}
+
+#[test]
+fn stake_weighted_votes_disapprove_against_the_majority_stake() {
+	ExtBuilder::default().build_and_execute(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = propose_to_staked_members(proposal);
+		assert_ok!(Collective::set_voting_mode(RuntimeOrigin::root(), VotingMode::StakeWeighted));
+
+		// two of three members, but only 20% of the stake, approve
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, true));
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, true));
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(3), hash, 0, false));
+
+		assert_ok!(Collective::close(
+			RuntimeOrigin::signed(3),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+		System::assert_has_event(RuntimeEvent::Collective(CollectiveEvent::Closed {
+			proposal_hash: hash,
+			yes: 0,
+			no: 2,
+		}));
+		System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Disapproved {
+			proposal_hash: hash,
+		}));
+	});
+}
+
+#[test]
+fn stake_weighted_votes_count_heads_without_stake() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Collective::set_members(
+			RuntimeOrigin::root(),
+			vec![1, 2, 3],
+			None,
+			MaxMembers::get()
+		));
+		assert_ok!(Collective::set_voting_mode(RuntimeOrigin::root(), VotingMode::StakeWeighted));
+
+		assert_eq!(Collective::count_votes(&[1, 2], &[3], 3), (2, 1));
+	});
+}
+
 #[should_panic(expected = "Members length cannot exceed MaxMembers.")]
 #[test]
 fn genesis_build_panics_with_too_many_members() {
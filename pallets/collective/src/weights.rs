@@ -23,6 +23,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `smohan-dev-host`, CPU: `AMD EPYC 7B12`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! The weights of `set_voting_mode` and `tally_stake` are hand-written
+//! placeholders until the pallet is benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -59,6 +62,8 @@ pub trait WeightInfo {
 	fn close_disapproved(m: u32, p: u32, ) -> Weight;
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight;
 	fn disapprove_proposal(p: u32, ) -> Weight;
+	fn set_voting_mode() -> Weight;
+	fn tally_stake(m: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_collective` using the CORD node and recommended hardware.
@@ -310,6 +315,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(p.into()))
 	}
+	/// Storage: `Council::CurrentVotingMode` (r:0 w:1)
+	/// Proof: `Council::CurrentVotingMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn set_voting_mode() -> Weight {
+		Weight::from_parts(7_140_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Council::CurrentVotingMode` (r:1 w:0)
+	/// Proof: `Council::CurrentVotingMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:50 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 50]`.
+	/// Hand-written placeholder, not a benchmark result.
+	fn tally_stake(m: u32, ) -> Weight {
+		Weight::from_parts(8_446_327, 1627)
+			.saturating_add(Weight::from_parts(4_013_572, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(m.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -560,4 +587,26 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(p.into()))
 	}
+	/// Storage: `Council::CurrentVotingMode` (r:0 w:1)
+	/// Proof: `Council::CurrentVotingMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn set_voting_mode() -> Weight {
+		Weight::from_parts(7_140_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Council::CurrentVotingMode` (r:1 w:0)
+	/// Proof: `Council::CurrentVotingMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:50 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 50]`.
+	/// Hand-written placeholder, not a benchmark result.
+	fn tally_stake(m: u32, ) -> Weight {
+		Weight::from_parts(8_446_327, 1627)
+			.saturating_add(Weight::from_parts(4_013_572, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(m.into()))
+	}
 }
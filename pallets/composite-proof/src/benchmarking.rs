@@ -0,0 +1,82 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use codec::Encode;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn proof_hash(seed: &[u8]) -> H256 {
+	<sp_runtime::traits::BlakeTwo256 as Hash>::hash(&seed.encode())
+}
+
+fn assert_anchored<T: Config>(
+	submitter: T::AccountId,
+	proof_type: ProofType,
+	proof_root: H256,
+	public_inputs_hash: H256,
+) {
+	assert!(Pallet::<T>::verify_proof_root(proof_root));
+	assert_last_event::<T>(
+		Event::CompositeProofAnchored { submitter, proof_root, proof_type, public_inputs_hash }
+			.into(),
+	);
+}
+
+benchmarks! {
+	anchor_merkle_proof {
+		let caller: T::AccountId = whitelisted_caller();
+		let proof_root = proof_hash(b"root");
+		let public_inputs_hash = proof_hash(b"public inputs");
+	}: anchor_composite_proof(RawOrigin::Signed(caller.clone()), ProofType::MerkleProof, proof_root, public_inputs_hash)
+	verify {
+		assert_anchored::<T>(caller, ProofType::MerkleProof, proof_root, public_inputs_hash);
+	}
+
+	anchor_groth16_proof {
+		let caller: T::AccountId = whitelisted_caller();
+		let proof_root = proof_hash(b"root");
+		let public_inputs_hash = proof_hash(b"public inputs");
+	}: anchor_composite_proof(RawOrigin::Signed(caller.clone()), ProofType::Groth16, proof_root, public_inputs_hash)
+	verify {
+		assert_anchored::<T>(caller, ProofType::Groth16, proof_root, public_inputs_hash);
+	}
+
+	anchor_plonk_proof {
+		let caller: T::AccountId = whitelisted_caller();
+		let proof_root = proof_hash(b"root");
+		let public_inputs_hash = proof_hash(b"public inputs");
+	}: anchor_composite_proof(RawOrigin::Signed(caller.clone()), ProofType::Plonk, proof_root, public_inputs_hash)
+	verify {
+		assert_anchored::<T>(caller, ProofType::Plonk, proof_root, public_inputs_hash);
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
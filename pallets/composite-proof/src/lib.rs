@@ -0,0 +1,158 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Composite Proof Pallet
+//!
+//! The Composite Proof Pallet anchors the roots of proofs built off chain
+//! over credentials, such as Merkle proofs or Groth16 and PLONK SNARKs. Only
+//! the proof root and the hash of the public inputs reach the chain; the
+//! witness data stays with the prover. A verifier holding a proof checks
+//! with `verify_proof_root` that its root was anchored, and reads the
+//! submitter and the block of the anchor from `CompositeProofs`.
+//!
+//! A proof root can be anchored once, and the anchor is never updated or
+//! removed.
+//!
+//! ### Storage
+//!
+//! - `CompositeProofs`: The anchor of every proof root.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `anchor_composite_proof` - Anchor the root of a proof.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+use sp_core::H256;
+pub use types::{CompositeProofEntry, ProofType};
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of a proof anchor.
+	pub type CompositeProofEntryOf<T> =
+		CompositeProofEntry<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// proof anchors by proof root
+	#[pallet::storage]
+	#[pallet::getter(fn composite_proofs)]
+	pub type CompositeProofs<T: Config> =
+		StorageMap<_, Blake2_128Concat, H256, CompositeProofEntryOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A proof root was anchored.
+		CompositeProofAnchored {
+			submitter: T::AccountId,
+			proof_root: H256,
+			proof_type: ProofType,
+			public_inputs_hash: H256,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The proof root is already anchored.
+		ProofAlreadyAnchored,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Anchor `proof_root`, the root of a proof of type `proof_type`, as
+		/// of the current block. `public_inputs_hash` is the hash of the
+		/// public inputs of the proof, and is only recorded in the event.
+		#[pallet::call_index(0)]
+		#[pallet::weight(Pallet::<T>::anchor_weight(proof_type))]
+		pub fn anchor_composite_proof(
+			origin: OriginFor<T>,
+			proof_type: ProofType,
+			proof_root: H256,
+			public_inputs_hash: H256,
+		) -> DispatchResult {
+			let submitter = ensure_signed(origin)?;
+			ensure!(
+				!CompositeProofs::<T>::contains_key(proof_root),
+				Error::<T>::ProofAlreadyAnchored
+			);
+
+			let block = frame_system::Pallet::<T>::block_number();
+			CompositeProofs::<T>::insert(
+				proof_root,
+				CompositeProofEntry { submitter: submitter.clone(), block, proof_type },
+			);
+
+			Self::deposit_event(Event::CompositeProofAnchored {
+				submitter,
+				proof_root,
+				proof_type,
+				public_inputs_hash,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `root` is an anchored proof root.
+	pub fn verify_proof_root(root: H256) -> bool {
+		CompositeProofs::<T>::contains_key(root)
+	}
+
+	/// The weight of anchoring a proof root of type `proof_type`.
+	fn anchor_weight(proof_type: &ProofType) -> frame_support::weights::Weight {
+		match proof_type {
+			ProofType::MerkleProof => T::WeightInfo::anchor_merkle_proof(),
+			ProofType::Groth16 => T::WeightInfo::anchor_groth16_proof(),
+			ProofType::Plonk => T::WeightInfo::anchor_plonk_proof(),
+		}
+	}
+}
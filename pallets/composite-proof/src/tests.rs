@@ -0,0 +1,104 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+const ROOT: H256 = H256::repeat_byte(1);
+const PUBLIC_INPUTS: H256 = H256::repeat_byte(2);
+
+#[test]
+fn anchor_composite_proof_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CompositeProof::anchor_composite_proof(
+				RuntimeOrigin::root(),
+				ProofType::Groth16,
+				ROOT,
+				PUBLIC_INPUTS
+			),
+			BadOrigin
+		);
+
+		System::set_block_number(5);
+		assert_ok!(CompositeProof::anchor_composite_proof(
+			RuntimeOrigin::signed(ALICE),
+			ProofType::Groth16,
+			ROOT,
+			PUBLIC_INPUTS
+		));
+		assert_eq!(
+			CompositeProof::composite_proofs(ROOT),
+			Some(CompositeProofEntry {
+				submitter: ALICE,
+				block: 5,
+				proof_type: ProofType::Groth16
+			})
+		);
+		System::assert_last_event(
+			Event::CompositeProofAnchored {
+				submitter: ALICE,
+				proof_root: ROOT,
+				proof_type: ProofType::Groth16,
+				public_inputs_hash: PUBLIC_INPUTS,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn proof_root_can_only_be_anchored_once() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CompositeProof::anchor_composite_proof(
+			RuntimeOrigin::signed(ALICE),
+			ProofType::MerkleProof,
+			ROOT,
+			PUBLIC_INPUTS
+		));
+		// Neither by another account, nor as another kind of proof.
+		assert_noop!(
+			CompositeProof::anchor_composite_proof(
+				RuntimeOrigin::signed(BOB),
+				ProofType::Plonk,
+				ROOT,
+				H256::zero()
+			),
+			Error::<Test>::ProofAlreadyAnchored
+		);
+	});
+}
+
+#[test]
+fn verify_proof_root_reports_anchored_roots() {
+	new_test_ext().execute_with(|| {
+		assert!(!CompositeProof::verify_proof_root(ROOT));
+
+		assert_ok!(CompositeProof::anchor_composite_proof(
+			RuntimeOrigin::signed(BOB),
+			ProofType::Plonk,
+			ROOT,
+			PUBLIC_INPUTS
+		));
+		assert!(CompositeProof::verify_proof_root(ROOT));
+		// The public inputs hash is not a proof root.
+		assert!(!CompositeProof::verify_proof_root(PUBLIC_INPUTS));
+	});
+}
@@ -0,0 +1,46 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The kind of proof a root is anchored for.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum ProofType {
+	/// The root of a Merkle tree.
+	MerkleProof,
+	/// A Groth16 SNARK.
+	Groth16,
+	/// A PLONK SNARK.
+	Plonk,
+}
+
+/// An anchored proof root.
+///
+/// ## Fields
+///
+/// - `submitter`: The account that anchored the proof root.
+/// - `block`: The block the proof root was anchored at.
+/// - `proof_type`: The kind of proof the root belongs to.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct CompositeProofEntry<AccountId, BlockNumber> {
+	pub submitter: AccountId,
+	pub block: BlockNumber,
+	pub proof_type: ProofType,
+}
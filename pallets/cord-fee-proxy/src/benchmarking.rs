@@ -0,0 +1,127 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::dispatch::Pays;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Zero};
+use sp_std::vec;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Approve a sponsor and let it pay the fees of `caller`.
+fn setup_delegation<T: Config>(caller: &T::AccountId) -> T::AccountId {
+	let sponsor: T::AccountId = account("sponsor", 0, 0);
+	Sponsors::<T>::insert(&sponsor, ());
+	Delegations::<T>::insert(
+		caller,
+		Delegation { sponsor: sponsor.clone(), budget: BalanceOf::<T>::max_value() },
+	);
+	sponsor
+}
+
+benchmarks! {
+	where_clause {
+		where
+			BalanceOf<T>: Send + Sync + From<u64>,
+			<T as frame_system::Config>::RuntimeCall:
+				Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	}
+
+	approve_sponsor {
+		let who: T::AccountId = account("sponsor", 0, 0);
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert_last_event::<T>(Event::SponsorApproved { who }.into());
+	}
+
+	revoke_sponsor {
+		let who: T::AccountId = account("sponsor", 0, 0);
+		Sponsors::<T>::insert(&who, ());
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert_last_event::<T>(Event::SponsorRevoked { who }.into());
+	}
+
+	// The dispatch overhead of a sponsored call, without the call itself.
+	sponsor_call {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_delegation::<T>(&caller);
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+	}: _(RawOrigin::Signed(caller.clone()), Box::new(call), BalanceOf::<T>::max_value())
+	verify {
+		assert_last_event::<T>(Event::CallSponsored { who: caller, result: Ok(()) }.into());
+	}
+
+	// Replacing an existing delegation.
+	delegate {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let sponsor = setup_delegation::<T>(&caller);
+		let budget = BalanceOf::<T>::from(1_000u64);
+	}: _(RawOrigin::Signed(sponsor.clone()), caller.clone(), budget)
+	verify {
+		assert_last_event::<T>(Event::Delegated { sponsor, caller, budget }.into());
+	}
+
+	undelegate {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let sponsor = setup_delegation::<T>(&caller);
+	}: _(RawOrigin::Signed(sponsor.clone()), caller.clone())
+	verify {
+		assert_last_event::<T>(Event::Undelegated { sponsor, caller }.into());
+	}
+
+	// The sponsor charged and refunded the fee of a sponsored call by the
+	// extension.
+	sponsor_fee {
+		let caller: T::AccountId = whitelisted_caller();
+		let sponsor = setup_delegation::<T>(&caller);
+		T::BenchmarkHelper::fund_sponsor(&sponsor);
+
+		let inner: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let max_fee = BalanceOf::<T>::max_value();
+		let call: <T as Config>::RuntimeCall =
+			Call::<T>::sponsor_call { call: Box::new(inner), max_fee }.into();
+		let info = call.get_dispatch_info();
+		let post_info = PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes };
+		let call: <T as frame_system::Config>::RuntimeCall = call.into();
+	}: {
+		let pre = FeeProxyExtension::<T>::from(Zero::zero())
+			.pre_dispatch(&caller, &call, &info, 100)
+			.ok();
+		assert!(pre.is_some());
+		assert!(FeeProxyExtension::<T>::post_dispatch(pre, &info, &post_info, 100, &Ok(())).is_ok());
+	}
+	verify {
+		assert!(Delegations::<T>::get(&caller).unwrap().budget < BalanceOf::<T>::max_value());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
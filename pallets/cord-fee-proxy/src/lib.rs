@@ -0,0 +1,419 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Fee Proxy Pallet
+//!
+//! The Fee Proxy Pallet lets sponsors pay the transaction fees of callers
+//! they delegated to, so new network participants can transact before they
+//! hold any WAY.
+//!
+//! Root approves the sponsors. A sponsor delegates to a caller with a fee
+//! budget, and the caller wraps its calls in `sponsor_call`. The call is
+//! dispatched with the caller as origin, while the `FeeProxyExtension`
+//! signed extension withdraws the fee from the sponsor instead of the
+//! caller and deducts it from the budget. The part of the fee refunded after
+//! the dispatch goes back into the budget.
+//!
+//! The extension takes the place of
+//! `pallet_transaction_payment::ChargeTransactionPayment` in the signed
+//! extensions of the runtime and charges every other call as usual.
+//!
+//! ### Storage
+//!
+//! - `Sponsors`: The accounts approved to pay the fees of other callers.
+//! - `Delegations`: The sponsor and the remaining fee budget of each
+//!   caller.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `approve_sponsor` - Allow an account to sponsor callers.
+//! - `revoke_sponsor` - Stop an account from sponsoring callers.
+//! - `sponsor_call` - Dispatch a call with the fee paid by the sponsor.
+//! - `delegate` - Pay the fees of a caller, up to a budget.
+//! - `undelegate` - Stop paying the fees of a caller.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::{DispatchInfo, GetDispatchInfo, PostDispatchInfo},
+	traits::IsSubType,
+};
+pub use pallet::*;
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, Saturating, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError},
+	DispatchResult, RuntimeDebug,
+};
+use sp_std::boxed::Box;
+pub use weights::WeightInfo;
+
+/// Sets up the sponsor the benchmarks need.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+	/// Give `sponsor` enough funds to pay a transaction fee.
+	fn fund_sponsor(sponsor: &AccountId);
+}
+
+pub type BalanceOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// The sponsor paying the fees of a caller, and what is left of the fees it
+/// agreed to pay.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct Delegation<AccountId, Balance> {
+	/// The account paying the fees.
+	pub sponsor: AccountId,
+	/// The fees the sponsor still pays for the caller.
+	pub budget: Balance,
+}
+
+pub type DelegationOf<T> = Delegation<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The runtime call type a sponsored call is made of.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>
+			+ From<Call<Self>>
+			+ IsSubType<Call<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// Funds the sponsor for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// accounts approved to pay the transaction fees of other callers
+	#[pallet::storage]
+	pub type Sponsors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// sponsor and remaining fee budget of the sponsored callers
+	#[pallet::storage]
+	pub type Delegations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DelegationOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account may sponsor callers.
+		SponsorApproved { who: T::AccountId },
+		/// An account may no longer sponsor callers.
+		SponsorRevoked { who: T::AccountId },
+		/// A sponsor pays the fees of a caller, up to the budget.
+		Delegated { sponsor: T::AccountId, caller: T::AccountId, budget: BalanceOf<T> },
+		/// A sponsor no longer pays the fees of a caller.
+		Undelegated { sponsor: T::AccountId, caller: T::AccountId },
+		/// A sponsored call was dispatched.
+		CallSponsored { who: T::AccountId, result: DispatchResult },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already an approved sponsor
+		AlreadySponsor,
+		/// The account is not an approved sponsor
+		NotSponsor,
+		/// The caller is sponsored by another account
+		AlreadyDelegated,
+		/// The caller is not sponsored by the account
+		NotDelegated,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Allow `who` to pay the transaction fees of other callers.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_sponsor())]
+		pub fn approve_sponsor(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(!Sponsors::<T>::contains_key(&who), Error::<T>::AlreadySponsor);
+			Sponsors::<T>::insert(&who, ());
+
+			Self::deposit_event(Event::SponsorApproved { who });
+			Ok(())
+		}
+
+		/// Stop `who` from paying the transaction fees of other callers.
+		///
+		/// The delegations of `who` are kept, but can not be used until it
+		/// is approved again.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_sponsor())]
+		pub fn revoke_sponsor(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(Sponsors::<T>::contains_key(&who), Error::<T>::NotSponsor);
+			Sponsors::<T>::remove(&who);
+
+			Self::deposit_event(Event::SponsorRevoked { who });
+			Ok(())
+		}
+
+		/// Dispatch `call` with the signed origin of a sponsored caller.
+		///
+		/// The `FeeProxyExtension` charges the fee of the extrinsic to the
+		/// sponsor of the caller, provided it does not exceed `max_fee` or
+		/// the remaining budget.
+		#[pallet::call_index(2)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				<T as pallet::Config>::WeightInfo::sponsor_call()
+					.saturating_add(<T as pallet::Config>::WeightInfo::sponsor_fee())
+					.saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn sponsor_call(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+			_max_fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let delegation = Delegations::<T>::get(&who).ok_or(Error::<T>::NotDelegated)?;
+			ensure!(Sponsors::<T>::contains_key(&delegation.sponsor), Error::<T>::NotSponsor);
+
+			let result = call.dispatch(origin);
+			Self::deposit_event(Event::CallSponsored {
+				who,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(())
+		}
+
+		/// Pay the transaction fees of the `sponsor_call`s of `caller`, up to
+		/// `budget` in total. Delegating again replaces the budget.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			caller: T::AccountId,
+			budget: BalanceOf<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			ensure!(Sponsors::<T>::contains_key(&sponsor), Error::<T>::NotSponsor);
+			if let Some(delegation) = Delegations::<T>::get(&caller) {
+				ensure!(delegation.sponsor == sponsor, Error::<T>::AlreadyDelegated);
+			}
+			Delegations::<T>::insert(&caller, Delegation { sponsor: sponsor.clone(), budget });
+
+			Self::deposit_event(Event::Delegated { sponsor, caller, budget });
+			Ok(())
+		}
+
+		/// Stop paying the transaction fees of `caller`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>, caller: T::AccountId) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			let delegation = Delegations::<T>::get(&caller).ok_or(Error::<T>::NotDelegated)?;
+			ensure!(delegation.sponsor == sponsor, Error::<T>::NotDelegated);
+			Delegations::<T>::remove(&caller);
+
+			Self::deposit_event(Event::Undelegated { sponsor, caller });
+			Ok(())
+		}
+	}
+}
+
+/// The `FeeProxyExtension` struct.
+///
+/// Wraps `ChargeTransactionPayment` and charges the fee of `sponsor_call`
+/// extrinsics to the sponsor of the caller. It encodes like the tip of
+/// `ChargeTransactionPayment`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, scale_info::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct FeeProxyExtension<T: Config>(#[codec(compact)] BalanceOf<T>);
+
+impl<T: Config> sp_std::fmt::Debug for FeeProxyExtension<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "FeeProxyExtension<{:?}>", self.0)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+/// A sponsored extrinsic: the caller, its sponsor and the fee charged to
+/// the sponsor.
+pub type SponsoredFee<T> =
+	(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, BalanceOf<T>);
+
+impl<T: Config> FeeProxyExtension<T>
+where
+	BalanceOf<T>: Send + Sync + From<u64>,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	/// Create new `SignedExtension` to charge a fee with the given `tip`.
+	pub fn from(tip: BalanceOf<T>) -> Self {
+		Self(tip)
+	}
+
+	/// The fee payment extension this extension hands the fee over to.
+	fn inner(&self) -> ChargeTransactionPayment<T> {
+		ChargeTransactionPayment::<T>::from(self.0)
+	}
+
+	/// The sponsor paying the fee of `call`, with the fee, if `call` is a
+	/// sponsored call.
+	fn sponsor(
+		&self,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		len: usize,
+	) -> Result<Option<(T::AccountId, BalanceOf<T>)>, TransactionValidityError> {
+		let max_fee = match <T as Config>::RuntimeCall::from_ref(call).is_sub_type() {
+			Some(Call::sponsor_call { max_fee, .. }) => *max_fee,
+			_ => return Ok(None),
+		};
+
+		let delegation = Delegations::<T>::get(who).ok_or(InvalidTransaction::BadSigner)?;
+		if !Sponsors::<T>::contains_key(&delegation.sponsor) {
+			return Err(InvalidTransaction::BadSigner.into())
+		}
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.0);
+		if fee > max_fee || fee > delegation.budget {
+			return Err(InvalidTransaction::Payment.into())
+		}
+		Ok(Some((delegation.sponsor, fee)))
+	}
+}
+
+/// Implementation of the `SignedExtension` trait for the `FeeProxyExtension`
+/// struct.
+impl<T: Config> SignedExtension for FeeProxyExtension<T>
+where
+	BalanceOf<T>: Send + Sync + From<u64>,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = (<ChargeTransactionPayment<T> as SignedExtension>::Pre, Option<SponsoredFee<T>>);
+	const IDENTIFIER: &'static str = "FeeProxyExtension";
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		match self.sponsor(who, call, info, len)? {
+			Some((sponsor, _)) => self.inner().validate(&sponsor, call, info, len),
+			None => self.inner().validate(who, call, info, len),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let (sponsor, fee) = match self.sponsor(who, call, info, len)? {
+			Some(sponsored) => sponsored,
+			None => return Ok((self.inner().pre_dispatch(who, call, info, len)?, None)),
+		};
+
+		let pre = self.inner().pre_dispatch(&sponsor, call, info, len)?;
+		Delegations::<T>::mutate(who, |delegation| {
+			if let Some(delegation) = delegation {
+				delegation.budget = delegation.budget.saturating_sub(fee);
+			}
+		});
+		Ok((pre, Some((who.clone(), sponsor, fee))))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (pre, sponsored) = match pre {
+			Some((pre, sponsored)) => (Some(pre), sponsored),
+			None => (None, None),
+		};
+
+		if let (Some((tip, ..)), Some((caller, sponsor, fee))) = (&pre, sponsored) {
+			// Return the refunded part of the fee to the budget, unless the
+			// delegation changed during the dispatch.
+			let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+				len as u32, info, post_info, *tip,
+			);
+			Delegations::<T>::mutate(&caller, |delegation| match delegation {
+				Some(delegation) if delegation.sponsor == sponsor => {
+					delegation.budget =
+						delegation.budget.saturating_add(fee.saturating_sub(actual_fee));
+				},
+				_ => {},
+			});
+		}
+
+		ChargeTransactionPayment::<T>::post_dispatch(pre, info, post_info, len, result)
+	}
+}
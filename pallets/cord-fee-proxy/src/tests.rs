@@ -0,0 +1,235 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::Pays, weights::Weight};
+use sp_runtime::traits::{BadOrigin, Hash};
+
+const LEN: usize = 100;
+const BUDGET: Balance = INITIAL_BALANCE / 10;
+
+fn remark_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1] })
+}
+
+fn sponsored_call(max_fee: Balance) -> RuntimeCall {
+	RuntimeCall::FeeProxy(Call::sponsor_call { call: Box::new(remark_call()), max_fee })
+}
+
+fn fee(call: &RuntimeCall) -> Balance {
+	TransactionPayment::compute_fee(LEN as u32, &call.get_dispatch_info(), 0)
+}
+
+fn charge(who: AccountId, call: &RuntimeCall) -> Result<(), TransactionValidityError> {
+	FeeProxyExtension::<Test>::from(0)
+		.pre_dispatch(&who, call, &call.get_dispatch_info(), LEN)
+		.map(|_| ())
+}
+
+/// Run an extrinsic of `who` through the extension, using `actual_weight`
+/// of its estimated weight, and return the fee actually paid.
+fn submit(who: AccountId, call: &RuntimeCall, actual_weight: Weight) -> Balance {
+	let info = call.get_dispatch_info();
+	let post_info = PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes };
+	let pre = FeeProxyExtension::<Test>::from(0).pre_dispatch(&who, call, &info, LEN).unwrap();
+	assert_ok!(FeeProxyExtension::<Test>::post_dispatch(
+		Some(pre),
+		&info,
+		&post_info,
+		LEN,
+		&Ok(())
+	));
+	TransactionPayment::compute_actual_fee(LEN as u32, &info, &post_info, 0)
+}
+
+/// Approve `SPONSOR` and let it pay `BUDGET` of the fees of `CALLER`.
+fn setup_delegation() {
+	assert_ok!(FeeProxy::approve_sponsor(RuntimeOrigin::root(), SPONSOR));
+	assert_ok!(FeeProxy::delegate(RuntimeOrigin::signed(SPONSOR), CALLER, BUDGET));
+}
+
+fn budget() -> Balance {
+	Delegations::<Test>::get(CALLER).unwrap().budget
+}
+
+#[test]
+fn approve_and_revoke_sponsor_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FeeProxy::approve_sponsor(RuntimeOrigin::signed(STRANGER), SPONSOR),
+			BadOrigin
+		);
+
+		assert_ok!(FeeProxy::approve_sponsor(RuntimeOrigin::root(), SPONSOR));
+		assert!(Sponsors::<Test>::contains_key(SPONSOR));
+		System::assert_last_event(Event::SponsorApproved { who: SPONSOR }.into());
+		assert_noop!(
+			FeeProxy::approve_sponsor(RuntimeOrigin::root(), SPONSOR),
+			Error::<Test>::AlreadySponsor
+		);
+
+		assert_ok!(FeeProxy::revoke_sponsor(RuntimeOrigin::root(), SPONSOR));
+		assert!(!Sponsors::<Test>::contains_key(SPONSOR));
+		System::assert_last_event(Event::SponsorRevoked { who: SPONSOR }.into());
+		assert_noop!(
+			FeeProxy::revoke_sponsor(RuntimeOrigin::root(), SPONSOR),
+			Error::<Test>::NotSponsor
+		);
+	});
+}
+
+#[test]
+fn delegate_and_undelegate_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FeeProxy::delegate(RuntimeOrigin::signed(SPONSOR), CALLER, BUDGET),
+			Error::<Test>::NotSponsor
+		);
+
+		setup_delegation();
+		assert_eq!(
+			Delegations::<Test>::get(CALLER),
+			Some(Delegation { sponsor: SPONSOR, budget: BUDGET })
+		);
+		System::assert_last_event(
+			Event::Delegated { sponsor: SPONSOR, caller: CALLER, budget: BUDGET }.into(),
+		);
+
+		// Only the sponsor of a caller can change its delegation.
+		assert_ok!(FeeProxy::approve_sponsor(RuntimeOrigin::root(), STRANGER));
+		assert_noop!(
+			FeeProxy::delegate(RuntimeOrigin::signed(STRANGER), CALLER, BUDGET),
+			Error::<Test>::AlreadyDelegated
+		);
+		assert_noop!(
+			FeeProxy::undelegate(RuntimeOrigin::signed(STRANGER), CALLER),
+			Error::<Test>::NotDelegated
+		);
+
+		assert_ok!(FeeProxy::delegate(RuntimeOrigin::signed(SPONSOR), CALLER, 1));
+		assert_eq!(budget(), 1);
+
+		assert_ok!(FeeProxy::undelegate(RuntimeOrigin::signed(SPONSOR), CALLER));
+		assert!(!Delegations::<Test>::contains_key(CALLER));
+		System::assert_last_event(Event::Undelegated { sponsor: SPONSOR, caller: CALLER }.into());
+		assert_noop!(
+			FeeProxy::undelegate(RuntimeOrigin::signed(SPONSOR), CALLER),
+			Error::<Test>::NotDelegated
+		);
+	});
+}
+
+#[test]
+fn sponsor_call_dispatches_as_the_caller() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FeeProxy::sponsor_call(RuntimeOrigin::signed(CALLER), Box::new(remark_call()), 0),
+			Error::<Test>::NotDelegated
+		);
+
+		setup_delegation();
+		assert_ok!(FeeProxy::sponsor_call(
+			RuntimeOrigin::signed(CALLER),
+			Box::new(remark_call()),
+			0
+		));
+		System::assert_has_event(
+			frame_system::Event::Remarked {
+				sender: CALLER,
+				hash: <Test as frame_system::Config>::Hashing::hash(&[1]),
+			}
+			.into(),
+		);
+		System::assert_last_event(Event::CallSponsored { who: CALLER, result: Ok(()) }.into());
+
+		assert_ok!(FeeProxy::revoke_sponsor(RuntimeOrigin::root(), SPONSOR));
+		assert_noop!(
+			FeeProxy::sponsor_call(RuntimeOrigin::signed(CALLER), Box::new(remark_call()), 0),
+			Error::<Test>::NotSponsor
+		);
+	});
+}
+
+#[test]
+fn sponsor_pays_the_fee_of_sponsored_calls_from_the_budget() {
+	new_test_ext().execute_with(|| {
+		setup_delegation();
+
+		let call = sponsored_call(INITIAL_BALANCE);
+		let estimated = call.get_dispatch_info().weight.ref_time();
+		let paid = submit(CALLER, &call, Weight::from_parts(estimated / 2, 0));
+		assert!(paid < fee(&call));
+
+		assert_eq!(Balances::free_balance(CALLER), 0);
+		assert_eq!(Balances::free_balance(SPONSOR), INITIAL_BALANCE - paid);
+		// The refunded part of the fee went back into the budget.
+		assert_eq!(budget(), BUDGET - paid);
+	});
+}
+
+#[test]
+fn other_calls_are_charged_to_the_caller() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeProxy::approve_sponsor(RuntimeOrigin::root(), SPONSOR));
+		assert_ok!(FeeProxy::delegate(RuntimeOrigin::signed(SPONSOR), STRANGER, BUDGET));
+
+		let call = remark_call();
+		assert_ok!(charge(STRANGER, &call));
+		assert_eq!(Balances::free_balance(STRANGER), INITIAL_BALANCE - fee(&call));
+		assert_eq!(Balances::free_balance(SPONSOR), INITIAL_BALANCE);
+		assert_eq!(Delegations::<Test>::get(STRANGER).unwrap().budget, BUDGET);
+	});
+}
+
+#[test]
+fn sponsored_calls_are_rejected_without_an_approved_sponsor() {
+	new_test_ext().execute_with(|| {
+		let call = sponsored_call(INITIAL_BALANCE);
+		assert_eq!(charge(CALLER, &call), Err(InvalidTransaction::BadSigner.into()));
+
+		setup_delegation();
+		assert_ok!(FeeProxy::revoke_sponsor(RuntimeOrigin::root(), SPONSOR));
+		assert_eq!(charge(CALLER, &call), Err(InvalidTransaction::BadSigner.into()));
+		assert_eq!(Balances::free_balance(SPONSOR), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn sponsored_calls_above_the_fee_limit_or_the_budget_are_rejected() {
+	new_test_ext().execute_with(|| {
+		setup_delegation();
+
+		let call = sponsored_call(1);
+		assert_eq!(charge(CALLER, &call), Err(InvalidTransaction::Payment.into()));
+		assert_eq!(
+			FeeProxyExtension::<Test>::from(0).validate(
+				&CALLER,
+				&call,
+				&call.get_dispatch_info(),
+				LEN
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		let call = sponsored_call(INITIAL_BALANCE);
+		assert_ok!(FeeProxy::delegate(RuntimeOrigin::signed(SPONSOR), CALLER, fee(&call) - 1));
+		assert_eq!(charge(CALLER, &call), Err(InvalidTransaction::Payment.into()));
+		assert_eq!(Balances::free_balance(SPONSOR), INITIAL_BALANCE);
+	});
+}
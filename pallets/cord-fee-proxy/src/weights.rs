@@ -0,0 +1,169 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_fee_proxy`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_fee_proxy
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/cord-fee-proxy/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_fee_proxy`.
+pub trait WeightInfo {
+	fn approve_sponsor() -> Weight;
+	fn revoke_sponsor() -> Weight;
+	fn sponsor_call() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn sponsor_fee() -> Weight;
+}
+
+/// Weights for `pallet_cord_fee_proxy` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:1)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn approve_sponsor() -> Weight {
+		Weight::from_parts(12_140_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:1)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn revoke_sponsor() -> Weight {
+		Weight::from_parts(12_860_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `FeeProxy::Delegations` (r:1 w:0)
+	/// Proof: `FeeProxy::Delegations` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:0)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn sponsor_call() -> Weight {
+		Weight::from_parts(11_480_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(0_u64))
+	}
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:0)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `FeeProxy::Delegations` (r:1 w:1)
+	/// Proof: `FeeProxy::Delegations` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		Weight::from_parts(15_310_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `FeeProxy::Delegations` (r:1 w:1)
+	/// Proof: `FeeProxy::Delegations` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		Weight::from_parts(13_020_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `FeeProxy::Delegations` (r:1 w:1)
+	/// Proof: `FeeProxy::Delegations` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:0)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+	/// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn sponsor_fee() -> Weight {
+		Weight::from_parts(58_730_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:1)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn approve_sponsor() -> Weight {
+		Weight::from_parts(12_140_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:1)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn revoke_sponsor() -> Weight {
+		Weight::from_parts(12_860_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `FeeProxy::Delegations` (r:1 w:0)
+	/// Proof: `FeeProxy::Delegations` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:0)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn sponsor_call() -> Weight {
+		Weight::from_parts(11_480_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(0_u64))
+	}
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:0)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `FeeProxy::Delegations` (r:1 w:1)
+	/// Proof: `FeeProxy::Delegations` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		Weight::from_parts(15_310_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `FeeProxy::Delegations` (r:1 w:1)
+	/// Proof: `FeeProxy::Delegations` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		Weight::from_parts(13_020_000, 3561)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `FeeProxy::Delegations` (r:1 w:1)
+	/// Proof: `FeeProxy::Delegations` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `FeeProxy::Sponsors` (r:1 w:0)
+	/// Proof: `FeeProxy::Sponsors` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+	/// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn sponsor_fee() -> Weight {
+		Weight::from_parts(58_730_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
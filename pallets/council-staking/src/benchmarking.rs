@@ -0,0 +1,117 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use pallet_cord_weighted_democracy::{AccountVote, Conviction, ReferendumInfoOf, Vote, VotingOf};
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// An account bonded with `MinCouncilBond`.
+fn bonded<T: Config>(who: &T::AccountId) -> BalanceOf<T> {
+	let amount = T::MinCouncilBond::get();
+	<T as Config>::Currency::make_free_balance_be(
+		who,
+		amount
+			.saturating_add(amount)
+			.saturating_add(<T as Config>::Currency::minimum_balance()),
+	);
+	Pallet::<T>::bond(RawOrigin::Signed(who.clone()).into(), amount).expect("Bond should work.");
+	amount
+}
+
+benchmarks! {
+	bond {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::MinCouncilBond::get();
+		<T as Config>::Currency::make_free_balance_be(
+			&caller,
+			amount.saturating_add(<T as Config>::Currency::minimum_balance()),
+		);
+	}: _(RawOrigin::Signed(caller.clone()), amount)
+	verify {
+		assert_last_event::<T>(Event::Bonded { who: caller, amount, total: amount }.into());
+	}
+
+	slash {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, SEED);
+		let amount = bonded::<T>(&member);
+
+		// A supermajority of 80% the member voted against.
+		let ref_index = 0;
+		ReferendumInfoOf::<T>::insert(
+			ref_index,
+			ReferendumInfo::Finished {
+				approved: true,
+				end: frame_system::Pallet::<T>::block_number(),
+				tally: Tally { ayes: 4u32.into(), nays: 1u32.into() },
+			},
+		);
+		VotingOf::<T>::insert(
+			ref_index,
+			&member,
+			AccountVote::Standard {
+				vote: Vote { aye: false, conviction: Conviction::Locked1x },
+				balance: 1u32.into(),
+			},
+		);
+	}: _(RawOrigin::Signed(caller), ref_index, member.clone())
+	verify {
+		let slashed = T::CouncilSlashFraction::get() * amount;
+		assert_last_event::<T>(Event::Slashed { who: member, ref_index, amount: slashed }.into());
+	}
+
+	unbond {
+		let caller: T::AccountId = whitelisted_caller();
+		bonded::<T>(&caller);
+		let unbonding_at =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingDelay::get());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_last_event::<T>(Event::Unbonding { who: caller, unbonding_at }.into());
+	}
+
+	withdraw_unbonded {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = bonded::<T>(&caller);
+		Pallet::<T>::unbond(RawOrigin::Signed(caller.clone()).into())?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingDelay::get()),
+		);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Bonds::<T>::get(&caller), None);
+		assert_last_event::<T>(Event::Withdrawn { who: caller, amount }.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,284 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Council Staking Pallet
+//!
+//! The Council Staking Pallet gives council members a stake in their
+//! decisions. An incoming member bonds at least `MinCouncilBond`, which stays
+//! reserved for as long as they sit on the council. A member that voted
+//! against a referendum of the weighted democracy pallet that then passed
+//! with a supermajority of more than 75% of the vote weight can be slashed
+//! by `CouncilSlashFraction` of their bond, once per referendum. The slashed
+//! balance is burned.
+//!
+//! A member leaving the council starts unbonding, and can withdraw their
+//! bond `UnbondingDelay` blocks later. The bond can still be slashed until
+//! it is withdrawn.
+//!
+//! ### Storage
+//!
+//! - `Bonds`: The bond of every council member.
+//! - `Slashes`: The members slashed for dissenting in every referendum.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `bond` - Bond balance as a council member.
+//! - `slash` - Slash a member for dissenting in a supermajority referendum.
+//! - `unbond` - Start the unbonding delay of a bond.
+//! - `withdraw_unbonded` - Release a bond once its unbonding delay passed.
+//!
+//! ### Public Functions
+//!
+//! - `is_bonded` - Whether an account holds a bond of at least `MinCouncilBond`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+pub use types::CouncilBond;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, ReservableCurrency};
+use pallet_cord_weighted_democracy::{ReferendumIndex, ReferendumInfo, Tally};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill,
+};
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of the bond of a council member.
+	pub type CouncilBondOf<T> = CouncilBond<BalanceOf<T>, BlockNumberFor<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_cord_weighted_democracy::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency bonds are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The smallest bond of a council member.
+		#[pallet::constant]
+		type MinCouncilBond: Get<BalanceOf<Self>>;
+		/// The fraction of a bond slashed for dissenting in a supermajority
+		/// referendum.
+		#[pallet::constant]
+		type CouncilSlashFraction: Get<Perbill>;
+		/// The blocks between the start of unbonding and the withdrawal of a
+		/// bond.
+		#[pallet::constant]
+		type UnbondingDelay: Get<BlockNumberFor<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// bonds of council members
+	#[pallet::storage]
+	#[pallet::getter(fn bonds)]
+	pub type Bonds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, CouncilBondOf<T>, OptionQuery>;
+
+	/// members slashed for dissenting, by referendum
+	#[pallet::storage]
+	#[pallet::getter(fn slashes)]
+	pub type Slashes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ReferendumIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A council member bonded `amount`, for a bond of `total`.
+		Bonded { who: T::AccountId, amount: BalanceOf<T>, total: BalanceOf<T> },
+		/// The bond of a council member was slashed for dissenting in a
+		/// referendum.
+		Slashed { who: T::AccountId, ref_index: ReferendumIndex, amount: BalanceOf<T> },
+		/// A council member started unbonding.
+		Unbonding { who: T::AccountId, unbonding_at: BlockNumberFor<T> },
+		/// The bond of a former council member was released.
+		Withdrawn { who: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The bond would be below `MinCouncilBond`.
+		BondTooLow,
+		/// The account has no bond.
+		NotBonded,
+		/// The bond is unbonding.
+		AlreadyUnbonding,
+		/// The bond is not unbonding.
+		NotUnbonding,
+		/// The unbonding delay of the bond has not passed yet.
+		StillUnbonding,
+		/// The referendum is not finished.
+		ReferendumNotFinished,
+		/// The referendum did not pass with a supermajority.
+		NotSupermajority,
+		/// The member did not vote against the referendum.
+		NoDissent,
+		/// The member was already slashed for the referendum.
+		AlreadySlashed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Bond `amount` more as a council member. The bond, including what
+		/// the caller bonded before, must be at least `MinCouncilBond`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::bond())]
+		pub fn bond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut bond = Bonds::<T>::get(&who)
+				.unwrap_or(CouncilBond { amount: Zero::zero(), unbonding_at: None });
+			ensure!(bond.unbonding_at.is_none(), Error::<T>::AlreadyUnbonding);
+
+			bond.amount = bond.amount.saturating_add(amount);
+			ensure!(bond.amount >= T::MinCouncilBond::get(), Error::<T>::BondTooLow);
+
+			<T as Config>::Currency::reserve(&who, amount)?;
+			let total = bond.amount;
+			Bonds::<T>::insert(&who, bond);
+
+			Self::deposit_event(Event::Bonded { who, amount, total });
+			Ok(())
+		}
+
+		/// Slash `member` by `CouncilSlashFraction` of their bond for voting
+		/// against referendum `ref_index`, which passed with a supermajority
+		/// of more than 75% of the vote weight. Any signed account can report
+		/// the dissent.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::slash())]
+		pub fn slash(
+			origin: OriginFor<T>,
+			ref_index: ReferendumIndex,
+			member: T::AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut bond = Bonds::<T>::get(&member).ok_or(Error::<T>::NotBonded)?;
+			ensure!(!Slashes::<T>::contains_key(ref_index, &member), Error::<T>::AlreadySlashed);
+
+			match pallet_cord_weighted_democracy::ReferendumInfoOf::<T>::get(ref_index) {
+				Some(ReferendumInfo::Finished { approved: true, tally, .. }) =>
+					ensure!(Self::is_supermajority(&tally), Error::<T>::NotSupermajority),
+				Some(ReferendumInfo::Finished { .. }) =>
+					return Err(Error::<T>::NotSupermajority.into()),
+				_ => return Err(Error::<T>::ReferendumNotFinished.into()),
+			}
+			let (ayes, nays) =
+				pallet_cord_weighted_democracy::VotingOf::<T>::get(ref_index, &member)
+					.ok_or(Error::<T>::NoDissent)?
+					.weights();
+			ensure!(nays > ayes, Error::<T>::NoDissent);
+
+			let due = T::CouncilSlashFraction::get() * bond.amount;
+			let (_, unslashed) = <T as Config>::Currency::slash_reserved(&member, due);
+			let amount = due.saturating_sub(unslashed);
+			bond.amount = bond.amount.saturating_sub(amount);
+			Bonds::<T>::insert(&member, bond);
+			Slashes::<T>::insert(ref_index, &member, amount);
+
+			Self::deposit_event(Event::Slashed { who: member, ref_index, amount });
+			Ok(())
+		}
+
+		/// Start unbonding the bond of the caller. It can be withdrawn
+		/// `UnbondingDelay` blocks later, and can be slashed until then.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unbond())]
+		pub fn unbond(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut bond = Bonds::<T>::get(&who).ok_or(Error::<T>::NotBonded)?;
+			ensure!(bond.unbonding_at.is_none(), Error::<T>::AlreadyUnbonding);
+
+			let unbonding_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingDelay::get());
+			bond.unbonding_at = Some(unbonding_at);
+			Bonds::<T>::insert(&who, bond);
+
+			Self::deposit_event(Event::Unbonding { who, unbonding_at });
+			Ok(())
+		}
+
+		/// Release the bond of the caller once its unbonding delay passed.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bond = Bonds::<T>::get(&who).ok_or(Error::<T>::NotBonded)?;
+			let unbonding_at = bond.unbonding_at.ok_or(Error::<T>::NotUnbonding)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= unbonding_at,
+				Error::<T>::StillUnbonding
+			);
+
+			<T as Config>::Currency::unreserve(&who, bond.amount);
+			Bonds::<T>::remove(&who);
+
+			Self::deposit_event(Event::Withdrawn { who, amount: bond.amount });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `who` holds a bond of at least `MinCouncilBond` and is not
+	/// unbonding.
+	pub fn is_bonded(who: &T::AccountId) -> bool {
+		Bonds::<T>::get(who).map_or(false, |bond| {
+			bond.unbonding_at.is_none() && bond.amount >= T::MinCouncilBond::get()
+		})
+	}
+
+	/// Whether the ayes of `tally` are more than 75% of its weight.
+	fn is_supermajority(tally: &Tally<pallet_cord_weighted_democracy::BalanceOf<T>>) -> bool {
+		tally.ayes > tally.nays.saturating_mul(3u32.into())
+	}
+}
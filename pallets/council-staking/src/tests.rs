@@ -0,0 +1,177 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use pallet_cord_weighted_democracy::{AccountVote, Conviction, Vote};
+use sp_core::H256;
+
+const VOTING_PERIOD: u64 = 10;
+
+/// Start a referendum, cast `votes` and close it once voting ended.
+fn referendum(votes: &[(AccountId, bool, Conviction, Balance)]) -> ReferendumIndex {
+	let ref_index = WeightedDemocracy::referendum_count();
+	assert_ok!(WeightedDemocracy::start_referendum(
+		RuntimeOrigin::root(),
+		H256::repeat_byte(ref_index as u8),
+		VOTING_PERIOD
+	));
+	for &(who, aye, conviction, balance) in votes {
+		assert_ok!(WeightedDemocracy::vote_weighted(
+			RuntimeOrigin::signed(who),
+			ref_index,
+			AccountVote::Standard { vote: Vote { aye, conviction }, balance }
+		));
+	}
+	System::set_block_number(System::block_number() + VOTING_PERIOD);
+	assert_ok!(WeightedDemocracy::close_referendum(RuntimeOrigin::signed(1), ref_index));
+	ref_index
+}
+
+#[test]
+fn bond_requires_the_minimum_bond() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CouncilStaking::bond(RuntimeOrigin::signed(2), MIN_COUNCIL_BOND - 1),
+			Error::<Test>::BondTooLow
+		);
+		assert!(!CouncilStaking::is_bonded(&2));
+
+		assert_ok!(CouncilStaking::bond(RuntimeOrigin::signed(2), MIN_COUNCIL_BOND));
+		System::assert_last_event(
+			Event::Bonded { who: 2, amount: MIN_COUNCIL_BOND, total: MIN_COUNCIL_BOND }.into(),
+		);
+		// A top up only has to reach the minimum with the existing bond.
+		assert_ok!(CouncilStaking::bond(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Balances::reserved_balance(2), MIN_COUNCIL_BOND + 1);
+		assert_eq!(
+			CouncilStaking::bonds(2),
+			Some(CouncilBond { amount: MIN_COUNCIL_BOND + 1, unbonding_at: None })
+		);
+		assert!(CouncilStaking::is_bonded(&2));
+
+		assert_noop!(
+			CouncilStaking::bond(RuntimeOrigin::signed(3), 2_000),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn slash_punishes_dissent_from_a_supermajority() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CouncilStaking::bond(RuntimeOrigin::signed(2), 200));
+		assert_ok!(CouncilStaking::bond(RuntimeOrigin::signed(3), 200));
+		// 400 ayes against 100 nays, 80% of the weight.
+		let ref_index = referendum(&[
+			(1, true, Conviction::Locked4x, 100),
+			(2, false, Conviction::Locked1x, 100),
+		]);
+
+		assert_ok!(CouncilStaking::slash(RuntimeOrigin::signed(1), ref_index, 2));
+		System::assert_last_event(Event::Slashed { who: 2, ref_index, amount: 20 }.into());
+		assert_eq!(Balances::reserved_balance(2), 180);
+		assert_eq!(Balances::free_balance(2), 800);
+		assert_eq!(CouncilStaking::bonds(2).map(|bond| bond.amount), Some(180));
+		assert_eq!(CouncilStaking::slashes(ref_index, 2), Some(20));
+
+		assert_noop!(
+			CouncilStaking::slash(RuntimeOrigin::signed(1), ref_index, 2),
+			Error::<Test>::AlreadySlashed
+		);
+		// Account 3 did not vote, account 1 has no bond.
+		assert_noop!(
+			CouncilStaking::slash(RuntimeOrigin::signed(1), ref_index, 3),
+			Error::<Test>::NoDissent
+		);
+		assert_noop!(
+			CouncilStaking::slash(RuntimeOrigin::signed(2), ref_index, 1),
+			Error::<Test>::NotBonded
+		);
+	});
+}
+
+#[test]
+fn slash_requires_a_finished_supermajority() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CouncilStaking::bond(RuntimeOrigin::signed(2), 200));
+		// 300 ayes against 100 nays is exactly 75% of the weight.
+		let ref_index = referendum(&[
+			(1, true, Conviction::Locked3x, 100),
+			(2, false, Conviction::Locked1x, 100),
+		]);
+		assert_noop!(
+			CouncilStaking::slash(RuntimeOrigin::signed(1), ref_index, 2),
+			Error::<Test>::NotSupermajority
+		);
+
+		let rejected = referendum(&[(2, false, Conviction::Locked1x, 100)]);
+		assert_noop!(
+			CouncilStaking::slash(RuntimeOrigin::signed(1), rejected, 2),
+			Error::<Test>::NotSupermajority
+		);
+
+		assert_ok!(WeightedDemocracy::start_referendum(
+			RuntimeOrigin::root(),
+			H256::zero(),
+			VOTING_PERIOD
+		));
+		assert_noop!(
+			CouncilStaking::slash(RuntimeOrigin::signed(1), rejected + 1, 2),
+			Error::<Test>::ReferendumNotFinished
+		);
+	});
+}
+
+#[test]
+fn unbond_releases_the_bond_after_the_delay() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(CouncilStaking::unbond(RuntimeOrigin::signed(2)), Error::<Test>::NotBonded);
+		assert_ok!(CouncilStaking::bond(RuntimeOrigin::signed(2), 200));
+		assert_noop!(
+			CouncilStaking::withdraw_unbonded(RuntimeOrigin::signed(2)),
+			Error::<Test>::NotUnbonding
+		);
+
+		assert_ok!(CouncilStaking::unbond(RuntimeOrigin::signed(2)));
+		let unbonding_at = 1 + UNBONDING_DELAY;
+		System::assert_last_event(Event::Unbonding { who: 2, unbonding_at }.into());
+		assert!(!CouncilStaking::is_bonded(&2));
+		assert_noop!(
+			CouncilStaking::unbond(RuntimeOrigin::signed(2)),
+			Error::<Test>::AlreadyUnbonding
+		);
+		assert_noop!(
+			CouncilStaking::bond(RuntimeOrigin::signed(2), 100),
+			Error::<Test>::AlreadyUnbonding
+		);
+
+		System::set_block_number(unbonding_at - 1);
+		assert_noop!(
+			CouncilStaking::withdraw_unbonded(RuntimeOrigin::signed(2)),
+			Error::<Test>::StillUnbonding
+		);
+
+		System::set_block_number(unbonding_at);
+		assert_ok!(CouncilStaking::withdraw_unbonded(RuntimeOrigin::signed(2)));
+		System::assert_last_event(Event::Withdrawn { who: 2, amount: 200 }.into());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(CouncilStaking::bonds(2), None);
+	});
+}
@@ -0,0 +1,85 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+const COLLECTION: CollectionId = 0;
+const NFT: NftId = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Mint a transferable NFT for an active credential of `owner`.
+fn minted_nft<T: Config>(owner: &T::AccountId) -> CredentialIdOf<T> {
+	let credential = T::Credentials::active_credential(owner);
+	Pallet::<T>::mint_credential_nft(
+		RawOrigin::Signed(owner.clone()).into(),
+		credential,
+		COLLECTION,
+		NFT,
+		true,
+	)
+	.expect("Should mint the NFT");
+	credential
+}
+
+benchmarks! {
+	mint_credential_nft {
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let credential = T::Credentials::active_credential(&holder);
+	}: _(RawOrigin::Signed(holder.clone()), credential, COLLECTION, NFT, true)
+	verify {
+		assert_last_event::<T>(Event::CredentialNftMinted {
+			nft_id: NFT,
+			collection_id: COLLECTION,
+			credential,
+			owner: holder,
+			transferable: true,
+		}.into());
+	}
+
+	transfer_nft {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		minted_nft::<T>(&owner);
+	}: _(RawOrigin::Signed(owner.clone()), NFT, to.clone())
+	verify {
+		assert_last_event::<T>(Event::NftTransferred { nft_id: NFT, from: owner, to }.into());
+	}
+
+	burn_nft {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let credential = minted_nft::<T>(&owner);
+	}: _(RawOrigin::Signed(owner), NFT)
+	verify {
+		assert!(CredentialNfts::<T>::get(credential).is_none());
+		assert_last_event::<T>(Event::NftBurned { nft_id: NFT, credential }.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
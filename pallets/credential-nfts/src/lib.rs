@@ -0,0 +1,230 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Credential NFTs Pallet
+//!
+//! The Credential NFTs Pallet represents CORD credentials as unique tokens,
+//! so that they can be traded or delegated. The holder of an active
+//! credential mints an NFT for it in a collection, and decides at mint
+//! whether the NFT can be transferred. A credential is represented by at
+//! most one NFT.
+//!
+//! The owner of a transferable NFT can transfer it to another account. The
+//! owner of an NFT can burn it, which also revokes the credential it
+//! represents.
+//!
+//! ### Storage
+//!
+//! - `Nfts`: The minted NFTs.
+//! - `CredentialNfts`: The NFT minted for every credential.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `mint_credential_nft` - Mint an NFT for an active credential.
+//! - `transfer_nft` - Transfer a transferable NFT.
+//! - `burn_nft` - Burn an NFT and revoke its credential.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod traits;
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+pub use traits::CredentialInspect;
+pub use types::NftDetails;
+pub use weights::WeightInfo;
+
+/// Identifier of a collection.
+pub type CollectionId = u64;
+/// Identifier of an NFT.
+pub type NftId = u64;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Identifier of a credential.
+	pub type CredentialIdOf<T> = <T as frame_system::Config>::Hash;
+	/// Type of an NFT.
+	pub type NftDetailsOf<T> =
+		NftDetails<<T as frame_system::Config>::AccountId, CollectionId, CredentialIdOf<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The credentials NFTs are minted for.
+		type Credentials: CredentialInspect<CredentialIdOf<Self>, Self::AccountId>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// minted NFTs by identifier
+	#[pallet::storage]
+	#[pallet::getter(fn nfts)]
+	pub type Nfts<T: Config> = StorageMap<_, Twox64Concat, NftId, NftDetailsOf<T>, OptionQuery>;
+
+	/// NFTs by the credential they were minted for
+	#[pallet::storage]
+	#[pallet::getter(fn credential_nfts)]
+	pub type CredentialNfts<T: Config> =
+		StorageMap<_, Blake2_128Concat, CredentialIdOf<T>, NftId, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An NFT was minted for a credential.
+		CredentialNftMinted {
+			nft_id: NftId,
+			collection_id: CollectionId,
+			credential: CredentialIdOf<T>,
+			owner: T::AccountId,
+			transferable: bool,
+		},
+		/// An NFT was transferred.
+		NftTransferred { nft_id: NftId, from: T::AccountId, to: T::AccountId },
+		/// An NFT was burned and its credential revoked.
+		NftBurned { nft_id: NftId, credential: CredentialIdOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No NFT has the identifier.
+		NftNotFound,
+		/// An NFT with the identifier was already minted.
+		NftAlreadyExists,
+		/// The credential is not active.
+		CredentialNotActive,
+		/// The caller does not hold the credential.
+		NotCredentialHolder,
+		/// An NFT was already minted for the credential.
+		CredentialAlreadyMinted,
+		/// The caller does not own the NFT.
+		NotNftOwner,
+		/// The NFT was minted as non-transferable.
+		NotTransferable,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Mint NFT `nft_id` in collection `collection_id` for
+		/// `credential_id`, an active credential held by the caller. The
+		/// caller owns the NFT, and can only transfer it if `transferable` is
+		/// set.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::mint_credential_nft())]
+		pub fn mint_credential_nft(
+			origin: OriginFor<T>,
+			credential_id: CredentialIdOf<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			transferable: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!Nfts::<T>::contains_key(nft_id), Error::<T>::NftAlreadyExists);
+			ensure!(
+				!CredentialNfts::<T>::contains_key(credential_id),
+				Error::<T>::CredentialAlreadyMinted
+			);
+			let holder = T::Credentials::active_holder(&credential_id)
+				.ok_or(Error::<T>::CredentialNotActive)?;
+			ensure!(holder == who, Error::<T>::NotCredentialHolder);
+
+			Nfts::<T>::insert(
+				nft_id,
+				NftDetails {
+					owner: who.clone(),
+					collection: collection_id,
+					credential: credential_id,
+					transferable,
+				},
+			);
+			CredentialNfts::<T>::insert(credential_id, nft_id);
+
+			Self::deposit_event(Event::CredentialNftMinted {
+				nft_id,
+				collection_id,
+				credential: credential_id,
+				owner: who,
+				transferable,
+			});
+			Ok(())
+		}
+
+		/// Transfer NFT `nft_id` to `to`. Only the owner of the NFT can
+		/// transfer it, and only if it was minted as transferable.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_nft())]
+		pub fn transfer_nft(
+			origin: OriginFor<T>,
+			nft_id: NftId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Nfts::<T>::try_mutate(nft_id, |nft| {
+				let nft = nft.as_mut().ok_or(Error::<T>::NftNotFound)?;
+				ensure!(nft.owner == who, Error::<T>::NotNftOwner);
+				ensure!(nft.transferable, Error::<T>::NotTransferable);
+				nft.owner = to.clone();
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::NftTransferred { nft_id, from: who, to });
+			Ok(())
+		}
+
+		/// Burn NFT `nft_id` and revoke the credential it represents. Only
+		/// the owner of the NFT can burn it.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::burn_nft())]
+		pub fn burn_nft(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let nft = Nfts::<T>::get(nft_id).ok_or(Error::<T>::NftNotFound)?;
+			ensure!(nft.owner == who, Error::<T>::NotNftOwner);
+
+			T::Credentials::revoke(&nft.credential, &who)?;
+
+			Nfts::<T>::remove(nft_id);
+			CredentialNfts::<T>::remove(nft.credential);
+
+			Self::deposit_event(Event::NftBurned { nft_id, credential: nft.credential });
+			Ok(())
+		}
+	}
+}
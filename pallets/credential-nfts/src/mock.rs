@@ -0,0 +1,110 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate as pallet_cord_credential_nfts;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchResult,
+};
+use sp_std::collections::btree_map::BTreeMap;
+
+pub type AccountId = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		CredentialNfts: pallet_cord_credential_nfts,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Hash = H256;
+	type RuntimeCall = RuntimeCall;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub static ActiveCredentials: BTreeMap<H256, AccountId> = BTreeMap::new();
+}
+
+/// Treats the credentials in `ActiveCredentials` as active credentials of
+/// the account they are paired with, and revokes a credential by removing
+/// it.
+pub struct TestCredentials;
+impl CredentialInspect<H256, AccountId> for TestCredentials {
+	fn active_holder(credential: &H256) -> Option<AccountId> {
+		ActiveCredentials::get().get(credential).copied()
+	}
+
+	fn revoke(credential: &H256, _who: &AccountId) -> DispatchResult {
+		ActiveCredentials::mutate(|credentials| credentials.remove(credential));
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn active_credential(holder: &AccountId) -> H256 {
+		let credential = H256::repeat_byte(0xbe);
+		ActiveCredentials::mutate(|credentials| credentials.insert(credential, *holder));
+		credential
+	}
+}
+
+impl pallet_cord_credential_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Credentials = TestCredentials;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	ActiveCredentials::set(BTreeMap::new());
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
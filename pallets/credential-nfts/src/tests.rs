@@ -0,0 +1,180 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Error, Event, NftDetails};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+
+const COLLECTION: u64 = 7;
+const NFT: u64 = 1;
+const CREDENTIAL: H256 = H256::repeat_byte(1);
+const CAROL: AccountId = 3;
+
+fn activate(credential: H256, holder: AccountId) {
+	ActiveCredentials::mutate(|credentials| credentials.insert(credential, holder));
+}
+
+fn mint(transferable: bool) {
+	activate(CREDENTIAL, ALICE);
+	assert_ok!(CredentialNfts::mint_credential_nft(
+		RuntimeOrigin::signed(ALICE),
+		CREDENTIAL,
+		COLLECTION,
+		NFT,
+		transferable
+	));
+}
+
+#[test]
+fn mint_credential_nft_works() {
+	new_test_ext().execute_with(|| {
+		mint(true);
+
+		assert_eq!(
+			CredentialNfts::nfts(NFT),
+			Some(NftDetails {
+				owner: ALICE,
+				collection: COLLECTION,
+				credential: CREDENTIAL,
+				transferable: true
+			})
+		);
+		assert_eq!(CredentialNfts::credential_nfts(CREDENTIAL), Some(NFT));
+		System::assert_last_event(
+			Event::CredentialNftMinted {
+				nft_id: NFT,
+				collection_id: COLLECTION,
+				credential: CREDENTIAL,
+				owner: ALICE,
+				transferable: true,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn mint_credential_nft_requires_an_active_credential_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CredentialNfts::mint_credential_nft(
+				RuntimeOrigin::signed(ALICE),
+				CREDENTIAL,
+				COLLECTION,
+				NFT,
+				true
+			),
+			Error::<Test>::CredentialNotActive
+		);
+
+		activate(CREDENTIAL, BOB);
+		assert_noop!(
+			CredentialNfts::mint_credential_nft(
+				RuntimeOrigin::signed(ALICE),
+				CREDENTIAL,
+				COLLECTION,
+				NFT,
+				true
+			),
+			Error::<Test>::NotCredentialHolder
+		);
+	});
+}
+
+#[test]
+fn mint_credential_nft_rejects_duplicates() {
+	new_test_ext().execute_with(|| {
+		mint(true);
+		let other = H256::repeat_byte(2);
+		activate(other, ALICE);
+
+		assert_noop!(
+			CredentialNfts::mint_credential_nft(
+				RuntimeOrigin::signed(ALICE),
+				other,
+				COLLECTION,
+				NFT,
+				true
+			),
+			Error::<Test>::NftAlreadyExists
+		);
+		assert_noop!(
+			CredentialNfts::mint_credential_nft(
+				RuntimeOrigin::signed(ALICE),
+				CREDENTIAL,
+				COLLECTION,
+				NFT + 1,
+				true
+			),
+			Error::<Test>::CredentialAlreadyMinted
+		);
+	});
+}
+
+#[test]
+fn transfer_nft_moves_a_transferable_nft() {
+	new_test_ext().execute_with(|| {
+		mint(true);
+
+		assert_noop!(
+			CredentialNfts::transfer_nft(RuntimeOrigin::signed(BOB), NFT, CAROL),
+			Error::<Test>::NotNftOwner
+		);
+		assert_ok!(CredentialNfts::transfer_nft(RuntimeOrigin::signed(ALICE), NFT, BOB));
+
+		assert_eq!(CredentialNfts::nfts(NFT).map(|nft| nft.owner), Some(BOB));
+		System::assert_last_event(
+			Event::NftTransferred { nft_id: NFT, from: ALICE, to: BOB }.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_nft_rejects_non_transferable_nfts() {
+	new_test_ext().execute_with(|| {
+		mint(false);
+
+		assert_noop!(
+			CredentialNfts::transfer_nft(RuntimeOrigin::signed(ALICE), NFT, BOB),
+			Error::<Test>::NotTransferable
+		);
+		assert_noop!(
+			CredentialNfts::transfer_nft(RuntimeOrigin::signed(ALICE), NFT + 1, BOB),
+			Error::<Test>::NftNotFound
+		);
+	});
+}
+
+#[test]
+fn burn_nft_revokes_the_credential() {
+	new_test_ext().execute_with(|| {
+		mint(true);
+		assert_ok!(CredentialNfts::transfer_nft(RuntimeOrigin::signed(ALICE), NFT, BOB));
+
+		assert_noop!(
+			CredentialNfts::burn_nft(RuntimeOrigin::signed(ALICE), NFT),
+			Error::<Test>::NotNftOwner
+		);
+		assert_ok!(CredentialNfts::burn_nft(RuntimeOrigin::signed(BOB), NFT));
+
+		assert!(CredentialNfts::nfts(NFT).is_none());
+		assert!(CredentialNfts::credential_nfts(CREDENTIAL).is_none());
+		assert!(!ActiveCredentials::get().contains_key(&CREDENTIAL));
+		System::assert_last_event(Event::NftBurned { nft_id: NFT, credential: CREDENTIAL }.into());
+	});
+}
@@ -0,0 +1,37 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// An NFT representing a credential.
+///
+/// ## Fields
+///
+/// - `owner`: The account owning the NFT.
+/// - `collection`: The collection the NFT was minted in.
+/// - `credential`: The credential the NFT represents.
+/// - `transferable`: Whether the owner can transfer the NFT.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct NftDetails<AccountId, CollectionId, CredentialId> {
+	pub owner: AccountId,
+	pub collection: CollectionId,
+	pub credential: CredentialId,
+	pub transferable: bool,
+}
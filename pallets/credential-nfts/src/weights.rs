@@ -0,0 +1,140 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_credential_nfts`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_credential_nfts
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/credential-nfts/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_credential_nfts`.
+pub trait WeightInfo {
+	fn mint_credential_nft() -> Weight;
+	fn transfer_nft() -> Weight;
+	fn burn_nft() -> Weight;
+}
+
+/// Weights for `pallet_cord_credential_nfts` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CredentialNfts::Nfts` (r:1 w:1)
+	/// Proof: `CredentialNfts::Nfts` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `CredentialNfts::CredentialNfts` (r:1 w:1)
+	/// Proof: `CredentialNfts::CredentialNfts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Credential::Credentials` (r:1 w:0)
+	/// Proof: `Credential::Credentials` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Entries` (r:1 w:0)
+	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:0)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(180), added: 2655, mode: `MaxEncodedLen`)
+	fn mint_credential_nft() -> Weight {
+		Weight::from_parts(42_870_000, 3725)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CredentialNfts::Nfts` (r:1 w:1)
+	/// Proof: `CredentialNfts::Nfts` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	fn transfer_nft() -> Weight {
+		Weight::from_parts(17_540_000, 3554)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CredentialNfts::Nfts` (r:1 w:1)
+	/// Proof: `CredentialNfts::Nfts` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Credential::Credentials` (r:1 w:0)
+	/// Proof: `Credential::Credentials` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:1)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(180), added: 2655, mode: `MaxEncodedLen`)
+	/// Storage: `CredentialNfts::CredentialNfts` (r:0 w:1)
+	/// Proof: `CredentialNfts::CredentialNfts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn burn_nft() -> Weight {
+		Weight::from_parts(39_610_000, 3725)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `CredentialNfts::Nfts` (r:1 w:1)
+	/// Proof: `CredentialNfts::Nfts` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `CredentialNfts::CredentialNfts` (r:1 w:1)
+	/// Proof: `CredentialNfts::CredentialNfts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Credential::Credentials` (r:1 w:0)
+	/// Proof: `Credential::Credentials` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Entries` (r:1 w:0)
+	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:0)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(180), added: 2655, mode: `MaxEncodedLen`)
+	fn mint_credential_nft() -> Weight {
+		Weight::from_parts(42_870_000, 3725)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CredentialNfts::Nfts` (r:1 w:1)
+	/// Proof: `CredentialNfts::Nfts` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	fn transfer_nft() -> Weight {
+		Weight::from_parts(17_540_000, 3554)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CredentialNfts::Nfts` (r:1 w:1)
+	/// Proof: `CredentialNfts::Nfts` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Credential::Credentials` (r:1 w:0)
+	/// Proof: `Credential::Credentials` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::Statements` (r:1 w:0)
+	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:1)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(180), added: 2655, mode: `MaxEncodedLen`)
+	/// Storage: `CredentialNfts::CredentialNfts` (r:0 w:1)
+	/// Proof: `CredentialNfts::CredentialNfts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn burn_nft() -> Weight {
+		Weight::from_parts(39_610_000, 3725)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
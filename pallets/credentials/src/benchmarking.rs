@@ -0,0 +1,129 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::{Encode, MaxEncodedLen};
+use cord_utilities::traits::{GenerateBenchmarkOrigin, IdentifierNamespace, StorageDeposit};
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{sp_runtime::traits::Hash, traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use identifier::{IdentifierType, Ss58Identifier};
+use pallet_chain_space::SpaceCodeOf;
+use pallet_schema::{InputSchemaOf, SchemaEntryOf, SchemaHashOf};
+use pallet_statement::{AuthorizationIdOf, SchemaIdOf, SpaceIdOf, StatementDetailsOf};
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// Generates a schema ID from a schema digest.
+pub fn generate_schema_id<T: Config>(digest: &SchemaHashOf<T>) -> SchemaIdOf {
+	Ss58Identifier::create_identifier(
+		&<T as pallet_schema::Config>::IdentifierNamespace::namespaced(&digest.encode()[..]),
+		IdentifierType::Schema,
+	)
+	.unwrap()
+}
+
+/// Generates a space ID from a digest.
+pub fn generate_space_id<T: Config>(digest: &SpaceCodeOf<T>) -> SpaceIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Space).unwrap()
+}
+
+/// Generates an authorization ID from a digest.
+pub fn generate_authorization_id<T: Config>(digest: &SpaceCodeOf<T>) -> AuthorizationIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Authorization)
+		.unwrap()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	where_clause {
+		where
+		<T as pallet_statement::Config>::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::SpaceCreatorId>,
+	}
+	issue_credential {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id = generate_authorization_id::<T>(&auth_digest);
+
+		// The largest schema makes the schema lookup the most expensive.
+		let schema: InputSchemaOf<T> = BoundedVec::try_from(vec![77u8; T::MaxEncodedSchemaLength::get() as usize])
+			.expect("Schema should fit the max encoded schema length.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id = generate_schema_id::<T>(&schema_id_digest);
+
+		let digest = <T as frame_system::Config>::Hashing::hash(&[88u8; 32][..]);
+
+		// The schema and the statement deposits are held from the caller.
+		let deposit_len = SchemaEntryOf::<T>::max_encoded_len() as u32 +
+			StatementDetailsOf::<T>::max_encoded_len() as u32;
+		<T as pallet_schema::Config>::StorageDeposit::fund(&caller, deposit_len);
+		let origin = <T as pallet_statement::Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest)?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity).expect("Approval should not fail.");
+		pallet_schema::Pallet::<T>::create(origin.clone(), schema, authorization_id.clone())?;
+
+		let identifier = <T as frame_system::Config>::Hashing::hash(
+			&[
+				&schema_id.encode()[..],
+				&digest.encode()[..],
+				&holder.encode()[..],
+				&frame_system::Pallet::<T>::block_number().encode()[..],
+			]
+			.concat()[..],
+		);
+	}: _<T::RuntimeOrigin>(origin, schema_id, digest, holder, authorization_id)
+	verify {
+		let credential = Credentials::<T>::get(identifier).expect("Credential should be issued.");
+		assert_last_event::<T>(Event::CredentialIssued {
+			identifier,
+			statement: credential.statement,
+			schema: credential.schema,
+			issuer: did,
+			holder: credential.holder,
+		}.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,185 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Credentials Pallet
+//!
+//! Issuing a credential otherwise takes separate calls to the schema, chain
+//! space and statement pallets. `issue_credential` checks that the schema is
+//! active, anchors the credential digest as a statement in the issuer's
+//! space and records the credential holder in one atomic call.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use crate::{pallet::*, types::CredentialEntry, weights::WeightInfo};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use cord_utilities::traits::CallSources;
+	use frame_support::{pallet_prelude::*, traits::StorageVersion};
+	use frame_system::pallet_prelude::*;
+	use pallet_statement::{
+		AuthorizationIdOf, SchemaIdOf, StatementCreatorOf, StatementDigestOf, StatementIdOf,
+	};
+	use sp_runtime::traits::Hash;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of a credential identifier.
+	pub type CredentialIdOf<T> = <T as frame_system::Config>::Hash;
+	/// Type of a credential holder.
+	pub type CredentialHolderOf<T> = <T as frame_system::Config>::AccountId;
+	/// Type of an issued credential.
+	pub type CredentialEntryOf<T> = CredentialEntry<
+		SchemaIdOf,
+		StatementIdOf,
+		StatementDigestOf<T>,
+		StatementCreatorOf<T>,
+		CredentialHolderOf<T>,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_schema::Config + pallet_statement::Config
+	{
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Issued credentials, keyed by credential identifier.
+	#[pallet::storage]
+	#[pallet::getter(fn credentials)]
+	pub type Credentials<T> =
+		StorageMap<_, Blake2_128Concat, CredentialIdOf<T>, CredentialEntryOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A credential has been issued.
+		/// \[credential identifier, statement identifier, schema identifier,
+		/// issuer, holder\]
+		CredentialIssued {
+			identifier: CredentialIdOf<T>,
+			statement: StatementIdOf,
+			schema: SchemaIdOf,
+			issuer: StatementCreatorOf<T>,
+			holder: CredentialHolderOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A credential with the same identifier has already been issued.
+		CredentialAlreadyIssued,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Issue a credential of schema `schema_id` to `holder`.
+		///
+		/// The schema must exist and must not be archived. The credential
+		/// `digest` is registered as a statement of that schema, which
+		/// requires the issuer to hold `authorization` in a chain space. The
+		/// credential identifier is derived from the schema identifier, the
+		/// digest, the holder and the current block number. The storage
+		/// deposit of the statement is held from the sender.
+		///
+		/// Either every step succeeds or none of them is applied.
+		///
+		/// # Events
+		/// - `CredentialIssued`, along with the `Register` event of the statement pallet.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::issue_credential())]
+		pub fn issue_credential(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			digest: StatementDigestOf<T>,
+			holder: CredentialHolderOf<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let source = <T as pallet_statement::Config>::EnsureOrigin::ensure_origin(origin)?;
+			let issuer = source.subject();
+
+			frame_support::storage::with_storage_layer(|| {
+				pallet_schema::Pallet::<T>::is_valid(&schema_id)?;
+
+				let block_number = frame_system::Pallet::<T>::block_number();
+				let identifier = <T as frame_system::Config>::Hashing::hash(
+					&[
+						&schema_id.encode()[..],
+						&digest.encode()[..],
+						&holder.encode()[..],
+						&block_number.encode()[..],
+					]
+					.concat()[..],
+				);
+				ensure!(
+					!<Credentials<T>>::contains_key(identifier),
+					Error::<T>::CredentialAlreadyIssued
+				);
+
+				let statement = pallet_statement::Pallet::<T>::register_statement(
+					issuer.clone(),
+					&source.sender(),
+					digest,
+					&authorization,
+					Some(schema_id.clone()),
+				)?;
+
+				<Credentials<T>>::insert(
+					identifier,
+					CredentialEntryOf::<T> {
+						schema: schema_id.clone(),
+						statement: statement.clone(),
+						digest,
+						issuer: issuer.clone(),
+						holder: holder.clone(),
+						created_at: block_number,
+					},
+				);
+
+				Self::deposit_event(Event::CredentialIssued {
+					identifier,
+					statement,
+					schema: schema_id,
+					issuer,
+					holder,
+				});
+
+				Ok(())
+			})
+		}
+	}
+}
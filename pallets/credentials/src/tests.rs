@@ -0,0 +1,166 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use codec::Encode;
+use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
+use frame_support::{assert_err, assert_ok, BoundedVec};
+use frame_system::RawOrigin;
+use identifier::{IdentifierType, Ss58Identifier};
+use pallet_chain_space::SpaceCodeOf;
+use pallet_schema::{InputSchemaOf, SchemaHashOf};
+use pallet_statement::{AuthorizationIdOf, SchemaIdOf, SpaceIdOf, StatementDigestOf};
+use sp_runtime::{traits::Hash, AccountId32};
+
+/// Generates a schema ID from a schema digest.
+pub fn generate_schema_id<T: Config>(digest: &SchemaHashOf<T>) -> SchemaIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Schema).unwrap()
+}
+
+/// Generates a space ID from a digest.
+pub fn generate_space_id<T: Config>(digest: &SpaceCodeOf<T>) -> SpaceIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Space).unwrap()
+}
+
+/// Generates an authorization ID from a digest.
+pub fn generate_authorization_id<T: Config>(digest: &SpaceCodeOf<T>) -> AuthorizationIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Authorization)
+		.unwrap()
+}
+
+pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
+pub(crate) const DID_01: SubjectId = SubjectId(AccountId32::new([5u8; 32]));
+pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
+pub(crate) const HOLDER_00: AccountId = AccountId::new([9u8; 32]);
+
+struct Setup {
+	schema_id: SchemaIdOf,
+	authorization_id: AuthorizationIdOf,
+	digest: StatementDigestOf<Test>,
+}
+
+/// Creates an approved space owned by `DID_00` and a schema in it.
+fn setup() -> Setup {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [11u8; 256].to_vec();
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id = generate_authorization_id::<Test>(&auth_digest);
+
+	assert_ok!(Space::create(DoubleOrigin(author.clone(), creator.clone()).into(), space_digest));
+	assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, 5u64));
+	assert_ok!(Schema::create(
+		DoubleOrigin(author, creator).into(),
+		schema,
+		authorization_id.clone()
+	));
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+	Setup { schema_id, authorization_id, digest }
+}
+
+#[test]
+fn issue_credential_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let Setup { schema_id, authorization_id, digest } = setup();
+
+		assert_ok!(Credential::issue_credential(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			schema_id.clone(),
+			digest,
+			HOLDER_00,
+			authorization_id,
+		));
+
+		let identifier = <Test as frame_system::Config>::Hashing::hash(
+			&[
+				&schema_id.encode()[..],
+				&digest.encode()[..],
+				&HOLDER_00.encode()[..],
+				&1u64.encode()[..],
+			]
+			.concat()[..],
+		);
+		let credential = Credential::credentials(identifier).expect("Credential is issued.");
+		assert_eq!(credential.schema, schema_id);
+		assert_eq!(credential.issuer, DID_00);
+		assert_eq!(credential.holder, HOLDER_00);
+
+		let statement = Statement::statements(&credential.statement)
+			.expect("The credential digest is anchored as a statement.");
+		assert_eq!(statement.digest, digest);
+		assert_eq!(statement.schema, Some(schema_id));
+	});
+}
+
+#[test]
+fn issue_credential_with_unknown_schema_should_fail() {
+	new_test_ext().execute_with(|| {
+		let Setup { authorization_id, digest, .. } = setup();
+		let unknown_schema = generate_schema_id::<Test>(&[0u8; 32].into());
+
+		assert_err!(
+			Credential::issue_credential(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				unknown_schema,
+				digest,
+				HOLDER_00,
+				authorization_id,
+			),
+			pallet_schema::Error::<Test>::SchemaNotFound
+		);
+	});
+}
+
+#[test]
+fn failed_issuance_should_not_anchor_a_statement() {
+	new_test_ext().execute_with(|| {
+		let Setup { schema_id, authorization_id, digest } = setup();
+
+		// `DID_01` is not a delegate of the space.
+		assert!(Credential::issue_credential(
+			DoubleOrigin(ACCOUNT_00, DID_01).into(),
+			schema_id,
+			digest,
+			HOLDER_00,
+			authorization_id,
+		)
+		.is_err());
+
+		assert_eq!(pallet_statement::Statements::<Test>::iter().count(), 0);
+		assert_eq!(Credentials::<Test>::iter().count(), 0);
+	});
+}
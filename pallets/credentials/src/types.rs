@@ -0,0 +1,41 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// A credential issued through the credentials pallet.
+///
+/// ## Fields
+///
+/// - `schema`: The schema the credential conforms to.
+/// - `statement`: The statement anchoring the credential digest.
+/// - `digest`: The digest of the credential.
+/// - `issuer`: The identity that issued the credential.
+/// - `holder`: The account the credential was issued to.
+/// - `created_at`: The block the credential was issued at.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct CredentialEntry<SchemaId, StatementId, StatementDigest, Issuer, Holder, BlockNumber> {
+	pub schema: SchemaId,
+	pub statement: StatementId,
+	pub digest: StatementDigest,
+	pub issuer: Issuer,
+	pub holder: Holder,
+	pub created_at: BlockNumber,
+}
@@ -0,0 +1,76 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn existential_deposits<T: Config>(n: u32) -> T::CreditBalance {
+	T::ExistentialDeposit::get().saturating_mul(n.into())
+}
+
+benchmarks! {
+	issue_credit {
+		let to: T::AccountId = account("to", 0, SEED);
+		let amount = existential_deposits::<T>(10);
+	}: _(RawOrigin::Root, to.clone(), amount)
+	verify {
+		assert_eq!(Pallet::<T>::balance(&to), amount);
+		assert_last_event::<T>(Event::CreditIssued { to, amount }.into());
+	}
+
+	// The caller keeps part of its balance, so the issuance is adjusted
+	// without reaping the account.
+	burn_credit {
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::mint_into(&caller, existential_deposits::<T>(10))?;
+		let amount = existential_deposits::<T>(5);
+	}: _(RawOrigin::Signed(caller.clone()), amount)
+	verify {
+		assert_eq!(Pallet::<T>::balance(&caller), amount);
+		assert_last_event::<T>(Event::CreditBurned { who: caller, amount }.into());
+	}
+
+	// The recipient has no credits yet.
+	transfer_credit {
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::mint_into(&caller, existential_deposits::<T>(10))?;
+		let to: T::AccountId = account("to", 0, SEED);
+		let amount = existential_deposits::<T>(5);
+	}: _(RawOrigin::Signed(caller.clone()), to.clone(), amount)
+	verify {
+		assert_eq!(Pallet::<T>::balance(&to), amount);
+		assert_last_event::<T>(Event::CreditTransferred { from: caller, to, amount }.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
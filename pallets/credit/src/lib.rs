@@ -0,0 +1,293 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Credit Pallet
+//!
+//! The Credit Pallet keeps a ledger of credit tokens, separate from the WAY
+//! balances of the Balances pallet. Credits are issued by `CreditOrigin` and
+//! can be transferred and burned by their holders. The pallet implements the
+//! `fungible` traits, so other pallets can use credits as a currency.
+//!
+//! Credit balances below `ExistentialDeposit` are removed, and the dust is
+//! taken out of the total issuance.
+//!
+//! ### Storage
+//!
+//! - `Credits`: The credit balance of every account.
+//! - `TotalIssuance`: The sum of all credit balances.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `issue_credit` - Mint credits into an account.
+//! - `burn_credit` - Burn credits of the caller.
+//! - `transfer_credit` - Transfer credits of the caller to another account.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use frame_support::traits::{
+	fungible,
+	tokens::{
+		DepositConsequence, Fortitude, Precision, Preservation, Provenance, WithdrawConsequence,
+	},
+	Get,
+};
+pub use pallet::*;
+use sp_runtime::{
+	traits::{CheckedAdd, CheckedSub, Saturating, Zero},
+	DispatchError,
+};
+use sp_std::vec::Vec;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::tokens::Balance};
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The type of a credit balance.
+		type CreditBalance: Balance + MaybeSerializeDeserialize;
+		/// The minimum credit balance an account must keep.
+		#[pallet::constant]
+		type ExistentialDeposit: Get<Self::CreditBalance>;
+		/// Origin allowed to issue credits.
+		type CreditOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// credit balance of an account
+	#[pallet::storage]
+	#[pallet::getter(fn credits)]
+	pub type Credits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::CreditBalance, ValueQuery>;
+
+	/// sum of all credit balances
+	#[pallet::storage]
+	#[pallet::getter(fn total_issuance)]
+	pub type TotalIssuance<T: Config> = StorageValue<_, T::CreditBalance, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub balances: Vec<(T::AccountId, T::CreditBalance)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (who, amount) in &self.balances {
+				assert!(
+					*amount >= T::ExistentialDeposit::get(),
+					"the credit balance of an account is below the existential deposit"
+				);
+				Credits::<T>::mutate(who, |balance| *balance = balance.saturating_add(*amount));
+				TotalIssuance::<T>::mutate(|issuance| *issuance = issuance.saturating_add(*amount));
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Credits were issued into an account.
+		CreditIssued { to: T::AccountId, amount: T::CreditBalance },
+		/// Credits were burned by their holder.
+		CreditBurned { who: T::AccountId, amount: T::CreditBalance },
+		/// Credits were transferred between accounts.
+		CreditTransferred { from: T::AccountId, to: T::AccountId, amount: T::CreditBalance },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Mint `amount` credits into `to`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::issue_credit())]
+		pub fn issue_credit(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			amount: T::CreditBalance,
+		) -> DispatchResult {
+			T::CreditOrigin::ensure_origin(origin)?;
+
+			<Self as fungible::Mutate<_>>::mint_into(&to, amount)?;
+
+			Self::deposit_event(Event::CreditIssued { to, amount });
+			Ok(())
+		}
+
+		/// Burn `amount` credits of the caller. A remaining balance below the
+		/// existential deposit is burned as well.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::burn_credit())]
+		pub fn burn_credit(origin: OriginFor<T>, amount: T::CreditBalance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let burned = <Self as fungible::Unbalanced<_>>::decrease_balance(
+				&who,
+				amount,
+				Precision::Exact,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			)?;
+			TotalIssuance::<T>::mutate(|issuance| *issuance = issuance.saturating_sub(burned));
+
+			Self::deposit_event(Event::CreditBurned { who, amount: burned });
+			Ok(())
+		}
+
+		/// Transfer `amount` credits of the caller to `to`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_credit())]
+		pub fn transfer_credit(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			amount: T::CreditBalance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			<Self as fungible::Mutate<_>>::transfer(&from, &to, amount, Preservation::Expendable)?;
+
+			Self::deposit_event(Event::CreditTransferred { from, to, amount });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> fungible::Inspect<T::AccountId> for Pallet<T> {
+	type Balance = T::CreditBalance;
+
+	fn total_issuance() -> Self::Balance {
+		TotalIssuance::<T>::get()
+	}
+
+	fn minimum_balance() -> Self::Balance {
+		T::ExistentialDeposit::get()
+	}
+
+	fn total_balance(who: &T::AccountId) -> Self::Balance {
+		Credits::<T>::get(who)
+	}
+
+	fn balance(who: &T::AccountId) -> Self::Balance {
+		Credits::<T>::get(who)
+	}
+
+	fn reducible_balance(
+		who: &T::AccountId,
+		preservation: Preservation,
+		_force: Fortitude,
+	) -> Self::Balance {
+		let balance = Credits::<T>::get(who);
+		match preservation {
+			Preservation::Expendable => balance,
+			Preservation::Protect | Preservation::Preserve =>
+				balance.saturating_sub(T::ExistentialDeposit::get()),
+		}
+	}
+
+	fn can_deposit(
+		who: &T::AccountId,
+		amount: Self::Balance,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		if amount.is_zero() {
+			return DepositConsequence::Success
+		}
+		if provenance == Provenance::Minted &&
+			TotalIssuance::<T>::get().checked_add(&amount).is_none()
+		{
+			return DepositConsequence::Overflow
+		}
+
+		match Credits::<T>::get(who).checked_add(&amount) {
+			None => DepositConsequence::Overflow,
+			Some(balance) if balance < T::ExistentialDeposit::get() =>
+				DepositConsequence::BelowMinimum,
+			Some(_) => DepositConsequence::Success,
+		}
+	}
+
+	fn can_withdraw(
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		if amount.is_zero() {
+			return WithdrawConsequence::Success
+		}
+
+		let Some(rest) = Credits::<T>::get(who).checked_sub(&amount) else {
+			return WithdrawConsequence::BalanceLow
+		};
+		if TotalIssuance::<T>::get().checked_sub(&amount).is_none() {
+			return WithdrawConsequence::Underflow
+		}
+		if !rest.is_zero() && rest < T::ExistentialDeposit::get() {
+			return WithdrawConsequence::ReducedToZero(rest)
+		}
+		WithdrawConsequence::Success
+	}
+}
+
+impl<T: Config> fungible::Unbalanced<T::AccountId> for Pallet<T> {
+	fn handle_dust(dust: fungible::Dust<T::AccountId, Self>) {
+		TotalIssuance::<T>::mutate(|issuance| *issuance = issuance.saturating_sub(dust.0));
+	}
+
+	fn write_balance(
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Option<Self::Balance>, DispatchError> {
+		if amount < T::ExistentialDeposit::get() {
+			Credits::<T>::remove(who);
+			return Ok(Some(amount).filter(|dust| !dust.is_zero()))
+		}
+
+		Credits::<T>::insert(who, amount);
+		Ok(None)
+	}
+
+	fn set_total_issuance(amount: Self::Balance) {
+		TotalIssuance::<T>::put(amount);
+	}
+}
+
+impl<T: Config> fungible::Mutate<T::AccountId> for Pallet<T> {}
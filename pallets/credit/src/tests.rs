@@ -0,0 +1,97 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::BadOrigin, TokenError};
+
+#[test]
+fn genesis_credits_are_issued() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Credit::credits(ALICE), 1_000);
+		assert_eq!(Credit::total_issuance(), 1_000);
+		assert_eq!(<Credit as fungible::Inspect<_>>::minimum_balance(), 10);
+	});
+}
+
+#[test]
+fn issue_credit_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Credit::issue_credit(RuntimeOrigin::signed(ALICE), BOB, 100), BadOrigin);
+		assert_noop!(Credit::issue_credit(RuntimeOrigin::root(), BOB, 5), TokenError::BelowMinimum);
+
+		assert_ok!(Credit::issue_credit(RuntimeOrigin::root(), BOB, 100));
+		assert_eq!(Credit::credits(BOB), 100);
+		assert_eq!(Credit::total_issuance(), 1_100);
+		System::assert_last_event(Event::CreditIssued { to: BOB, amount: 100 }.into());
+	});
+}
+
+#[test]
+fn transfer_credit_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Credit::transfer_credit(RuntimeOrigin::signed(ALICE), BOB, 5),
+			TokenError::BelowMinimum
+		);
+		assert_noop!(
+			Credit::transfer_credit(RuntimeOrigin::signed(ALICE), BOB, 1_001),
+			TokenError::FundsUnavailable
+		);
+
+		assert_ok!(Credit::transfer_credit(RuntimeOrigin::signed(ALICE), BOB, 400));
+		assert_eq!(Credit::credits(ALICE), 600);
+		assert_eq!(Credit::credits(BOB), 400);
+		assert_eq!(Credit::total_issuance(), 1_000);
+		System::assert_last_event(
+			Event::CreditTransferred { from: ALICE, to: BOB, amount: 400 }.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_credit_removes_dust() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Credit::transfer_credit(RuntimeOrigin::signed(ALICE), BOB, 995));
+
+		assert!(!Credits::<Test>::contains_key(ALICE));
+		assert_eq!(Credit::credits(BOB), 995);
+		assert_eq!(Credit::total_issuance(), 995);
+	});
+}
+
+#[test]
+fn burn_credit_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Credit::burn_credit(RuntimeOrigin::signed(BOB), 10),
+			TokenError::FundsUnavailable
+		);
+
+		assert_ok!(Credit::burn_credit(RuntimeOrigin::signed(ALICE), 300));
+		assert_eq!(Credit::credits(ALICE), 700);
+		assert_eq!(Credit::total_issuance(), 700);
+		System::assert_last_event(Event::CreditBurned { who: ALICE, amount: 300 }.into());
+
+		// the remaining balance is below the existential deposit
+		assert_ok!(Credit::burn_credit(RuntimeOrigin::signed(ALICE), 695));
+		assert!(!Credits::<Test>::contains_key(ALICE));
+		assert_eq!(Credit::total_issuance(), 0);
+	});
+}
@@ -0,0 +1,92 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::Get;
+use sp_runtime::{app_crypto::sr25519, traits::Hash};
+
+use cord_utilities::traits::GenerateBenchmarkOrigin;
+
+const DELEGATE_SEED: u32 = 0;
+
+fn delegate<T: Config>(index: u32) -> (AccountIdOf<T>, DelegateIdOf<T>)
+where
+	T::DelegateIdentifier: From<T::AccountId>,
+{
+	let account: AccountIdOf<T> = account("delegate", index, DELEGATE_SEED);
+	(account.clone(), account.into())
+}
+
+fn delegation_id<T: Config>(index: u32) -> DelegationIdOf<T> {
+	T::Hashing::hash_of(&index)
+}
+
+benchmarks! {
+	where_clause {
+		where
+		T::AccountId: From<sr25519::Public>,
+		T::DelegateIdentifier: From<T::AccountId>,
+		T::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::DelegateIdentifier>,
+	}
+
+	create_delegation {
+		let (root_account, root_creator) = delegate::<T>(0);
+		let (caller, creator) = delegate::<T>(1);
+		let (_, child_delegate) = delegate::<T>(2);
+		let root = delegation_id::<T>(0);
+		let child = delegation_id::<T>(1);
+
+		let root_origin = T::EnsureOrigin::generate_origin(root_account, root_creator);
+		Pallet::<T>::create_delegation(root_origin, root, None, creator.clone(), Permissions::all()).expect("Should create the root delegation.");
+
+		let origin = T::EnsureOrigin::generate_origin(caller, creator);
+	}: _<T::RuntimeOrigin>(origin, child, Some(root), child_delegate.clone(), Permissions::all())
+	verify {
+		assert!(Pallet::<T>::is_delegated(&child_delegate, &root));
+	}
+
+	revoke_delegation {
+		let r in 1 .. T::MaxDepth::get();
+
+		// A chain of `r` delegations, each created by the delegate of its parent.
+		let root = delegation_id::<T>(0);
+		let mut parent = None;
+		for i in 0 .. r {
+			let (account, creator) = delegate::<T>(i);
+			let (_, next) = delegate::<T>(i + 1);
+			let origin = T::EnsureOrigin::generate_origin(account, creator);
+			Pallet::<T>::create_delegation(origin, delegation_id::<T>(i), parent, next, Permissions::all()).expect("Should create the delegation.");
+			parent = Some(delegation_id::<T>(i));
+		}
+
+		let (caller, revoker) = delegate::<T>(0);
+		let origin = T::EnsureOrigin::generate_origin(caller, revoker);
+	}: _<T::RuntimeOrigin>(origin, root, r)
+	verify {
+		let (_, last) = delegate::<T>(r);
+		assert!(!Pallet::<T>::is_delegated(&last, &root));
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
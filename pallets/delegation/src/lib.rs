@@ -0,0 +1,349 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Delegation Pallet
+//!
+//! Delegations form trees. Anyone can start a hierarchy by creating a root
+//! delegation to a delegate of their choice. The delegate of a node holding
+//! the `DELEGATE` permission can in turn delegate a subset of its
+//! permissions further down, up to `MaxDepth` levels below the root.
+//!
+//! Revoking a node revokes its whole subtree. A node can be revoked by its
+//! creator, its delegate, or the creator or delegate of any of its
+//! ancestors.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(test)]
+pub mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+pub use crate::{
+	pallet::*,
+	types::{DelegationNode, Permissions},
+	weights::WeightInfo,
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*, sp_runtime::SaturatedConversion, traits::StorageVersion,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::{vec, vec::Vec};
+
+	use cord_utilities::traits::CallSources;
+
+	use super::WeightInfo;
+	use crate::types::{DelegationNode, Permissions};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type DelegationIdOf<T> = <T as frame_system::Config>::Hash;
+	pub type DelegateIdOf<T> = <T as Config>::DelegateIdentifier;
+	pub type DelegationChildrenOf<T> = BoundedVec<DelegationIdOf<T>, <T as Config>::MaxChildren>;
+	pub type DelegationNodeOf<T> = DelegationNode<
+		DelegationIdOf<T>,
+		DelegateIdOf<T>,
+		DelegationChildrenOf<T>,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type EnsureOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = <Self as Config>::OriginSuccess,
+		>;
+		type OriginSuccess: CallSources<AccountIdOf<Self>, DelegateIdOf<Self>>;
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The type of a creator or delegate identifier.
+		type DelegateIdentifier: Parameter + MaxEncodedLen;
+		/// The maximum depth of a delegation below the root of its hierarchy.
+		#[pallet::constant]
+		type MaxDepth: Get<u32>;
+		/// The maximum number of direct children of a delegation.
+		#[pallet::constant]
+		type MaxChildren: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Map of delegation identifier -> delegation node.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation_hierarchy)]
+	pub type DelegationHierarchy<T> =
+		StorageMap<_, Blake2_128Concat, DelegationIdOf<T>, DelegationNodeOf<T>>;
+
+	/// Number of active delegations of a delegate within a hierarchy,
+	/// keyed by delegate and root identifier.
+	#[pallet::storage]
+	#[pallet::getter(fn delegates)]
+	pub type Delegates<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DelegateIdOf<T>,
+		Blake2_128Concat,
+		DelegationIdOf<T>,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A delegation has been created.
+		DelegationCreated {
+			delegation_id: DelegationIdOf<T>,
+			root: DelegationIdOf<T>,
+			creator: DelegateIdOf<T>,
+			delegate: DelegateIdOf<T>,
+		},
+		/// A delegation and its subtree have been revoked. `revoked` is the
+		/// number of delegations revoked.
+		DelegationRevoked {
+			delegation_id: DelegationIdOf<T>,
+			revoker: DelegateIdOf<T>,
+			revoked: u32,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A delegation with the same identifier already exists.
+		AlreadyExists,
+		/// The delegation does not exist.
+		DelegationNotFound,
+		/// The parent delegation does not exist.
+		ParentNotFound,
+		/// The parent delegation has been revoked.
+		ParentRevoked,
+		/// The delegation has already been revoked.
+		AlreadyRevoked,
+		/// The caller is not allowed to perform the operation on the
+		/// delegation.
+		UnauthorizedDelegation,
+		/// The requested permissions are not a subset of the parent's.
+		PermissionsExceedParent,
+		/// The delegation would be deeper than the maximum depth.
+		MaxDepthExceeded,
+		/// The parent delegation cannot hold more children.
+		TooManyChildren,
+		/// The subtree holds more delegations than the revocation limit.
+		ExceededRevocationBounds,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a delegation to `delegate` with the given `permissions`.
+		///
+		/// Without a `parent`, the delegation becomes the root of a new
+		/// hierarchy. With a `parent`, the caller must be the delegate of
+		/// the active parent, which must hold the `DELEGATE` permission and
+		/// every permission being granted.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_delegation())]
+		pub fn create_delegation(
+			origin: OriginFor<T>,
+			delegation_id: DelegationIdOf<T>,
+			parent: Option<DelegationIdOf<T>>,
+			delegate: DelegateIdOf<T>,
+			permissions: Permissions,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(
+				!DelegationHierarchy::<T>::contains_key(delegation_id),
+				Error::<T>::AlreadyExists
+			);
+
+			let (root, depth) = match parent {
+				Some(parent_id) => {
+					let mut parent_node = DelegationHierarchy::<T>::get(parent_id)
+						.ok_or(Error::<T>::ParentNotFound)?;
+					ensure!(!parent_node.revoked, Error::<T>::ParentRevoked);
+					ensure!(parent_node.delegate == creator, Error::<T>::UnauthorizedDelegation);
+					ensure!(
+						parent_node.permissions.contains(Permissions::DELEGATE),
+						Error::<T>::UnauthorizedDelegation
+					);
+					ensure!(
+						parent_node.permissions.contains(permissions),
+						Error::<T>::PermissionsExceedParent
+					);
+
+					let depth = parent_node.depth.saturating_add(1);
+					ensure!(depth <= T::MaxDepth::get(), Error::<T>::MaxDepthExceeded);
+
+					parent_node
+						.children
+						.try_push(delegation_id)
+						.map_err(|_| Error::<T>::TooManyChildren)?;
+					let root = parent_node.root;
+					DelegationHierarchy::<T>::insert(parent_id, parent_node);
+
+					(root, depth)
+				},
+				None => (delegation_id, 0),
+			};
+
+			DelegationHierarchy::<T>::insert(
+				delegation_id,
+				DelegationNodeOf::<T> {
+					root,
+					parent,
+					children: BoundedVec::default(),
+					creator: creator.clone(),
+					delegate: delegate.clone(),
+					permissions,
+					depth,
+					revoked: false,
+					created_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			Delegates::<T>::mutate(&delegate, root, |count| *count = count.saturating_add(1));
+
+			Self::deposit_event(Event::<T>::DelegationCreated {
+				delegation_id,
+				root,
+				creator,
+				delegate,
+			});
+
+			Ok(())
+		}
+
+		/// Revoke a delegation together with every delegation below it.
+		///
+		/// The caller must be the creator or delegate of the delegation or
+		/// of one of its ancestors. `max_revocations` bounds the number of
+		/// delegations revoked; the call fails without revoking anything if
+		/// the subtree holds more active delegations.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_delegation(*max_revocations))]
+		pub fn revoke_delegation(
+			origin: OriginFor<T>,
+			delegation_id: DelegationIdOf<T>,
+			max_revocations: u32,
+		) -> DispatchResultWithPostInfo {
+			let revoker = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let node = DelegationHierarchy::<T>::get(delegation_id)
+				.ok_or(Error::<T>::DelegationNotFound)?;
+			ensure!(!node.revoked, Error::<T>::AlreadyRevoked);
+			ensure!(
+				Self::is_authorized_revoker(&revoker, &node),
+				Error::<T>::UnauthorizedDelegation
+			);
+
+			let revoked = Self::revoke_subtree(delegation_id, max_revocations)?;
+
+			Self::deposit_event(Event::<T>::DelegationRevoked { delegation_id, revoker, revoked });
+
+			Ok(Some(<T as Config>::WeightInfo::revoke_delegation(revoked)).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether `who` is the delegate of an active delegation in the
+		/// hierarchy rooted at `root`.
+		pub fn is_delegated(who: &DelegateIdOf<T>, root: &DelegationIdOf<T>) -> bool {
+			Delegates::<T>::get(who, root) > 0
+		}
+
+		/// Whether `who` created or is the delegate of `node` or one of its
+		/// ancestors.
+		fn is_authorized_revoker(who: &DelegateIdOf<T>, node: &DelegationNodeOf<T>) -> bool {
+			if &node.creator == who || &node.delegate == who {
+				return true
+			}
+
+			let mut parent = node.parent;
+			while let Some(parent_id) = parent {
+				match DelegationHierarchy::<T>::get(parent_id) {
+					Some(ancestor) => {
+						if &ancestor.creator == who || &ancestor.delegate == who {
+							return true
+						}
+						parent = ancestor.parent;
+					},
+					None => return false,
+				}
+			}
+
+			false
+		}
+
+		/// Revoke the active delegations of the subtree rooted at
+		/// `delegation_id`, returning how many were revoked.
+		///
+		/// The subtree is collected before anything is written, so exceeding
+		/// `max_revocations` leaves storage untouched. Children that are
+		/// already revoked are skipped, as their own subtree was revoked with
+		/// them.
+		fn revoke_subtree(
+			delegation_id: DelegationIdOf<T>,
+			max_revocations: u32,
+		) -> Result<u32, DispatchError> {
+			let mut pending = vec![delegation_id];
+			let mut subtree = Vec::new();
+
+			while let Some(current) = pending.pop() {
+				let node =
+					DelegationHierarchy::<T>::get(current).ok_or(Error::<T>::DelegationNotFound)?;
+				if node.revoked {
+					continue
+				}
+
+				ensure!(
+					subtree.len() < max_revocations.saturated_into::<usize>(),
+					Error::<T>::ExceededRevocationBounds
+				);
+				pending.extend(node.children.iter().copied());
+				subtree.push((current, node));
+			}
+
+			let revoked = subtree.len().saturated_into::<u32>();
+			for (id, mut node) in subtree {
+				node.revoked = true;
+				Delegates::<T>::mutate_exists(&node.delegate, node.root, |count| {
+					*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+				});
+				DelegationHierarchy::<T>::insert(id, node);
+			}
+
+			Ok(revoked)
+		}
+	}
+}
@@ -0,0 +1,242 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	AccountId32,
+};
+
+pub(crate) const ACCOUNT_00: TestDelegateAccount = AccountId32::new([1u8; 32]);
+pub(crate) const ACCOUNT_01: TestDelegateAccount = AccountId32::new([2u8; 32]);
+pub(crate) const ACCOUNT_02: TestDelegateAccount = AccountId32::new([3u8; 32]);
+pub(crate) const ACCOUNT_03: TestDelegateAccount = AccountId32::new([4u8; 32]);
+pub(crate) const DID_00: TestDelegateIdentifier = SubjectId(ACCOUNT_00);
+pub(crate) const DID_01: TestDelegateIdentifier = SubjectId(ACCOUNT_01);
+pub(crate) const DID_02: TestDelegateIdentifier = SubjectId(ACCOUNT_02);
+pub(crate) const DID_03: TestDelegateIdentifier = SubjectId(ACCOUNT_03);
+
+fn delegation_id(seed: u32) -> DelegationIdOf<Test> {
+	BlakeTwo256::hash_of(&seed)
+}
+
+fn origin(account: TestDelegateAccount, subject: TestDelegateIdentifier) -> RuntimeOrigin {
+	DoubleOrigin(account, subject).into()
+}
+
+#[test]
+fn create_delegation_builds_a_hierarchy() {
+	new_test_ext().execute_with(|| {
+		let root = delegation_id(0);
+		let child = delegation_id(1);
+
+		assert_ok!(Delegation::create_delegation(
+			origin(ACCOUNT_00, DID_00),
+			root,
+			None,
+			DID_01,
+			Permissions::ISSUE | Permissions::DELEGATE,
+		));
+		assert_ok!(Delegation::create_delegation(
+			origin(ACCOUNT_01, DID_01),
+			child,
+			Some(root),
+			DID_02,
+			Permissions::ISSUE,
+		));
+
+		let root_node = Delegation::delegation_hierarchy(root).unwrap();
+		assert_eq!(root_node.root, root);
+		assert_eq!(root_node.children.into_inner(), vec![child]);
+
+		let child_node = Delegation::delegation_hierarchy(child).unwrap();
+		assert_eq!(child_node.root, root);
+		assert_eq!(child_node.parent, Some(root));
+		assert_eq!(child_node.creator, DID_01);
+		assert_eq!(child_node.depth, 1);
+
+		assert!(Delegation::is_delegated(&DID_01, &root));
+		assert!(Delegation::is_delegated(&DID_02, &root));
+		assert!(!Delegation::is_delegated(&DID_00, &root));
+	});
+}
+
+#[test]
+fn create_delegation_requires_the_rights_of_the_parent() {
+	new_test_ext().execute_with(|| {
+		let root = delegation_id(0);
+		let child = delegation_id(1);
+
+		assert_ok!(Delegation::create_delegation(
+			origin(ACCOUNT_00, DID_00),
+			root,
+			None,
+			DID_01,
+			Permissions::DELEGATE,
+		));
+
+		// Only the delegate of the parent can delegate further.
+		assert_noop!(
+			Delegation::create_delegation(
+				origin(ACCOUNT_00, DID_00),
+				child,
+				Some(root),
+				DID_02,
+				Permissions::DELEGATE,
+			),
+			Error::<Test>::UnauthorizedDelegation
+		);
+		// Permissions can only be narrowed down the hierarchy.
+		assert_noop!(
+			Delegation::create_delegation(
+				origin(ACCOUNT_01, DID_01),
+				child,
+				Some(root),
+				DID_02,
+				Permissions::ISSUE,
+			),
+			Error::<Test>::PermissionsExceedParent
+		);
+		assert_noop!(
+			Delegation::create_delegation(
+				origin(ACCOUNT_01, DID_01),
+				root,
+				Some(root),
+				DID_02,
+				Permissions::DELEGATE,
+			),
+			Error::<Test>::AlreadyExists
+		);
+		assert_noop!(
+			Delegation::create_delegation(
+				origin(ACCOUNT_01, DID_01),
+				child,
+				Some(delegation_id(42)),
+				DID_02,
+				Permissions::DELEGATE,
+			),
+			Error::<Test>::ParentNotFound
+		);
+	});
+}
+
+#[test]
+fn create_delegation_respects_the_depth_limit() {
+	new_test_ext().execute_with(|| {
+		let accounts = [ACCOUNT_00, ACCOUNT_01];
+		let mut parent = None;
+
+		for depth in 0..=MaxDepth::get() {
+			let creator = accounts[depth as usize % 2].clone();
+			let delegate = accounts[(depth as usize + 1) % 2].clone();
+			assert_ok!(Delegation::create_delegation(
+				origin(creator.clone(), SubjectId(creator)),
+				delegation_id(depth),
+				parent,
+				SubjectId(delegate),
+				Permissions::DELEGATE,
+			));
+			parent = Some(delegation_id(depth));
+		}
+
+		let depth = MaxDepth::get() + 1;
+		let creator = accounts[depth as usize % 2].clone();
+		assert_noop!(
+			Delegation::create_delegation(
+				origin(creator.clone(), SubjectId(creator)),
+				delegation_id(depth),
+				parent,
+				DID_03,
+				Permissions::DELEGATE,
+			),
+			Error::<Test>::MaxDepthExceeded
+		);
+	});
+}
+
+#[test]
+fn revoke_delegation_revokes_the_subtree() {
+	new_test_ext().execute_with(|| {
+		let root = delegation_id(0);
+		let child = delegation_id(1);
+		let grandchild = delegation_id(2);
+
+		assert_ok!(Delegation::create_delegation(
+			origin(ACCOUNT_00, DID_00),
+			root,
+			None,
+			DID_01,
+			Permissions::ISSUE | Permissions::DELEGATE,
+		));
+		assert_ok!(Delegation::create_delegation(
+			origin(ACCOUNT_01, DID_01),
+			child,
+			Some(root),
+			DID_02,
+			Permissions::ISSUE | Permissions::DELEGATE,
+		));
+		assert_ok!(Delegation::create_delegation(
+			origin(ACCOUNT_02, DID_02),
+			grandchild,
+			Some(child),
+			DID_03,
+			Permissions::ISSUE,
+		));
+
+		// Delegates below a node cannot revoke it.
+		assert_noop!(
+			Delegation::revoke_delegation(origin(ACCOUNT_03, DID_03), child, 3),
+			Error::<Test>::UnauthorizedDelegation
+		);
+		assert_noop!(
+			Delegation::revoke_delegation(origin(ACCOUNT_00, DID_00), root, 2),
+			Error::<Test>::ExceededRevocationBounds
+		);
+
+		// The creator of the root can revoke any node of the hierarchy.
+		assert_ok!(Delegation::revoke_delegation(origin(ACCOUNT_00, DID_00), child, 2));
+		assert!(Delegation::delegation_hierarchy(child).unwrap().revoked);
+		assert!(Delegation::delegation_hierarchy(grandchild).unwrap().revoked);
+		assert!(!Delegation::delegation_hierarchy(root).unwrap().revoked);
+		assert!(Delegation::is_delegated(&DID_01, &root));
+		assert!(!Delegation::is_delegated(&DID_02, &root));
+		assert!(!Delegation::is_delegated(&DID_03, &root));
+
+		assert_noop!(
+			Delegation::revoke_delegation(origin(ACCOUNT_00, DID_00), grandchild, 1),
+			Error::<Test>::AlreadyRevoked
+		);
+		assert_noop!(
+			Delegation::create_delegation(
+				origin(ACCOUNT_02, DID_02),
+				delegation_id(3),
+				Some(child),
+				DID_03,
+				Permissions::ISSUE,
+			),
+			Error::<Test>::ParentRevoked
+		);
+
+		// Revoked children are skipped when the root is revoked.
+		assert_ok!(Delegation::revoke_delegation(origin(ACCOUNT_01, DID_01), root, 1));
+		assert!(!Delegation::is_delegated(&DID_01, &root));
+	});
+}
@@ -0,0 +1,69 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Types of the delegation pallet.
+
+use bitflags::bitflags;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+bitflags! {
+	/// Rights a delegation grants to its delegate.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub struct Permissions: u32 {
+		/// Issue credentials on behalf of the hierarchy.
+		const ISSUE = 0b0000_0001;
+		/// Create child delegations.
+		const DELEGATE = 0b0000_0010;
+	}
+}
+
+impl Default for Permissions {
+	/// By default a delegation only grants the `ISSUE` permission.
+	fn default() -> Self {
+		Permissions::ISSUE
+	}
+}
+
+/// A node of a delegation hierarchy.
+///
+/// The root node of a hierarchy has no parent and its `root` is its own
+/// identifier. Every other node is created by the delegate of its parent and
+/// holds a subset of the parent's permissions.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DelegationNode<DelegationId, DelegateId, Children, BlockNumber> {
+	/// Identifier of the root node of the hierarchy.
+	pub root: DelegationId,
+	/// Identifier of the parent node, `None` for the root node.
+	pub parent: Option<DelegationId>,
+	/// Identifiers of the child nodes.
+	pub children: Children,
+	/// The identity that created the node.
+	pub creator: DelegateId,
+	/// The identity the rights are delegated to.
+	pub delegate: DelegateId,
+	/// The rights granted to the delegate.
+	pub permissions: Permissions,
+	/// Distance from the root node, which is at depth zero.
+	pub depth: u32,
+	/// Whether the node, and with it every descendant, has been revoked.
+	pub revoked: bool,
+	/// Block the node was created at.
+	pub created_at: BlockNumber,
+}
@@ -0,0 +1,83 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{sp_runtime::SaturatedConversion, traits::Get, BoundedVec};
+use sp_runtime::{app_crypto::sr25519, traits::Hash};
+use sp_std::vec;
+
+use cord_utilities::traits::GenerateBenchmarkOrigin;
+
+const CALLER_SEED: u32 = 0;
+const SUBJECT_SEED: u32 = 1;
+
+fn generate_document<T: Config>(length: u32, fill: u8) -> DidDocumentInput<T> {
+	BoundedVec::try_from(vec![fill; length.saturated_into()])
+		.expect("BoundedVec creation should not fail.")
+}
+
+benchmarks! {
+	where_clause {
+		where
+		T::AccountId: From<sr25519::Public>,
+		T::DidIdentifier: From<T::AccountId>,
+		T::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::DidIdentifier>,
+	}
+
+	create_did {
+		let l in 1 .. T::MaxDocumentLength::get();
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let identifier: DidIdentifierOf<T> = account("subject", 0, SUBJECT_SEED);
+		let origin = T::EnsureOrigin::generate_origin(caller, identifier.clone());
+	}: _<T::RuntimeOrigin>(origin, generate_document::<T>(l, b'a'))
+	verify {
+		assert!(Documents::<T>::get(&identifier).is_some());
+	}
+
+	update_did {
+		let l in 1 .. T::MaxDocumentLength::get();
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let identifier: DidIdentifierOf<T> = account("subject", 0, SUBJECT_SEED);
+		let origin = T::EnsureOrigin::generate_origin(caller, identifier.clone());
+
+		Pallet::<T>::create_did(origin.clone(), generate_document::<T>(l, b'a')).expect("Should anchor the DID document.");
+	}: _<T::RuntimeOrigin>(origin, generate_document::<T>(l, b'b'))
+	verify {
+		let document = generate_document::<T>(l, b'b');
+		assert_eq!(Documents::<T>::get(&identifier).unwrap().digest, T::Hashing::hash(&document[..]));
+	}
+
+	deactivate_did {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let identifier: DidIdentifierOf<T> = account("subject", 0, SUBJECT_SEED);
+		let origin = T::EnsureOrigin::generate_origin(caller, identifier.clone());
+
+		Pallet::<T>::create_did(origin.clone(), generate_document::<T>(T::MaxDocumentLength::get(), b'a')).expect("Should anchor the DID document.");
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert_eq!(Documents::<T>::get(&identifier).unwrap().status, DidStatus::Deactivated);
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
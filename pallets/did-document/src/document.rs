@@ -0,0 +1,45 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The status of an anchored DID document.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub enum DidStatus {
+	/// The document can be resolved and updated.
+	Active,
+	/// The document can be resolved but no longer updated.
+	Deactivated,
+}
+
+/// The on-chain anchor of a DID document.
+#[derive(Clone, Encode, Decode, RuntimeDebug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct DidDocument<Hash, AccountId, BlockNumber> {
+	/// The hash of the DID document.
+	pub digest: Hash,
+	/// The status of the DID document.
+	pub status: DidStatus,
+	/// The account that anchored the document.
+	pub creator: AccountId,
+	/// The block number at which the document was first anchored.
+	pub created_at: BlockNumber,
+	/// The block number of the last update or deactivation.
+	pub updated_at: BlockNumber,
+}
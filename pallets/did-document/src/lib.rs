@@ -0,0 +1,266 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pallet anchoring W3C DID documents.
+//!
+//! A DID subject anchors the hash of its DID document on chain. The
+//! document itself is kept off chain; resolvers fetch it and check it
+//! against the anchored hash and status.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod document;
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(test)]
+pub mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+pub use crate::{
+	document::{DidDocument, DidStatus},
+	pallet::*,
+	weights::WeightInfo,
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*, sp_runtime::SaturatedConversion, traits::StorageVersion,
+		Blake2_128Concat,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Hash, Zero};
+	use sp_std::vec::Vec;
+
+	use cord_utilities::traits::CallSources;
+
+	use super::WeightInfo;
+	use crate::document::{DidDocument, DidStatus};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type HashOf<T> = <T as frame_system::Config>::Hash;
+	pub type DidIdentifierOf<T> = <T as Config>::DidIdentifier;
+	pub type DidDocumentInput<T> = BoundedVec<u8, <T as Config>::MaxDocumentLength>;
+	pub type DidDocumentOf<T> = DidDocument<HashOf<T>, AccountIdOf<T>, BlockNumberFor<T>>;
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Map of DID identifier -> anchored document.
+	#[pallet::storage]
+	#[pallet::getter(fn documents)]
+	pub type Documents<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidDocumentOf<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type EnsureOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = <Self as Config>::OriginSuccess,
+		>;
+		type OriginSuccess: CallSources<AccountIdOf<Self>, DidIdentifierOf<Self>>;
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The type of a DID identifier.
+		type DidIdentifier: Parameter + MaxEncodedLen + MaybeSerializeDeserialize;
+		/// The max encoded length of a DID document.
+		#[pallet::constant]
+		type MaxDocumentLength: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A DID document has been anchored.
+		DidDocumentCreated { identifier: DidIdentifierOf<T>, digest: HashOf<T> },
+		/// An anchored DID document has been replaced.
+		DidDocumentUpdated { identifier: DidIdentifierOf<T>, digest: HashOf<T> },
+		/// A DID document has been deactivated.
+		DidDocumentDeactivated { identifier: DidIdentifierOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The DID already has an anchored document.
+		AlreadyExists,
+		/// The DID has no anchored document.
+		NotFound,
+		/// The DID document has been deactivated and cannot be modified.
+		Deactivated,
+		/// The new document is identical to the anchored one.
+		DocumentUnchanged,
+	}
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Documents anchored at genesis, as `(identifier, creator, document)`
+		/// triples.
+		pub documents: Vec<(DidIdentifierOf<T>, AccountIdOf<T>, Vec<u8>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (identifier, creator, document) in &self.documents {
+				assert!(
+					document.len() <= T::MaxDocumentLength::get().saturated_into::<usize>(),
+					"Genesis DID documents must not exceed the max document length"
+				);
+				assert!(
+					!Documents::<T>::contains_key(identifier),
+					"Genesis DID identifiers must be unique"
+				);
+
+				Documents::<T>::insert(
+					identifier,
+					DidDocumentOf::<T> {
+						digest: T::Hashing::hash(document),
+						status: DidStatus::Active,
+						creator: creator.clone(),
+						created_at: Zero::zero(),
+						updated_at: Zero::zero(),
+					},
+				);
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Anchor the DID document of the DID specified in the origin.
+		///
+		/// Only the hash of the document is stored. The DID must not already
+		/// have an anchored document, even a deactivated one.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_did(document.len().saturated_into()))]
+		pub fn create_did(origin: OriginFor<T>, document: DidDocumentInput<T>) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let creator = source.sender();
+			let identifier = source.subject();
+
+			ensure!(!Documents::<T>::contains_key(&identifier), Error::<T>::AlreadyExists);
+
+			let digest = T::Hashing::hash(&document[..]);
+			let block_number = frame_system::Pallet::<T>::block_number();
+
+			Documents::<T>::insert(
+				&identifier,
+				DidDocumentOf::<T> {
+					digest,
+					status: DidStatus::Active,
+					creator,
+					created_at: block_number,
+					updated_at: block_number,
+				},
+			);
+			Self::deposit_event(Event::<T>::DidDocumentCreated { identifier, digest });
+
+			Ok(())
+		}
+
+		/// Replace the anchored DID document of the DID specified in the
+		/// origin.
+		///
+		/// The anchored document must be active.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_did(document.len().saturated_into()))]
+		pub fn update_did(origin: OriginFor<T>, document: DidDocumentInput<T>) -> DispatchResult {
+			let identifier = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let mut anchored = Self::active_document(&identifier)?;
+			let digest = T::Hashing::hash(&document[..]);
+			ensure!(anchored.digest != digest, Error::<T>::DocumentUnchanged);
+
+			anchored.digest = digest;
+			anchored.updated_at = frame_system::Pallet::<T>::block_number();
+
+			Documents::<T>::insert(&identifier, anchored);
+			Self::deposit_event(Event::<T>::DidDocumentUpdated { identifier, digest });
+
+			Ok(())
+		}
+
+		/// Deactivate the anchored DID document of the DID specified in the
+		/// origin.
+		///
+		/// Deactivation is permanent: the document stays resolvable with a
+		/// deactivated status and can no longer be updated.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::deactivate_did())]
+		pub fn deactivate_did(origin: OriginFor<T>) -> DispatchResult {
+			let identifier = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let mut anchored = Self::active_document(&identifier)?;
+			anchored.status = DidStatus::Deactivated;
+			anchored.updated_at = frame_system::Pallet::<T>::block_number();
+
+			Documents::<T>::insert(&identifier, anchored);
+			Self::deposit_event(Event::<T>::DidDocumentDeactivated { identifier });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The document anchored by the provided DID, if any.
+		pub fn resolve_did(identifier: &DidIdentifierOf<T>) -> Option<DidDocumentOf<T>> {
+			Documents::<T>::get(identifier)
+		}
+
+		/// Whether the provided DID anchored a document.
+		pub fn did_exists(identifier: &DidIdentifierOf<T>) -> bool {
+			Documents::<T>::contains_key(identifier)
+		}
+
+		/// The DIDs whose documents the provided account anchored. This
+		/// walks every anchored document, so it is only meant for runtime
+		/// API calls.
+		pub fn dids_of_creator(creator: &AccountIdOf<T>) -> Vec<DidIdentifierOf<T>> {
+			Documents::<T>::iter()
+				.filter(|(_, document)| &document.creator == creator)
+				.map(|(identifier, _)| identifier)
+				.collect()
+		}
+
+		/// The anchored document of the provided DID, provided it is still
+		/// active.
+		fn active_document(
+			identifier: &DidIdentifierOf<T>,
+		) -> Result<DidDocumentOf<T>, DispatchError> {
+			let anchored = Documents::<T>::get(identifier).ok_or(Error::<T>::NotFound)?;
+			ensure!(anchored.status == DidStatus::Active, Error::<T>::Deactivated);
+
+			Ok(anchored)
+		}
+	}
+}
@@ -0,0 +1,180 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	AccountId32, BuildStorage,
+};
+
+pub(crate) const ACCOUNT_00: TestDidCreator = AccountId32::new([1u8; 32]);
+pub(crate) const ACCOUNT_01: TestDidCreator = AccountId32::new([2u8; 32]);
+pub(crate) const DID_00: TestDidIdentifier = SubjectId(ACCOUNT_00);
+pub(crate) const DOCUMENT_00: &[u8] = br#"{"id":"did:cord:00","verificationMethod":[]}"#;
+pub(crate) const DOCUMENT_01: &[u8] = br#"{"id":"did:cord:00","service":[]}"#;
+
+fn document(input: &[u8]) -> DidDocumentInput<Test> {
+	BoundedVec::try_from(input.to_vec()).expect("Test documents fit the max length.")
+}
+
+#[test]
+fn create_did_anchors_the_document_hash() {
+	new_test_ext().execute_with(|| {
+		assert!(Pallet::<Test>::resolve_did(&DID_00).is_none());
+
+		assert_ok!(Pallet::<Test>::create_did(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			document(DOCUMENT_00),
+		));
+
+		assert_eq!(
+			Pallet::<Test>::resolve_did(&DID_00),
+			Some(DidDocumentOf::<Test> {
+				digest: BlakeTwo256::hash(DOCUMENT_00),
+				status: DidStatus::Active,
+				creator: ACCOUNT_00,
+				created_at: 1,
+				updated_at: 1,
+			})
+		);
+
+		// A DID anchors a single document.
+		assert_noop!(
+			Pallet::<Test>::create_did(
+				DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				document(DOCUMENT_01),
+			),
+			Error::<Test>::AlreadyExists
+		);
+	})
+}
+
+#[test]
+fn dids_of_creator_lists_the_anchored_dids() {
+	new_test_ext().execute_with(|| {
+		let did_01 = SubjectId(ACCOUNT_01);
+		assert!(!Pallet::<Test>::did_exists(&DID_00));
+
+		assert_ok!(Pallet::<Test>::create_did(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			document(DOCUMENT_00),
+		));
+		assert_ok!(Pallet::<Test>::create_did(
+			DoubleOrigin(ACCOUNT_00, did_01.clone()).into(),
+			document(DOCUMENT_01),
+		));
+
+		assert!(Pallet::<Test>::did_exists(&DID_00));
+		// Documents are listed in storage order.
+		let dids = Pallet::<Test>::dids_of_creator(&ACCOUNT_00);
+		assert_eq!(dids.len(), 2);
+		assert!(dids.contains(&DID_00) && dids.contains(&did_01));
+		assert!(Pallet::<Test>::dids_of_creator(&ACCOUNT_01).is_empty());
+	})
+}
+
+#[test]
+fn update_did_replaces_the_document_hash() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::update_did(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				document(DOCUMENT_01),
+			),
+			Error::<Test>::NotFound
+		);
+
+		assert_ok!(Pallet::<Test>::create_did(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			document(DOCUMENT_00),
+		));
+		assert_noop!(
+			Pallet::<Test>::update_did(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				document(DOCUMENT_00),
+			),
+			Error::<Test>::DocumentUnchanged
+		);
+
+		System::set_block_number(2);
+		assert_ok!(Pallet::<Test>::update_did(
+			DoubleOrigin(ACCOUNT_01, DID_00).into(),
+			document(DOCUMENT_01),
+		));
+
+		let anchored = Pallet::<Test>::resolve_did(&DID_00).unwrap();
+		assert_eq!(anchored.digest, BlakeTwo256::hash(DOCUMENT_01));
+		// The creator is the account that first anchored the document.
+		assert_eq!(anchored.creator, ACCOUNT_00);
+		assert_eq!((anchored.created_at, anchored.updated_at), (1, 2));
+	})
+}
+
+#[test]
+fn deactivate_did_is_permanent() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::create_did(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			document(DOCUMENT_00),
+		));
+		assert_ok!(Pallet::<Test>::deactivate_did(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+
+		let anchored = Pallet::<Test>::resolve_did(&DID_00).unwrap();
+		assert_eq!(anchored.status, DidStatus::Deactivated);
+		assert_eq!(anchored.digest, BlakeTwo256::hash(DOCUMENT_00));
+
+		assert_noop!(
+			Pallet::<Test>::deactivate_did(DoubleOrigin(ACCOUNT_00, DID_00).into()),
+			Error::<Test>::Deactivated
+		);
+		assert_noop!(
+			Pallet::<Test>::update_did(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				document(DOCUMENT_01),
+			),
+			Error::<Test>::Deactivated
+		);
+		assert_noop!(
+			Pallet::<Test>::create_did(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				document(DOCUMENT_01),
+			),
+			Error::<Test>::AlreadyExists
+		);
+	})
+}
+
+#[test]
+fn genesis_documents_are_resolvable() {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	GenesisConfig::<Test> {
+		documents: vec![(DID_00, ACCOUNT_00, DOCUMENT_00.to_vec())],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		let anchored = Pallet::<Test>::resolve_did(&DID_00).unwrap();
+		assert_eq!(anchored.digest, BlakeTwo256::hash(DOCUMENT_00));
+		assert_eq!(anchored.status, DidStatus::Active);
+		assert_eq!(anchored.created_at, 0);
+	})
+}
@@ -0,0 +1,112 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_did_document`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_did_document
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/did-document/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_did_document`.
+pub trait WeightInfo {
+	fn create_did(l: u32, ) -> Weight;
+	fn update_did(l: u32, ) -> Weight;
+	fn deactivate_did() -> Weight;
+}
+
+/// Weights for `pallet_cord_did_document` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `DidDocument::Documents` (r:1 w:1)
+	/// Proof: `DidDocument::Documents` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 5120]`.
+	fn create_did(l: u32, ) -> Weight {
+		Weight::from_parts(12_083_511, 3586)
+			.saturating_add(Weight::from_parts(1_311, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidDocument::Documents` (r:1 w:1)
+	/// Proof: `DidDocument::Documents` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 5120]`.
+	fn update_did(l: u32, ) -> Weight {
+		Weight::from_parts(13_472_094, 3586)
+			.saturating_add(Weight::from_parts(1_308, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidDocument::Documents` (r:1 w:1)
+	/// Proof: `DidDocument::Documents` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn deactivate_did() -> Weight {
+		Weight::from_parts(12_370_000, 3586)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `DidDocument::Documents` (r:1 w:1)
+	/// Proof: `DidDocument::Documents` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 5120]`.
+	fn create_did(l: u32, ) -> Weight {
+		Weight::from_parts(12_083_511, 3586)
+			.saturating_add(Weight::from_parts(1_311, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidDocument::Documents` (r:1 w:1)
+	/// Proof: `DidDocument::Documents` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 5120]`.
+	fn update_did(l: u32, ) -> Weight {
+		Weight::from_parts(13_472_094, 3586)
+			.saturating_add(Weight::from_parts(1_308, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidDocument::Documents` (r:1 w:1)
+	/// Proof: `DidDocument::Documents` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn deactivate_did() -> Weight {
+		Weight::from_parts(12_370_000, 3586)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -102,7 +102,7 @@ pub mod pallet {
 			+ TryFrom<Vec<u8>, Error = Error<Self>>
 			+ MaxEncodedLen;
 		/// The type of a name owner.
-		type DidNameOwner: Parameter + MaxEncodedLen;
+		type DidNameOwner: Parameter + MaxEncodedLen + MaybeSerializeDeserialize;
 		/// The min encoded length of a name.
 		#[pallet::constant]
 		type MinNameLength: Get<u32>;
@@ -166,6 +166,27 @@ pub mod pallet {
 		InvalidFormat,
 	}
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Names registered at genesis, as `(owner, name)` pairs.
+		pub names: Vec<(DidNameOwnerOf<T>, Vec<u8>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (owner, name) in &self.names {
+				let name = DidNameOf::<T>::try_from(name.clone())
+					.expect("Genesis DID names must be valid");
+				assert!(!Names::<T>::contains_key(owner), "Genesis DID name owners must be unique");
+				assert!(!Owner::<T>::contains_key(&name), "Genesis DID names must be unique");
+
+				Pallet::<T>::register_name(name, owner.clone());
+			}
+		}
+	}
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
@@ -247,6 +268,16 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The owner of the provided name, if registered.
+		pub fn lookup_did(name: &DidNameOf<T>) -> Option<DidNameOwnerOf<T>> {
+			Owner::<T>::get(name).map(|ownership| ownership.owner)
+		}
+
+		/// The name registered by the provided owner, if any.
+		pub fn lookup_name(owner: &DidNameOwnerOf<T>) -> Option<DidNameOf<T>> {
+			Names::<T>::get(owner)
+		}
+
 		/// Verify that the claiming preconditions are verified. Specifically:
 		/// - The name input data can be decoded as a valid name
 		/// - The name does not already exist
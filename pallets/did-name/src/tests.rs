@@ -25,7 +25,7 @@ use frame_support::{assert_noop, assert_ok, BoundedVec};
 // Owner, Pallet};
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
 use frame_system::RawOrigin;
-use sp_runtime::{AccountId32, DispatchError};
+use sp_runtime::{AccountId32, BuildStorage, DispatchError};
 
 pub(crate) const ACCOUNT_00: TestDidNamePayer = AccountId32::new([1u8; 32]);
 pub(crate) const ACCOUNT_01: TestDidNamePayer = AccountId32::new([2u8; 32]);
@@ -269,3 +269,40 @@ fn unbanning_unauthorized_origin() {
 		);
 	})
 }
+
+// #############################################################################
+// Genesis and lookups
+
+#[test]
+fn genesis_names_are_registered() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	GenesisConfig::<Test> { names: vec![(DID_00, DID_NAME_00_INPUT.to_vec())] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(Pallet::<Test>::lookup_did(&did_name_00), Some(DID_00));
+		assert_eq!(Pallet::<Test>::lookup_name(&DID_00), Some(did_name_00));
+		assert_eq!(Pallet::<Test>::lookup_name(&DID_01), None);
+	})
+}
+
+#[test]
+fn lookups_follow_registration() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_eq!(Pallet::<Test>::lookup_did(&did_name_00), None);
+
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_eq!(Pallet::<Test>::lookup_did(&did_name_00), Some(DID_00));
+		assert_eq!(Pallet::<Test>::lookup_name(&DID_00), Some(did_name_00.clone()));
+
+		assert_ok!(Pallet::<Test>::release(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+		assert_eq!(Pallet::<Test>::lookup_did(&did_name_00), None);
+		assert_eq!(Pallet::<Test>::lookup_name(&DID_00), None);
+	})
+}
@@ -0,0 +1,96 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// File a dispute of `holder` against an active credential they hold, and
+/// return the dispute with the issuer of the credential.
+fn filed_dispute<T: Config>(holder: &T::AccountId) -> (DisputeIdOf<T>, T::AccountId) {
+	let credential = T::Credentials::active_credential(holder);
+	let (_, issuer) =
+		T::Credentials::active_parties(&credential).expect("the credential is active");
+	Pallet::<T>::file_dispute(
+		RawOrigin::Signed(holder.clone()).into(),
+		credential,
+		T::Hashing::hash(b"reason"),
+	)
+	.expect("Should file the dispute");
+	let dispute_id = OpenDisputes::<T>::get(credential).expect("the dispute is open");
+	(dispute_id, issuer)
+}
+
+benchmarks! {
+	file_dispute {
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let credential = T::Credentials::active_credential(&holder);
+		let reason = T::Hashing::hash(b"reason");
+	}: _(RawOrigin::Signed(holder.clone()), credential, reason)
+	verify {
+		let dispute_id = OpenDisputes::<T>::get(credential).expect("the dispute is open");
+		assert_last_event::<T>(Event::DisputeFiled {
+			dispute_id,
+			credential_id: credential,
+			challenger: holder,
+		}.into());
+	}
+
+	respond_to_dispute {
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let (dispute_id, issuer) = filed_dispute::<T>(&holder);
+		let response = T::Hashing::hash(b"response");
+	}: _(RawOrigin::Signed(issuer.clone()), dispute_id, response)
+	verify {
+		assert_last_event::<T>(Event::DisputeResponded { dispute_id, issuer }.into());
+	}
+
+	resolve_dispute {
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let (dispute_id, issuer) = filed_dispute::<T>(&holder);
+		Pallet::<T>::respond_to_dispute(
+			RawOrigin::Signed(issuer).into(),
+			dispute_id,
+			T::Hashing::hash(b"response"),
+		)
+		.expect("Should respond to the dispute");
+		let origin =
+			T::ResolveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, dispute_id, DisputeOutcome::Upheld)
+	verify {
+		assert_last_event::<T>(Event::DisputeResolved {
+			dispute_id,
+			outcome: DisputeOutcome::Upheld,
+		}.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,246 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Dispute Pallet
+//!
+//! The Dispute Pallet lets the holder of a credential challenge the
+//! attestation of its issuer. The holder files a dispute with the digest of
+//! their reasons, and the issuer of the credential can respond with the
+//! digest of a counter statement. The documents behind both digests are kept
+//! off chain.
+//!
+//! A dispute is resolved by `ResolveOrigin`, a council majority in the CORD
+//! runtime. Upholding a dispute revokes the credential, dismissing it keeps
+//! the credential. A credential has at most one unresolved dispute at a time.
+//!
+//! ### Storage
+//!
+//! - `Disputes`: The filed disputes, kept after their resolution.
+//! - `OpenDisputes`: The unresolved dispute of every credential.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `file_dispute` - Dispute a credential held by the caller.
+//! - `respond_to_dispute` - Respond to a dispute as the credential issuer.
+//! - `resolve_dispute` - Uphold or dismiss a dispute.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod traits;
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+pub use traits::DisputedCredentials;
+pub use types::{DisputeEntry, DisputeOutcome, DisputeStatus};
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Hash;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Identifier of a credential.
+	pub type CredentialIdOf<T> = <T as frame_system::Config>::Hash;
+	/// Identifier of a dispute.
+	pub type DisputeIdOf<T> = <T as frame_system::Config>::Hash;
+	/// Type of a dispute.
+	pub type DisputeEntryOf<T> = DisputeEntry<
+		<T as frame_system::Config>::AccountId,
+		CredentialIdOf<T>,
+		<T as frame_system::Config>::Hash,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The credentials disputes are filed against.
+		type Credentials: DisputedCredentials<CredentialIdOf<Self>, Self::AccountId>;
+		/// The origin allowed to resolve disputes.
+		type ResolveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// filed disputes by identifier
+	#[pallet::storage]
+	#[pallet::getter(fn disputes)]
+	pub type Disputes<T: Config> =
+		StorageMap<_, Blake2_128Concat, DisputeIdOf<T>, DisputeEntryOf<T>, OptionQuery>;
+
+	/// unresolved dispute of a credential
+	#[pallet::storage]
+	#[pallet::getter(fn open_disputes)]
+	pub type OpenDisputes<T: Config> =
+		StorageMap<_, Blake2_128Concat, CredentialIdOf<T>, DisputeIdOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A dispute was filed against a credential.
+		DisputeFiled {
+			dispute_id: DisputeIdOf<T>,
+			credential_id: CredentialIdOf<T>,
+			challenger: T::AccountId,
+		},
+		/// The issuer of a disputed credential responded.
+		DisputeResponded { dispute_id: DisputeIdOf<T>, issuer: T::AccountId },
+		/// A dispute was resolved.
+		DisputeResolved { dispute_id: DisputeIdOf<T>, outcome: DisputeOutcome },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No dispute has the identifier.
+		DisputeNotFound,
+		/// The credential is not active.
+		CredentialNotActive,
+		/// The caller does not hold the credential.
+		NotCredentialHolder,
+		/// The credential already has an unresolved dispute.
+		DisputeAlreadyOpen,
+		/// The caller did not issue the disputed credential.
+		NotCredentialIssuer,
+		/// The issuer already responded to the dispute.
+		AlreadyResponded,
+		/// The dispute was already resolved.
+		DisputeAlreadyResolved,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dispute `credential_id`, an active credential held by the caller.
+		/// `reason_digest` is the digest of the reasons of the caller.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::file_dispute())]
+		pub fn file_dispute(
+			origin: OriginFor<T>,
+			credential_id: CredentialIdOf<T>,
+			reason_digest: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!OpenDisputes::<T>::contains_key(credential_id),
+				Error::<T>::DisputeAlreadyOpen
+			);
+			let (holder, issuer) = T::Credentials::active_parties(&credential_id)
+				.ok_or(Error::<T>::CredentialNotActive)?;
+			ensure!(holder == who, Error::<T>::NotCredentialHolder);
+
+			let block = frame_system::Pallet::<T>::block_number();
+			let dispute_id = T::Hashing::hash_of(&(&credential_id, &who, block));
+
+			Disputes::<T>::insert(
+				dispute_id,
+				DisputeEntry {
+					challenger: who.clone(),
+					issuer,
+					credential_id,
+					reason_digest,
+					response_digest: None,
+					block,
+					status: DisputeStatus::Open,
+				},
+			);
+			OpenDisputes::<T>::insert(credential_id, dispute_id);
+
+			Self::deposit_event(Event::DisputeFiled { dispute_id, credential_id, challenger: who });
+			Ok(())
+		}
+
+		/// Respond to dispute `dispute_id` with `response_digest`, the digest
+		/// of a counter statement. Only the issuer of the disputed credential
+		/// can respond, once, before the dispute is resolved.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::respond_to_dispute())]
+		pub fn respond_to_dispute(
+			origin: OriginFor<T>,
+			dispute_id: DisputeIdOf<T>,
+			response_digest: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Disputes::<T>::try_mutate(dispute_id, |dispute| {
+				let dispute = dispute.as_mut().ok_or(Error::<T>::DisputeNotFound)?;
+				ensure!(dispute.issuer == who, Error::<T>::NotCredentialIssuer);
+				ensure!(dispute.status != DisputeStatus::Responded, Error::<T>::AlreadyResponded);
+				ensure!(dispute.status == DisputeStatus::Open, Error::<T>::DisputeAlreadyResolved);
+				dispute.response_digest = Some(response_digest);
+				dispute.status = DisputeStatus::Responded;
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::DisputeResponded { dispute_id, issuer: who });
+			Ok(())
+		}
+
+		/// Resolve dispute `dispute_id`. Upholding it revokes the disputed
+		/// credential, dismissing it keeps the credential. The issuer does
+		/// not need to have responded.
+		///
+		/// The dispatch origin must be `ResolveOrigin`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			dispute_id: DisputeIdOf<T>,
+			outcome: DisputeOutcome,
+		) -> DispatchResult {
+			T::ResolveOrigin::ensure_origin(origin)?;
+			let mut dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(
+				matches!(dispute.status, DisputeStatus::Open | DisputeStatus::Responded),
+				Error::<T>::DisputeAlreadyResolved
+			);
+
+			dispute.status = match outcome {
+				DisputeOutcome::Upheld => {
+					T::Credentials::revoke(&dispute.credential_id)?;
+					DisputeStatus::Upheld
+				},
+				DisputeOutcome::Dismissed => DisputeStatus::Dismissed,
+			};
+			OpenDisputes::<T>::remove(dispute.credential_id);
+			Disputes::<T>::insert(dispute_id, dispute);
+
+			Self::deposit_event(Event::DisputeResolved { dispute_id, outcome });
+			Ok(())
+		}
+	}
+}
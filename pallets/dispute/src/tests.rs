@@ -0,0 +1,170 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, DisputeEntry, DisputeOutcome, DisputeStatus, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError,
+};
+
+const CREDENTIAL: H256 = H256::repeat_byte(1);
+const REASON: H256 = H256::repeat_byte(2);
+const RESPONSE: H256 = H256::repeat_byte(3);
+
+/// Files a dispute of Alice against the credential Bob issued to her.
+fn file() -> H256 {
+	ActiveCredentials::mutate(|credentials| credentials.insert(CREDENTIAL, (ALICE, BOB)));
+	assert_ok!(Dispute::file_dispute(RuntimeOrigin::signed(ALICE), CREDENTIAL, REASON));
+	BlakeTwo256::hash_of(&(&CREDENTIAL, &ALICE, 1u64))
+}
+
+#[test]
+fn file_dispute_works() {
+	new_test_ext().execute_with(|| {
+		let dispute_id = file();
+
+		assert_eq!(
+			Dispute::disputes(dispute_id),
+			Some(DisputeEntry {
+				challenger: ALICE,
+				issuer: BOB,
+				credential_id: CREDENTIAL,
+				reason_digest: REASON,
+				response_digest: None,
+				block: 1,
+				status: DisputeStatus::Open,
+			})
+		);
+		assert_eq!(Dispute::open_disputes(CREDENTIAL), Some(dispute_id));
+		System::assert_last_event(
+			Event::DisputeFiled { dispute_id, credential_id: CREDENTIAL, challenger: ALICE }.into(),
+		);
+	});
+}
+
+#[test]
+fn file_dispute_requires_an_active_credential_of_the_caller() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Dispute::file_dispute(RuntimeOrigin::signed(ALICE), CREDENTIAL, REASON),
+			Error::<Test>::CredentialNotActive
+		);
+
+		ActiveCredentials::mutate(|credentials| credentials.insert(CREDENTIAL, (ALICE, BOB)));
+		assert_noop!(
+			Dispute::file_dispute(RuntimeOrigin::signed(BOB), CREDENTIAL, REASON),
+			Error::<Test>::NotCredentialHolder
+		);
+	});
+}
+
+#[test]
+fn file_dispute_refuses_a_second_open_dispute() {
+	new_test_ext().execute_with(|| {
+		file();
+		System::set_block_number(2);
+
+		assert_noop!(
+			Dispute::file_dispute(RuntimeOrigin::signed(ALICE), CREDENTIAL, REASON),
+			Error::<Test>::DisputeAlreadyOpen
+		);
+	});
+}
+
+#[test]
+fn respond_to_dispute_works() {
+	new_test_ext().execute_with(|| {
+		let dispute_id = file();
+
+		assert_noop!(
+			Dispute::respond_to_dispute(RuntimeOrigin::signed(ALICE), dispute_id, RESPONSE),
+			Error::<Test>::NotCredentialIssuer
+		);
+		assert_ok!(Dispute::respond_to_dispute(RuntimeOrigin::signed(BOB), dispute_id, RESPONSE));
+
+		let dispute = Dispute::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.response_digest, Some(RESPONSE));
+		assert_eq!(dispute.status, DisputeStatus::Responded);
+		System::assert_last_event(Event::DisputeResponded { dispute_id, issuer: BOB }.into());
+
+		assert_noop!(
+			Dispute::respond_to_dispute(RuntimeOrigin::signed(BOB), dispute_id, RESPONSE),
+			Error::<Test>::AlreadyResponded
+		);
+	});
+}
+
+#[test]
+fn upholding_a_dispute_revokes_the_credential() {
+	new_test_ext().execute_with(|| {
+		let dispute_id = file();
+		assert_ok!(Dispute::respond_to_dispute(RuntimeOrigin::signed(BOB), dispute_id, RESPONSE));
+
+		assert_noop!(
+			Dispute::resolve_dispute(
+				RuntimeOrigin::signed(ALICE),
+				dispute_id,
+				DisputeOutcome::Upheld
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Dispute::resolve_dispute(
+			RuntimeOrigin::root(),
+			dispute_id,
+			DisputeOutcome::Upheld
+		));
+
+		assert_eq!(Dispute::disputes(dispute_id).unwrap().status, DisputeStatus::Upheld);
+		assert_eq!(Dispute::open_disputes(CREDENTIAL), None);
+		assert!(ActiveCredentials::get().get(&CREDENTIAL).is_none());
+		System::assert_last_event(
+			Event::DisputeResolved { dispute_id, outcome: DisputeOutcome::Upheld }.into(),
+		);
+
+		assert_noop!(
+			Dispute::resolve_dispute(RuntimeOrigin::root(), dispute_id, DisputeOutcome::Dismissed),
+			Error::<Test>::DisputeAlreadyResolved
+		);
+	});
+}
+
+#[test]
+fn dismissing_a_dispute_keeps_the_credential() {
+	new_test_ext().execute_with(|| {
+		let dispute_id = file();
+
+		assert_ok!(Dispute::resolve_dispute(
+			RuntimeOrigin::root(),
+			dispute_id,
+			DisputeOutcome::Dismissed
+		));
+
+		assert_eq!(Dispute::disputes(dispute_id).unwrap().status, DisputeStatus::Dismissed);
+		assert!(ActiveCredentials::get().get(&CREDENTIAL).is_some());
+		assert_noop!(
+			Dispute::respond_to_dispute(RuntimeOrigin::signed(BOB), dispute_id, RESPONSE),
+			Error::<Test>::DisputeAlreadyResolved
+		);
+
+		// The credential can be disputed again.
+		System::set_block_number(2);
+		assert_ok!(Dispute::file_dispute(RuntimeOrigin::signed(ALICE), CREDENTIAL, REASON));
+	});
+}
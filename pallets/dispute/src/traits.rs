@@ -0,0 +1,32 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use sp_runtime::DispatchResult;
+
+/// Lookup and revocation of the credentials disputes are filed against.
+pub trait DisputedCredentials<CredentialId, AccountId> {
+	/// The holder and the issuer of `credential`, if it is active.
+	fn active_parties(credential: &CredentialId) -> Option<(AccountId, AccountId)>;
+
+	/// Revoke `credential` after a dispute against it was upheld.
+	fn revoke(credential: &CredentialId) -> DispatchResult;
+
+	/// An active credential held by `holder`, for benchmarks.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn active_credential(holder: &AccountId) -> CredentialId;
+}
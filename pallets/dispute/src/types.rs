@@ -0,0 +1,65 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Progress of a dispute.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum DisputeStatus {
+	/// Filed, awaiting a response of the issuer.
+	Open,
+	/// The issuer responded, awaiting a resolution.
+	Responded,
+	/// Resolved in favour of the challenger, the credential was revoked.
+	Upheld,
+	/// Resolved in favour of the issuer, the credential was kept.
+	Dismissed,
+}
+
+/// Resolution of a dispute.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum DisputeOutcome {
+	/// Revoke the disputed credential.
+	Upheld,
+	/// Keep the disputed credential.
+	Dismissed,
+}
+
+/// A dispute filed by the holder of a credential against its issuer.
+///
+/// ## Fields
+///
+/// - `challenger`: The holder who filed the dispute.
+/// - `issuer`: The issuer of the disputed credential.
+/// - `credential_id`: The disputed credential.
+/// - `reason_digest`: Digest of the reasons of the challenger.
+/// - `response_digest`: Digest of the response of the issuer, if any.
+/// - `block`: The block the dispute was filed in.
+/// - `status`: The progress of the dispute.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DisputeEntry<AccountId, CredentialId, Digest, BlockNumber> {
+	pub challenger: AccountId,
+	pub issuer: AccountId,
+	pub credential_id: CredentialId,
+	pub reason_digest: Digest,
+	pub response_digest: Option<Digest>,
+	pub block: BlockNumber,
+	pub status: DisputeStatus,
+}
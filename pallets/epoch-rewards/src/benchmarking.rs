@@ -0,0 +1,82 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+use pallet_authorship::EventHandler;
+
+const SEED: u32 = 0;
+const MAX_VALIDATORS: u32 = 1_000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	note_author {
+		let author: T::AccountId = account("author", 0, SEED);
+	}: {
+		<Pallet<T> as EventHandler<_, _>>::note_author(author.clone());
+	}
+	verify {
+		assert_eq!(EpochPoints::<T>::get(&author), 1);
+	}
+
+	// Every validator earns points and is paid into a new account.
+	on_epoch_end {
+		let v in 1 .. MAX_VALIDATORS;
+
+		let validators =
+			(0..v).map(|i| account("validator", i, SEED)).collect::<Vec<T::AccountId>>();
+		for who in &validators {
+			EpochPoints::<T>::insert(who, 1);
+		}
+		let pool = T::Currency::minimum_balance().saturating_mul((2 * v).into());
+		EpochRewardPool::<T>::put(pool);
+	}: {
+		Pallet::<T>::on_epoch_end();
+	}
+	verify {
+		assert!(validators.iter().all(|who| !T::Currency::free_balance(who).is_zero()));
+	}
+
+	claim_reward {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::Currency::minimum_balance();
+		UnclaimedRewards::<T>::insert(&caller, amount);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_last_event::<T>(Event::RewardClaimed { who: caller, amount }.into());
+	}
+
+	set_epoch_reward_pool {
+		let pool = T::Currency::minimum_balance();
+	}: _(RawOrigin::Root, pool)
+	verify {
+		assert_last_event::<T>(Event::EpochRewardPoolSet { pool }.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
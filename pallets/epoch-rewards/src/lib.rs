@@ -0,0 +1,237 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+//! # Epoch Rewards Pallet
+//!
+//! The Epoch Rewards Pallet pays the validators at the end of every BABE
+//! epoch in proportion to the blocks they authored during the epoch.
+//!
+//! Every authored block earns its author one reward point. At the end of the
+//! epoch the `EpochRewardPool` is split between the validators by their share
+//! of the epoch points and deposited into their accounts. A reward that can
+//! not be deposited, as it is below the existential deposit of an account
+//! that does not exist, is kept as an unclaimed reward the validator pulls
+//! with `claim_reward`.
+//!
+//! ### Storage
+//!
+//! - `EpochRewardPool`: The amount paid out every epoch. It starts at zero and is set by
+//!   `PoolOrigin`.
+//! - `EpochPoints`: Reward points of every validator in the current epoch.
+//! - `UnclaimedRewards`: Rewards not yet deposited into the validator accounts.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `claim_reward` - Deposit the unclaimed rewards of the caller.
+//! - `set_epoch_reward_pool` - Set the amount paid out every epoch.
+//!
+//! ### Integration
+//!
+//! The pallet is a `pallet_authorship::EventHandler`, counting the reward
+//! points, and exposes `on_epoch_end` to be called at the epoch change.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use frame_support::{
+	dispatch::DispatchClass,
+	traits::{Currency, Imbalance},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill,
+};
+use sp_std::prelude::*;
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency the rewards are paid in.
+		type Currency: Currency<Self::AccountId>;
+		/// Origin allowed to set the epoch reward pool.
+		type PoolOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// amount paid out to the validators every epoch
+	#[pallet::storage]
+	#[pallet::getter(fn epoch_reward_pool)]
+	pub type EpochRewardPool<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// reward points of a validator in the current epoch
+	#[pallet::storage]
+	#[pallet::getter(fn epoch_points)]
+	pub type EpochPoints<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// rewards not yet deposited into a validator account
+	#[pallet::storage]
+	#[pallet::getter(fn unclaimed_rewards)]
+	pub type UnclaimedRewards<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub epoch_reward_pool: BalanceOf<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			EpochRewardPool::<T>::put(self.epoch_reward_pool);
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The epoch reward pool was updated.
+		EpochRewardPoolSet { pool: BalanceOf<T> },
+		/// An epoch reward was deposited into a validator account.
+		RewardPaid { who: T::AccountId, amount: BalanceOf<T> },
+		/// An epoch reward could not be deposited and awaits a claim.
+		RewardDeferred { who: T::AccountId, amount: BalanceOf<T> },
+		/// The unclaimed rewards of a validator were deposited.
+		RewardClaimed { who: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The pool is already set to the given value
+		EpochRewardPoolUnchanged,
+		/// The caller has no unclaimed rewards
+		NoUnclaimedRewards,
+		/// The unclaimed rewards are below the existential deposit
+		RewardBelowExistentialDeposit,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Deposit the unclaimed epoch rewards of the caller into its
+		/// account.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_reward())]
+		pub fn claim_reward(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amount = UnclaimedRewards::<T>::take(&who);
+			ensure!(!amount.is_zero(), Error::<T>::NoUnclaimedRewards);
+			let deposited = T::Currency::deposit_creating(&who, amount);
+			ensure!(!deposited.peek().is_zero(), Error::<T>::RewardBelowExistentialDeposit);
+
+			Self::deposit_event(Event::RewardClaimed { who, amount });
+			Ok(())
+		}
+
+		/// Set the amount paid out to the validators at the end of every
+		/// epoch.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_epoch_reward_pool())]
+		pub fn set_epoch_reward_pool(origin: OriginFor<T>, pool: BalanceOf<T>) -> DispatchResult {
+			T::PoolOrigin::ensure_origin(origin)?;
+
+			ensure!(EpochRewardPool::<T>::get() != pool, Error::<T>::EpochRewardPoolUnchanged);
+			EpochRewardPool::<T>::put(pool);
+
+			Self::deposit_event(Event::EpochRewardPoolSet { pool });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Split the epoch reward pool between the validators by their share of
+	/// the epoch points, and reset the points for the next epoch.
+	pub fn on_epoch_end() {
+		let points = EpochPoints::<T>::drain().collect::<Vec<_>>();
+		let total = points.iter().fold(0u32, |total, (_, points)| total.saturating_add(*points));
+		let pool = EpochRewardPool::<T>::get();
+
+		if !total.is_zero() && !pool.is_zero() {
+			for (who, points) in &points {
+				let amount = Perbill::from_rational(*points, total).mul_floor(pool);
+				Self::reward(who, amount);
+			}
+		}
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::on_epoch_end(points.len() as u32),
+			DispatchClass::Mandatory,
+		);
+	}
+
+	/// Deposit `amount` into the account of `who`, or keep it as an
+	/// unclaimed reward if the deposit fails.
+	fn reward(who: &T::AccountId, amount: BalanceOf<T>) {
+		if amount.is_zero() {
+			return
+		}
+
+		if T::Currency::deposit_creating(who, amount).peek().is_zero() {
+			UnclaimedRewards::<T>::mutate(who, |unclaimed| {
+				*unclaimed = unclaimed.saturating_add(amount)
+			});
+			Self::deposit_event(Event::RewardDeferred { who: who.clone(), amount });
+		} else {
+			Self::deposit_event(Event::RewardPaid { who: who.clone(), amount });
+		}
+	}
+}
+
+impl<T: Config> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+	fn note_author(author: T::AccountId) {
+		EpochPoints::<T>::mutate(&author, |points| *points = points.saturating_add(1));
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::note_author(),
+			DispatchClass::Mandatory,
+		);
+	}
+}
@@ -0,0 +1,107 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use pallet_authorship::EventHandler;
+use sp_runtime::traits::BadOrigin;
+
+fn author_blocks(who: AccountId, blocks: u32) {
+	for _ in 0..blocks {
+		<EpochRewards as EventHandler<_, _>>::note_author(who);
+	}
+}
+
+#[test]
+fn note_author_counts_points() {
+	new_test_ext().execute_with(|| {
+		author_blocks(1, 3);
+		author_blocks(2, 1);
+
+		assert_eq!(EpochRewards::epoch_points(1), 3);
+		assert_eq!(EpochRewards::epoch_points(2), 1);
+	});
+}
+
+#[test]
+fn epoch_rewards_are_split_by_points() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EpochRewards::set_epoch_reward_pool(RuntimeOrigin::root(), 100));
+		author_blocks(1, 3);
+		author_blocks(2, 1);
+
+		EpochRewards::on_epoch_end();
+
+		assert_eq!(Balances::free_balance(1), 1_075);
+		assert_eq!(Balances::free_balance(2), 1_025);
+		System::assert_last_event(Event::RewardPaid { who: 2, amount: 25 }.into());
+		// Points are reset for the next epoch.
+		assert_eq!(EpochRewards::epoch_points(1), 0);
+		assert_eq!(EpochRewards::epoch_points(2), 0);
+	});
+}
+
+#[test]
+fn rewards_below_existential_deposit_are_claimed_later() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EpochRewards::set_epoch_reward_pool(RuntimeOrigin::root(), 50));
+		author_blocks(1, 9);
+		author_blocks(3, 1);
+
+		EpochRewards::on_epoch_end();
+		assert_eq!(Balances::free_balance(1), 1_045);
+		assert_eq!(EpochRewards::unclaimed_rewards(3), 5);
+		System::assert_has_event(Event::RewardDeferred { who: 3, amount: 5 }.into());
+		assert_noop!(
+			EpochRewards::claim_reward(RuntimeOrigin::signed(3)),
+			Error::<Test>::RewardBelowExistentialDeposit
+		);
+
+		author_blocks(1, 9);
+		author_blocks(3, 1);
+		EpochRewards::on_epoch_end();
+		assert_eq!(EpochRewards::unclaimed_rewards(3), EXISTENTIAL_DEPOSIT);
+
+		assert_ok!(EpochRewards::claim_reward(RuntimeOrigin::signed(3)));
+		assert_eq!(Balances::free_balance(3), EXISTENTIAL_DEPOSIT);
+		assert_eq!(EpochRewards::unclaimed_rewards(3), 0);
+		System::assert_last_event(
+			Event::RewardClaimed { who: 3, amount: EXISTENTIAL_DEPOSIT }.into(),
+		);
+
+		assert_noop!(
+			EpochRewards::claim_reward(RuntimeOrigin::signed(3)),
+			Error::<Test>::NoUnclaimedRewards
+		);
+	});
+}
+
+#[test]
+fn set_epoch_reward_pool_requires_pool_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(EpochRewards::set_epoch_reward_pool(RuntimeOrigin::signed(1), 100), BadOrigin);
+		assert_noop!(
+			EpochRewards::set_epoch_reward_pool(RuntimeOrigin::root(), 0),
+			Error::<Test>::EpochRewardPoolUnchanged
+		);
+
+		assert_ok!(EpochRewards::set_epoch_reward_pool(RuntimeOrigin::root(), 100));
+		assert_eq!(EpochRewards::epoch_reward_pool(), 100);
+		System::assert_last_event(Event::EpochRewardPoolSet { pool: 100 }.into());
+	});
+}
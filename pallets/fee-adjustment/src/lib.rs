@@ -0,0 +1,161 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Fee Adjustment Pallet
+//!
+//! The Fee Adjustment Pallet holds the governance-controlled divisor of the
+//! weight part of the transaction fee.
+//!
+//! The runtime's weight to fee conversion stays a pure function of the
+//! weight. The adjustment reaches the fee through the fee multiplier of the
+//! transaction payment pallet instead: used as its `FeeMultiplierUpdate`,
+//! `AdjustedFeeMultiplier` sets the multiplier of the next block to
+//! `1 / Adjustment`. A new adjustment therefore takes effect from the block
+//! after the one it was set in.
+//!
+//! ### Storage
+//!
+//! - `Adjustment`: The divisor of the weight part of the fee.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `set_fee_adjustment` - Change the divisor of the weight part of the fee.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use core::marker::PhantomData;
+pub use pallet::*;
+use pallet_transaction_payment::{Multiplier, MultiplierUpdate};
+use sp_runtime::{
+	traits::{Bounded, CheckedDiv, Convert, One, Zero},
+	Perquintill,
+};
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin allowed to change the fee adjustment.
+		type AdjustmentOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::type_value]
+	pub fn DefaultAdjustment() -> Multiplier {
+		Multiplier::one()
+	}
+
+	/// divisor of the weight part of the transaction fee
+	#[pallet::storage]
+	#[pallet::getter(fn adjustment)]
+	pub type Adjustment<T> = StorageValue<_, Multiplier, ValueQuery, DefaultAdjustment>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The fee adjustment was changed.
+		FeeAdjustmentSet { adjustment: Multiplier },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The fee adjustment must be above zero
+		ZeroFeeAdjustment,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the divisor of the weight part of the transaction fee.
+		/// Raising it lowers the fee of heavy extrinsics.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_adjustment())]
+		pub fn set_fee_adjustment(origin: OriginFor<T>, adjustment: Multiplier) -> DispatchResult {
+			T::AdjustmentOrigin::ensure_origin(origin)?;
+			ensure!(!adjustment.is_zero(), Error::<T>::ZeroFeeAdjustment);
+
+			Adjustment::<T>::put(adjustment);
+
+			Self::deposit_event(Event::FeeAdjustmentSet { adjustment });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The fee multiplier of the current adjustment: `1 / Adjustment`,
+	/// saturating at the maximum multiplier.
+	pub fn fee_multiplier() -> Multiplier {
+		Multiplier::one()
+			.checked_div(&Adjustment::<T>::get())
+			.unwrap_or_else(Multiplier::max_value)
+	}
+}
+
+/// A `FeeMultiplierUpdate` for the transaction payment pallet that replaces
+/// the multiplier with `1 / Adjustment` at the end of every block, ignoring
+/// the block's congestion.
+pub struct AdjustedFeeMultiplier<T>(PhantomData<T>);
+
+impl<T: Config> Convert<Multiplier, Multiplier> for AdjustedFeeMultiplier<T> {
+	fn convert(_previous: Multiplier) -> Multiplier {
+		Pallet::<T>::fee_multiplier()
+	}
+}
+
+impl<T: Config> MultiplierUpdate for AdjustedFeeMultiplier<T> {
+	fn min() -> Multiplier {
+		Multiplier::zero()
+	}
+	fn max() -> Multiplier {
+		Multiplier::max_value()
+	}
+	// The multiplier does not track a block fullness target.
+	fn target() -> Perquintill {
+		Default::default()
+	}
+	fn variability() -> Multiplier {
+		Default::default()
+	}
+}
@@ -0,0 +1,57 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::BadOrigin, FixedPointNumber};
+
+#[test]
+fn set_fee_adjustment_works() {
+	new_test_ext().execute_with(|| {
+		let adjustment = Multiplier::saturating_from_integer(4);
+		assert_eq!(FeeAdjustment::adjustment(), Multiplier::one());
+
+		assert_noop!(
+			FeeAdjustment::set_fee_adjustment(RuntimeOrigin::signed(1), adjustment),
+			BadOrigin
+		);
+		assert_noop!(
+			FeeAdjustment::set_fee_adjustment(RuntimeOrigin::root(), Multiplier::zero()),
+			Error::<Test>::ZeroFeeAdjustment
+		);
+
+		assert_ok!(FeeAdjustment::set_fee_adjustment(RuntimeOrigin::root(), adjustment));
+		assert_eq!(FeeAdjustment::adjustment(), adjustment);
+		System::assert_last_event(Event::FeeAdjustmentSet { adjustment }.into());
+	});
+}
+
+#[test]
+fn fee_multiplier_is_the_inverse_of_the_adjustment() {
+	new_test_ext().execute_with(|| {
+		let next = |previous| AdjustedFeeMultiplier::<Test>::convert(previous);
+		assert_eq!(next(Multiplier::saturating_from_integer(7)), Multiplier::one());
+
+		assert_ok!(FeeAdjustment::set_fee_adjustment(
+			RuntimeOrigin::root(),
+			Multiplier::saturating_from_integer(4)
+		));
+		assert_eq!(next(Multiplier::one()), Multiplier::from_rational(1, 4));
+	});
+}
@@ -0,0 +1,73 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::benchmarks;
+use frame_support::{dispatch::Pays, weights::Weight};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+use sp_std::vec;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T: Send + Sync,
+			<T as frame_system::Config>::RuntimeCall:
+				Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	}
+
+	set_fee_rebate_percent {
+		let rebate = Permill::from_percent(10);
+	}: _(RawOrigin::Root, rebate)
+	verify {
+		assert_last_event::<T>(Event::FeeRebatePercentSet { rebate }.into());
+	}
+
+	// An author paying its own fee is rebated from the rebate account.
+	rebate_fee {
+		let author = T::BenchmarkHelper::author();
+		let funds = T::Currency::minimum_balance().saturating_mul(1_000u32.into());
+		T::Currency::set_balance(&author, funds);
+		T::Currency::set_balance(&T::RebateAccount::get(), funds);
+		FeeRebatePercent::<T>::put(Permill::from_percent(100));
+
+		let call: <T as frame_system::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let info = DispatchInfo { weight: Weight::from_parts(1_000_000, 0), ..Default::default() };
+		let post_info = PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes };
+	}: {
+		let pre = RebateFee::<T>::new().pre_dispatch(&author, &call, &info, 100).ok();
+		assert!(RebateFee::<T>::post_dispatch(pre, &info, &post_info, 100, &Ok(())).is_ok());
+	}
+	verify {
+		assert!(T::Currency::balance(&author) > funds);
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,287 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Fee Rebate Pallet
+//!
+//! The Fee Rebate Pallet pays extrinsic authors, the network members, back a
+//! share of their transaction fees from the treasury account.
+//!
+//! The `RebateFee` signed extension runs after the fee payment extension of
+//! the runtime. Once the extrinsic is dispatched and its fee settled, it
+//! credits the author with `FeeRebatePercent` of the fee actually paid,
+//! excluding the tip. Extrinsics whose fee is paid by someone other than the
+//! signer, as told by `FeePayer`, are not rebated. The rebate is skipped
+//! while the rebate account can not pay it.
+//!
+//! ### Storage
+//!
+//! - `FeeRebatePercent`: The share of the fee rebated. It starts at zero and
+//!   is set by `RebateOrigin`.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `set_fee_rebate_percent` - Set the share of the fee rebated to authors.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	traits::{
+		fungible::{Inspect, Mutate},
+		tokens::Preservation,
+		Get,
+	},
+};
+use network_membership::traits::IsMember;
+pub use pallet::*;
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion, SignedExtension,
+		Zero,
+	},
+	transaction_validity::TransactionValidityError,
+	DispatchResult, Permill,
+};
+use sp_std::marker::PhantomData;
+pub use weights::WeightInfo;
+
+/// Tells whether the signer of an extrinsic pays its transaction fee.
+pub trait FeePayer<AccountId, Call> {
+	/// Whether `who` pays the fee of its extrinsic calling `call` itself,
+	/// rather than a sponsor.
+	fn pays_own_fee(who: &AccountId, call: &Call) -> bool;
+}
+
+/// Every signer pays its own fee.
+impl<AccountId, Call> FeePayer<AccountId, Call> for () {
+	fn pays_own_fee(_who: &AccountId, _call: &Call) -> bool {
+		true
+	}
+}
+
+/// Sets up the extrinsic authors the benchmarks need.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+	/// An account `Config::Authors` counts as an extrinsic author.
+	fn author() -> AccountId;
+}
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency the rebate is paid in.
+		type Currency: Mutate<Self::AccountId>;
+		/// The account paying the rebates.
+		type RebateAccount: Get<Self::AccountId>;
+		/// The extrinsic authors eligible for a rebate.
+		type Authors: IsMember<Self::AccountId>;
+		/// Tells which extrinsics are paid for by their signer. The others
+		/// are not rebated.
+		type FeePayer: FeePayer<Self::AccountId, <Self as frame_system::Config>::RuntimeCall>;
+		/// Origin allowed to set the rebated share of the fee.
+		type RebateOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// Provides an extrinsic author for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// share of the transaction fee rebated to extrinsic authors
+	#[pallet::storage]
+	#[pallet::getter(fn fee_rebate_percent)]
+	pub type FeeRebatePercent<T> = StorageValue<_, Permill, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The rebated share of the fee was updated.
+		FeeRebatePercentSet { rebate: Permill },
+		/// An extrinsic author was paid a rebate on its fee.
+		FeeRebated { who: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The rebate is already set to the given value
+		FeeRebateUnchanged,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the share of the transaction fee rebated to extrinsic
+		/// authors.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_rebate_percent())]
+		pub fn set_fee_rebate_percent(origin: OriginFor<T>, rebate: Permill) -> DispatchResult {
+			T::RebateOrigin::ensure_origin(origin)?;
+
+			ensure!(FeeRebatePercent::<T>::get() != rebate, Error::<T>::FeeRebateUnchanged);
+			FeeRebatePercent::<T>::put(rebate);
+
+			Self::deposit_event(Event::FeeRebatePercentSet { rebate });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The rebate owed on a transaction `fee`.
+	pub fn rebate_for(fee: u128) -> BalanceOf<T> {
+		FeeRebatePercent::<T>::get().mul_floor(fee).saturated_into()
+	}
+
+	/// Whether the extrinsic of `who` calling `call` is rebated: `who` is
+	/// an extrinsic author paying its own fee and the rebate is enabled.
+	fn is_rebated(who: &T::AccountId, call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		!FeeRebatePercent::<T>::get().is_zero() &&
+			T::Authors::is_member(who) &&
+			T::FeePayer::pays_own_fee(who, call)
+	}
+
+	/// Pay `who` the rebate on a transaction `fee`, if the rebate account
+	/// can afford it.
+	fn rebate(who: &T::AccountId, fee: u128) {
+		let amount = Self::rebate_for(fee);
+		if !amount.is_zero() &&
+			T::Currency::transfer(&T::RebateAccount::get(), who, amount, Preservation::Preserve)
+				.is_ok()
+		{
+			Self::deposit_event(Event::FeeRebated { who: who.clone(), amount });
+		}
+	}
+}
+
+/// The `RebateFee` struct.
+///
+/// Pays extrinsic authors their rebate once the transaction fee is settled.
+/// It has to come after the fee payment extension in the signed extensions
+/// of the runtime, so that the fee is refunded before the rebate is
+/// computed.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, scale_info::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct RebateFee<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for RebateFee<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "RebateFee")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> RebateFee<T> {
+	/// Create new `SignedExtension` to rebate transaction fees.
+	pub fn new() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+}
+
+/// Implementation of the `SignedExtension` trait for the `RebateFee` struct.
+///
+/// The rebate never makes an extrinsic invalid, so `validate` is left to the
+/// default.
+impl<T: Config + Send + Sync> SignedExtension for RebateFee<T>
+where
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	/// The author to rebate after the dispatch.
+	type Pre = Option<T::AccountId>;
+	const IDENTIFIER: &'static str = "RebateFee";
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		if !Pallet::<T>::is_rebated(who, call) {
+			return Ok(None)
+		}
+
+		// The rebate is transferred after the dispatch, outside of the
+		// weight of the call.
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			<T as pallet::Config>::WeightInfo::rebate_fee(),
+			info.class,
+		);
+		Ok(Some(who.clone()))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		_result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if let Some(Some(who)) = pre {
+			let fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+				len as u32,
+				info,
+				post_info,
+				Zero::zero(),
+			);
+			Pallet::<T>::rebate(&who, fee.saturated_into());
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,179 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchClass, DispatchInfo, Pays, PostDispatchInfo},
+	weights::Weight,
+};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use sp_runtime::traits::BadOrigin;
+
+const LEN: usize = 100;
+
+fn remark_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn sponsored_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] })
+}
+
+fn info() -> DispatchInfo {
+	DispatchInfo { weight: Weight::from_parts(1_000, 0), ..Default::default() }
+}
+
+fn post_info(actual_weight: Option<u64>) -> PostDispatchInfo {
+	PostDispatchInfo {
+		actual_weight: actual_weight.map(|w| Weight::from_parts(w, 0)),
+		pays_fee: Pays::Yes,
+	}
+}
+
+/// Run an extrinsic of `who` through the fee payment and rebate extensions,
+/// in the runtime order, using `actual_weight` of its estimated weight.
+fn submit(who: AccountId, call: RuntimeCall, actual_weight: Option<u64>) {
+	type Extensions = (ChargeTransactionPayment<Test>, RebateFee<Test>);
+
+	let pre = (ChargeTransactionPayment::<Test>::from(0), RebateFee::<Test>::new())
+		.pre_dispatch(&who, &call, &info(), LEN)
+		.unwrap();
+	assert_ok!(Extensions::post_dispatch(
+		Some(pre),
+		&info(),
+		&post_info(actual_weight),
+		LEN,
+		&Ok(())
+	));
+}
+
+fn fee() -> Balance {
+	TransactionPayment::compute_fee(LEN as u32, &info(), 0)
+}
+
+fn enable_rebate() {
+	assert_ok!(FeeRebate::set_fee_rebate_percent(RuntimeOrigin::root(), Permill::from_percent(50)));
+}
+
+#[test]
+fn set_fee_rebate_percent_works() {
+	new_test_ext().execute_with(|| {
+		let rebate = Permill::from_percent(20);
+		assert_noop!(
+			FeeRebate::set_fee_rebate_percent(RuntimeOrigin::signed(AUTHOR), rebate),
+			BadOrigin
+		);
+
+		assert_ok!(FeeRebate::set_fee_rebate_percent(RuntimeOrigin::root(), rebate));
+		assert_eq!(FeeRebate::fee_rebate_percent(), rebate);
+		System::assert_last_event(Event::FeeRebatePercentSet { rebate }.into());
+
+		assert_noop!(
+			FeeRebate::set_fee_rebate_percent(RuntimeOrigin::root(), rebate),
+			Error::<Test>::FeeRebateUnchanged
+		);
+	});
+}
+
+#[test]
+fn authors_are_rebated_part_of_their_fee() {
+	new_test_ext().execute_with(|| {
+		enable_rebate();
+
+		submit(AUTHOR, remark_call(), None);
+		let rebate = fee() / 2;
+		assert_eq!(Balances::free_balance(AUTHOR), INITIAL_BALANCE - fee() + rebate);
+		assert_eq!(Balances::free_balance(TREASURY), INITIAL_BALANCE - rebate);
+		System::assert_has_event(Event::FeeRebated { who: AUTHOR, amount: rebate }.into());
+	});
+}
+
+#[test]
+fn rebate_is_computed_from_the_fee_actually_paid() {
+	new_test_ext().execute_with(|| {
+		enable_rebate();
+
+		submit(AUTHOR, remark_call(), Some(400));
+		let paid =
+			TransactionPayment::compute_actual_fee(LEN as u32, &info(), &post_info(Some(400)), 0);
+		assert!(paid < fee());
+		assert_eq!(Balances::free_balance(AUTHOR), INITIAL_BALANCE - paid + paid / 2);
+		assert_eq!(Balances::free_balance(TREASURY), INITIAL_BALANCE - paid / 2);
+	});
+}
+
+#[test]
+fn rebate_weight_is_registered() {
+	new_test_ext().execute_with(|| {
+		enable_rebate();
+		let consumed = || *System::block_weight().get(DispatchClass::Normal);
+
+		let before = consumed();
+		assert_ok!(RebateFee::<Test>::new().pre_dispatch(&AUTHOR, &remark_call(), &info(), LEN));
+		assert_eq!(consumed(), before + <() as WeightInfo>::rebate_fee());
+
+		let before = consumed();
+		assert_ok!(RebateFee::<Test>::new().pre_dispatch(&STRANGER, &remark_call(), &info(), LEN));
+		assert_eq!(consumed(), before);
+	});
+}
+
+#[test]
+fn other_accounts_pay_the_full_fee() {
+	new_test_ext().execute_with(|| {
+		enable_rebate();
+
+		submit(STRANGER, remark_call(), None);
+		assert_eq!(Balances::free_balance(STRANGER), INITIAL_BALANCE - fee());
+		assert_eq!(Balances::free_balance(TREASURY), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn sponsored_extrinsics_are_not_rebated() {
+	new_test_ext().execute_with(|| {
+		enable_rebate();
+
+		submit(AUTHOR, sponsored_call(), None);
+		assert_eq!(Balances::free_balance(AUTHOR), INITIAL_BALANCE - fee());
+		assert_eq!(Balances::free_balance(TREASURY), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn no_rebate_is_paid_at_zero_percent() {
+	new_test_ext().execute_with(|| {
+		submit(AUTHOR, remark_call(), None);
+		assert_eq!(Balances::free_balance(AUTHOR), INITIAL_BALANCE - fee());
+		assert_eq!(Balances::free_balance(TREASURY), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn rebate_is_skipped_while_the_treasury_is_short() {
+	new_test_ext().execute_with(|| {
+		enable_rebate();
+		<Balances as Mutate<AccountId>>::set_balance(&TREASURY, 10);
+
+		submit(AUTHOR, remark_call(), None);
+		assert_eq!(Balances::free_balance(AUTHOR), INITIAL_BALANCE - fee());
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+	});
+}
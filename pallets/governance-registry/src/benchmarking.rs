@@ -0,0 +1,84 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// The governance proposal benchmarked against. The schema identifier type
+/// does not change the encoding of the proposal.
+type ProposalOf<T> = CordProposal<<T as frame_system::Config>::AccountId, ()>;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Register an open proposal of `proposer`.
+fn created_proposal<T: Config>(proposer: &T::AccountId) -> (ProposalOf<T>, T::Hash) {
+	let proposal = ProposalOf::<T>::AddAuthority(account("candidate", 0, SEED));
+	<Pallet<T> as ProposalObserver<_, ProposalOf<T>>>::on_proposal_created(proposer, &proposal);
+	let proposal_hash = T::Hashing::hash_of(&proposal);
+	(proposal, proposal_hash)
+}
+
+benchmarks! {
+	describe_proposal {
+		let proposer: T::AccountId = account("proposer", 0, SEED);
+		let (_, proposal_hash) = created_proposal::<T>(&proposer);
+		let description_digest = T::Hashing::hash(b"description");
+	}: _(RawOrigin::Signed(proposer), proposal_hash, description_digest)
+	verify {
+		assert_last_event::<T>(Event::ProposalDescribed { proposal_hash, description_digest }.into());
+	}
+
+	on_proposal_created {
+		let proposer: T::AccountId = account("proposer", 0, SEED);
+		let proposal = ProposalOf::<T>::AddAuthority(account("candidate", 0, SEED));
+	}: {
+		<Pallet<T> as ProposalObserver<_, ProposalOf<T>>>::on_proposal_created(&proposer, &proposal);
+	}
+	verify {
+		assert!(ProposalMetas::<T>::contains_key(T::Hashing::hash_of(&proposal)));
+	}
+
+	on_proposal_resolved {
+		let proposer: T::AccountId = account("proposer", 0, SEED);
+		let (proposal, proposal_hash) = created_proposal::<T>(&proposer);
+	}: {
+		<Pallet<T> as ProposalObserver<_, ProposalOf<T>>>::on_proposal_resolved(
+			&proposal,
+			ProposalOutcome::Executed,
+		);
+	}
+	verify {
+		assert_last_event::<T>(Event::ProposalResolved {
+			proposal_hash,
+			outcome: ProposalOutcome::Executed,
+		}.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
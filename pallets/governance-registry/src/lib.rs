@@ -0,0 +1,200 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Governance Registry Pallet
+//!
+//! The Governance Registry Pallet keeps CORD specific metadata of the
+//! proposals of the Governance Pallet. When a proposal is created, the
+//! registry records its proposer and the category of CORD entity it acts on,
+//! keyed by the hash of the proposal. Once the proposal is executed, fails or
+//! is cancelled, the registry records the outcome. The metadata is kept after
+//! the proposal has been resolved.
+//!
+//! The proposer of an open proposal can attach the digest of a description
+//! of the proposal, whose document is kept off chain.
+//!
+//! Proposals with equal content share a hash. While a proposal is open, an
+//! equal proposal keeps the metadata of the first one.
+//!
+//! ### Storage
+//!
+//! - `ProposalMetas`: The metadata of every proposal, by hash.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `describe_proposal` - Attach a description digest to an open proposal.
+//!
+//! ### Integration
+//!
+//! The pallet implements `pallet_cord_governance::ProposalObserver`, and is set as
+//! the `ProposalObserver` of the Governance Pallet. Its metadata is queried
+//! through `get_proposal_meta`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::Encode;
+pub use pallet::*;
+use pallet_cord_governance::{CordProposal, ProposalObserver, ProposalOutcome};
+use sp_runtime::traits::Hash;
+pub use types::{GovernanceCategory, GovernanceMeta};
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Metadata of a proposal.
+	pub type GovernanceMetaOf<T> =
+		GovernanceMeta<<T as frame_system::Config>::Hash, <T as frame_system::Config>::AccountId>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// proposal metadata by proposal hash
+	#[pallet::storage]
+	#[pallet::getter(fn get_proposal_meta)]
+	pub type ProposalMetas<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, GovernanceMetaOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The metadata of a new proposal was registered.
+		ProposalRegistered {
+			proposal_hash: T::Hash,
+			category: GovernanceCategory,
+			proposer: T::AccountId,
+		},
+		/// The proposer described a proposal.
+		ProposalDescribed { proposal_hash: T::Hash, description_digest: T::Hash },
+		/// The outcome of a proposal was recorded.
+		ProposalResolved { proposal_hash: T::Hash, outcome: ProposalOutcome },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No proposal has the hash.
+		ProposalNotFound,
+		/// The caller did not create the proposal.
+		NotProposer,
+		/// The proposal was already resolved.
+		ProposalResolved,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Attach `description_digest` to the open proposal `proposal_hash`,
+		/// replacing any earlier description. Only the proposer can describe
+		/// a proposal.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::describe_proposal())]
+		pub fn describe_proposal(
+			origin: OriginFor<T>,
+			proposal_hash: T::Hash,
+			description_digest: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ProposalMetas::<T>::try_mutate(proposal_hash, |meta| {
+				let meta = meta.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
+				ensure!(meta.proposer == who, Error::<T>::NotProposer);
+				ensure!(meta.outcome.is_none(), Error::<T>::ProposalResolved);
+				meta.description_digest = Some(description_digest);
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::ProposalDescribed { proposal_hash, description_digest });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config, SchemaId: Encode>
+	ProposalObserver<T::AccountId, CordProposal<T::AccountId, SchemaId>> for Pallet<T>
+{
+	fn on_proposal_created(
+		proposer: &T::AccountId,
+		proposal: &CordProposal<T::AccountId, SchemaId>,
+	) {
+		let proposal_hash = T::Hashing::hash_of(proposal);
+		if ProposalMetas::<T>::get(proposal_hash).map_or(false, |meta| meta.outcome.is_none()) {
+			return
+		}
+
+		let category = GovernanceCategory::of(proposal);
+		ProposalMetas::<T>::insert(
+			proposal_hash,
+			GovernanceMeta {
+				proposal_hash,
+				category,
+				description_digest: None,
+				proposer: proposer.clone(),
+				outcome: None,
+			},
+		);
+		Self::deposit_event(Event::ProposalRegistered {
+			proposal_hash,
+			category,
+			proposer: proposer.clone(),
+		});
+	}
+
+	fn on_proposal_resolved(
+		proposal: &CordProposal<T::AccountId, SchemaId>,
+		outcome: ProposalOutcome,
+	) {
+		let proposal_hash = T::Hashing::hash_of(proposal);
+		let resolved = ProposalMetas::<T>::mutate(proposal_hash, |meta| match meta {
+			Some(meta) if meta.outcome.is_none() => {
+				meta.outcome = Some(outcome);
+				true
+			},
+			_ => false,
+		});
+		if resolved {
+			Self::deposit_event(Event::ProposalResolved { proposal_hash, outcome });
+		}
+	}
+}
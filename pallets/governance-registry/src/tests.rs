@@ -0,0 +1,169 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Error, Event, GovernanceCategory, GovernanceMeta};
+use frame_support::{assert_noop, assert_ok};
+use pallet_cord_governance::{CordProposal, ProposalObserver, ProposalOutcome};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	Perquintill,
+};
+
+const DESCRIPTION: H256 = H256::repeat_byte(1);
+
+fn create(proposer: AccountId, proposal: &Proposal) -> H256 {
+	GovernanceRegistry::on_proposal_created(&proposer, proposal);
+	BlakeTwo256::hash_of(proposal)
+}
+
+fn resolve(proposal: &Proposal, outcome: ProposalOutcome) {
+	<GovernanceRegistry as ProposalObserver<_, Proposal>>::on_proposal_resolved(proposal, outcome);
+}
+
+#[test]
+fn created_proposals_are_registered_with_their_category() {
+	new_test_ext().execute_with(|| {
+		let proposal_hash = create(ALICE, &CordProposal::AddAuthority(BOB));
+
+		assert_eq!(
+			GovernanceRegistry::get_proposal_meta(proposal_hash),
+			Some(GovernanceMeta {
+				proposal_hash,
+				category: GovernanceCategory::Authority,
+				description_digest: None,
+				proposer: ALICE,
+				outcome: None,
+			})
+		);
+		System::assert_last_event(
+			Event::ProposalRegistered {
+				proposal_hash,
+				category: GovernanceCategory::Authority,
+				proposer: ALICE,
+			}
+			.into(),
+		);
+
+		let categories = [
+			(CordProposal::RemoveAuthority(BOB), GovernanceCategory::Authority),
+			(CordProposal::UpdateInflationRate(Perquintill::one()), GovernanceCategory::Treasury),
+			(CordProposal::ArchiveSchema(7), GovernanceCategory::Schema),
+			(CordProposal::SetNetworkMembership(BOB, true), GovernanceCategory::NetworkMembership),
+		];
+		for (proposal, category) in categories {
+			let proposal_hash = create(ALICE, &proposal);
+			assert_eq!(
+				GovernanceRegistry::get_proposal_meta(proposal_hash).map(|meta| meta.category),
+				Some(category)
+			);
+		}
+	});
+}
+
+#[test]
+fn an_open_proposal_keeps_its_metadata() {
+	new_test_ext().execute_with(|| {
+		let proposal = CordProposal::AddAuthority(BOB);
+		let proposal_hash = create(ALICE, &proposal);
+
+		create(BOB, &proposal);
+		assert_eq!(GovernanceRegistry::get_proposal_meta(proposal_hash).unwrap().proposer, ALICE);
+
+		resolve(&proposal, ProposalOutcome::Cancelled);
+		create(BOB, &proposal);
+		let meta = GovernanceRegistry::get_proposal_meta(proposal_hash).unwrap();
+		assert_eq!(meta.proposer, BOB);
+		assert_eq!(meta.outcome, None);
+	});
+}
+
+#[test]
+fn resolved_proposals_record_their_outcome() {
+	new_test_ext().execute_with(|| {
+		let proposal = CordProposal::UpdateInflationRate(Perquintill::one());
+		let proposal_hash = create(ALICE, &proposal);
+
+		resolve(&proposal, ProposalOutcome::Executed);
+
+		assert_eq!(
+			GovernanceRegistry::get_proposal_meta(proposal_hash).unwrap().outcome,
+			Some(ProposalOutcome::Executed)
+		);
+		System::assert_last_event(
+			Event::ProposalResolved { proposal_hash, outcome: ProposalOutcome::Executed }.into(),
+		);
+	});
+}
+
+#[test]
+fn describe_proposal_works() {
+	new_test_ext().execute_with(|| {
+		let proposal = CordProposal::AddAuthority(BOB);
+		let proposal_hash = create(ALICE, &proposal);
+
+		assert_noop!(
+			GovernanceRegistry::describe_proposal(
+				RuntimeOrigin::signed(BOB),
+				proposal_hash,
+				DESCRIPTION
+			),
+			Error::<Test>::NotProposer
+		);
+		assert_ok!(GovernanceRegistry::describe_proposal(
+			RuntimeOrigin::signed(ALICE),
+			proposal_hash,
+			DESCRIPTION
+		));
+
+		assert_eq!(
+			GovernanceRegistry::get_proposal_meta(proposal_hash).unwrap().description_digest,
+			Some(DESCRIPTION)
+		);
+		System::assert_last_event(
+			Event::ProposalDescribed { proposal_hash, description_digest: DESCRIPTION }.into(),
+		);
+	});
+}
+
+#[test]
+fn describe_proposal_requires_an_open_proposal() {
+	new_test_ext().execute_with(|| {
+		let proposal = CordProposal::AddAuthority(BOB);
+		let proposal_hash = BlakeTwo256::hash_of(&proposal);
+		assert_noop!(
+			GovernanceRegistry::describe_proposal(
+				RuntimeOrigin::signed(ALICE),
+				proposal_hash,
+				DESCRIPTION
+			),
+			Error::<Test>::ProposalNotFound
+		);
+
+		create(ALICE, &proposal);
+		resolve(&proposal, ProposalOutcome::Failed);
+		assert_noop!(
+			GovernanceRegistry::describe_proposal(
+				RuntimeOrigin::signed(ALICE),
+				proposal_hash,
+				DESCRIPTION
+			),
+			Error::<Test>::ProposalResolved
+		);
+	});
+}
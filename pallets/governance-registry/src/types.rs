@@ -0,0 +1,74 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use pallet_cord_governance::{CordProposal, ProposalOutcome};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The CORD entity a governance proposal acts on.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum GovernanceCategory {
+	/// The authorities producing blocks.
+	Authority,
+	/// The network membership of accounts.
+	NetworkMembership,
+	/// The treasury and its inflation.
+	Treasury,
+	/// Schemas.
+	Schema,
+	/// Chain spaces.
+	ChainSpace,
+	/// Statements.
+	Statement,
+	/// DIDs.
+	Did,
+	/// Assets.
+	Asset,
+}
+
+impl GovernanceCategory {
+	/// The category of `proposal`.
+	pub fn of<AccountId, SchemaId>(proposal: &CordProposal<AccountId, SchemaId>) -> Self {
+		match proposal {
+			CordProposal::AddAuthority(_) | CordProposal::RemoveAuthority(_) => Self::Authority,
+			CordProposal::UpdateInflationRate(_) => Self::Treasury,
+			CordProposal::ArchiveSchema(_) => Self::Schema,
+			CordProposal::SetNetworkMembership(..) => Self::NetworkMembership,
+		}
+	}
+}
+
+/// The metadata of a governance proposal.
+///
+/// ## Fields
+///
+/// - `proposal_hash`: The hash of the proposal.
+/// - `category`: The entity the proposal acts on.
+/// - `description_digest`: Digest of the description of the proposal, once
+///   the proposer described it.
+/// - `proposer`: The council member who created the proposal.
+/// - `outcome`: How the proposal was resolved, `None` while it is open.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct GovernanceMeta<Hash, AccountId> {
+	pub proposal_hash: Hash,
+	pub category: GovernanceCategory,
+	pub description_digest: Option<Hash>,
+	pub proposer: AccountId,
+	pub outcome: Option<ProposalOutcome>,
+}
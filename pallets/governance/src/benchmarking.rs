@@ -0,0 +1,109 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::BoundedVec;
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Add `count` accounts to the council and return them.
+fn add_council_members<T: Config>(count: u32) -> Vec<T::AccountId> {
+	(0..count)
+		.map(|i| {
+			let member: T::AccountId = account("member", i, SEED);
+			T::CouncilMembers::add(&member);
+			member
+		})
+		.collect()
+}
+
+fn proposal<T: Config>() -> CordProposalOf<T> {
+	CordProposal::AddAuthority(account("candidate", 0, SEED))
+}
+
+benchmarks! {
+	// The council is large enough for the proposal to stay open.
+	propose {
+		let members = add_council_members::<T>(T::MaxApprovals::get());
+		let proposer = members[0].clone();
+	}: _(RawOrigin::Signed(proposer.clone()), proposal::<T>())
+	verify {
+		assert!(Proposals::<T>::contains_key(0));
+		assert_last_event::<T>(
+			Event::Proposed { index: 0, proposer, proposal: proposal::<T>() }.into(),
+		);
+	}
+
+	// The last approval the proposal can hold; the approvals of all the other
+	// members are counted before it is dispatched.
+	approve {
+		let members = add_council_members::<T>(T::MaxApprovals::get());
+		let (approver, others) = members.split_last().expect("MaxApprovals is not zero");
+		Proposals::<T>::insert(0, ProposalInfo {
+			proposer: others[0].clone(),
+			proposal: proposal::<T>(),
+			approvals: BoundedVec::truncate_from(others.to_vec()),
+			created_at: frame_system::Pallet::<T>::block_number(),
+		});
+		let approver = approver.clone();
+	}: _(RawOrigin::Signed(approver.clone()), 0, Weight::MAX)
+	verify {
+		assert!(Proposals::<T>::get(0).map_or(true, |info| info.approvals.contains(&approver)));
+	}
+
+	cancel {
+		let members = add_council_members::<T>(1);
+		let proposer = members[0].clone();
+		Proposals::<T>::insert(0, ProposalInfo {
+			proposer: proposer.clone(),
+			proposal: proposal::<T>(),
+			approvals: BoundedVec::truncate_from(members),
+			created_at: frame_system::Pallet::<T>::block_number(),
+		});
+	}: _(RawOrigin::Signed(proposer), 0)
+	verify {
+		assert!(!Proposals::<T>::contains_key(0));
+	}
+
+	// Like `propose`, with the proposal looked up from its preimage.
+	propose_preimage {
+		let members = add_council_members::<T>(T::MaxApprovals::get());
+		let proposer = members[0].clone();
+		let encoded = proposal::<T>().encode();
+		let len = encoded.len() as u32;
+		let hash = T::Preimages::note(encoded.into())?;
+	}: _(RawOrigin::Signed(proposer.clone()), hash, len, Weight::MAX)
+	verify {
+		assert!(Proposals::<T>::contains_key(0));
+		assert_last_event::<T>(
+			Event::Proposed { index: 0, proposer, proposal: proposal::<T>() }.into(),
+		);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test)
+}
@@ -0,0 +1,342 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Governance Pallet
+//!
+//! The Governance Pallet lets the council decide on CORD specific actions,
+//! described by `CordProposal`, instead of arbitrary calls. A council member
+//! proposes an action, and the proposal is executed with root origin once
+//! `QuorumPercent` of the council approved it. The runtime decides which
+//! call carries out each kind of proposal through `ProposalCall`.
+//!
+//! Approvals of accounts that left the council are ignored when counting
+//! towards the quorum.
+//!
+//! Instead of passing the proposal in the call, a council member can note
+//! its encoding as a preimage first and propose it by hash through
+//! `propose_preimage`. The proposal is looked up in `Preimages` and decoded
+//! before it is stored.
+//!
+//! ### Storage
+//!
+//! - `Proposals`: The open proposals, by index.
+//! - `ProposalCount`: The number of proposals created so far.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `propose` - Propose an action and approve it.
+//! - `propose_preimage` - Propose the action noted as the given preimage and
+//!   approve it.
+//! - `approve` - Approve an open proposal and execute it if the quorum is
+//!   reached.
+//! - `cancel` - Withdraw an open proposal. Only its proposer can cancel it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod traits;
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::{Decode, MaxEncodedLen};
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::{Get, QueryPreimage, SortedMembers, StorePreimage},
+	weights::Weight,
+	BoundedVec,
+};
+pub use pallet::*;
+use sp_runtime::{
+	traits::{Convert, Dispatchable},
+	Percent,
+};
+use sp_std::vec;
+pub use traits::ProposalObserver;
+pub use types::{CordProposal, ProposalInfo, ProposalOutcome};
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Index of a proposal.
+	pub type ProposalIndex = u32;
+	/// A proposal understood by this runtime.
+	pub type CordProposalOf<T> =
+		CordProposal<<T as frame_system::Config>::AccountId, <T as Config>::SchemaId>;
+	/// An open proposal along with its approvals.
+	pub type ProposalInfoOf<T> = ProposalInfo<
+		<T as frame_system::Config>::AccountId,
+		CordProposalOf<T>,
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxApprovals>,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The call dispatched when a proposal passes.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo;
+		/// Identifier of the schemas archived through `ArchiveSchema`.
+		type SchemaId: Parameter + MaxEncodedLen;
+		/// The call carrying out a proposal, or `None` if the runtime can
+		/// not execute this kind of proposal.
+		type ProposalCall: Convert<CordProposalOf<Self>, Option<<Self as Config>::RuntimeCall>>;
+		/// The preimages proposals can be looked up from.
+		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+		/// Council members allowed to propose and approve.
+		type CouncilMembers: SortedMembers<Self::AccountId>;
+		/// Share of the council that must approve a proposal.
+		#[pallet::constant]
+		type QuorumPercent: Get<Percent>;
+		/// Maximum number of approvals kept for a proposal.
+		#[pallet::constant]
+		type MaxApprovals: Get<u32>;
+		/// Notified when proposals are created and resolved.
+		type ProposalObserver: ProposalObserver<Self::AccountId, CordProposalOf<Self>>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// open proposals
+	#[pallet::storage]
+	#[pallet::getter(fn proposals)]
+	pub type Proposals<T: Config> =
+		StorageMap<_, Twox64Concat, ProposalIndex, ProposalInfoOf<T>, OptionQuery>;
+
+	/// number of proposals created so far
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_count)]
+	pub type ProposalCount<T> = StorageValue<_, ProposalIndex, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A proposal was created.
+		Proposed { index: ProposalIndex, proposer: T::AccountId, proposal: CordProposalOf<T> },
+		/// A council member approved a proposal.
+		Approved { index: ProposalIndex, who: T::AccountId },
+		/// A proposal reached the quorum and was dispatched.
+		Executed { index: ProposalIndex, result: DispatchResult },
+		/// A proposal was withdrawn by its proposer.
+		Cancelled { index: ProposalIndex },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The origin is not a council member.
+		NotCouncilMember,
+		/// The runtime can not execute this kind of proposal.
+		UnsupportedProposal,
+		/// There is no proposal with the given index.
+		ProposalNotFound,
+		/// The council member already approved the proposal.
+		AlreadyApproved,
+		/// The proposal can not hold more approvals.
+		TooManyApprovals,
+		/// Only the proposer can cancel a proposal.
+		NotProposer,
+		/// The weight of the proposal is above the given bound.
+		WrongProposalWeight,
+		/// The preimage of the proposal is not available
+		PreimageNotFound,
+		/// The preimage is longer than any proposal
+		PreimageTooLarge,
+		/// The preimage does not decode to a proposal
+		UndecodablePreimage,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose an action to the council. The proposer's approval is
+		/// counted right away.
+		#[pallet::call_index(0)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::propose()
+				.saturating_add(Pallet::<T>::proposal_weight(proposal))
+		)]
+		pub fn propose(origin: OriginFor<T>, proposal: CordProposalOf<T>) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(T::CouncilMembers::contains(&proposer), Error::<T>::NotCouncilMember);
+
+			Self::do_propose(proposer, proposal, Weight::MAX)
+		}
+
+		/// Approve an open proposal. The approval reaching the quorum
+		/// dispatches the proposal, provided its weight is within
+		/// `weight_bound`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::approve().saturating_add(*weight_bound)
+		)]
+		pub fn approve(
+			origin: OriginFor<T>,
+			index: ProposalIndex,
+			weight_bound: Weight,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::CouncilMembers::contains(&who), Error::<T>::NotCouncilMember);
+
+			let mut info = Proposals::<T>::get(index).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(!info.approvals.contains(&who), Error::<T>::AlreadyApproved);
+			info.approvals.try_push(who.clone()).map_err(|_| Error::<T>::TooManyApprovals)?;
+			Self::deposit_event(Event::Approved { index, who });
+
+			Self::execute_if_approved(index, info, weight_bound)
+		}
+
+		/// Withdraw an open proposal. Only the proposer can cancel it.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel())]
+		pub fn cancel(origin: OriginFor<T>, index: ProposalIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let info = Proposals::<T>::get(index).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(info.proposer == who, Error::<T>::NotProposer);
+
+			Proposals::<T>::remove(index);
+			T::ProposalObserver::on_proposal_resolved(&info.proposal, ProposalOutcome::Cancelled);
+
+			Self::deposit_event(Event::Cancelled { index });
+			Ok(())
+		}
+
+		/// Propose the action whose encoding was noted as the preimage
+		/// `proposal_hash`. Like `approve`, the proposal is dispatched right
+		/// away only if its weight is within `weight_bound`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::propose_preimage().saturating_add(*weight_bound)
+		)]
+		pub fn propose_preimage(
+			origin: OriginFor<T>,
+			proposal_hash: T::Hash,
+			proposal_len: u32,
+			weight_bound: Weight,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(T::CouncilMembers::contains(&proposer), Error::<T>::NotCouncilMember);
+			ensure!(
+				proposal_len as usize <= CordProposalOf::<T>::max_encoded_len(),
+				Error::<T>::PreimageTooLarge
+			);
+
+			let bytes = T::Preimages::fetch(&proposal_hash, Some(proposal_len))
+				.map_err(|_| Error::<T>::PreimageNotFound)?;
+			let proposal = CordProposalOf::<T>::decode(&mut &bytes[..])
+				.map_err(|_| Error::<T>::UndecodablePreimage)?;
+
+			Self::do_propose(proposer, proposal, weight_bound)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The number of current council members whose approval passes a
+	/// proposal.
+	pub fn quorum() -> u32 {
+		let council_size = T::CouncilMembers::count() as u32;
+		T::QuorumPercent::get().mul_ceil(council_size).max(1)
+	}
+
+	/// The weight of the call carrying out `proposal`.
+	pub fn proposal_weight(proposal: &CordProposalOf<T>) -> Weight {
+		T::ProposalCall::convert(proposal.clone())
+			.map_or(Weight::zero(), |call| call.get_dispatch_info().weight)
+	}
+
+	/// Store a new proposal approved by its proposer, who must be a council
+	/// member.
+	fn do_propose(
+		proposer: T::AccountId,
+		proposal: CordProposalOf<T>,
+		weight_bound: Weight,
+	) -> sp_runtime::DispatchResult {
+		frame_support::ensure!(
+			T::ProposalCall::convert(proposal.clone()).is_some(),
+			Error::<T>::UnsupportedProposal
+		);
+
+		let index = ProposalCount::<T>::get();
+		ProposalCount::<T>::put(index.saturating_add(1));
+
+		let info = ProposalInfo {
+			proposer: proposer.clone(),
+			proposal: proposal.clone(),
+			approvals: BoundedVec::truncate_from(vec![proposer.clone()]),
+			created_at: frame_system::Pallet::<T>::block_number(),
+		};
+		T::ProposalObserver::on_proposal_created(&proposer, &proposal);
+		Self::deposit_event(Event::Proposed { index, proposer, proposal });
+
+		Self::execute_if_approved(index, info, weight_bound)
+	}
+
+	/// Dispatch the proposal if enough current council members approved it,
+	/// or store it along with its new approval otherwise.
+	fn execute_if_approved(
+		index: ProposalIndex,
+		info: ProposalInfoOf<T>,
+		weight_bound: Weight,
+	) -> sp_runtime::DispatchResult {
+		let approvals =
+			info.approvals.iter().filter(|a| T::CouncilMembers::contains(a)).count() as u32;
+		if approvals < Self::quorum() {
+			Proposals::<T>::insert(index, info);
+			return Ok(())
+		}
+
+		let call = T::ProposalCall::convert(info.proposal.clone())
+			.ok_or(Error::<T>::UnsupportedProposal)?;
+		frame_support::ensure!(
+			call.get_dispatch_info().weight.all_lte(weight_bound),
+			Error::<T>::WrongProposalWeight
+		);
+		Proposals::<T>::remove(index);
+
+		let result = call
+			.dispatch(frame_system::RawOrigin::Root.into())
+			.map(|_| ())
+			.map_err(|e| e.error);
+		let outcome =
+			if result.is_ok() { ProposalOutcome::Executed } else { ProposalOutcome::Failed };
+		T::ProposalObserver::on_proposal_resolved(&info.proposal, outcome);
+		Self::deposit_event(Event::Executed { index, result });
+		Ok(())
+	}
+}
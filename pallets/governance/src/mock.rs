@@ -0,0 +1,169 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate as pallet_cord_governance;
+use codec::Encode;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64, FetchResult},
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	BuildStorage, DispatchError,
+};
+use sp_std::{borrow::Cow, collections::btree_map::BTreeMap};
+
+pub type AccountId = u64;
+pub type SchemaId = u32;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// Storage key written by a passed `AddAuthority` proposal.
+pub const AUTHORITY_KEY: &[u8] = b":test:authority";
+/// Storage key written by a passed `UpdateInflationRate` proposal.
+pub const INFLATION_KEY: &[u8] = b":test:inflation";
+
+construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Governance: pallet_cord_governance,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Hash = H256;
+	type RuntimeCall = RuntimeCall;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub static CouncilMembers: Vec<AccountId> = vec![1, 2, 3];
+	pub const QuorumPercent: Percent = Percent::from_percent(60);
+	pub static NotedPreimages: BTreeMap<H256, Vec<u8>> = BTreeMap::new();
+}
+
+pub struct TestCouncil;
+impl SortedMembers<AccountId> for TestCouncil {
+	fn sorted_members() -> Vec<AccountId> {
+		let mut members = CouncilMembers::get();
+		members.sort();
+		members
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &AccountId) {
+		CouncilMembers::mutate(|members| members.push(*who));
+	}
+}
+
+/// Keeps noted preimages in memory. Requests are not tracked.
+pub struct TestPreimages;
+impl QueryPreimage for TestPreimages {
+	type H = BlakeTwo256;
+
+	fn len(hash: &H256) -> Option<u32> {
+		NotedPreimages::get().get(hash).map(|bytes| bytes.len() as u32)
+	}
+
+	fn fetch(hash: &H256, len: Option<u32>) -> FetchResult {
+		let bytes = NotedPreimages::get().get(hash).cloned().ok_or(DispatchError::Unavailable)?;
+		if len.map_or(false, |len| len as usize != bytes.len()) {
+			return Err(DispatchError::Unavailable)
+		}
+		Ok(Cow::Owned(bytes))
+	}
+
+	fn is_requested(_hash: &H256) -> bool {
+		false
+	}
+
+	fn request(_hash: &H256) {}
+
+	fn unrequest(_hash: &H256) {}
+}
+
+impl StorePreimage for TestPreimages {
+	const MAX_LENGTH: usize = 4096;
+
+	fn note(bytes: Cow<[u8]>) -> Result<H256, DispatchError> {
+		let hash = BlakeTwo256::hash(&bytes);
+		NotedPreimages::mutate(|preimages| preimages.insert(hash, bytes.into_owned()));
+		Ok(hash)
+	}
+}
+
+/// Carries out proposals by writing to well-known storage keys, which root
+/// can do through `set_storage`. Schemas can not be archived.
+pub struct TestProposalCall;
+impl Convert<CordProposalOf<Test>, Option<RuntimeCall>> for TestProposalCall {
+	fn convert(proposal: CordProposalOf<Test>) -> Option<RuntimeCall> {
+		let items = match proposal {
+			CordProposal::AddAuthority(who) => vec![(AUTHORITY_KEY.to_vec(), who.encode())],
+			CordProposal::UpdateInflationRate(rate) =>
+				vec![(INFLATION_KEY.to_vec(), rate.encode())],
+			CordProposal::RemoveAuthority(_) |
+			CordProposal::SetNetworkMembership(..) |
+			CordProposal::ArchiveSchema(_) => return None,
+		};
+		Some(RuntimeCall::System(frame_system::Call::set_storage { items }))
+	}
+}
+
+impl pallet_cord_governance::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type SchemaId = SchemaId;
+	type ProposalCall = TestProposalCall;
+	type Preimages = TestPreimages;
+	type CouncilMembers = TestCouncil;
+	type QuorumPercent = QuorumPercent;
+	type MaxApprovals = ConstU32<5>;
+	type ProposalObserver = ();
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	CouncilMembers::set(vec![1, 2, 3]);
+	NotedPreimages::set(BTreeMap::new());
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,182 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::{traits::BadOrigin, Perquintill};
+
+fn add_authority(who: AccountId) -> CordProposalOf<Test> {
+	CordProposal::AddAuthority(who)
+}
+
+fn authority() -> Option<Vec<u8>> {
+	sp_io::storage::get(AUTHORITY_KEY).map(|v| v.to_vec())
+}
+
+#[test]
+fn propose_requires_a_council_member_and_a_supported_proposal() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Governance::propose(RuntimeOrigin::root(), add_authority(7)), BadOrigin);
+		assert_noop!(
+			Governance::propose(RuntimeOrigin::signed(4), add_authority(7)),
+			Error::<Test>::NotCouncilMember
+		);
+		assert_noop!(
+			Governance::propose(RuntimeOrigin::signed(1), CordProposal::ArchiveSchema(9)),
+			Error::<Test>::UnsupportedProposal
+		);
+
+		assert_ok!(Governance::propose(RuntimeOrigin::signed(1), add_authority(7)));
+		System::assert_last_event(
+			Event::Proposed { index: 0, proposer: 1, proposal: add_authority(7) }.into(),
+		);
+		assert_eq!(Governance::proposal_count(), 1);
+		assert_eq!(Governance::proposals(0).unwrap().approvals.into_inner(), vec![1]);
+	});
+}
+
+#[test]
+fn proposal_is_executed_once_the_quorum_is_reached() {
+	new_test_ext().execute_with(|| {
+		// 60% of a council of three rounds up to two approvals.
+		assert_eq!(Governance::quorum(), 2);
+
+		let rate = Perquintill::from_percent(2);
+		assert_ok!(Governance::propose(
+			RuntimeOrigin::signed(1),
+			CordProposal::UpdateInflationRate(rate)
+		));
+		assert_eq!(sp_io::storage::get(INFLATION_KEY), None);
+
+		assert_noop!(
+			Governance::approve(RuntimeOrigin::signed(1), 0, Weight::MAX),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_noop!(
+			Governance::approve(RuntimeOrigin::signed(4), 0, Weight::MAX),
+			Error::<Test>::NotCouncilMember
+		);
+		assert_noop!(
+			Governance::approve(RuntimeOrigin::signed(2), 1, Weight::MAX),
+			Error::<Test>::ProposalNotFound
+		);
+
+		assert_ok!(Governance::approve(RuntimeOrigin::signed(2), 0, Weight::MAX));
+		System::assert_has_event(Event::Approved { index: 0, who: 2 }.into());
+		System::assert_last_event(Event::Executed { index: 0, result: Ok(()) }.into());
+		assert_eq!(sp_io::storage::get(INFLATION_KEY).map(|v| v.to_vec()), Some(rate.encode()));
+		assert!(Governance::proposals(0).is_none());
+	});
+}
+
+#[test]
+fn approvals_of_former_council_members_do_not_count() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Governance::propose(RuntimeOrigin::signed(1), add_authority(7)));
+		CouncilMembers::set(vec![2, 3, 4]);
+
+		assert_ok!(Governance::approve(RuntimeOrigin::signed(2), 0, Weight::MAX));
+		assert_eq!(authority(), None);
+		assert_eq!(Governance::proposals(0).unwrap().approvals.into_inner(), vec![1, 2]);
+
+		assert_ok!(Governance::approve(RuntimeOrigin::signed(3), 0, Weight::MAX));
+		assert_eq!(authority(), Some(7u64.encode()));
+	});
+}
+
+#[test]
+fn approve_respects_the_weight_bound() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Governance::propose(RuntimeOrigin::signed(1), add_authority(7)));
+
+		assert_noop!(
+			Governance::approve(RuntimeOrigin::signed(2), 0, Weight::zero()),
+			Error::<Test>::WrongProposalWeight
+		);
+
+		let weight_bound = Governance::proposal_weight(&add_authority(7));
+		assert_ok!(Governance::approve(RuntimeOrigin::signed(2), 0, weight_bound));
+		assert_eq!(authority(), Some(7u64.encode()));
+	});
+}
+
+#[test]
+fn only_the_proposer_can_cancel() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Governance::propose(RuntimeOrigin::signed(1), add_authority(7)));
+
+		assert_noop!(Governance::cancel(RuntimeOrigin::signed(2), 0), Error::<Test>::NotProposer);
+		assert_noop!(
+			Governance::cancel(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::ProposalNotFound
+		);
+
+		assert_ok!(Governance::cancel(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::Cancelled { index: 0 }.into());
+		assert!(Governance::proposals(0).is_none());
+		assert_noop!(
+			Governance::approve(RuntimeOrigin::signed(2), 0, Weight::MAX),
+			Error::<Test>::ProposalNotFound
+		);
+	});
+}
+
+#[test]
+fn propose_preimage_looks_up_the_proposal() {
+	new_test_ext().execute_with(|| {
+		let encoded = add_authority(7).encode();
+		let len = encoded.len() as u32;
+		let hash = TestPreimages::note(encoded.into()).unwrap();
+
+		assert_noop!(
+			Governance::propose_preimage(RuntimeOrigin::signed(4), hash, len, Weight::MAX),
+			Error::<Test>::NotCouncilMember
+		);
+		assert_noop!(
+			Governance::propose_preimage(RuntimeOrigin::signed(1), H256::zero(), len, Weight::MAX),
+			Error::<Test>::PreimageNotFound
+		);
+		assert_noop!(
+			Governance::propose_preimage(RuntimeOrigin::signed(1), hash, u32::MAX, Weight::MAX),
+			Error::<Test>::PreimageTooLarge
+		);
+
+		assert_ok!(Governance::propose_preimage(RuntimeOrigin::signed(1), hash, len, Weight::MAX));
+		System::assert_last_event(
+			Event::Proposed { index: 0, proposer: 1, proposal: add_authority(7) }.into(),
+		);
+
+		assert_ok!(Governance::approve(RuntimeOrigin::signed(2), 0, Weight::MAX));
+		assert_eq!(authority(), Some(7u64.encode()));
+	});
+}
+
+#[test]
+fn propose_preimage_rejects_undecodable_preimages() {
+	new_test_ext().execute_with(|| {
+		let hash = TestPreimages::note(vec![0xff].into()).unwrap();
+
+		assert_noop!(
+			Governance::propose_preimage(RuntimeOrigin::signed(1), hash, 1, Weight::MAX),
+			Error::<Test>::UndecodablePreimage
+		);
+	});
+}
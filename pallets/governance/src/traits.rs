@@ -0,0 +1,34 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::ProposalOutcome;
+
+/// Notified when proposals are created and resolved.
+pub trait ProposalObserver<AccountId, Proposal> {
+	/// `proposer` created `proposal`.
+	fn on_proposal_created(proposer: &AccountId, proposal: &Proposal);
+
+	/// `proposal` left the open proposals with `outcome`.
+	fn on_proposal_resolved(proposal: &Proposal, outcome: ProposalOutcome);
+}
+
+impl<AccountId, Proposal> ProposalObserver<AccountId, Proposal> for () {
+	fn on_proposal_created(_proposer: &AccountId, _proposal: &Proposal) {}
+
+	fn on_proposal_resolved(_proposal: &Proposal, _outcome: ProposalOutcome) {}
+}
@@ -0,0 +1,64 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{Perquintill, RuntimeDebug};
+
+/// An action the council can decide on.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum CordProposal<AccountId, SchemaId> {
+	/// Add a network member to the authority membership.
+	AddAuthority(AccountId),
+	/// Remove a member from the authority membership.
+	RemoveAuthority(AccountId),
+	/// Set the annual inflation rate minted into the treasury.
+	UpdateInflationRate(Perquintill),
+	/// Archive a schema.
+	ArchiveSchema(SchemaId),
+	/// Grant (`true`) or revoke (`false`) the network membership of an
+	/// account.
+	SetNetworkMembership(AccountId, bool),
+}
+
+/// How a proposal left the open proposals.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum ProposalOutcome {
+	/// The proposal reached the quorum and its call succeeded.
+	Executed,
+	/// The proposal reached the quorum but its call failed.
+	Failed,
+	/// The proposer withdrew the proposal.
+	Cancelled,
+}
+
+/// An open proposal.
+///
+/// ## Fields
+///
+/// - `proposer`: The council member who created the proposal.
+/// - `proposal`: The proposed action.
+/// - `approvals`: The accounts that approved the proposal, proposer included.
+/// - `created_at`: The block the proposal was created at.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct ProposalInfo<AccountId, Proposal, Approvals, BlockNumber> {
+	pub proposer: AccountId,
+	pub proposal: Proposal,
+	pub approvals: Approvals,
+	pub created_at: BlockNumber,
+}
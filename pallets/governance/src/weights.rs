@@ -0,0 +1,151 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_governance`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_governance
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/governance/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_governance`.
+pub trait WeightInfo {
+	fn propose() -> Weight;
+	fn approve() -> Weight;
+	fn cancel() -> Weight;
+	fn propose_preimage() -> Weight;
+}
+
+/// Weights for `pallet_cord_governance` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CouncilMembership::Members` (r:1 w:0)
+	/// Proof: `CouncilMembership::Members` (`max_values`: Some(1), `max_size`: Some(1601), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::ProposalCount` (r:1 w:1)
+	/// Proof: `Governance::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::Proposals` (r:0 w:1)
+	/// Proof: `Governance::Proposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn propose() -> Weight {
+		Weight::from_parts(17_920_000, 3086)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CouncilMembership::Members` (r:1 w:0)
+	/// Proof: `CouncilMembership::Members` (`max_values`: Some(1), `max_size`: Some(1601), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Proof: `Governance::Proposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn approve() -> Weight {
+		Weight::from_parts(43_160_000, 5167)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Proof: `Governance::Proposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn cancel() -> Weight {
+		Weight::from_parts(13_070_000, 5167)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CouncilMembership::Members` (r:1 w:0)
+	/// Proof: `CouncilMembership::Members` (`max_values`: Some(1), `max_size`: Some(1601), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:1 w:0)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `Measured`)
+	/// Storage: `Governance::ProposalCount` (r:1 w:1)
+	/// Proof: `Governance::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::Proposals` (r:0 w:1)
+	/// Proof: `Governance::Proposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn propose_preimage() -> Weight {
+		Weight::from_parts(30_310_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `CouncilMembership::Members` (r:1 w:0)
+	/// Proof: `CouncilMembership::Members` (`max_values`: Some(1), `max_size`: Some(1601), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::ProposalCount` (r:1 w:1)
+	/// Proof: `Governance::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::Proposals` (r:0 w:1)
+	/// Proof: `Governance::Proposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn propose() -> Weight {
+		Weight::from_parts(17_920_000, 3086)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CouncilMembership::Members` (r:1 w:0)
+	/// Proof: `CouncilMembership::Members` (`max_values`: Some(1), `max_size`: Some(1601), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Proof: `Governance::Proposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn approve() -> Weight {
+		Weight::from_parts(43_160_000, 5167)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Proof: `Governance::Proposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn cancel() -> Weight {
+		Weight::from_parts(13_070_000, 5167)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CouncilMembership::Members` (r:1 w:0)
+	/// Proof: `CouncilMembership::Members` (`max_values`: Some(1), `max_size`: Some(1601), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:1 w:0)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `Measured`)
+	/// Storage: `Governance::ProposalCount` (r:1 w:1)
+	/// Proof: `Governance::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::Proposals` (r:0 w:1)
+	/// Proof: `Governance::Proposals` (`max_values`: None, `max_size`: Some(1702), added: 4177, mode: `MaxEncodedLen`)
+	fn propose_preimage() -> Weight {
+		Weight::from_parts(30_310_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
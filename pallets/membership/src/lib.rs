@@ -372,6 +372,22 @@ impl<T: Config<I>, I: 'static> SortedMembers<T::AccountId> for Pallet<T, I> {
 	fn count() -> usize {
 		Members::<T, I>::decode_len().unwrap_or(0)
 	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(new_member: &T::AccountId) {
+		use frame_support::{assert_ok, traits::EnsureOrigin};
+		let new_member_lookup = T::Lookup::unlookup(new_member.clone());
+
+		if let Ok(origin) = T::AddOrigin::try_successful_origin() {
+			assert_ok!(Pallet::<T, I>::add_member(origin, new_member_lookup));
+		} else {
+			log::error!(
+				target: "runtime::membership",
+				"Failed to add `{:?}` in `SortedMembers::add`.",
+				new_member
+			);
+		}
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -640,6 +656,7 @@ mod tests {
 	parameter_types! {
 		pub const MembershipPeriod: BlockNumberFor<Test> = 5;
 		pub const MaxMembersPerBlock: u32 = 5;
+		pub const MaxExtrinsicsPerBlock: u32 = 5;
 	}
 
 	impl pallet_network_membership::Config for Test {
@@ -647,6 +664,7 @@ mod tests {
 		type RuntimeEvent = RuntimeEvent;
 		type MembershipPeriod = MembershipPeriod;
 		type MaxMembersPerBlock = MaxMembersPerBlock;
+		type MaxExtrinsicsPerBlock = MaxExtrinsicsPerBlock;
 		type WeightInfo = ();
 	}
 
@@ -0,0 +1,106 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// `s` signers of weight one, sorted by account.
+fn signers<T: Config>(s: u32) -> Vec<(T::AccountId, u16)> {
+	let mut signers: Vec<_> = (0..s).map(|i| (account("signer", i, SEED), 1)).collect();
+	signers.sort_by(|a, b| a.0.cmp(&b.0));
+	signers
+}
+
+/// Create a multisig authority of `s` signers needing all of them to act.
+fn create_authority<T: Config>(s: u32) -> (T::AccountId, Vec<T::AccountId>) {
+	let signers = signers::<T>(s);
+	Pallet::<T>::create_multisig_authority(
+		RawOrigin::Signed(whitelisted_caller()).into(),
+		signers.clone(),
+		s as u16,
+	)
+	.expect("valid signers; qed");
+	let authority = Pallet::<T>::authority_id(&signers);
+	(authority, signers.into_iter().map(|(signer, _)| signer).collect())
+}
+
+benchmarks! {
+	create_multisig_authority {
+		let s in 1 .. T::MaxSigners::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let signers = signers::<T>(s);
+		let authority = Pallet::<T>::authority_id(&signers);
+	}: _(RawOrigin::Signed(caller), signers, s as u16)
+	verify {
+		assert!(MultisigAuthorities::<T>::contains_key(&authority));
+		assert_last_event::<T>(
+			Event::MultisigAuthorityCreated { authority, threshold: s as u16 }.into()
+		);
+	}
+
+	propose_authority_action {
+		let s in 2 .. T::MaxSigners::get();
+
+		let (authority, signers) = create_authority::<T>(s);
+		let action = AuthorityAction::Add(account("candidate", 0, SEED));
+		let action_hash = Pallet::<T>::action_hash(&authority, &action);
+	}: _(RawOrigin::Signed(signers[0].clone()), authority, action)
+	verify {
+		assert!(PendingActions::<T>::contains_key(action_hash));
+	}
+
+	sign_authority_action {
+		let s in 2 .. T::MaxSigners::get();
+
+		let (authority, signers) = create_authority::<T>(s);
+		let action = AuthorityAction::Add(account("candidate", 0, SEED));
+		let action_hash = Pallet::<T>::action_hash(&authority, &action);
+		Pallet::<T>::propose_authority_action(
+			RawOrigin::Signed(signers[0].clone()).into(),
+			authority,
+			action,
+		)?;
+		for signer in &signers[1..signers.len() - 1] {
+			Pallet::<T>::sign_authority_action(RawOrigin::Signed(signer.clone()).into(), action_hash)?;
+		}
+		// The last approval reaches the threshold and dispatches the action.
+		let last = signers[signers.len() - 1].clone();
+	}: _(RawOrigin::Signed(last), action_hash)
+	verify {
+		assert!(!PendingActions::<T>::contains_key(action_hash));
+		assert_last_event::<T>(Event::ActionExecuted { action_hash, result: Ok(()) }.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
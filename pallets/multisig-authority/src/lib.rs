@@ -0,0 +1,357 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Multisig Authority Pallet
+//!
+//! The Multisig Authority Pallet places a set of weighted signers behind a
+//! single authority identity, so that no single key can change the
+//! authority set on its behalf.
+//!
+//! A multisig authority is created from a list of `(AccountId, weight)`
+//! signers and a threshold. Its identity is derived from a hash of the
+//! sorted signers. Any signer can propose an authority action, adding an
+//! account to or removing it from the authority set. Signers approve the
+//! action by its hash and, once the accumulated weight reaches the
+//! threshold, the action is dispatched to the `AuthorityManager`.
+//!
+//! ### Storage
+//!
+//! - `MultisigAuthorities`: Signers and threshold of each multisig authority.
+//! - `PendingActions`: Proposed actions with their approvals so far.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `create_multisig_authority` - Register a new multisig authority.
+//! - `propose_authority_action` - Propose an authority action as a signer.
+//! - `sign_authority_action` - Approve a pending authority action.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{dispatch::DispatchResult, BoundedVec};
+pub use pallet::*;
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash, TrailingZeroInput},
+	RuntimeDebug,
+};
+use sp_std::prelude::*;
+pub use weights::WeightInfo;
+
+/// Adds and removes members of the authority set.
+pub trait AuthorityManager<AccountId> {
+	/// Add `who` to the authority set.
+	fn add_authority(who: &AccountId) -> DispatchResult;
+	/// Remove `who` from the authority set.
+	fn remove_authority(who: &AccountId) -> DispatchResult;
+}
+
+impl<AccountId> AuthorityManager<AccountId> for () {
+	fn add_authority(_who: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn remove_authority(_who: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// A change of the authority set.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AuthorityAction<AccountId> {
+	/// Add the account to the authority set.
+	Add(AccountId),
+	/// Remove the account from the authority set.
+	Remove(AccountId),
+}
+
+/// The signers of a multisig authority and the weight needed to act.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MultisigAuthority<Signers> {
+	/// The signers with their weights, sorted by account.
+	pub signers: Signers,
+	/// The approval weight needed to dispatch an action.
+	pub threshold: u16,
+}
+
+/// An authority action waiting for approvals.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingAction<AccountId, Approvals> {
+	/// The multisig authority the action was proposed for.
+	pub authority: AccountId,
+	/// The proposed action.
+	pub action: AuthorityAction<AccountId>,
+	/// The signers who approved the action.
+	pub approvals: Approvals,
+	/// The sum of the weights of the approving signers.
+	pub approved_weight: u32,
+}
+
+pub type MultisigAuthorityOf<T> = MultisigAuthority<
+	BoundedVec<(<T as frame_system::Config>::AccountId, u16), <T as Config>::MaxSigners>,
+>;
+
+pub type PendingActionOf<T> = PendingAction<
+	<T as frame_system::Config>::AccountId,
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxSigners>,
+>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Applies the approved authority actions.
+		type AuthorityManager: AuthorityManager<Self::AccountId>;
+		/// The maximum number of signers of a multisig authority.
+		#[pallet::constant]
+		type MaxSigners: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// multisig authorities stored as a map: authority id => signers
+	#[pallet::storage]
+	#[pallet::getter(fn multisig_authorities)]
+	pub type MultisigAuthorities<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, MultisigAuthorityOf<T>, OptionQuery>;
+
+	/// authority actions awaiting approval stored as a map: action hash =>
+	/// pending action
+	#[pallet::storage]
+	#[pallet::getter(fn pending_actions)]
+	pub type PendingActions<T: Config> =
+		StorageMap<_, Identity, H256, PendingActionOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A multisig authority was created.
+		MultisigAuthorityCreated { authority: T::AccountId, threshold: u16 },
+		/// A signer proposed an authority action.
+		ActionProposed {
+			authority: T::AccountId,
+			action_hash: H256,
+			action: AuthorityAction<T::AccountId>,
+		},
+		/// A signer approved a pending authority action.
+		ActionApproved { action_hash: H256, who: T::AccountId, approved_weight: u32 },
+		/// An authority action reached its threshold and was dispatched.
+		ActionExecuted { action_hash: H256, result: DispatchResult },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A multisig authority needs at least one signer
+		NoSigners,
+		/// More signers than `MaxSigners`
+		TooManySigners,
+		/// A signer is listed more than once
+		DuplicateSigner,
+		/// Signer weights must be non-zero
+		ZeroSignerWeight,
+		/// The threshold is zero or above the total signer weight
+		InvalidThreshold,
+		/// A multisig authority with the same signers exists
+		MultisigAuthorityAlreadyExists,
+		/// No multisig authority with the given id
+		MultisigAuthorityNotFound,
+		/// The caller is not a signer of the multisig authority
+		NotASigner,
+		/// The same action is already awaiting approval
+		ActionAlreadyPending,
+		/// No pending action with the given hash
+		ActionNotFound,
+		/// The caller already approved the action
+		AlreadyApproved,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a multisig authority from weighted signers. The authority
+		/// id is derived from the signers and acts once the approvals of its
+		/// signers add up to `threshold`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_multisig_authority(signers.len() as u32))]
+		pub fn create_multisig_authority(
+			origin: OriginFor<T>,
+			mut signers: Vec<(T::AccountId, u16)>,
+			threshold: u16,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(!signers.is_empty(), Error::<T>::NoSigners);
+			signers.sort_by(|a, b| a.0.cmp(&b.0));
+			ensure!(
+				signers.windows(2).all(|pair| pair[0].0 != pair[1].0),
+				Error::<T>::DuplicateSigner
+			);
+			ensure!(signers.iter().all(|(_, weight)| *weight > 0), Error::<T>::ZeroSignerWeight);
+			let total_weight: u32 = signers.iter().map(|(_, weight)| u32::from(*weight)).sum();
+			ensure!(
+				threshold > 0 && u32::from(threshold) <= total_weight,
+				Error::<T>::InvalidThreshold
+			);
+			let signers: BoundedVec<_, T::MaxSigners> =
+				signers.try_into().map_err(|_| Error::<T>::TooManySigners)?;
+
+			let authority = Self::authority_id(&signers);
+			ensure!(
+				!MultisigAuthorities::<T>::contains_key(&authority),
+				Error::<T>::MultisigAuthorityAlreadyExists
+			);
+
+			MultisigAuthorities::<T>::insert(&authority, MultisigAuthority { signers, threshold });
+
+			Self::deposit_event(Event::MultisigAuthorityCreated { authority, threshold });
+			Ok(())
+		}
+
+		/// Propose an authority action for a multisig authority the caller
+		/// signs for. The proposal counts as the caller's approval.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_authority_action(T::MaxSigners::get()))]
+		pub fn propose_authority_action(
+			origin: OriginFor<T>,
+			authority: T::AccountId,
+			action: AuthorityAction<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let multisig = MultisigAuthorities::<T>::get(&authority)
+				.ok_or(Error::<T>::MultisigAuthorityNotFound)?;
+			let weight = Self::signer_weight(&multisig, &who).ok_or(Error::<T>::NotASigner)?;
+
+			let action_hash = Self::action_hash(&authority, &action);
+			ensure!(
+				!PendingActions::<T>::contains_key(action_hash),
+				Error::<T>::ActionAlreadyPending
+			);
+
+			let mut approvals = BoundedVec::new();
+			approvals.try_push(who.clone()).map_err(|_| Error::<T>::TooManySigners)?;
+			let pending = PendingAction {
+				authority: authority.clone(),
+				action: action.clone(),
+				approvals,
+				approved_weight: weight.into(),
+			};
+
+			Self::deposit_event(Event::ActionProposed { authority, action_hash, action });
+			Self::deposit_event(Event::ActionApproved {
+				action_hash,
+				who,
+				approved_weight: pending.approved_weight,
+			});
+			Self::approve_or_execute(action_hash, pending, multisig.threshold);
+			Ok(())
+		}
+
+		/// Approve a pending authority action as a signer of its multisig
+		/// authority. The action is dispatched once the approved weight
+		/// reaches the threshold.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::sign_authority_action(T::MaxSigners::get()))]
+		pub fn sign_authority_action(origin: OriginFor<T>, action_hash: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut pending =
+				PendingActions::<T>::get(action_hash).ok_or(Error::<T>::ActionNotFound)?;
+			let multisig = MultisigAuthorities::<T>::get(&pending.authority)
+				.ok_or(Error::<T>::MultisigAuthorityNotFound)?;
+			let weight = Self::signer_weight(&multisig, &who).ok_or(Error::<T>::NotASigner)?;
+			ensure!(!pending.approvals.contains(&who), Error::<T>::AlreadyApproved);
+
+			pending
+				.approvals
+				.try_push(who.clone())
+				.map_err(|_| Error::<T>::TooManySigners)?;
+			pending.approved_weight = pending.approved_weight.saturating_add(weight.into());
+
+			Self::deposit_event(Event::ActionApproved {
+				action_hash,
+				who,
+				approved_weight: pending.approved_weight,
+			});
+			Self::approve_or_execute(action_hash, pending, multisig.threshold);
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The id of the multisig authority with the given sorted signers.
+	pub fn authority_id(signers: &[(T::AccountId, u16)]) -> T::AccountId {
+		let entropy = BlakeTwo256::hash_of(&(b"cord::multisig-authority", signers));
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// The hash identifying `action` proposed for `authority`.
+	pub fn action_hash(authority: &T::AccountId, action: &AuthorityAction<T::AccountId>) -> H256 {
+		BlakeTwo256::hash_of(&(b"cord::multisig-authority-action", authority, action))
+	}
+
+	fn signer_weight(multisig: &MultisigAuthorityOf<T>, who: &T::AccountId) -> Option<u16> {
+		multisig
+			.signers
+			.binary_search_by(|(signer, _)| signer.cmp(who))
+			.ok()
+			.map(|index| multisig.signers[index].1)
+	}
+
+	/// Store `pending`, or dispatch it if its approvals reached `threshold`.
+	fn approve_or_execute(action_hash: H256, pending: PendingActionOf<T>, threshold: u16) {
+		if pending.approved_weight < u32::from(threshold) {
+			PendingActions::<T>::insert(action_hash, pending);
+			return
+		}
+
+		PendingActions::<T>::remove(action_hash);
+		let result = match &pending.action {
+			AuthorityAction::Add(who) => T::AuthorityManager::add_authority(who),
+			AuthorityAction::Remove(who) => T::AuthorityManager::remove_authority(who),
+		};
+		Self::deposit_event(Event::ActionExecuted { action_hash, result });
+	}
+}
@@ -0,0 +1,106 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate as pallet_cord_multisig_authority;
+use frame_support::{
+	construct_runtime, derive_impl,
+	traits::{ConstU32, ConstU64},
+};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use std::cell::RefCell;
+
+pub type AccountId = u64;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		MultisigAuthority: pallet_cord_multisig_authority,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Hash = H256;
+	type RuntimeCall = RuntimeCall;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+thread_local! {
+	/// Authority actions applied by `MockAuthorityManager`.
+	pub static APPLIED_ACTIONS: RefCell<Vec<AuthorityAction<AccountId>>> = RefCell::new(Vec::new());
+}
+
+/// Records the applied actions instead of changing an authority set.
+pub struct MockAuthorityManager;
+impl AuthorityManager<AccountId> for MockAuthorityManager {
+	fn add_authority(who: &AccountId) -> DispatchResult {
+		APPLIED_ACTIONS.with(|actions| actions.borrow_mut().push(AuthorityAction::Add(*who)));
+		Ok(())
+	}
+
+	fn remove_authority(who: &AccountId) -> DispatchResult {
+		APPLIED_ACTIONS.with(|actions| actions.borrow_mut().push(AuthorityAction::Remove(*who)));
+		Ok(())
+	}
+}
+
+pub fn applied_actions() -> Vec<AuthorityAction<AccountId>> {
+	APPLIED_ACTIONS.with(|actions| actions.borrow().clone())
+}
+
+impl pallet_cord_multisig_authority::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityManager = MockAuthorityManager;
+	type MaxSigners = ConstU32<100>;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		APPLIED_ACTIONS.with(|actions| actions.borrow_mut().clear());
+	});
+	ext
+}
@@ -0,0 +1,181 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+
+/// Create a multisig authority of signers 1, 2 and 3 with weights 1, 1 and
+/// 2 that needs a weight of 3 to act.
+fn create_authority() -> AccountId {
+	assert_ok!(MultisigAuthority::create_multisig_authority(
+		RuntimeOrigin::signed(1),
+		vec![(3, 2), (1, 1), (2, 1)],
+		3
+	));
+	MultisigAuthority::authority_id(&[(1, 1), (2, 1), (3, 2)])
+}
+
+#[test]
+fn create_multisig_authority_derives_the_id_from_the_sorted_signers() {
+	new_test_ext().execute_with(|| {
+		let authority = create_authority();
+
+		let multisig = MultisigAuthority::multisig_authorities(authority).unwrap();
+		assert_eq!(multisig.signers.into_inner(), vec![(1, 1), (2, 1), (3, 2)]);
+		assert_eq!(multisig.threshold, 3);
+		System::assert_last_event(
+			Event::MultisigAuthorityCreated { authority, threshold: 3 }.into(),
+		);
+
+		assert_noop!(
+			MultisigAuthority::create_multisig_authority(
+				RuntimeOrigin::signed(2),
+				vec![(2, 1), (1, 1), (3, 2)],
+				3
+			),
+			Error::<Test>::MultisigAuthorityAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn create_multisig_authority_rejects_invalid_signers() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		assert_noop!(
+			MultisigAuthority::create_multisig_authority(origin.clone(), vec![], 1),
+			Error::<Test>::NoSigners
+		);
+		assert_noop!(
+			MultisigAuthority::create_multisig_authority(origin.clone(), vec![(1, 1), (1, 2)], 1),
+			Error::<Test>::DuplicateSigner
+		);
+		assert_noop!(
+			MultisigAuthority::create_multisig_authority(origin.clone(), vec![(1, 1), (2, 0)], 1),
+			Error::<Test>::ZeroSignerWeight
+		);
+		assert_noop!(
+			MultisigAuthority::create_multisig_authority(origin.clone(), vec![(1, 1), (2, 1)], 0),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			MultisigAuthority::create_multisig_authority(origin.clone(), vec![(1, 1), (2, 1)], 3),
+			Error::<Test>::InvalidThreshold
+		);
+		let signers = (0..101).map(|i| (i, 1)).collect();
+		assert_noop!(
+			MultisigAuthority::create_multisig_authority(origin, signers, 1),
+			Error::<Test>::TooManySigners
+		);
+	});
+}
+
+#[test]
+fn actions_are_dispatched_once_the_threshold_is_met() {
+	new_test_ext().execute_with(|| {
+		let authority = create_authority();
+		let action = AuthorityAction::Add(10);
+		let action_hash = MultisigAuthority::action_hash(&authority, &action);
+
+		assert_ok!(MultisigAuthority::propose_authority_action(
+			RuntimeOrigin::signed(1),
+			authority,
+			action.clone()
+		));
+		assert_ok!(MultisigAuthority::sign_authority_action(RuntimeOrigin::signed(2), action_hash));
+		assert_eq!(MultisigAuthority::pending_actions(action_hash).unwrap().approved_weight, 2);
+		assert!(applied_actions().is_empty());
+
+		assert_ok!(MultisigAuthority::sign_authority_action(RuntimeOrigin::signed(3), action_hash));
+		assert_eq!(MultisigAuthority::pending_actions(action_hash), None);
+		assert_eq!(applied_actions(), vec![action]);
+		System::assert_last_event(Event::ActionExecuted { action_hash, result: Ok(()) }.into());
+	});
+}
+
+#[test]
+fn a_signer_above_the_threshold_acts_alone() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigAuthority::create_multisig_authority(
+			RuntimeOrigin::signed(1),
+			vec![(1, 1), (2, 3)],
+			3
+		));
+		let authority = MultisigAuthority::authority_id(&[(1, 1), (2, 3)]);
+
+		assert_ok!(MultisigAuthority::propose_authority_action(
+			RuntimeOrigin::signed(2),
+			authority,
+			AuthorityAction::Remove(10)
+		));
+		assert_eq!(applied_actions(), vec![AuthorityAction::Remove(10)]);
+	});
+}
+
+#[test]
+fn approvals_are_restricted_to_signers() {
+	new_test_ext().execute_with(|| {
+		let authority = create_authority();
+		let action = AuthorityAction::Remove(10);
+		let action_hash = MultisigAuthority::action_hash(&authority, &action);
+
+		assert_noop!(
+			MultisigAuthority::propose_authority_action(
+				RuntimeOrigin::signed(4),
+				authority,
+				action.clone()
+			),
+			Error::<Test>::NotASigner
+		);
+		assert_noop!(
+			MultisigAuthority::propose_authority_action(
+				RuntimeOrigin::signed(1),
+				99,
+				action.clone()
+			),
+			Error::<Test>::MultisigAuthorityNotFound
+		);
+		assert_noop!(
+			MultisigAuthority::sign_authority_action(RuntimeOrigin::signed(1), action_hash),
+			Error::<Test>::ActionNotFound
+		);
+
+		assert_ok!(MultisigAuthority::propose_authority_action(
+			RuntimeOrigin::signed(1),
+			authority,
+			action.clone()
+		));
+		assert_noop!(
+			MultisigAuthority::propose_authority_action(
+				RuntimeOrigin::signed(2),
+				authority,
+				action
+			),
+			Error::<Test>::ActionAlreadyPending
+		);
+		assert_noop!(
+			MultisigAuthority::sign_authority_action(RuntimeOrigin::signed(1), action_hash),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_noop!(
+			MultisigAuthority::sign_authority_action(RuntimeOrigin::signed(4), action_hash),
+			Error::<Test>::NotASigner
+		);
+	});
+}
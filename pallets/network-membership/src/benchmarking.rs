@@ -20,7 +20,7 @@
 
 use super::*;
 use crate::Pallet;
-use frame_benchmarking::{account, benchmarks};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 
 const SEED: u32 = 0;
@@ -29,8 +29,21 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Pre-populate the members map with `count` members without expiry.
+fn add_members<T: Config>(count: u32) {
+	for i in 0..count {
+		let member: T::AccountId = account("member", i, SEED);
+		Pallet::<T>::add_member_and_schedule_expiry(&member, false);
+	}
+}
+
 benchmarks! {
-	where_clause { where <T::RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin: Clone }
+	where_clause {
+		where
+			<T::RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin: Clone,
+			T: Send + Sync,
+			T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	}
 	nominate {
 
 	   let authority: T::AccountId = account("authority", 0, SEED);
@@ -60,6 +73,52 @@ benchmarks! {
 		assert_last_event::<T>(Event::MembershipRevoked { member: authority }.into());
 	}
 
+	renew_membership {
+		let authority: T::AccountId = account("authority", 1, SEED);
+
+		let auth_origin = RawOrigin::Root;
+		Pallet::<T>::nominate(auth_origin.clone().into(), authority.clone(), true ).expect("Should add authority");
+		let expiry_block = frame_system::Pallet::<T>::block_number() + T::MembershipPeriod::get() + 1u32.into();
+	}: _(auth_origin, authority.clone(), expiry_block)
+	verify {
+		assert_last_event::<T>(Event::MembershipRenewed { member: authority }.into());
+	}
+
+	is_member {
+		let m in 1 .. 1_000;
+
+		add_members::<T>(m);
+		let member: T::AccountId = account("member", m - 1, SEED);
+	}: {
+		assert!(Pallet::<T>::is_member_inner(&member));
+	}
+	verify {
+		assert_eq!(Members::<T>::count(), m);
+	}
+
+	set_rate_limit {
+		let member: T::AccountId = account("member", 0, SEED);
+	}: _(RawOrigin::Root, member.clone(), 10)
+	verify {
+		assert_eq!(RateLimits::<T>::get(&member), Some(10));
+		assert_last_event::<T>(Event::RateLimitSet { who: member, limit: 10 }.into());
+	}
+
+	rate_limiter_validate {
+		let caller: T::AccountId = whitelisted_caller();
+		let block_number = frame_system::Pallet::<T>::block_number();
+
+		RateLimits::<T>::insert(&caller, 2);
+		ExtrinsicCount::<T>::insert(&caller, (block_number, 1));
+		let call: T::RuntimeCall = frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+		let info = DispatchInfo::default();
+	}: {
+		assert!(RateLimiter::<T>::new().validate(&caller, &call, &info, 0).is_ok());
+	}
+	verify {
+		assert_eq!(Pallet::<T>::extrinsics_in_block(&caller), 1);
+	}
+
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -22,6 +22,7 @@
 pub mod weights;
 
 use codec::{Decode, Encode};
+use cord_primitives::CordTransactionError;
 use frame_support::dispatch::DispatchInfo;
 pub use pallet::*;
 
@@ -58,7 +59,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	pub(crate) type CordAccountOf<T> = <T as frame_system::Config>::AccountId;
 
@@ -72,6 +73,10 @@ pub mod pallet {
 		type MembershipPeriod: Get<BlockNumberFor<Self>>;
 		#[pallet::constant]
 		type MaxMembersPerBlock: Get<u32>;
+		/// Maximum number of extrinsics an account can have included in a
+		/// single block, unless overridden through `set_rate_limit`
+		#[pallet::constant]
+		type MaxExtrinsicsPerBlock: Get<u32>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -110,6 +115,19 @@ pub mod pallet {
 	pub(crate) type MembershipBlacklist<T: Config> =
 		StorageMap<_, Blake2_128Concat, CordAccountOf<T>, ()>;
 
+	/// maps an account to the last block it submitted an extrinsic in and
+	/// the number of extrinsics it submitted in that block, cleared at the
+	/// start of the next block
+	#[pallet::storage]
+	pub type ExtrinsicCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, CordAccountOf<T>, (BlockNumberFor<T>, u32), ValueQuery>;
+
+	/// maps an account to its per-block extrinsic limit override
+	#[pallet::storage]
+	#[pallet::getter(fn rate_limits)]
+	pub type RateLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, CordAccountOf<T>, u32, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -123,6 +141,8 @@ pub mod pallet {
 		MembershipRevoked { member: CordAccountOf<T> },
 		/// A membership renew request
 		MembershipRenewalRequested { member: CordAccountOf<T> },
+		/// The per-block extrinsic limit of an account was set
+		RateLimitSet { who: CordAccountOf<T>, limit: u32 },
 	}
 
 	#[pallet::error]
@@ -141,15 +161,18 @@ pub mod pallet {
 		MembershipExpired,
 		/// Max members limit exceeded
 		MaxMembersExceededForTheBlock,
+		/// The expiry block is not in the future
+		InvalidExpiryBlock,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let weight = Self::clear_extrinsic_counts();
 			if n > BlockNumberFor::<T>::zero() {
-				Self::renew_or_expire_memberships(n)
+				weight.saturating_add(Self::renew_or_expire_memberships(n))
 			} else {
-				Weight::zero()
+				weight
 			}
 		}
 	}
@@ -244,6 +267,62 @@ pub mod pallet {
 			Self::deposit_event(Event::MembershipRevoked { member });
 			Ok(())
 		}
+
+		/// Renew a membership until `expiry_block`. Only root or council
+		/// origin can perform this action.
+		///
+		/// Unlike `renew`, which extends the membership by the membership
+		/// period once it expires, the new expiry applies immediately and
+		/// also turns a non-expiring membership into an expiring one.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::renew_membership())]
+		pub fn renew_membership(
+			origin: OriginFor<T>,
+			member: CordAccountOf<T>,
+			expiry_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			let member_details =
+				<Members<T>>::get(&member).ok_or(Error::<T>::MembershipNotFound)?;
+			ensure!(
+				expiry_block > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiryBlock
+			);
+
+			if !member_details.expire_on.is_zero() {
+				MembershipsExpiresOn::<T>::mutate(member_details.expire_on, |members| {
+					members.retain(|x| x != &member)
+				});
+			}
+			MembershipsExpiresOn::<T>::try_mutate(expiry_block, |members| {
+				members
+					.try_push(member.clone())
+					.map_err(|_| Error::<T>::MaxMembersExceededForTheBlock)
+			})?;
+			Members::<T>::insert(&member, MemberData::new(expiry_block));
+
+			Self::deposit_event(Event::MembershipRenewed { member });
+			Ok(())
+		}
+
+		/// Override the number of extrinsics `who` can have included in a
+		/// single block. Only root or council origin can perform this
+		/// action.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_rate_limit())]
+		pub fn set_rate_limit(
+			origin: OriginFor<T>,
+			who: CordAccountOf<T>,
+			limit: u32,
+		) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			RateLimits::<T>::insert(&who, limit);
+
+			Self::deposit_event(Event::RateLimitSet { who, limit });
+			Ok(())
+		}
 	}
 }
 
@@ -252,7 +331,7 @@ impl<T: Config> Pallet<T> {
 		if expires {
 			let block_number = frame_system::pallet::Pallet::<T>::block_number();
 			let expire_on = block_number + T::MembershipPeriod::get();
-			Members::<T>::insert(member, MemberData { expire_on });
+			Members::<T>::insert(member, MemberData::new(expire_on));
 
 			// the member has just been created, increment its provider
 			let _ = frame_system::Pallet::<T>::inc_providers(member);
@@ -264,7 +343,7 @@ impl<T: Config> Pallet<T> {
 			});
 		} else {
 			let expire_on = BlockNumberFor::<T>::zero();
-			Members::<T>::insert(member, MemberData { expire_on });
+			Members::<T>::insert(member, MemberData::new(expire_on));
 			// the member has just been created, increment its provider
 			let _ = frame_system::Pallet::<T>::inc_providers(member);
 		}
@@ -275,7 +354,7 @@ impl<T: Config> Pallet<T> {
 		expire_on: BlockNumberFor<T>,
 	) {
 		let schedule_expiry = expire_on + T::MembershipPeriod::get();
-		Members::<T>::insert(&member, MemberData { expire_on: schedule_expiry });
+		Members::<T>::insert(&member, MemberData::new(schedule_expiry));
 		let _ = MembershipsExpiresOn::<T>::try_mutate(schedule_expiry, |members| {
 			members.try_push(member).map_err(|_| Error::<T>::MaxMembersExceededForTheBlock)
 		});
@@ -311,6 +390,34 @@ impl<T: Config> Pallet<T> {
 
 		total_weight
 	}
+	/// drop the extrinsic counts of the previous block
+	///
+	/// The map only holds the signers of the previous block, which the block
+	/// weight and length limits bound, so it is cleared in one go.
+	fn clear_extrinsic_counts() -> Weight {
+		let removed = ExtrinsicCount::<T>::clear(u32::MAX, None);
+		T::DbWeight::get().reads_writes(removed.loops.into(), removed.unique.into())
+	}
+
+	/// the number of extrinsics `who` can have included in a single block
+	pub fn rate_limit(who: &CordAccountOf<T>) -> u32 {
+		RateLimits::<T>::get(who).unwrap_or_else(T::MaxExtrinsicsPerBlock::get)
+	}
+
+	/// the number of extrinsics `who` already had included in the current
+	/// block
+	///
+	/// Transaction pool validation runs on the state of the previous block,
+	/// before its counts are cleared, so they are told apart by block number.
+	pub fn extrinsics_in_block(who: &CordAccountOf<T>) -> u32 {
+		let (block, count) = ExtrinsicCount::<T>::get(who);
+		if block == frame_system::Pallet::<T>::block_number() {
+			count
+		} else {
+			0
+		}
+	}
+
 	/// check if identity is member
 	pub fn is_member_inner(member: &CordAccountOf<T>) -> bool {
 		Members::<T>::contains_key(member)
@@ -406,15 +513,100 @@ where
 		_info: &DispatchInfoOf<Self::Call>,
 		_len: usize,
 	) -> TransactionValidity {
-		if <Members<T>>::contains_key(who) {
-			Ok(ValidTransaction {
-				priority: 0,
-				longevity: TransactionLongevity::max_value(),
-				propagate: true,
-				..Default::default()
-			})
+		let now = frame_system::Pallet::<T>::block_number();
+		match <Members<T>>::get(who) {
+			None => Err(CordTransactionError::NotAuthorized)?,
+			// A membership whose expiry could not be scheduled outlives its
+			// expiry block.
+			Some(member) if !member.expire_on.is_zero() && member.expire_on < now =>
+				Err(CordTransactionError::MembershipExpired)?,
+			Some(_) => {},
+		}
+		Ok(ValidTransaction {
+			priority: 0,
+			longevity: TransactionLongevity::max_value(),
+			propagate: true,
+			..Default::default()
+		})
+	}
+}
+
+/// The `RateLimiter` struct.
+///
+/// Rejects an extrinsic once its signer already had `rate_limit` extrinsics
+/// included in the current block. Validation reports the rejection as
+/// `CordTransactionError::RateLimitExceeded`. When the block is built, the
+/// rejection is reported as exhausted resources instead, so the block author
+/// keeps the extrinsic in the pool for a later block rather than dropping it.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, scale_info::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct RateLimiter<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for RateLimiter<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "RateLimiter")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> RateLimiter<T> {
+	/// Create new `SignedExtension` to throttle extrinsic submission.
+	pub fn new() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+}
+
+/// Implementation of the `SignedExtension` trait for the `RateLimiter`
+/// struct.
+impl<T: Config + Send + Sync> SignedExtension for RateLimiter<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+{
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+	const IDENTIFIER: &'static str = "RateLimiter";
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		if self.validate(who, call, info, len).is_err() {
+			return Err(InvalidTransaction::ExhaustsResources.into())
+		}
+
+		let count = Pallet::<T>::extrinsics_in_block(who);
+		ExtrinsicCount::<T>::insert(
+			who,
+			(frame_system::Pallet::<T>::block_number(), count.saturating_add(1)),
+		);
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if Pallet::<T>::extrinsics_in_block(who) < Pallet::<T>::rate_limit(who) {
+			Ok(ValidTransaction::default())
 		} else {
-			Err(InvalidTransaction::Call.into())
+			Err(CordTransactionError::RateLimitExceeded.into())
 		}
 	}
 }
@@ -77,6 +77,7 @@ impl frame_system::Config for Test {
 parameter_types! {
 	pub const MembershipPeriod: BlockNumberFor<Test> = 5;
 	pub const MaxMembersPerBlock: u32 = 5;
+	pub const MaxExtrinsicsPerBlock: u32 = 3;
 }
 
 impl pallet_network_membership::Config for Test {
@@ -84,6 +85,7 @@ impl pallet_network_membership::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MembershipPeriod = MembershipPeriod;
 	type MaxMembersPerBlock = MaxMembersPerBlock;
+	type MaxExtrinsicsPerBlock = MaxExtrinsicsPerBlock;
 	type WeightInfo = ();
 }
 
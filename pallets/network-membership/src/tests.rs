@@ -15,12 +15,20 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{mock::*, Error, Event, MemberData};
+use crate::{mock::*, CheckNetworkMembership, Error, Event, MemberData, RateLimiter};
+use cord_primitives::CordTransactionError;
 
-use frame_support::{assert_err, assert_ok, error::BadOrigin};
+use frame_support::{assert_err, assert_ok, dispatch::DispatchInfo, error::BadOrigin};
 use frame_system::RawOrigin;
 use network_membership::traits::*;
-use sp_runtime::traits::IsMember;
+use sp_runtime::{
+	traits::{IsMember, SignedExtension},
+	transaction_validity::InvalidTransaction,
+};
+
+fn remark_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
 
 #[test]
 fn test_genesis_build() {
@@ -29,7 +37,7 @@ fn test_genesis_build() {
 		// Verify state
 		assert_eq!(
 			NetworkMembership::members(AccountId::new([11u8; 32])),
-			Some(MemberData { expire_on: 5 })
+			Some(MemberData::new(5))
 		);
 		assert_eq!(NetworkMembership::members_count(), 1);
 	});
@@ -53,7 +61,7 @@ fn test_add_member_request() {
 		// This ensures that the account was successfully added
 		assert_eq!(
 			NetworkMembership::members(AccountId::new([13u8; 32])),
-			Some(MemberData { expire_on: 1 + MembershipPeriod::get() })
+			Some(MemberData::new(1 + MembershipPeriod::get()))
 		);
 
 		assert_eq!(NetworkMembership::members_count(), 2);
@@ -126,7 +134,7 @@ fn test_renew_membership() {
 		// This ensures that the account was successfully added
 		assert_eq!(
 			NetworkMembership::members(AccountId::new([13u8; 32])),
-			Some(MemberData { expire_on: 6 + MembershipPeriod::get() })
+			Some(MemberData::new(6 + MembershipPeriod::get()))
 		);
 
 		assert_eq!(NetworkMembership::members_count(), 1);
@@ -272,9 +280,154 @@ fn test_renew_membership_again_should_fail() {
 		// This ensures that the account was successfully added
 		assert_eq!(
 			NetworkMembership::members(AccountId::new([13u8; 32])),
-			Some(MemberData { expire_on: 6 + MembershipPeriod::get() })
+			Some(MemberData::new(6 + MembershipPeriod::get()))
 		);
 
 		assert_eq!(NetworkMembership::members_count(), 1);
 	});
 }
+
+#[test]
+fn test_renew_membership_until_expiry_block() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(NetworkMembership::nominate(
+			RawOrigin::Root.into(),
+			AccountId::new([13u8; 32]),
+			true
+		));
+
+		run_to_block(2);
+		assert_err!(
+			NetworkMembership::renew_membership(
+				RawOrigin::Root.into(),
+				AccountId::new([13u8; 32]),
+				2
+			),
+			Error::<Test>::InvalidExpiryBlock
+		);
+		assert_err!(
+			NetworkMembership::renew_membership(
+				RawOrigin::Root.into(),
+				AccountId::new([14u8; 32]),
+				3
+			),
+			Error::<Test>::MembershipNotFound
+		);
+
+		// Bring the expiry forward from block 6 to block 3.
+		assert_ok!(NetworkMembership::renew_membership(
+			RawOrigin::Root.into(),
+			AccountId::new([13u8; 32]),
+			3
+		));
+		System::assert_has_event(RuntimeEvent::NetworkMembership(Event::MembershipRenewed {
+			member: AccountId::new([13u8; 32]),
+		}));
+		assert_eq!(
+			NetworkMembership::members(AccountId::new([13u8; 32])),
+			Some(MemberData::new(3))
+		);
+
+		run_to_block(3);
+		assert!(!NetworkMembership::is_member(&AccountId::new([13u8; 32])));
+		System::assert_has_event(RuntimeEvent::NetworkMembership(Event::MembershipExpired {
+			member: AccountId::new([13u8; 32]),
+		}));
+
+		// The expiry initially scheduled at block 6 no longer applies.
+		assert!(crate::MembershipsExpiresOn::<Test>::get(6).is_empty());
+	});
+}
+
+#[test]
+fn test_rate_limiter_throttles_within_a_block() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let who = AccountId::new([11u8; 32]);
+		let call = remark_call();
+		let info = DispatchInfo::default();
+
+		for _ in 0..MaxExtrinsicsPerBlock::get() {
+			assert_ok!(RateLimiter::<Test>::new().pre_dispatch(&who, &call, &info, 0));
+		}
+		assert_eq!(NetworkMembership::extrinsics_in_block(&who), 3);
+		assert_eq!(
+			RateLimiter::<Test>::new().validate(&who, &call, &info, 0),
+			Err(CordTransactionError::RateLimitExceeded.into())
+		);
+		assert_eq!(
+			RateLimiter::<Test>::new().pre_dispatch(&who, &call, &info, 0),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+
+		// The count starts over in the next block.
+		run_to_block(2);
+		assert!(!crate::ExtrinsicCount::<Test>::contains_key(&who));
+		assert_eq!(NetworkMembership::extrinsics_in_block(&who), 0);
+		assert_ok!(RateLimiter::<Test>::new().pre_dispatch(&who, &call, &info, 0));
+	});
+}
+
+#[test]
+fn test_check_network_membership_reports_cord_error_codes() {
+	new_test_ext().execute_with(|| {
+		run_to_block(2);
+		let member = AccountId::new([11u8; 32]);
+		let stranger = AccountId::new([12u8; 32]);
+		let stale = AccountId::new([13u8; 32]);
+		let call = remark_call();
+		let info = DispatchInfo::default();
+
+		assert_ok!(CheckNetworkMembership::<Test>::new().validate(&member, &call, &info, 0));
+		assert_eq!(
+			CheckNetworkMembership::<Test>::new().validate(&stranger, &call, &info, 0),
+			Err(InvalidTransaction::Custom(1).into())
+		);
+
+		// A membership left in place past its expiry block.
+		crate::Members::<Test>::insert(&stale, MemberData::new(1));
+		assert_eq!(
+			CheckNetworkMembership::<Test>::new().validate(&stale, &call, &info, 0),
+			Err(CordTransactionError::MembershipExpired.into())
+		);
+		assert_eq!(
+			CordTransactionError::from_code(3),
+			Some(CordTransactionError::MembershipExpired)
+		);
+	});
+}
+
+#[test]
+fn test_set_rate_limit() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let who = AccountId::new([11u8; 32]);
+		let other = AccountId::new([12u8; 32]);
+		let call = remark_call();
+		let info = DispatchInfo::default();
+
+		assert_err!(
+			NetworkMembership::set_rate_limit(RuntimeOrigin::signed(other.clone()), who.clone(), 1),
+			BadOrigin
+		);
+
+		assert_ok!(NetworkMembership::set_rate_limit(RawOrigin::Root.into(), who.clone(), 1));
+		System::assert_last_event(RuntimeEvent::NetworkMembership(Event::RateLimitSet {
+			who: who.clone(),
+			limit: 1,
+		}));
+		assert_eq!(NetworkMembership::rate_limit(&who), 1);
+		assert_eq!(NetworkMembership::rate_limit(&other), MaxExtrinsicsPerBlock::get());
+
+		assert_ok!(RateLimiter::<Test>::new().pre_dispatch(&who, &call, &info, 0));
+		assert_eq!(
+			RateLimiter::<Test>::new().validate(&who, &call, &info, 0),
+			Err(CordTransactionError::RateLimitExceeded.into())
+		);
+		// Other accounts keep the default limit.
+		assert_ok!(RateLimiter::<Test>::new().pre_dispatch(&other, &call, &info, 0));
+		assert_ok!(RateLimiter::<Test>::new().pre_dispatch(&other, &call, &info, 0));
+	});
+}
@@ -42,8 +42,22 @@ pub struct RuntimeDispatchWeightInfo<Weight = frame_support::weights::Weight> {
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct MemberData<BlockNumber: Decode + Encode + TypeInfo> {
 	pub expire_on: BlockNumber,
+	pub meta: AuthorMeta,
 }
 
+impl<BlockNumber: Decode + Encode + TypeInfo> MemberData<BlockNumber> {
+	/// The data of a member expiring at `expire_on`, with default metadata.
+	pub fn new(expire_on: BlockNumber) -> Self {
+		Self { expire_on, meta: AuthorMeta::default() }
+	}
+}
+
+/// Metadata kept for a network member as an extrinsic author. A field added
+/// later starts out at its default through a storage migration.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AuthorMeta {}
+
 // #[cfg(feature = "std")]
 // impl<BlockNumber: Decode + Encode + TypeInfo> Serialize for
 // MemberData<BlockNumber> { 	fn serialize<S>(&self, serializer: S) ->
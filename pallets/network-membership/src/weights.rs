@@ -23,6 +23,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `smohan-dev-host`, CPU: `AMD EPYC 7B12`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! The weights of `renew_membership`, `is_member`, `set_rate_limit` and `rate_limiter_validate` are hand-written
+//! placeholders until the pallet is benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -52,6 +55,10 @@ pub trait WeightInfo {
 	fn nominate() -> Weight;
 	fn renew() -> Weight;
 	fn revoke() -> Weight;
+	fn renew_membership() -> Weight;
+	fn is_member(m: u32, ) -> Weight;
+	fn set_rate_limit() -> Weight;
+	fn rate_limiter_validate() -> Weight;
 }
 
 /// Weights for `pallet_network_membership` using the CORD node and recommended hardware.
@@ -102,6 +109,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `NetworkMembership::Members` (r:1 w:1)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::MembershipsExpiresOn` (r:2 w:2)
+	/// Proof: `NetworkMembership::MembershipsExpiresOn` (`max_values`: None, `max_size`: Some(32022), added: 34497, mode: `MaxEncodedLen`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn renew_membership() -> Weight {
+		Weight::from_parts(20_540_000, 69984)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NetworkMembership::Members` (r:1 w:0)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 1000]`.
+	/// Hand-written placeholder, not a benchmark result.
+	fn is_member(m: u32, ) -> Weight {
+		Weight::from_parts(4_672_183, 3517)
+			.saturating_add(Weight::from_parts(312, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `NetworkMembership::RateLimits` (r:0 w:1)
+	/// Proof: `NetworkMembership::RateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn set_rate_limit() -> Weight {
+		Weight::from_parts(8_530_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::ExtrinsicCount` (r:1 w:0)
+	/// Proof: `NetworkMembership::ExtrinsicCount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::RateLimits` (r:1 w:0)
+	/// Proof: `NetworkMembership::RateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn rate_limiter_validate() -> Weight {
+		Weight::from_parts(7_310_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -151,4 +193,39 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `NetworkMembership::Members` (r:1 w:1)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::MembershipsExpiresOn` (r:2 w:2)
+	/// Proof: `NetworkMembership::MembershipsExpiresOn` (`max_values`: None, `max_size`: Some(32022), added: 34497, mode: `MaxEncodedLen`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn renew_membership() -> Weight {
+		Weight::from_parts(20_540_000, 69984)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NetworkMembership::Members` (r:1 w:0)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 1000]`.
+	/// Hand-written placeholder, not a benchmark result.
+	fn is_member(m: u32, ) -> Weight {
+		Weight::from_parts(4_672_183, 3517)
+			.saturating_add(Weight::from_parts(312, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `NetworkMembership::RateLimits` (r:0 w:1)
+	/// Proof: `NetworkMembership::RateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn set_rate_limit() -> Weight {
+		Weight::from_parts(8_530_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::ExtrinsicCount` (r:1 w:0)
+	/// Proof: `NetworkMembership::ExtrinsicCount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::RateLimits` (r:1 w:0)
+	/// Proof: `NetworkMembership::RateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn rate_limiter_validate() -> Weight {
+		Weight::from_parts(7_310_000, 3521)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
 }
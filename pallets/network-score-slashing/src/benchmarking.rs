@@ -0,0 +1,66 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const MAX_OFFENDERS: u32 = 1_000;
+
+benchmarks! {
+	// Offenders start at `MinScore`, so every slash also suspends them.
+	on_offence {
+		let n in 1 .. MAX_OFFENDERS;
+
+		let offenders = (0..n)
+			.map(|i| OffenceDetails {
+				offender: (account("offender", i, SEED), ()),
+				reporters: vec![],
+			})
+			.collect::<Vec<OffenceDetails<T::AccountId, (T::AccountId, ())>>>();
+		for offender in &offenders {
+			pallet_cord_reliability_score::ReliabilityScore::<T>::insert(
+				&offender.offender.0,
+				T::MinScore::get(),
+			);
+		}
+	}: {
+		<Pallet<T> as OnOffenceHandler<_, _, Weight>>::on_offence(
+			&offenders,
+			&[],
+			SlashStrategy::BlackList,
+			0,
+		);
+	}
+	verify {
+		let slashed = T::MinScore::get()
+			.saturating_mul(FixedU128::from(Pallet::<T>::slash_fraction(n).left_from_one()));
+		assert!(offenders.iter().all(|offender| {
+			pallet_cord_reliability_score::Pallet::<T>::get_score(&offender.offender.0) == slashed
+		}));
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
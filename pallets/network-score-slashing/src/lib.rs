@@ -0,0 +1,136 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+//! # Network Score Slashing Pallet
+//!
+//! The Network Score Slashing Pallet reduces the reliability score of
+//! validators reported for equivocation. It is an `OnOffenceHandler` of the
+//! offences pallet and acts on BABE and GRANDPA equivocation reports, the
+//! offences reported with the `BlackList` strategy.
+//!
+//! The slash fraction is proportional to the number of concurrent
+//! offenders: every offender adds `SlashPerOffender`, up to the whole score.
+//! The fraction is deducted from the offender `ReliabilityScore`, and a
+//! score falling below `MinScore` suspends the validator.
+//!
+//! ### Integration
+//!
+//! The pallet keeps no storage of its own and updates the scores kept by
+//! `pallet_cord_reliability_score`. It is meant to be chained with the authority
+//! membership offence handler, e.g. `(AuthorityMembership,
+//! NetworkScoreSlashing)`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use frame_support::weights::Weight;
+pub use pallet::*;
+use pallet_offences::{traits::OnOffenceHandler, SlashStrategy};
+use sp_runtime::{traits::Saturating, FixedU128, Perbill};
+use sp_staking::{offence::OffenceDetails, SessionIndex};
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_cord_reliability_score::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Fraction of the score slashed for every concurrent offender.
+		#[pallet::constant]
+		type SlashPerOffender: Get<Perbill>;
+		/// Validators slashed below this score are suspended.
+		#[pallet::constant]
+		type MinScore: Get<FixedU128>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The reliability score of an equivocating validator was slashed.
+		ScoreSlashed { who: T::AccountId, slash_fraction: Perbill, score: FixedU128 },
+		/// The reliability score of a validator fell below `MinScore`.
+		ValidatorSuspended { who: T::AccountId, score: FixedU128 },
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The fraction of the score slashed when `offenders` validators
+	/// equivocate concurrently.
+	pub fn slash_fraction(offenders: u32) -> Perbill {
+		let parts = T::SlashPerOffender::get().deconstruct().saturating_mul(offenders);
+		Perbill::from_parts(parts.min(Perbill::one().deconstruct()))
+	}
+
+	/// Deduct `slash_fraction` from the score of `who`, suspending the
+	/// validator once the score falls below `MinScore`.
+	fn slash(who: &T::AccountId, slash_fraction: Perbill) {
+		let score = pallet_cord_reliability_score::Pallet::<T>::get_score(who)
+			.saturating_mul(FixedU128::from(slash_fraction.left_from_one()));
+		pallet_cord_reliability_score::ReliabilityScore::<T>::insert(who, score);
+
+		Self::deposit_event(Event::ScoreSlashed { who: who.clone(), slash_fraction, score });
+		if score < T::MinScore::get() {
+			Self::deposit_event(Event::ValidatorSuspended { who: who.clone(), score });
+		}
+	}
+}
+
+impl<T: Config, FullIdentification>
+	OnOffenceHandler<T::AccountId, (T::AccountId, FullIdentification), Weight> for Pallet<T>
+{
+	fn on_offence(
+		offenders: &[OffenceDetails<T::AccountId, (T::AccountId, FullIdentification)>],
+		_slash_fraction: &[Perbill],
+		strategy: SlashStrategy,
+		_slash_session: SessionIndex,
+	) -> Weight {
+		// Only equivocations are slashed, offline validators are disconnected.
+		if strategy != SlashStrategy::BlackList {
+			return Weight::zero();
+		}
+
+		let count = offenders.len() as u32;
+		let slash_fraction = Self::slash_fraction(count);
+		for offender in offenders {
+			Self::slash(&offender.offender.0, slash_fraction);
+		}
+
+		<T as Config>::WeightInfo::on_offence(count)
+	}
+}
@@ -0,0 +1,82 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+use crate::mock::*;
+
+fn report(offenders: &[AccountId], strategy: SlashStrategy) {
+	let details = offenders
+		.iter()
+		.map(|who| OffenceDetails { offender: (*who, ()), reporters: vec![] })
+		.collect::<Vec<_>>();
+	<NetworkScoreSlashing as OnOffenceHandler<_, _, Weight>>::on_offence(
+		&details,
+		&[],
+		strategy,
+		0,
+	);
+}
+
+#[test]
+fn slash_fraction_grows_with_concurrent_offenders() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(NetworkScoreSlashing::slash_fraction(1), Perbill::from_percent(10));
+		assert_eq!(NetworkScoreSlashing::slash_fraction(3), Perbill::from_percent(30));
+		assert_eq!(NetworkScoreSlashing::slash_fraction(20), Perbill::one());
+	});
+}
+
+#[test]
+fn equivocation_slashes_the_score() {
+	new_test_ext().execute_with(|| {
+		report(&[1, 2], SlashStrategy::BlackList);
+
+		let score = FixedU128::from_rational(4, 5);
+		assert_eq!(ReliabilityScore::get_score(&1), score);
+		assert_eq!(ReliabilityScore::get_score(&2), score);
+		assert_eq!(ReliabilityScore::get_score(&3), FixedU128::one());
+		System::assert_last_event(
+			Event::ScoreSlashed { who: 2, slash_fraction: Perbill::from_percent(20), score }.into(),
+		);
+	});
+}
+
+#[test]
+fn validators_slashed_below_min_score_are_suspended() {
+	new_test_ext().execute_with(|| {
+		report(&[1], SlashStrategy::BlackList);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::NetworkScoreSlashing(Event::ValidatorSuspended { .. })
+		)));
+
+		report(&[1, 2, 3], SlashStrategy::BlackList);
+		let score = FixedU128::from_rational(63, 100);
+		assert_eq!(ReliabilityScore::get_score(&1), score);
+		System::assert_has_event(Event::ValidatorSuspended { who: 1, score }.into());
+	});
+}
+
+#[test]
+fn offline_reports_are_ignored() {
+	new_test_ext().execute_with(|| {
+		report(&[1], SlashStrategy::Disconnect);
+
+		assert_eq!(ReliabilityScore::reliability_score(1), None);
+		assert!(System::events().is_empty());
+	});
+}
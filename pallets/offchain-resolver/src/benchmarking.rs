@@ -0,0 +1,74 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::BoundedVec;
+use sp_runtime::app_crypto::sr25519;
+use sp_std::vec;
+
+use cord_utilities::traits::GenerateBenchmarkOrigin;
+
+const CALLER_SEED: u32 = 0;
+const SUBJECT_SEED: u32 = 1;
+
+fn generate_pointer() -> (CidOf, ResolverUrlOf) {
+	(
+		BoundedVec::try_from(vec![b'a'; MAX_CID_LENGTH as usize])
+			.expect("BoundedVec creation should not fail."),
+		BoundedVec::try_from(vec![b'b'; MAX_RESOLVER_URL_LENGTH as usize])
+			.expect("BoundedVec creation should not fail."),
+	)
+}
+
+benchmarks! {
+	where_clause {
+		where
+		T::AccountId: From<sr25519::Public>,
+		T::DidIdentifier: From<T::AccountId>,
+		T::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::DidIdentifier>,
+	}
+
+	set_document_pointer {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let identifier: DidIdentifierOf<T> = account("subject", 0, SUBJECT_SEED);
+		let origin = T::EnsureOrigin::generate_origin(caller, identifier.clone());
+		let (cid, resolver_url) = generate_pointer();
+	}: _<T::RuntimeOrigin>(origin, cid, resolver_url)
+	verify {
+		assert!(DocumentPointers::<T>::get(&identifier).is_some());
+	}
+
+	remove_document_pointer {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let identifier: DidIdentifierOf<T> = account("subject", 0, SUBJECT_SEED);
+		let origin = T::EnsureOrigin::generate_origin(caller, identifier.clone());
+		let (cid, resolver_url) = generate_pointer();
+
+		Pallet::<T>::set_document_pointer(origin.clone(), cid, resolver_url).expect("Should set the document pointer.");
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert!(DocumentPointers::<T>::get(&identifier).is_none());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,281 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Offchain Resolver Pallet
+//!
+//! Full DID documents are too large to be kept on chain. The Offchain
+//! Resolver Pallet stores, for every DID, a pointer to where its document is
+//! kept off chain: the content identifier of the document and the URL of a
+//! resolver serving it.
+//!
+//! The offchain worker fetches the documents that are not cached yet from
+//! the resolver URL followed by the content identifier, checks them against
+//! the digest of the DID document anchored on chain, and caches the matching
+//! ones in the persistent offchain local storage. A cached document is only
+//! served while its digest is still the anchored one, so replacing the
+//! anchored document makes the worker fetch it again.
+//!
+//! ### Storage
+//!
+//! - `DocumentPointers`: The pointer to the off-chain document of every DID.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `set_document_pointer` - Point the DID of the origin at an off-chain
+//!   document.
+//! - `remove_document_pointer` - Remove the pointer of the DID of the origin.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod traits;
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::{Decode, Encode};
+pub use pallet::*;
+use sp_runtime::offchain::{http, Duration, StorageKind};
+use sp_std::vec::Vec;
+pub use traits::DocumentDigest;
+pub use types::DidDocumentPointer;
+pub use weights::WeightInfo;
+
+/// Maximum length of the content identifier of a document.
+pub const MAX_CID_LENGTH: u32 = 64;
+
+/// Maximum length of the URL of a resolver.
+pub const MAX_RESOLVER_URL_LENGTH: u32 = 128;
+
+/// Prefix of the offchain local storage keys the documents are cached under.
+pub const DOCUMENT_CACHE_PREFIX: &[u8] = b"cord::offchain-resolver::";
+
+/// Time the offchain worker waits for a resolver, in milliseconds.
+const FETCH_TIMEOUT_MS: u64 = 5_000;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use cord_utilities::traits::CallSources;
+	use frame_support::{pallet_prelude::*, traits::StorageVersion};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Hash;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type HashOf<T> = <T as frame_system::Config>::Hash;
+	pub type DidIdentifierOf<T> = <T as Config>::DidIdentifier;
+	/// Content identifier of an off-chain document.
+	pub type CidOf = BoundedVec<u8, ConstU32<MAX_CID_LENGTH>>;
+	/// URL of a resolver.
+	pub type ResolverUrlOf = BoundedVec<u8, ConstU32<MAX_RESOLVER_URL_LENGTH>>;
+	pub type DidDocumentPointerOf = DidDocumentPointer<CidOf, ResolverUrlOf>;
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// pointers to the off-chain documents by DID identifier
+	#[pallet::storage]
+	#[pallet::getter(fn document_pointers)]
+	pub type DocumentPointers<T> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidDocumentPointerOf>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type EnsureOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = <Self as Config>::OriginSuccess,
+		>;
+		type OriginSuccess: CallSources<AccountIdOf<Self>, DidIdentifierOf<Self>>;
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The type of a DID identifier.
+		type DidIdentifier: Parameter + MaxEncodedLen;
+		/// The digests of the DID documents anchored on chain.
+		type DocumentDigest: DocumentDigest<Self::DidIdentifier, HashOf<Self>>;
+		/// The maximum number of documents the offchain worker fetches in a
+		/// block.
+		#[pallet::constant]
+		type MaxFetchesPerBlock: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A DID has been pointed at an off-chain document.
+		DocumentPointerSet { identifier: DidIdentifierOf<T>, cid: CidOf },
+		/// The pointer of a DID has been removed.
+		DocumentPointerRemoved { identifier: DidIdentifierOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The DID has no document pointer.
+		PointerNotFound,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(_now: BlockNumberFor<T>) {
+			Self::resolve_documents();
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Point the DID specified in the origin at the off-chain document
+		/// with content identifier `cid`, served by `resolver_url`. Replaces
+		/// the previous pointer of the DID, if any.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_document_pointer())]
+		pub fn set_document_pointer(
+			origin: OriginFor<T>,
+			cid: CidOf,
+			resolver_url: ResolverUrlOf,
+		) -> DispatchResult {
+			let identifier = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			DocumentPointers::<T>::insert(
+				&identifier,
+				DidDocumentPointerOf { cid: cid.clone(), resolver_url },
+			);
+			Self::deposit_event(Event::<T>::DocumentPointerSet { identifier, cid });
+
+			Ok(())
+		}
+
+		/// Remove the document pointer of the DID specified in the origin.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_document_pointer())]
+		pub fn remove_document_pointer(origin: OriginFor<T>) -> DispatchResult {
+			let identifier = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(DocumentPointers::<T>::contains_key(&identifier), Error::<T>::PointerNotFound);
+			DocumentPointers::<T>::remove(&identifier);
+			Self::deposit_event(Event::<T>::DocumentPointerRemoved { identifier });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The cached off-chain document of the provided DID, if its digest is
+		/// the one anchored on chain.
+		///
+		/// Reads the offchain local storage, so it can only be called in the
+		/// offchain context.
+		pub fn resolve_did_offchain(identifier: &DidIdentifierOf<T>) -> Option<Vec<u8>> {
+			let digest = T::DocumentDigest::document_digest(identifier)?;
+			Self::cached_document(identifier, &digest)
+		}
+
+		/// The document of `identifier` cached with digest `digest`, if any.
+		fn cached_document(identifier: &DidIdentifierOf<T>, digest: &HashOf<T>) -> Option<Vec<u8>> {
+			let cached = sp_io::offchain::local_storage_get(
+				StorageKind::PERSISTENT,
+				&cache_key(identifier),
+			)?;
+			let (cached_digest, document) =
+				<(HashOf<T>, Vec<u8>)>::decode(&mut &cached[..]).ok()?;
+
+			(&cached_digest == digest).then_some(document)
+		}
+
+		/// Fetch and cache the documents whose anchored digest is not cached
+		/// yet, up to `MaxFetchesPerBlock` of them.
+		fn resolve_documents() {
+			let pending = DocumentPointers::<T>::iter()
+				.filter_map(|(identifier, pointer)| {
+					let digest = T::DocumentDigest::document_digest(&identifier)?;
+					Self::cached_document(&identifier, &digest)
+						.is_none()
+						.then_some((identifier, pointer, digest))
+				})
+				.take(T::MaxFetchesPerBlock::get() as usize);
+
+			for (identifier, pointer, digest) in pending {
+				let document = match Self::fetch_document(&pointer) {
+					Ok(document) => document,
+					Err(e) => {
+						log::debug!(
+							target: "runtime::offchain-resolver",
+							"Skipping a DID document: {}",
+							e
+						);
+						continue
+					},
+				};
+				if T::Hashing::hash(&document) != digest {
+					log::debug!(
+						target: "runtime::offchain-resolver",
+						"Skipping a DID document which does not match its anchored digest"
+					);
+					continue
+				}
+
+				sp_io::offchain::local_storage_set(
+					StorageKind::PERSISTENT,
+					&cache_key(&identifier),
+					&(digest, document).encode(),
+				);
+			}
+		}
+
+		/// Fetch the document `pointer` points at.
+		fn fetch_document(pointer: &DidDocumentPointerOf) -> Result<Vec<u8>, &'static str> {
+			let url = [&pointer.resolver_url[..], &pointer.cid[..]].concat();
+			let url = sp_std::str::from_utf8(&url).map_err(|_| "the document URL is not UTF-8")?;
+			let deadline =
+				sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+
+			let pending = http::Request::get(url)
+				.deadline(deadline)
+				.send()
+				.map_err(|_| "failed to request the document")?;
+			let response = pending
+				.try_wait(deadline)
+				.map_err(|_| "the document request timed out")?
+				.map_err(|_| "the document request failed")?;
+			if response.code != 200 {
+				return Err("unexpected document response status")
+			}
+
+			Ok(response.body().collect::<Vec<u8>>())
+		}
+	}
+}
+
+/// The offchain local storage key the document of `identifier` is cached
+/// under.
+fn cache_key<DidIdentifier: Encode>(identifier: &DidIdentifier) -> Vec<u8> {
+	[DOCUMENT_CACHE_PREFIX, &identifier.encode()[..]].concat()
+}
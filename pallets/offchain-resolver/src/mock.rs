@@ -0,0 +1,124 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+use crate as pallet_cord_offchain_resolver;
+use crate::{Config, DocumentDigest};
+use cord_utilities::mock::{mock_origin, SubjectId};
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
+	BuildStorage, MultiSignature,
+};
+use sp_std::vec::Vec;
+
+type Hash = sp_core::H256;
+type Signature = MultiSignature;
+type AccountPublic = <Signature as Verify>::Signer;
+type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
+pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test{
+		System: frame_system,
+		OffchainResolver: pallet_cord_offchain_resolver,
+		MockOrigin: mock_origin,
+	}
+);
+
+parameter_types! {
+	pub const SS58Prefix: u8 = 29;
+}
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = Hash;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<2>;
+}
+
+pub(crate) type TestDidIdentifier = SubjectId;
+pub(crate) type TestDidCreator = AccountId;
+pub(crate) type TestOwnerOrigin =
+	mock_origin::EnsureDoubleOrigin<TestDidCreator, TestDidIdentifier>;
+pub(crate) type TestOriginSuccess = mock_origin::DoubleOrigin<TestDidCreator, TestDidIdentifier>;
+
+parameter_types! {
+	pub static AnchoredDigests: Vec<(TestDidIdentifier, Hash)> = Vec::new();
+}
+
+/// Serves the digests in `AnchoredDigests` as the anchored DID documents.
+pub struct TestDocumentDigests;
+impl DocumentDigest<TestDidIdentifier, Hash> for TestDocumentDigests {
+	fn document_digest(identifier: &TestDidIdentifier) -> Option<Hash> {
+		AnchoredDigests::get()
+			.into_iter()
+			.find_map(|(anchored, digest)| (&anchored == identifier).then_some(digest))
+	}
+}
+
+impl Config for Test {
+	type EnsureOrigin = TestOwnerOrigin;
+	type OriginSuccess = TestOriginSuccess;
+	type RuntimeEvent = RuntimeEvent;
+	type DidIdentifier = TestDidIdentifier;
+	type DocumentDigest = TestDocumentDigests;
+	type MaxFetchesPerBlock = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+impl mock_origin::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type AccountId = AccountId;
+	type SubjectId = SubjectId;
+}
+
+#[allow(dead_code)]
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	AnchoredDigests::set(Vec::new());
+	let mut ext = sp_io::TestExternalities::new(t);
+	#[cfg(feature = "runtime-benchmarks")]
+	let keystore = sp_keystore::testing::MemoryKeystore::new();
+	#[cfg(feature = "runtime-benchmarks")]
+	ext.register_extension(sp_keystore::KeystoreExt(sp_std::sync::Arc::new(keystore)));
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
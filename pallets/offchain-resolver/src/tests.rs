@@ -0,0 +1,129 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
+use frame_support::{assert_noop, assert_ok, pallet_prelude::Hooks, BoundedVec};
+use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	AccountId32,
+};
+
+pub(crate) const ACCOUNT_00: TestDidCreator = AccountId32::new([1u8; 32]);
+pub(crate) const DID_00: TestDidIdentifier = SubjectId(ACCOUNT_00);
+pub(crate) const DOCUMENT_00: &[u8] = br#"{"id":"did:cord:00","verificationMethod":[]}"#;
+pub(crate) const DOCUMENT_01: &[u8] = br#"{"id":"did:cord:00","service":[]}"#;
+const CID: &[u8] = b"bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku";
+const RESOLVER_URL: &[u8] = b"https://ipfs.io/ipfs/";
+
+fn set_pointer(identifier: TestDidIdentifier) {
+	assert_ok!(Pallet::<Test>::set_document_pointer(
+		DoubleOrigin(ACCOUNT_00, identifier).into(),
+		BoundedVec::try_from(CID.to_vec()).expect("The CID fits the max length."),
+		BoundedVec::try_from(RESOLVER_URL.to_vec()).expect("The URL fits the max length."),
+	));
+}
+
+fn anchor(identifier: TestDidIdentifier, document: &[u8]) {
+	AnchoredDigests::mutate(|digests| {
+		digests.retain(|(anchored, _)| anchored != &identifier);
+		digests.push((identifier, BlakeTwo256::hash(document)));
+	});
+}
+
+/// Test externalities whose resolver answers the pointer with `document`.
+fn offchain_ext(document: &[u8]) -> sp_io::TestExternalities {
+	let (offchain, offchain_state) = testing::TestOffchainExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+
+	offchain_state.write().expect_request(testing::PendingRequest {
+		method: "GET".into(),
+		uri: sp_std::str::from_utf8(&[RESOLVER_URL, CID].concat()).unwrap().into(),
+		response: Some(document.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+	ext
+}
+
+#[test]
+fn set_document_pointer_works() {
+	new_test_ext().execute_with(|| {
+		assert!(Pallet::<Test>::document_pointers(&DID_00).is_none());
+
+		set_pointer(DID_00);
+
+		let pointer = Pallet::<Test>::document_pointers(&DID_00).expect("The pointer is set.");
+		assert_eq!(&pointer.cid[..], CID);
+		assert_eq!(&pointer.resolver_url[..], RESOLVER_URL);
+		System::assert_last_event(
+			Event::<Test>::DocumentPointerSet { identifier: DID_00, cid: pointer.cid }.into(),
+		);
+	})
+}
+
+#[test]
+fn remove_document_pointer_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::remove_document_pointer(DoubleOrigin(ACCOUNT_00, DID_00).into()),
+			Error::<Test>::PointerNotFound
+		);
+
+		set_pointer(DID_00);
+		assert_ok!(Pallet::<Test>::remove_document_pointer(
+			DoubleOrigin(ACCOUNT_00, DID_00).into()
+		));
+
+		assert!(Pallet::<Test>::document_pointers(&DID_00).is_none());
+		System::assert_last_event(
+			Event::<Test>::DocumentPointerRemoved { identifier: DID_00 }.into(),
+		);
+	})
+}
+
+#[test]
+fn offchain_worker_caches_the_anchored_document() {
+	offchain_ext(DOCUMENT_00).execute_with(|| {
+		set_pointer(DID_00);
+		anchor(DID_00, DOCUMENT_00);
+		assert!(Pallet::<Test>::resolve_did_offchain(&DID_00).is_none());
+
+		Pallet::<Test>::offchain_worker(1);
+		assert_eq!(Pallet::<Test>::resolve_did_offchain(&DID_00), Some(DOCUMENT_00.to_vec()));
+
+		// The cache no longer resolves once another document is anchored.
+		anchor(DID_00, DOCUMENT_01);
+		assert!(Pallet::<Test>::resolve_did_offchain(&DID_00).is_none());
+	})
+}
+
+#[test]
+fn offchain_worker_rejects_a_document_not_matching_the_anchored_digest() {
+	offchain_ext(DOCUMENT_01).execute_with(|| {
+		set_pointer(DID_00);
+		anchor(DID_00, DOCUMENT_00);
+
+		Pallet::<Test>::offchain_worker(1);
+		assert!(Pallet::<Test>::resolve_did_offchain(&DID_00).is_none());
+	})
+}
@@ -0,0 +1,32 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Where the DID document of a DID is stored off chain.
+#[derive(Clone, Encode, Decode, RuntimeDebug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct DidDocumentPointer<Cid, ResolverUrl> {
+	/// The content identifier of the document.
+	pub cid: Cid,
+	/// The URL prefix the document is fetched from, such as
+	/// `https://ipfs.io/ipfs/`. The offchain worker requests it followed by
+	/// the `cid`.
+	pub resolver_url: ResolverUrl,
+}
@@ -55,6 +55,7 @@ type OpaqueTimeSlot = Vec<u8>;
 /// A type alias for a report identifier.
 type ReportIdOf<T> = <T as frame_system::Config>::Hash;
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SlashStrategy {
 	Disconnect,
 	BlackList,
@@ -142,8 +143,13 @@ where
 			SlashStrategy::BlackList
 		};
 
+		// The slash fraction is the same for all concurrent offenders.
+		let new_fraction = offence.slash_fraction(concurrent_offenders.len() as u32);
+		let slash_perbill: Vec<_> = (0..concurrent_offenders.len()).map(|_| new_fraction).collect();
+
 		T::OnOffenceHandler::on_offence(
 			&concurrent_offenders,
+			&slash_perbill,
 			slash_strategy,
 			offence.session_index(),
 		);
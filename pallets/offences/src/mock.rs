@@ -48,9 +48,14 @@ impl<Reporter, Offender> pallet_offences::OnOffenceHandler<Reporter, Offender, W
 {
 	fn on_offence(
 		_offenders: &[OffenceDetails<Reporter, Offender>],
+		slash_fraction: &[Perbill],
 		_strategy: SlashStrategy,
 		_offence_session: SessionIndex,
 	) -> Weight {
+		OnOffencePerbill::mutate(|f| {
+			*f = slash_fraction.to_vec();
+		});
+
 		OffenceWeight::get()
 	}
 }
@@ -19,8 +19,11 @@
 #![cfg(test)]
 
 use super::*;
-use crate::mock::{new_test_ext, offence_reports, Offence, Offences, RuntimeEvent, System, KIND};
+use crate::mock::{
+	new_test_ext, offence_reports, Offence, Offences, OnOffencePerbill, RuntimeEvent, System, KIND,
+};
 use frame_system::{EventRecord, Phase};
+use sp_runtime::Perbill;
 
 #[test]
 fn should_report_an_authority_and_trigger_on_offence_and_add_to_blacklist() {
@@ -234,3 +237,19 @@ fn should_properly_count_offences() {
 		);
 	});
 }
+
+#[test]
+fn should_pass_the_slash_fraction_of_every_concurrent_offender() {
+	new_test_ext().execute_with(|| {
+		// given
+		let time_slot = 42;
+		let offence = Offence { validator_set_count: 5, time_slot, offenders: vec![5, 9] };
+
+		// when
+		Offences::report_offence(vec![], offence).unwrap();
+
+		// then
+		// 5% base plus 2 out of 5 validators offending.
+		assert_eq!(OnOffencePerbill::get(), vec![Perbill::from_percent(45); 2]);
+	});
+}
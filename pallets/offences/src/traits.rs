@@ -16,6 +16,8 @@
 // You should have received a copy of the GNU General Public License
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
+use frame_support::weights::Weight;
+use sp_runtime::Perbill;
 use sp_staking::{offence::OffenceDetails, SessionIndex};
 
 use crate::SlashStrategy;
@@ -24,7 +26,25 @@ pub trait OnOffenceHandler<Reporter, Offender, Res> {
 	// Required method
 	fn on_offence(
 		offenders: &[OffenceDetails<Reporter, Offender>],
+		slash_fraction: &[Perbill],
 		slash_strategy: SlashStrategy,
 		session: SessionIndex,
 	) -> Res;
 }
+
+/// Forwards offences to both handlers, adding up the consumed weight.
+impl<Reporter, Offender, A, B> OnOffenceHandler<Reporter, Offender, Weight> for (A, B)
+where
+	A: OnOffenceHandler<Reporter, Offender, Weight>,
+	B: OnOffenceHandler<Reporter, Offender, Weight>,
+{
+	fn on_offence(
+		offenders: &[OffenceDetails<Reporter, Offender>],
+		slash_fraction: &[Perbill],
+		slash_strategy: SlashStrategy,
+		session: SessionIndex,
+	) -> Weight {
+		A::on_offence(offenders, slash_fraction, slash_strategy, session)
+			.saturating_add(B::on_offence(offenders, slash_fraction, slash_strategy, session))
+	}
+}
@@ -0,0 +1,91 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::{traits::Get, BoundedVec};
+use sp_std::vec::Vec;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// A pallet name of `MaxPalletNameLen` bytes, distinct for every `index`.
+fn pallet_id<T: Config>(index: u32) -> PalletIdOf<T> {
+	let mut name = index.to_le_bytes().to_vec();
+	name.resize(T::MaxPalletNameLen::get() as usize, b'_');
+	name.try_into().expect("The name should not exceed `MaxPalletNameLen`.")
+}
+
+/// Pause `count` pallets, without the checks of `pause_pallet`.
+fn pause_pallets<T: Config>(count: u32) {
+	let paused: Vec<_> = (0..count).map(pallet_id::<T>).collect();
+	PausedPallets::<T>::put(
+		BoundedVec::try_from(paused).expect("The pallets should not exceed `MaxPaused`."),
+	);
+}
+
+benchmarks! {
+	where_clause {
+		where
+			<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+	}
+
+	pause_pallet {
+		pause_pallets::<T>(T::MaxPaused::get() - 1);
+		let pallet_id = pallet_id::<T>(T::MaxPaused::get());
+		let origin =
+			T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, pallet_id.clone())
+	verify {
+		let block = frame_system::Pallet::<T>::block_number();
+		assert_last_event::<T>(Event::PalletPaused { pallet_id, block }.into());
+	}
+
+	resume_pallet {
+		pause_pallets::<T>(T::MaxPaused::get());
+		let pallet_id = pallet_id::<T>(T::MaxPaused::get() - 1);
+		let origin =
+			T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, pallet_id.clone())
+	verify {
+		let block = frame_system::Pallet::<T>::block_number();
+		assert_last_event::<T>(Event::PalletResumed { pallet_id, block }.into());
+	}
+
+	ensure_not_paused {
+		// The call is of no paused pallet, so every paused pallet is compared.
+		pause_pallets::<T>(T::MaxPaused::get());
+		let call: <T as frame_system::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+	}: {
+		assert!(EnsureNotPaused::<T>::contains(&call));
+	}
+	verify {
+		assert_eq!(PausedPallets::<T>::get().len() as u32, T::MaxPaused::get());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
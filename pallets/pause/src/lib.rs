@@ -0,0 +1,187 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Pause Pallet
+//!
+//! The Pause Pallet is an emergency circuit breaker. During an incident, a
+//! supermajority of the technical committee can pause the calls of an
+//! individual pallet without a full governance referendum, and resume them
+//! once the incident is resolved.
+//!
+//! Pallets are identified by their name in the runtime, such as `Schema`.
+//! `EnsureNotPaused` is a call filter that rejects the calls of paused
+//! pallets; a runtime uses it as, or as part of, its base call filter. The
+//! pallets of `UnpausablePallets` and this pallet itself can not be paused,
+//! so that the chain keeps producing blocks and paused pallets can be
+//! resumed.
+//!
+//! ### Storage
+//!
+//! - `PausedPallets`: The names of the paused pallets.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `pause_pallet` - Pause the calls of a pallet.
+//! - `resume_pallet` - Resume the calls of a paused pallet.
+//!
+//! ### Public Functions
+//!
+//! - `is_paused` - Whether the pallet of a name is paused.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Contains, GetCallMetadata, PalletInfoAccess};
+use sp_std::marker::PhantomData;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of the name of a pallet in the runtime.
+	pub type PalletIdOf<T> = BoundedVec<u8, <T as Config>::MaxPalletNameLen>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin that can pause and resume pallets.
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The pallets that can not be paused.
+		type UnpausablePallets: Contains<PalletIdOf<Self>>;
+		/// The most pallets paused at the same time.
+		#[pallet::constant]
+		type MaxPaused: Get<u32>;
+		/// The longest name of a pallet.
+		#[pallet::constant]
+		type MaxPalletNameLen: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// names of the paused pallets
+	#[pallet::storage]
+	#[pallet::getter(fn paused_pallets)]
+	pub type PausedPallets<T: Config> =
+		StorageValue<_, BoundedVec<PalletIdOf<T>, T::MaxPaused>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The calls of a pallet were paused.
+		PalletPaused { pallet_id: PalletIdOf<T>, block: BlockNumberFor<T> },
+		/// The calls of a paused pallet were resumed.
+		PalletResumed { pallet_id: PalletIdOf<T>, block: BlockNumberFor<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The pallet is already paused.
+		AlreadyPaused,
+		/// The pallet is not paused.
+		NotPaused,
+		/// The pallet can not be paused.
+		Unpausable,
+		/// `MaxPaused` pallets are already paused.
+		TooManyPaused,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pause the calls of the pallet named `pallet_id`, until it is
+		/// resumed.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::pause_pallet())]
+		pub fn pause_pallet(origin: OriginFor<T>, pallet_id: PalletIdOf<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(
+				pallet_id.as_slice() != <Self as PalletInfoAccess>::name().as_bytes() &&
+					!T::UnpausablePallets::contains(&pallet_id),
+				Error::<T>::Unpausable
+			);
+
+			PausedPallets::<T>::try_mutate(|paused| {
+				ensure!(!paused.contains(&pallet_id), Error::<T>::AlreadyPaused);
+				paused.try_push(pallet_id.clone()).map_err(|_| Error::<T>::TooManyPaused)
+			})?;
+
+			let block = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::PalletPaused { pallet_id, block });
+			Ok(())
+		}
+
+		/// Resume the calls of the paused pallet named `pallet_id`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::resume_pallet())]
+		pub fn resume_pallet(origin: OriginFor<T>, pallet_id: PalletIdOf<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			PausedPallets::<T>::try_mutate(|paused| {
+				let index =
+					paused.iter().position(|id| id == &pallet_id).ok_or(Error::<T>::NotPaused)?;
+				paused.remove(index);
+				Ok::<_, Error<T>>(())
+			})?;
+
+			let block = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::PalletResumed { pallet_id, block });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether the pallet named `pallet_name` is paused.
+	pub fn is_paused(pallet_name: &str) -> bool {
+		PausedPallets::<T>::get()
+			.iter()
+			.any(|id| id.as_slice() == pallet_name.as_bytes())
+	}
+}
+
+/// A call filter that rejects the calls of paused pallets.
+pub struct EnsureNotPaused<T>(PhantomData<T>);
+
+impl<T: Config, Call: GetCallMetadata> Contains<Call> for EnsureNotPaused<T> {
+	fn contains(call: &Call) -> bool {
+		!Pallet::<T>::is_paused(call.get_call_metadata().pallet_name)
+	}
+}
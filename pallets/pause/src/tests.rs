@@ -0,0 +1,91 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+fn pallet_id(name: &[u8]) -> PalletIdOf<Test> {
+	name.to_vec().try_into().unwrap()
+}
+
+fn remark() -> RuntimeCall {
+	frame_system::Call::remark { remark: vec![] }.into()
+}
+
+#[test]
+fn paused_pallets_are_filtered_until_resumed() {
+	new_test_ext().execute_with(|| {
+		assert!(EnsureNotPaused::<Test>::contains(&remark()));
+
+		assert_noop!(
+			Pause::pause_pallet(RuntimeOrigin::signed(1), pallet_id(b"System")),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Pause::pause_pallet(RuntimeOrigin::root(), pallet_id(b"System")));
+		System::assert_last_event(
+			Event::PalletPaused { pallet_id: pallet_id(b"System"), block: 1 }.into(),
+		);
+		assert!(Pause::is_paused("System"));
+		assert!(!EnsureNotPaused::<Test>::contains(&remark()));
+		assert_noop!(
+			Pause::pause_pallet(RuntimeOrigin::root(), pallet_id(b"System")),
+			Error::<Test>::AlreadyPaused
+		);
+
+		System::set_block_number(5);
+		assert_ok!(Pause::resume_pallet(RuntimeOrigin::root(), pallet_id(b"System")));
+		System::assert_last_event(
+			Event::PalletResumed { pallet_id: pallet_id(b"System"), block: 5 }.into(),
+		);
+		assert!(EnsureNotPaused::<Test>::contains(&remark()));
+		assert_noop!(
+			Pause::resume_pallet(RuntimeOrigin::root(), pallet_id(b"System")),
+			Error::<Test>::NotPaused
+		);
+	});
+}
+
+#[test]
+fn unpausable_pallets_can_not_be_paused() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pause::pause_pallet(RuntimeOrigin::root(), pallet_id(b"Pause")),
+			Error::<Test>::Unpausable
+		);
+		assert_noop!(
+			Pause::pause_pallet(RuntimeOrigin::root(), pallet_id(b"Timestamp")),
+			Error::<Test>::Unpausable
+		);
+	});
+}
+
+#[test]
+fn pause_pallet_is_bounded_by_max_paused() {
+	new_test_ext().execute_with(|| {
+		for i in 0..MAX_PAUSED {
+			assert_ok!(Pause::pause_pallet(RuntimeOrigin::root(), pallet_id(&[b'A' + i as u8])));
+		}
+		assert_noop!(
+			Pause::pause_pallet(RuntimeOrigin::root(), pallet_id(b"Schema")),
+			Error::<Test>::TooManyPaused
+		);
+		assert_eq!(Pause::paused_pallets().len() as u32, MAX_PAUSED);
+	});
+}
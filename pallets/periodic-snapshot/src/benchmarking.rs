@@ -0,0 +1,74 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	publish_snapshot {
+		let origin =
+			T::OperatorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let operator = T::OperatorOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		// A snapshot already exists, so it is read and replaced as the latest.
+		let interval = SnapshotInterval::<T>::get();
+		LatestSnapshot::<T>::put(interval);
+		let block = interval + interval;
+		frame_system::Pallet::<T>::set_block_number(block + 1u32.into());
+
+		let ipfs_cid: IpfsCid = vec![b'b'; MAX_CID_LENGTH as usize]
+			.try_into()
+			.expect("The CID should not exceed `MAX_CID_LENGTH`.");
+		let meta = SnapshotMeta {
+			block,
+			state_root: frame_system::Pallet::<T>::parent_hash(),
+			ipfs_cid: ipfs_cid.clone(),
+		};
+	}: _<T::RuntimeOrigin>(origin, meta.clone())
+	verify {
+		assert_eq!(Pallet::<T>::get_latest_snapshot(), Some(meta));
+		assert_last_event::<T>(Event::SnapshotPublished { operator, block, ipfs_cid }.into());
+	}
+
+	set_snapshot_interval {
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let interval = T::DefaultSnapshotInterval::get() + 1u32.into();
+	}: _<T::RuntimeOrigin>(origin, interval)
+	verify {
+		assert_eq!(SnapshotInterval::<T>::get(), interval);
+		assert_last_event::<T>(Event::SnapshotIntervalSet { interval }.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
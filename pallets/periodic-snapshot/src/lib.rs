@@ -0,0 +1,194 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Periodic Snapshot Pallet
+//!
+//! The Periodic Snapshot Pallet lets new nodes sync from a recent state
+//! snapshot instead of from genesis. Every `SnapshotInterval` blocks, an
+//! operator generates a snapshot of the state off-chain, publishes it on
+//! IPFS and records its metadata on-chain: the block of the snapshot, the
+//! state root to verify it against, and its IPFS CID.
+//!
+//! Snapshots are published in order: the block of a snapshot must be a
+//! multiple of the interval, past, and later than that of the latest
+//! snapshot. Governance sets the interval.
+//!
+//! ### Storage
+//!
+//! - `SnapshotInterval`: The blocks between two snapshots.
+//! - `Snapshots`: The metadata of every snapshot, by its block.
+//! - `LatestSnapshot`: The block of the latest snapshot.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `publish_snapshot` - Publish the metadata of a snapshot.
+//! - `set_snapshot_interval` - Set the blocks between two snapshots.
+//!
+//! ### Public Functions
+//!
+//! - `get_latest_snapshot` - The metadata of the latest snapshot.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+pub use types::{IpfsCid, SnapshotMeta, MAX_CID_LENGTH};
+pub use weights::WeightInfo;
+
+use sp_runtime::traits::Zero;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of the metadata of a snapshot.
+	pub type SnapshotMetaOf<T> = SnapshotMeta<BlockNumberFor<T>, <T as frame_system::Config>::Hash>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin of the operators that publish snapshots.
+		type OperatorOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+		/// The origin that sets the snapshot interval.
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The blocks between two snapshots until governance sets an
+		/// interval.
+		#[pallet::constant]
+		type DefaultSnapshotInterval: Get<BlockNumberFor<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// blocks between two snapshots
+	#[pallet::storage]
+	#[pallet::getter(fn snapshot_interval)]
+	pub type SnapshotInterval<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery, T::DefaultSnapshotInterval>;
+
+	/// metadata of the snapshots by their block
+	#[pallet::storage]
+	#[pallet::getter(fn snapshots)]
+	pub type Snapshots<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, SnapshotMetaOf<T>, OptionQuery>;
+
+	/// block of the latest snapshot
+	#[pallet::storage]
+	#[pallet::getter(fn latest_snapshot)]
+	pub type LatestSnapshot<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An operator published the snapshot of `block`.
+		SnapshotPublished { operator: T::AccountId, block: BlockNumberFor<T>, ipfs_cid: IpfsCid },
+		/// The snapshot interval was set.
+		SnapshotIntervalSet { interval: BlockNumberFor<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The block of the snapshot is not a multiple of the snapshot
+		/// interval.
+		NotSnapshotBlock,
+		/// The block of the snapshot is not in the past.
+		FutureSnapshot,
+		/// The block of the snapshot is not later than that of the latest
+		/// snapshot.
+		OutdatedSnapshot,
+		/// The snapshot interval is zero.
+		ZeroInterval,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Publish the metadata of the snapshot of `meta.block`, generated
+		/// off-chain by the operator.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::publish_snapshot())]
+		pub fn publish_snapshot(origin: OriginFor<T>, meta: SnapshotMetaOf<T>) -> DispatchResult {
+			let operator = T::OperatorOrigin::ensure_origin(origin)?;
+			let interval = SnapshotInterval::<T>::get();
+			ensure!(
+				!meta.block.is_zero() && !interval.is_zero() && (meta.block % interval).is_zero(),
+				Error::<T>::NotSnapshotBlock
+			);
+			ensure!(
+				meta.block < frame_system::Pallet::<T>::block_number(),
+				Error::<T>::FutureSnapshot
+			);
+			ensure!(
+				LatestSnapshot::<T>::get().map_or(true, |latest| meta.block > latest),
+				Error::<T>::OutdatedSnapshot
+			);
+
+			let block = meta.block;
+			let ipfs_cid = meta.ipfs_cid.clone();
+			Snapshots::<T>::insert(block, meta);
+			LatestSnapshot::<T>::put(block);
+
+			Self::deposit_event(Event::SnapshotPublished { operator, block, ipfs_cid });
+			Ok(())
+		}
+
+		/// Set the blocks between two snapshots. Snapshots already published
+		/// are kept.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_snapshot_interval())]
+		pub fn set_snapshot_interval(
+			origin: OriginFor<T>,
+			interval: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(!interval.is_zero(), Error::<T>::ZeroInterval);
+
+			SnapshotInterval::<T>::put(interval);
+
+			Self::deposit_event(Event::SnapshotIntervalSet { interval });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The metadata of the latest snapshot, if any was published.
+	pub fn get_latest_snapshot() -> Option<SnapshotMetaOf<T>> {
+		LatestSnapshot::<T>::get().and_then(Snapshots::<T>::get)
+	}
+}
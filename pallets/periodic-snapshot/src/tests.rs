@@ -0,0 +1,121 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+fn meta(block: u64) -> SnapshotMetaOf<Test> {
+	SnapshotMeta {
+		block,
+		state_root: H256::repeat_byte(block as u8),
+		ipfs_cid: b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+			.to_vec()
+			.try_into()
+			.unwrap(),
+	}
+}
+
+#[test]
+fn publish_snapshot_records_the_latest_snapshot() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(PeriodicSnapshot::get_latest_snapshot(), None);
+		System::set_block_number(2 * SNAPSHOT_INTERVAL + 1);
+
+		assert_noop!(
+			PeriodicSnapshot::publish_snapshot(RuntimeOrigin::signed(2), meta(SNAPSHOT_INTERVAL)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PeriodicSnapshot::publish_snapshot(
+			RuntimeOrigin::signed(1),
+			meta(SNAPSHOT_INTERVAL)
+		));
+		assert_ok!(PeriodicSnapshot::publish_snapshot(
+			RuntimeOrigin::signed(1),
+			meta(2 * SNAPSHOT_INTERVAL)
+		));
+		System::assert_last_event(
+			Event::SnapshotPublished {
+				operator: 1,
+				block: 2 * SNAPSHOT_INTERVAL,
+				ipfs_cid: meta(0).ipfs_cid,
+			}
+			.into(),
+		);
+		assert_eq!(PeriodicSnapshot::get_latest_snapshot(), Some(meta(2 * SNAPSHOT_INTERVAL)));
+		assert_eq!(PeriodicSnapshot::snapshots(SNAPSHOT_INTERVAL), Some(meta(SNAPSHOT_INTERVAL)));
+	});
+}
+
+#[test]
+fn publish_snapshot_requires_a_later_past_snapshot_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2 * SNAPSHOT_INTERVAL);
+
+		assert_noop!(
+			PeriodicSnapshot::publish_snapshot(
+				RuntimeOrigin::signed(1),
+				meta(SNAPSHOT_INTERVAL + 1)
+			),
+			Error::<Test>::NotSnapshotBlock
+		);
+		assert_noop!(
+			PeriodicSnapshot::publish_snapshot(RuntimeOrigin::signed(1), meta(0)),
+			Error::<Test>::NotSnapshotBlock
+		);
+		assert_noop!(
+			PeriodicSnapshot::publish_snapshot(
+				RuntimeOrigin::signed(1),
+				meta(2 * SNAPSHOT_INTERVAL)
+			),
+			Error::<Test>::FutureSnapshot
+		);
+
+		assert_ok!(PeriodicSnapshot::publish_snapshot(
+			RuntimeOrigin::signed(1),
+			meta(SNAPSHOT_INTERVAL)
+		));
+		assert_noop!(
+			PeriodicSnapshot::publish_snapshot(RuntimeOrigin::signed(1), meta(SNAPSHOT_INTERVAL)),
+			Error::<Test>::OutdatedSnapshot
+		);
+	});
+}
+
+#[test]
+fn set_snapshot_interval_changes_the_snapshot_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(SNAPSHOT_INTERVAL);
+
+		assert_noop!(
+			PeriodicSnapshot::set_snapshot_interval(RuntimeOrigin::signed(1), 10),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PeriodicSnapshot::set_snapshot_interval(RuntimeOrigin::root(), 0),
+			Error::<Test>::ZeroInterval
+		);
+		assert_ok!(PeriodicSnapshot::set_snapshot_interval(RuntimeOrigin::root(), 10));
+		System::assert_last_event(Event::SnapshotIntervalSet { interval: 10 }.into());
+
+		assert_ok!(PeriodicSnapshot::publish_snapshot(RuntimeOrigin::signed(1), meta(10)));
+		assert_eq!(PeriodicSnapshot::latest_snapshot(), Some(10));
+	});
+}
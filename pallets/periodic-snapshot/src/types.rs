@@ -0,0 +1,43 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The longest IPFS CID of a snapshot, in bytes.
+pub const MAX_CID_LENGTH: u32 = 64;
+
+/// The IPFS CID a snapshot is published under.
+pub type IpfsCid = BoundedVec<u8, ConstU32<MAX_CID_LENGTH>>;
+
+/// The metadata of a state snapshot generated off-chain.
+///
+/// ## Fields
+///
+/// - `block`: The block whose state the snapshot holds.
+/// - `state_root`: The state root of `block`, to verify the snapshot
+///   against.
+/// - `ipfs_cid`: The IPFS CID the snapshot can be fetched under.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SnapshotMeta<BlockNumber, Hash> {
+	pub block: BlockNumber,
+	pub state_root: Hash,
+	pub ipfs_cid: IpfsCid,
+}
@@ -0,0 +1,110 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+const REGISTRAR: H256 = H256::repeat_byte(1);
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Create the registrar `REGISTRAR`, returning its owner.
+fn create_registrar<T: Config>() -> Result<T::AccountId, BenchmarkError> {
+	let origin =
+		T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let owner = T::RegistrarOrigin::ensure_origin(origin.clone())
+		.map_err(|_| BenchmarkError::Weightless)?;
+	Pallet::<T>::create_registrar(origin, REGISTRAR, RegistrarScope::Global)?;
+	Ok(owner)
+}
+
+/// Delegate the authority of `REGISTRAR` down a chain of `length`
+/// sub-registrars, each granted the most depth it can be, returning the last
+/// one.
+fn delegation_chain<T: Config>(
+	owner: T::AccountId,
+	length: u32,
+) -> Result<T::AccountId, BenchmarkError> {
+	let mut delegator = owner;
+	let mut depth = T::MaxDelegationDepth::get();
+	for i in 0..length {
+		let delegate: T::AccountId = account("delegate", i, SEED);
+		depth -= 1;
+		Pallet::<T>::delegate_to(
+			RawOrigin::Signed(delegator).into(),
+			REGISTRAR,
+			delegate.clone(),
+			depth,
+		)?;
+		delegator = delegate;
+	}
+	Ok(delegator)
+}
+
+benchmarks! {
+	create_registrar {
+		let origin = T::RegistrarOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let owner = T::RegistrarOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, REGISTRAR, RegistrarScope::Global)
+	verify {
+		assert_last_event::<T>(
+			Event::RegistrarCreated { registrar_id: REGISTRAR, owner, scope: RegistrarScope::Global }
+				.into()
+		);
+	}
+
+	delegate_to {
+		let owner = create_registrar::<T>()?;
+		let delegator = delegation_chain::<T>(owner, 1)?;
+		let delegate: T::AccountId = account("delegate", 1, SEED);
+	}: _(RawOrigin::Signed(delegator.clone()), REGISTRAR, delegate.clone(), 0)
+	verify {
+		assert_last_event::<T>(
+			Event::AuthorityDelegated { registrar_id: REGISTRAR, delegator, delegate, max_depth: 0 }
+				.into()
+		);
+	}
+
+	resolve_authority_chain {
+		let d in 1 .. T::MaxDelegationDepth::get() as u32;
+
+		let owner = create_registrar::<T>()?;
+		let delegate = delegation_chain::<T>(owner.clone(), d)?;
+	}: {
+		assert_eq!(Pallet::<T>::resolve_authority_chain(&delegate).len() as u32, d);
+	}
+	verify {
+		assert_eq!(Pallet::<T>::resolve_authority_chain(&delegate).last(), Some(&owner));
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
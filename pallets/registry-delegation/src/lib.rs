@@ -0,0 +1,243 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Registry Delegation Pallet
+//!
+//! The Registry Delegation Pallet lets trusted issuers create registrars,
+//! and hand the authority of a registrar down a hierarchy of
+//! sub-registrars. Every registrar has a scope, which its sub-registrars
+//! share.
+//!
+//! The owner of a registrar holds a depth of `MaxDelegationDepth`, and every
+//! delegation is one level below the account delegating it: a delegate
+//! granted a `max_depth` can only delegate further with a `max_depth` of at
+//! most `max_depth - 1`, and a delegate with a `max_depth` of zero cannot
+//! delegate at all. An account holds at most one delegation, so the
+//! authority of a delegate resolves to a single chain of accounts ending at
+//! the owner of the registrar.
+//!
+//! ### Storage
+//!
+//! - `Registrars`: The details of every registrar.
+//! - `Delegations`: The delegation every sub-registrar holds.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `create_registrar` - Create a registrar owned by a trusted issuer.
+//! - `delegate_to` - Delegate the authority of a registrar to a sub-registrar.
+//!
+//! ### Public Functions
+//!
+//! - `resolve_authority_chain` - The accounts the authority of a delegate derives from.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+use sp_core::H256;
+use sp_std::prelude::*;
+pub use types::{DelegationDetails, RegistrarDetails, RegistrarScope};
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of the details of a registrar.
+	pub type RegistrarDetailsOf<T> =
+		RegistrarDetails<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+	/// Type of the delegation of a sub-registrar.
+	pub type DelegationDetailsOf<T> = DelegationDetails<<T as frame_system::Config>::AccountId>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin allowed to create registrars, yielding the owner of the
+		/// registrar.
+		type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+		/// The depth held by the owner of a registrar, bounding the length of
+		/// a delegation chain.
+		#[pallet::constant]
+		type MaxDelegationDepth: Get<u8>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// registrar details by registrar identifier
+	#[pallet::storage]
+	#[pallet::getter(fn registrars)]
+	pub type Registrars<T: Config> =
+		StorageMap<_, Blake2_128Concat, H256, RegistrarDetailsOf<T>, OptionQuery>;
+
+	/// delegations by sub-registrar
+	#[pallet::storage]
+	#[pallet::getter(fn delegations)]
+	pub type Delegations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DelegationDetailsOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A registrar was created.
+		RegistrarCreated { registrar_id: H256, owner: T::AccountId, scope: RegistrarScope },
+		/// The authority of a registrar was delegated to a sub-registrar.
+		AuthorityDelegated {
+			registrar_id: H256,
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			max_depth: u8,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A registrar with the identifier already exists.
+		RegistrarAlreadyExists,
+		/// No registrar with the identifier exists.
+		RegistrarNotFound,
+		/// The account holds no authority under the registrar.
+		NotAuthorized,
+		/// The depth exceeds the one allowed to the delegator.
+		DepthExceeded,
+		/// The delegate is the owner of the registrar.
+		DelegateIsOwner,
+		/// The delegate already holds a delegation.
+		AlreadyDelegated,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create the registrar `registrar_id` with authority over `scope`,
+		/// owned by the account of the origin.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_registrar())]
+		pub fn create_registrar(
+			origin: OriginFor<T>,
+			registrar_id: H256,
+			scope: RegistrarScope,
+		) -> DispatchResult {
+			let owner = T::RegistrarOrigin::ensure_origin(origin)?;
+			ensure!(
+				!Registrars::<T>::contains_key(registrar_id),
+				Error::<T>::RegistrarAlreadyExists
+			);
+
+			Registrars::<T>::insert(
+				registrar_id,
+				RegistrarDetails {
+					owner: owner.clone(),
+					scope,
+					created_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::RegistrarCreated { registrar_id, owner, scope });
+			Ok(())
+		}
+
+		/// Delegate the authority of the registrar `registrar_id` to
+		/// `delegate`, who can add `max_depth` levels of sub-registrars below
+		/// itself. The origin is the owner of the registrar or one of its
+		/// sub-registrars, and `max_depth` is below the depth it holds.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegate_to())]
+		pub fn delegate_to(
+			origin: OriginFor<T>,
+			registrar_id: H256,
+			delegate: T::AccountId,
+			max_depth: u8,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let registrar =
+				Registrars::<T>::get(registrar_id).ok_or(Error::<T>::RegistrarNotFound)?;
+
+			let held_depth = if delegator == registrar.owner {
+				T::MaxDelegationDepth::get()
+			} else {
+				Delegations::<T>::get(&delegator)
+					.filter(|delegation| delegation.registrar_id == registrar_id)
+					.ok_or(Error::<T>::NotAuthorized)?
+					.max_depth
+			};
+			ensure!(max_depth < held_depth, Error::<T>::DepthExceeded);
+			ensure!(delegate != registrar.owner, Error::<T>::DelegateIsOwner);
+			ensure!(!Delegations::<T>::contains_key(&delegate), Error::<T>::AlreadyDelegated);
+
+			Delegations::<T>::insert(
+				&delegate,
+				DelegationDetails { registrar_id, delegator: delegator.clone(), max_depth },
+			);
+
+			Self::deposit_event(Event::AuthorityDelegated {
+				registrar_id,
+				delegator,
+				delegate,
+				max_depth,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The accounts the authority of `delegate` derives from, starting with
+	/// its delegator and ending with the owner of the registrar. Empty if
+	/// `delegate` holds no delegation.
+	pub fn resolve_authority_chain(delegate: &T::AccountId) -> Vec<T::AccountId> {
+		let mut chain = Vec::new();
+		let Some(delegation) = Delegations::<T>::get(delegate) else { return chain };
+		let Some(registrar) = Registrars::<T>::get(delegation.registrar_id) else { return chain };
+
+		let mut delegator = delegation.delegator;
+		// Every delegation is a level below its delegator, so the chain is
+		// never longer than the depth held by the owner.
+		for _ in 0..T::MaxDelegationDepth::get() {
+			chain.push(delegator.clone());
+			if delegator == registrar.owner {
+				break
+			}
+			match Delegations::<T>::get(&delegator) {
+				Some(delegation) => delegator = delegation.delegator,
+				None => break,
+			}
+		}
+		chain
+	}
+}
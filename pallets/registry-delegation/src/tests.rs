@@ -0,0 +1,163 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+const REGISTRAR: H256 = H256::repeat_byte(1);
+const OTHER_REGISTRAR: H256 = H256::repeat_byte(2);
+
+fn create_registrar(registrar_id: H256) {
+	assert_ok!(RegistryDelegation::create_registrar(
+		RuntimeOrigin::signed(ISSUER),
+		registrar_id,
+		RegistrarScope::Global
+	));
+}
+
+#[test]
+fn create_registrar_works() {
+	new_test_ext().execute_with(|| {
+		let scope = RegistrarScope::Schema(H256::repeat_byte(7));
+		assert_noop!(
+			RegistryDelegation::create_registrar(RuntimeOrigin::signed(2), REGISTRAR, scope),
+			BadOrigin
+		);
+
+		assert_ok!(RegistryDelegation::create_registrar(
+			RuntimeOrigin::signed(ISSUER),
+			REGISTRAR,
+			scope
+		));
+		assert_eq!(
+			RegistryDelegation::registrars(REGISTRAR),
+			Some(RegistrarDetails { owner: ISSUER, scope, created_at: 1 })
+		);
+		System::assert_last_event(
+			Event::RegistrarCreated { registrar_id: REGISTRAR, owner: ISSUER, scope }.into(),
+		);
+
+		assert_noop!(
+			RegistryDelegation::create_registrar(RuntimeOrigin::signed(ISSUER), REGISTRAR, scope),
+			Error::<Test>::RegistrarAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn delegate_to_lowers_the_depth_at_every_level() {
+	new_test_ext().execute_with(|| {
+		create_registrar(REGISTRAR);
+
+		assert_noop!(
+			RegistryDelegation::delegate_to(
+				RuntimeOrigin::signed(ISSUER),
+				REGISTRAR,
+				2,
+				MAX_DELEGATION_DEPTH
+			),
+			Error::<Test>::DepthExceeded
+		);
+		assert_ok!(RegistryDelegation::delegate_to(
+			RuntimeOrigin::signed(ISSUER),
+			REGISTRAR,
+			2,
+			MAX_DELEGATION_DEPTH - 1
+		));
+		System::assert_last_event(
+			Event::AuthorityDelegated {
+				registrar_id: REGISTRAR,
+				delegator: ISSUER,
+				delegate: 2,
+				max_depth: 2,
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			RegistryDelegation::delegate_to(RuntimeOrigin::signed(2), REGISTRAR, 3, 2),
+			Error::<Test>::DepthExceeded
+		);
+		assert_ok!(RegistryDelegation::delegate_to(RuntimeOrigin::signed(2), REGISTRAR, 3, 1));
+		assert_ok!(RegistryDelegation::delegate_to(RuntimeOrigin::signed(3), REGISTRAR, 4, 0));
+		assert_eq!(
+			RegistryDelegation::delegations(4),
+			Some(DelegationDetails { registrar_id: REGISTRAR, delegator: 3, max_depth: 0 })
+		);
+
+		// A delegate with no depth left cannot delegate.
+		assert_noop!(
+			RegistryDelegation::delegate_to(RuntimeOrigin::signed(4), REGISTRAR, 5, 0),
+			Error::<Test>::DepthExceeded
+		);
+	});
+}
+
+#[test]
+fn delegate_to_checks_the_delegator_and_the_delegate() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RegistryDelegation::delegate_to(RuntimeOrigin::signed(ISSUER), REGISTRAR, 2, 0),
+			Error::<Test>::RegistrarNotFound
+		);
+
+		create_registrar(REGISTRAR);
+		create_registrar(OTHER_REGISTRAR);
+		assert_ok!(RegistryDelegation::delegate_to(
+			RuntimeOrigin::signed(ISSUER),
+			OTHER_REGISTRAR,
+			2,
+			1
+		));
+
+		// The authority of a delegate is limited to its own registrar.
+		assert_noop!(
+			RegistryDelegation::delegate_to(RuntimeOrigin::signed(2), REGISTRAR, 3, 0),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			RegistryDelegation::delegate_to(RuntimeOrigin::signed(3), REGISTRAR, 4, 0),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			RegistryDelegation::delegate_to(RuntimeOrigin::signed(2), OTHER_REGISTRAR, ISSUER, 0),
+			Error::<Test>::DelegateIsOwner
+		);
+		assert_noop!(
+			RegistryDelegation::delegate_to(RuntimeOrigin::signed(ISSUER), REGISTRAR, 2, 0),
+			Error::<Test>::AlreadyDelegated
+		);
+	});
+}
+
+#[test]
+fn resolve_authority_chain_ends_at_the_owner() {
+	new_test_ext().execute_with(|| {
+		create_registrar(REGISTRAR);
+		assert_ok!(RegistryDelegation::delegate_to(RuntimeOrigin::signed(ISSUER), REGISTRAR, 2, 2));
+		assert_ok!(RegistryDelegation::delegate_to(RuntimeOrigin::signed(2), REGISTRAR, 3, 1));
+		assert_ok!(RegistryDelegation::delegate_to(RuntimeOrigin::signed(3), REGISTRAR, 4, 0));
+
+		assert_eq!(RegistryDelegation::resolve_authority_chain(&4), vec![3, 2, ISSUER]);
+		assert_eq!(RegistryDelegation::resolve_authority_chain(&2), vec![ISSUER]);
+		assert!(RegistryDelegation::resolve_authority_chain(&ISSUER).is_empty());
+		assert!(RegistryDelegation::resolve_authority_chain(&5).is_empty());
+	});
+}
@@ -0,0 +1,61 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+
+/// What the authority of a registrar, and of its delegates, covers.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum RegistrarScope {
+	/// Credentials of any schema and space.
+	Global,
+	/// Credentials of the schema with the given digest.
+	Schema(H256),
+	/// Credentials of the space with the given digest.
+	Space(H256),
+}
+
+/// A registrar at the root of a delegation hierarchy.
+///
+/// ## Fields
+///
+/// - `owner`: The trusted issuer that created the registrar.
+/// - `scope`: What the authority of the registrar covers.
+/// - `created_at`: The block the registrar was created at.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct RegistrarDetails<AccountId, BlockNumber> {
+	pub owner: AccountId,
+	pub scope: RegistrarScope,
+	pub created_at: BlockNumber,
+}
+
+/// The authority delegated to a sub-registrar.
+///
+/// ## Fields
+///
+/// - `registrar_id`: The registrar the authority derives from.
+/// - `delegator`: The account that delegated the authority.
+/// - `max_depth`: How many levels of sub-registrars the delegate can add below itself.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DelegationDetails<AccountId> {
+	pub registrar_id: H256,
+	pub delegator: AccountId,
+	pub max_depth: u8,
+}
@@ -0,0 +1,122 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_registry_delegation`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_registry_delegation
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/registry-delegation/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_registry_delegation`.
+pub trait WeightInfo {
+	fn create_registrar() -> Weight;
+	fn delegate_to() -> Weight;
+	fn resolve_authority_chain(d: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_cord_registry_delegation` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Registry::TrustedIssuers` (r:1 w:0)
+	/// Proof: `Registry::TrustedIssuers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `RegistryDelegation::Registrars` (r:1 w:1)
+	/// Proof: `RegistryDelegation::Registrars` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	fn create_registrar() -> Weight {
+		Weight::from_parts(16_410_000, 3582)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RegistryDelegation::Registrars` (r:1 w:0)
+	/// Proof: `RegistryDelegation::Registrars` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `RegistryDelegation::Delegations` (r:2 w:1)
+	/// Proof: `RegistryDelegation::Delegations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn delegate_to() -> Weight {
+		Weight::from_parts(19_940_000, 6166)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RegistryDelegation::Delegations` (r:5 w:0)
+	/// Proof: `RegistryDelegation::Delegations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `RegistryDelegation::Registrars` (r:1 w:0)
+	/// Proof: `RegistryDelegation::Registrars` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 5]`.
+	fn resolve_authority_chain(d: u32, ) -> Weight {
+		Weight::from_parts(5_613_000, 3582)
+			.saturating_add(Weight::from_parts(2_612_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2588).saturating_mul(d.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Registry::TrustedIssuers` (r:1 w:0)
+	/// Proof: `Registry::TrustedIssuers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `RegistryDelegation::Registrars` (r:1 w:1)
+	/// Proof: `RegistryDelegation::Registrars` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	fn create_registrar() -> Weight {
+		Weight::from_parts(16_410_000, 3582)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RegistryDelegation::Registrars` (r:1 w:0)
+	/// Proof: `RegistryDelegation::Registrars` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `RegistryDelegation::Delegations` (r:2 w:1)
+	/// Proof: `RegistryDelegation::Delegations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	fn delegate_to() -> Weight {
+		Weight::from_parts(19_940_000, 6166)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RegistryDelegation::Delegations` (r:5 w:0)
+	/// Proof: `RegistryDelegation::Delegations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `RegistryDelegation::Registrars` (r:1 w:0)
+	/// Proof: `RegistryDelegation::Registrars` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 5]`.
+	fn resolve_authority_chain(d: u32, ) -> Weight {
+		Weight::from_parts(5_613_000, 3582)
+			.saturating_add(Weight::from_parts(2_612_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2588).saturating_mul(d.into()))
+	}
+}
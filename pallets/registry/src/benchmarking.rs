@@ -0,0 +1,70 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Zero;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Fill the registry with `count` issuers.
+fn add_issuers<T: Config>(count: u32) {
+	for i in 0..count {
+		let issuer: T::AccountId = account("issuer", i, SEED);
+		TrustedIssuers::<T>::insert(&issuer, BlockNumberFor::<T>::zero());
+	}
+}
+
+benchmarks! {
+	add_issuer {
+		add_issuers::<T>(T::MaxIssuers::get().saturating_sub(1));
+		let issuer: T::AccountId = account("new", 0, SEED);
+		let origin =
+			T::IssuerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, issuer.clone())
+	verify {
+		assert!(Pallet::<T>::is_trusted_issuer(&issuer));
+		assert_last_event::<T>(Event::IssuerAdded { issuer }.into());
+	}
+
+	remove_issuer {
+		add_issuers::<T>(T::MaxIssuers::get());
+		let issuer: T::AccountId = account("issuer", 0, SEED);
+		let origin =
+			T::IssuerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, issuer.clone())
+	verify {
+		assert!(!Pallet::<T>::is_trusted_issuer(&issuer));
+		assert_last_event::<T>(Event::IssuerRemoved { issuer }.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
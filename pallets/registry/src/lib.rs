@@ -0,0 +1,203 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Registry Pallet
+//!
+//! The Registry Pallet keeps the curated set of accounts trusted to issue
+//! credentials on the network.
+//!
+//! ### Storage
+//!
+//! - `TrustedIssuers`: The trusted issuers, mapped to the block they were added at. Bounded by
+//!   `MaxIssuers`.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `add_issuer` - Add an account to the trusted issuers.
+//! - `remove_issuer` - Remove an account from the trusted issuers.
+//!
+//! ### Public Functions
+//!
+//! - `is_trusted_issuer` - Whether an account is a trusted issuer. `Pallet` also implements
+//!   `Contains` for use as a `Config` item of other pallets.
+//! - `EnsureTrustedIssuer` - An origin check for accounts signing as a trusted issuer.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use frame_support::traits::{Contains, EnsureOrigin};
+use frame_system::RawOrigin;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Zero;
+	use sp_std::prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin allowed to add and remove trusted issuers.
+		type IssuerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Maximum number of trusted issuers.
+		#[pallet::constant]
+		type MaxIssuers: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// trusted issuers, mapped to the block they were added at
+	#[pallet::storage]
+	#[pallet::getter(fn trusted_issuers)]
+	pub type TrustedIssuers<T: Config> =
+		CountedStorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub issuers: Vec<T::AccountId>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			assert!(
+				self.issuers.len() as u32 <= T::MaxIssuers::get(),
+				"Genesis issuers must not exceed MaxIssuers"
+			);
+			for issuer in &self.issuers {
+				assert!(
+					!TrustedIssuers::<T>::contains_key(issuer),
+					"Genesis issuers must be unique"
+				);
+				TrustedIssuers::<T>::insert(issuer, BlockNumberFor::<T>::zero());
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account was added to the trusted issuers.
+		IssuerAdded { issuer: T::AccountId },
+		/// An account was removed from the trusted issuers.
+		IssuerRemoved { issuer: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already a trusted issuer
+		AlreadyTrusted,
+		/// The account is not a trusted issuer
+		NotTrusted,
+		/// The registry already holds `MaxIssuers` issuers
+		TooManyIssuers,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add an account to the trusted issuers.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_issuer())]
+		pub fn add_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			T::IssuerOrigin::ensure_origin(origin)?;
+
+			ensure!(!TrustedIssuers::<T>::contains_key(&issuer), Error::<T>::AlreadyTrusted);
+			ensure!(
+				TrustedIssuers::<T>::count() < T::MaxIssuers::get(),
+				Error::<T>::TooManyIssuers
+			);
+
+			TrustedIssuers::<T>::insert(&issuer, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::IssuerAdded { issuer });
+			Ok(())
+		}
+
+		/// Remove an account from the trusted issuers.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_issuer())]
+		pub fn remove_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			T::IssuerOrigin::ensure_origin(origin)?;
+
+			ensure!(TrustedIssuers::<T>::contains_key(&issuer), Error::<T>::NotTrusted);
+			TrustedIssuers::<T>::remove(&issuer);
+
+			Self::deposit_event(Event::IssuerRemoved { issuer });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `who` is a trusted issuer.
+	pub fn is_trusted_issuer(who: &T::AccountId) -> bool {
+		TrustedIssuers::<T>::contains_key(who)
+	}
+}
+
+impl<T: Config> Contains<T::AccountId> for Pallet<T> {
+	fn contains(who: &T::AccountId) -> bool {
+		Self::is_trusted_issuer(who)
+	}
+}
+
+/// Ensures that the origin is signed by a trusted issuer, and yields the
+/// issuer.
+pub struct EnsureTrustedIssuer<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> EnsureOrigin<T::RuntimeOrigin> for EnsureTrustedIssuer<T> {
+	type Success = T::AccountId;
+
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Signed(who) if Pallet::<T>::is_trusted_issuer(&who) => Ok(who),
+			r => Err(T::RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		let issuer: T::AccountId = frame_benchmarking::account("issuer", 0, 0);
+		TrustedIssuers::<T>::insert(&issuer, frame_system::Pallet::<T>::block_number());
+		Ok(RawOrigin::Signed(issuer).into())
+	}
+}
@@ -0,0 +1,86 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn add_issuer_works() {
+	new_test_ext().execute_with(|| {
+		assert!(!Registry::is_trusted_issuer(&ISSUER));
+		assert_noop!(Registry::add_issuer(RuntimeOrigin::signed(ISSUER), ISSUER), BadOrigin);
+
+		assert_ok!(Registry::add_issuer(RuntimeOrigin::root(), ISSUER));
+		assert!(Registry::is_trusted_issuer(&ISSUER));
+		assert!(<Registry as Contains<AccountId>>::contains(&ISSUER));
+		assert_eq!(Registry::trusted_issuers(ISSUER), Some(1));
+		System::assert_last_event(Event::IssuerAdded { issuer: ISSUER }.into());
+
+		assert_noop!(
+			Registry::add_issuer(RuntimeOrigin::root(), ISSUER),
+			Error::<Test>::AlreadyTrusted
+		);
+	});
+}
+
+#[test]
+fn add_issuer_fails_at_capacity() {
+	new_test_ext().execute_with(|| {
+		for issuer in 0..MAX_ISSUERS as AccountId {
+			assert_ok!(Registry::add_issuer(RuntimeOrigin::root(), issuer + 10));
+		}
+		assert_eq!(TrustedIssuers::<Test>::count(), MAX_ISSUERS);
+
+		assert_noop!(
+			Registry::add_issuer(RuntimeOrigin::root(), ISSUER),
+			Error::<Test>::TooManyIssuers
+		);
+	});
+}
+
+#[test]
+fn remove_issuer_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Registry::remove_issuer(RuntimeOrigin::root(), ISSUER),
+			Error::<Test>::NotTrusted
+		);
+		assert_ok!(Registry::add_issuer(RuntimeOrigin::root(), ISSUER));
+		assert_noop!(Registry::remove_issuer(RuntimeOrigin::signed(ISSUER), ISSUER), BadOrigin);
+
+		assert_ok!(Registry::remove_issuer(RuntimeOrigin::root(), ISSUER));
+		assert!(!Registry::is_trusted_issuer(&ISSUER));
+		assert_eq!(TrustedIssuers::<Test>::count(), 0);
+		System::assert_last_event(Event::IssuerRemoved { issuer: ISSUER }.into());
+	});
+}
+
+#[test]
+fn ensure_trusted_issuer_works() {
+	new_test_ext().execute_with(|| {
+		type Ensure = EnsureTrustedIssuer<Test>;
+
+		assert!(Ensure::try_origin(RuntimeOrigin::signed(ISSUER)).is_err());
+		assert!(Ensure::try_origin(RuntimeOrigin::root()).is_err());
+
+		assert_ok!(Registry::add_issuer(RuntimeOrigin::root(), ISSUER));
+		assert_eq!(Ensure::try_origin(RuntimeOrigin::signed(ISSUER)).ok(), Some(ISSUER));
+	});
+}
@@ -0,0 +1,73 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use pallet_authorship::EventHandler;
+
+const SEED: u32 = 0;
+const MAX_VALIDATORS: u32 = 1_000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	note_author {
+		let author: T::AccountId = account("author", 0, SEED);
+	}: {
+		<Pallet<T> as EventHandler<_, _>>::note_author(author.clone());
+	}
+	verify {
+		assert_eq!(AuthoredBlocks::<T>::get(&author), 1);
+	}
+
+	note_session_end {
+		let v in 1 .. MAX_VALIDATORS;
+
+		let validators =
+			(0..v).map(|i| account("validator", i, SEED)).collect::<Vec<T::AccountId>>();
+		for (i, who) in validators.iter().enumerate() {
+			AuthoredBlocks::<T>::insert(who, i as u32);
+			ReliabilityScore::<T>::insert(who, FixedU128::one());
+		}
+	}: {
+		Pallet::<T>::note_session_end(&validators);
+	}
+	verify {
+		assert!(validators.iter().all(|who| AuthoredBlocks::<T>::get(who) == 0));
+	}
+
+	reset_score {
+		let who: T::AccountId = account("validator", 0, SEED);
+		ReliabilityScore::<T>::insert(&who, FixedU128::zero());
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert_last_event::<T>(Event::ScoreReset { who }.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,214 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Reliability Score Pallet
+//!
+//! The Reliability Score Pallet keeps a fixed-point reliability score for
+//! every validator. Scores are updated at the end of each session from two
+//! signals:
+//! - the validator heartbeat status, as reported by `HeartbeatStatus`,
+//! - the validator share of the blocks authored during the session, relative to an even split
+//!   between the session validators.
+//!
+//! The session result is folded into the previous score as an exponential
+//! moving average weighted by `SmoothingFactor`. Validators without a history
+//! start with a score of one.
+//!
+//! ### Storage
+//!
+//! - `ReliabilityScore`: The current score of every validator.
+//! - `AuthoredBlocks`: Blocks authored by every validator in the current session.
+//!
+//! ### Integration
+//!
+//! The pallet is a `pallet_authorship::EventHandler`, counting authored
+//! blocks, and exposes `note_session_end` to be called by the session manager
+//! with the validators of the ending session. `is_below_threshold` reports
+//! validators whose score fell below `MinReliabilityScore`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use frame_support::dispatch::DispatchClass;
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	FixedPointNumber, FixedU128,
+};
+use sp_std::prelude::*;
+pub use weights::WeightInfo;
+
+/// Heartbeat status of validators during the current session.
+pub trait HeartbeatStatus<AccountId> {
+	/// Whether `who` sent a heartbeat or otherwise proved to be online.
+	fn is_online(who: &AccountId) -> bool;
+}
+
+impl<AccountId> HeartbeatStatus<AccountId> for () {
+	fn is_online(_who: &AccountId) -> bool {
+		true
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::Perbill;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Heartbeat status of the session validators.
+		type HeartbeatStatus: HeartbeatStatus<Self::AccountId>;
+		/// Weight of the ending session in the updated score.
+		#[pallet::constant]
+		type SmoothingFactor: Get<Perbill>;
+		/// Validators scoring below this value are considered unreliable.
+		#[pallet::constant]
+		type MinReliabilityScore: Get<FixedU128>;
+		/// Origin allowed to reset a validator score.
+		type ResetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// reliability score of a validator
+	#[pallet::storage]
+	#[pallet::getter(fn reliability_score)]
+	pub type ReliabilityScore<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, FixedU128, OptionQuery>;
+
+	/// blocks authored by a validator in the current session
+	#[pallet::storage]
+	#[pallet::getter(fn authored_blocks)]
+	pub type AuthoredBlocks<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The reliability score of a validator was updated.
+		ScoreUpdated { who: T::AccountId, score: FixedU128 },
+		/// The reliability score of a validator was reset.
+		ScoreReset { who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The validator has no reliability score
+		ScoreNotFound,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Reset the reliability score of a validator, e.g. after an
+		/// acknowledged outage.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reset_score())]
+		pub fn reset_score(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ResetOrigin::ensure_origin(origin)?;
+
+			ensure!(ReliabilityScore::<T>::contains_key(&who), Error::<T>::ScoreNotFound);
+			ReliabilityScore::<T>::remove(&who);
+
+			Self::deposit_event(Event::ScoreReset { who });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The reliability score of `who`. Validators without history score one.
+	pub fn get_score(who: &T::AccountId) -> FixedU128 {
+		ReliabilityScore::<T>::get(who).unwrap_or_else(FixedU128::one)
+	}
+
+	/// Whether the score of `who` fell below `MinReliabilityScore`.
+	pub fn is_below_threshold(who: &T::AccountId) -> bool {
+		Self::get_score(who) < T::MinReliabilityScore::get()
+	}
+
+	/// Update the scores of the validators of the ending session and reset
+	/// the authored block counters.
+	pub fn note_session_end(validators: &[T::AccountId]) {
+		let authored = validators.iter().map(AuthoredBlocks::<T>::get).collect::<Vec<_>>();
+		let total = authored.iter().fold(0u32, |total, blocks| total.saturating_add(*blocks));
+		let even_split = validators.len() as u32;
+		let factor = FixedU128::from(T::SmoothingFactor::get());
+
+		for (who, blocks) in validators.iter().zip(authored) {
+			let production = if total.is_zero() {
+				FixedU128::one()
+			} else {
+				FixedU128::saturating_from_rational(blocks.saturating_mul(even_split), total)
+					.min(FixedU128::one())
+			};
+			let heartbeat = if T::HeartbeatStatus::is_online(who) {
+				FixedU128::one()
+			} else {
+				FixedU128::zero()
+			};
+			let session_score = production.saturating_add(heartbeat) / FixedU128::from_u32(2);
+
+			let score = Self::get_score(who)
+				.saturating_mul(FixedU128::one().saturating_sub(factor))
+				.saturating_add(session_score.saturating_mul(factor));
+			ReliabilityScore::<T>::insert(who, score);
+
+			Self::deposit_event(Event::ScoreUpdated { who: who.clone(), score });
+		}
+
+		let _ = AuthoredBlocks::<T>::clear(u32::MAX, None);
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::note_session_end(even_split),
+			DispatchClass::Mandatory,
+		);
+	}
+}
+
+impl<T: Config> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+	fn note_author(author: T::AccountId) {
+		AuthoredBlocks::<T>::mutate(&author, |blocks| *blocks = blocks.saturating_add(1));
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::note_author(),
+			DispatchClass::Mandatory,
+		);
+	}
+}
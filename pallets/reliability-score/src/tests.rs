@@ -0,0 +1,100 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use pallet_authorship::EventHandler;
+use sp_runtime::traits::BadOrigin;
+
+fn author_blocks(who: AccountId, blocks: u32) {
+	for _ in 0..blocks {
+		<ReliabilityScore as EventHandler<_, _>>::note_author(who);
+	}
+}
+
+#[test]
+fn note_author_counts_blocks() {
+	new_test_ext().execute_with(|| {
+		author_blocks(1, 3);
+		author_blocks(2, 1);
+
+		assert_eq!(ReliabilityScore::authored_blocks(1), 3);
+		assert_eq!(ReliabilityScore::authored_blocks(2), 1);
+	});
+}
+
+#[test]
+fn validators_without_history_score_one() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ReliabilityScore::get_score(&1), FixedU128::one());
+		assert!(!ReliabilityScore::is_below_threshold(&1));
+	});
+}
+
+#[test]
+fn note_session_end_scores_block_production() {
+	new_test_ext().execute_with(|| {
+		author_blocks(1, 3);
+		author_blocks(2, 1);
+
+		ReliabilityScore::note_session_end(&[1, 2]);
+
+		// Validator 1 authored more than an even split, validator 2 half of it.
+		assert_eq!(ReliabilityScore::reliability_score(1), Some(FixedU128::one()));
+		assert_eq!(ReliabilityScore::reliability_score(2), Some(FixedU128::from_rational(7, 8)));
+		// Counters are reset for the next session.
+		assert_eq!(ReliabilityScore::authored_blocks(1), 0);
+		assert_eq!(ReliabilityScore::authored_blocks(2), 0);
+	});
+}
+
+#[test]
+fn offline_validators_fall_below_threshold() {
+	new_test_ext().execute_with(|| {
+		OfflineValidators::set(vec![2]);
+		author_blocks(1, 2);
+
+		ReliabilityScore::note_session_end(&[1, 2]);
+		assert_eq!(ReliabilityScore::get_score(&2), FixedU128::from_rational(1, 2));
+		assert!(ReliabilityScore::is_below_threshold(&2));
+		assert!(!ReliabilityScore::is_below_threshold(&1));
+
+		System::assert_last_event(
+			Event::ScoreUpdated { who: 2, score: FixedU128::from_rational(1, 2) }.into(),
+		);
+	});
+}
+
+#[test]
+fn reset_score_works() {
+	new_test_ext().execute_with(|| {
+		OfflineValidators::set(vec![1]);
+		ReliabilityScore::note_session_end(&[1]);
+		ReliabilityScore::note_session_end(&[1]);
+		assert!(ReliabilityScore::is_below_threshold(&1));
+
+		assert_noop!(ReliabilityScore::reset_score(RuntimeOrigin::signed(1), 1), BadOrigin);
+		assert_ok!(ReliabilityScore::reset_score(RuntimeOrigin::root(), 1));
+		assert_eq!(ReliabilityScore::get_score(&1), FixedU128::one());
+		assert_noop!(
+			ReliabilityScore::reset_score(RuntimeOrigin::root(), 1),
+			Error::<Test>::ScoreNotFound
+		);
+	});
+}
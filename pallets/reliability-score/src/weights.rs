@@ -0,0 +1,114 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_reliability_score`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_reliability_score
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/reliability-score/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_reliability_score`.
+pub trait WeightInfo {
+	fn note_author() -> Weight;
+	fn note_session_end(v: u32, ) -> Weight;
+	fn reset_score() -> Weight;
+}
+
+/// Weights for `pallet_cord_reliability_score` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ReliabilityScore::AuthoredBlocks` (r:1 w:1)
+	/// Proof: `ReliabilityScore::AuthoredBlocks` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn note_author() -> Weight {
+		Weight::from_parts(6_410_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ReliabilityScore::AuthoredBlocks` (r:1001 w:1000)
+	/// Proof: `ReliabilityScore::AuthoredBlocks` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `ReliabilityScore::ReliabilityScore` (r:1000 w:1000)
+	/// Proof: `ReliabilityScore::ReliabilityScore` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `v` is `[1, 1000]`.
+	fn note_session_end(v: u32, ) -> Weight {
+		Weight::from_parts(9_842_117, 990)
+			.saturating_add(Weight::from_parts(17_940_283, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2543).saturating_mul(v.into()))
+	}
+	/// Storage: `ReliabilityScore::ReliabilityScore` (r:1 w:1)
+	/// Proof: `ReliabilityScore::ReliabilityScore` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn reset_score() -> Weight {
+		Weight::from_parts(10_880_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ReliabilityScore::AuthoredBlocks` (r:1 w:1)
+	/// Proof: `ReliabilityScore::AuthoredBlocks` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn note_author() -> Weight {
+		Weight::from_parts(6_410_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ReliabilityScore::AuthoredBlocks` (r:1001 w:1000)
+	/// Proof: `ReliabilityScore::AuthoredBlocks` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `ReliabilityScore::ReliabilityScore` (r:1000 w:1000)
+	/// Proof: `ReliabilityScore::ReliabilityScore` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `v` is `[1, 1000]`.
+	fn note_session_end(v: u32, ) -> Weight {
+		Weight::from_parts(9_842_117, 990)
+			.saturating_add(Weight::from_parts(17_940_283, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2543).saturating_mul(v.into()))
+	}
+	/// Storage: `ReliabilityScore::ReliabilityScore` (r:1 w:1)
+	/// Proof: `ReliabilityScore::ReliabilityScore` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn reset_score() -> Weight {
+		Weight::from_parts(10_880_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
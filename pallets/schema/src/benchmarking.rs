@@ -19,8 +19,8 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use codec::Encode;
-use cord_utilities::traits::GenerateBenchmarkOrigin;
+use codec::{Encode, MaxEncodedLen};
+use cord_utilities::traits::{GenerateBenchmarkOrigin, IdentifierNamespace, StorageDeposit};
 use frame_benchmarking::{account, benchmarks};
 use frame_support::{sp_runtime::traits::Hash, traits::Get, BoundedVec};
 use frame_system::RawOrigin;
@@ -31,12 +31,22 @@ use sp_std::{
 
 const SEED: u32 = 0;
 
+/// Gives `caller` the balance to hold the deposit of the largest schema.
+fn fund_schema<T: Config>(caller: &T::AccountId) {
+	let len = SchemaEntryOf::<T>::max_encoded_len() as u32;
+	<T as Config>::StorageDeposit::fund(caller, len);
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
 pub fn generate_schema_id<T: Config>(digest: &SchemaHashOf<T>) -> SchemaIdOf {
-	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Schema).unwrap()
+	Ss58Identifier::create_identifier(
+		&T::IdentifierNamespace::namespaced(&digest.encode()[..]),
+		IdentifierType::Schema,
+	)
+	.unwrap()
 }
 
 /// Generates a space ID from a digest.
@@ -86,6 +96,7 @@ benchmarks! {
 
 		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
 
+		fund_schema::<T>(&caller);
 		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
 		let chain_space_origin = RawOrigin::Root.into();
 
@@ -96,6 +107,131 @@ benchmarks! {
 	verify {
 		assert_last_event::<T>(Event::<T>::Created { identifier: schema_id, creator: did1 }.into());
 	}
+
+	update {
+		let l in 1 .. T::MaxEncodedSchemaLength::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get().try_into().unwrap()).collect();
+		let schema = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		fund_schema::<T>(&caller);
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone()).expect("Schema creation should not fail.");
+
+		let new_raw_schema: Vec<u8> = (1u8..u8::MAX).cycle().take(l.try_into().unwrap()).collect();
+		let new_schema = BoundedVec::try_from(new_raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+
+	}: _<T::RuntimeOrigin>(origin, schema_id.clone(), new_schema, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Updated { identifier: schema_id, version: 1, author: did1 }.into());
+	}
+
+	archive {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get().try_into().unwrap()).collect();
+		let schema = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		fund_schema::<T>(&caller);
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone()).expect("Schema creation should not fail.");
+
+	}: _<T::RuntimeOrigin>(origin, schema_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Archived { identifier: schema_id, author: did1 }.into());
+	}
+
+	force_archive {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get().try_into().unwrap()).collect();
+		let schema = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		fund_schema::<T>(&caller);
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin, schema, authorization_id).expect("Schema creation should not fail.");
+
+	}: _(RawOrigin::Root, schema_id.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::ForceArchived { identifier: schema_id }.into());
+	}
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
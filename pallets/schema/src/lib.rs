@@ -56,7 +56,7 @@ use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError};
 
 /// Extra Types for Schema
 pub mod types;
@@ -67,7 +67,7 @@ use frame_support::ensure;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	pub use cord_utilities::traits::CallSources;
+	pub use cord_utilities::traits::{CallSources, IdentifierNamespace, StorageDeposit};
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 	pub use identifier::{IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier};
@@ -93,6 +93,8 @@ pub mod pallet {
 	/// Type for a schema entry
 	pub type SchemaEntryOf<T> =
 		SchemaEntry<InputSchemaOf<T>, SchemaHashOf<T>, SchemaCreatorOf<T>, SpaceIdOf>;
+	/// Type for the details of a schema
+	pub type SchemaDetailsOf<T> = SchemaDetails<SchemaEntryOf<T>>;
 
 	#[pallet::config]
 	pub trait Config:
@@ -107,6 +109,10 @@ pub mod pallet {
 		type SchemaCreatorId: Parameter + MaxEncodedLen;
 		#[pallet::constant]
 		type MaxEncodedSchemaLength: Get<u32>;
+		/// Scope of the derived schema identifiers.
+		type IdentifierNamespace: IdentifierNamespace;
+		/// The deposit held from the sender for the content of every schema.
+		type StorageDeposit: StorageDeposit<AccountIdOf<Self>>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -123,12 +129,35 @@ pub mod pallet {
 	#[pallet::getter(fn schemas)]
 	pub type Schemas<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, SchemaEntryOf<T>>;
 
+	/// schema versions stored on chain.
+	/// It maps from a schema identifier to the number of updates applied to
+	/// it. Schemas that were never updated are at version zero.
+	#[pallet::storage]
+	#[pallet::getter(fn schema_version)]
+	pub type SchemaVersion<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, u32, ValueQuery>;
+
+	/// archived schemas.
+	/// If a schema identifier is present, the schema has been archived and
+	/// can no longer be updated or used.
+	#[pallet::storage]
+	#[pallet::getter(fn is_archived)]
+	pub type Archived<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new schema has been created.
 		/// \[schema identifier, digest, author\]
 		Created { identifier: SchemaIdOf, creator: SchemaCreatorOf<T> },
+		/// A schema has been updated.
+		/// \[schema identifier, version, author\]
+		Updated { identifier: SchemaIdOf, version: u32, author: SchemaCreatorOf<T> },
+		/// A schema has been archived.
+		/// \[schema identifier, author\]
+		Archived { identifier: SchemaIdOf, author: SchemaCreatorOf<T> },
+		/// A schema has been archived by root.
+		/// \[schema identifier\]
+		ForceArchived { identifier: SchemaIdOf },
 	}
 
 	#[pallet::error]
@@ -147,6 +176,12 @@ pub mod pallet {
 		MaxEncodedSchemaLimitExceeded,
 		/// Empty transaction.
 		EmptyTransaction,
+		/// The schema has been archived.
+		SchemaArchived,
+		/// The new schema is identical to the anchored one.
+		SchemaUnchanged,
+		/// The authorization does not belong to the space of the schema.
+		UnauthorizedOperation,
 	}
 
 	#[pallet::call]
@@ -169,7 +204,8 @@ pub mod pallet {
 			tx_schema: InputSchemaOf<T>,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let creator = source.subject();
 
 			ensure!(tx_schema.len() > 0, Error::<T>::EmptyTransaction);
 			ensure!(
@@ -191,7 +227,7 @@ pub mod pallet {
 			);
 
 			let identifier = Ss58Identifier::create_identifier(
-				&(id_digest).encode()[..],
+				&T::IdentifierNamespace::namespaced(&id_digest.encode()[..]),
 				IdentifierType::Schema,
 			)
 			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
@@ -211,15 +247,14 @@ pub mod pallet {
 				block_number
 			);
 
-			<Schemas<T>>::insert(
-				&identifier,
-				SchemaEntryOf::<T> {
-					schema: tx_schema,
-					digest,
-					creator: creator.clone(),
-					space: space_id,
-				},
-			);
+			let entry = SchemaEntryOf::<T> {
+				schema: tx_schema,
+				digest,
+				creator: creator.clone(),
+				space: space_id,
+			};
+			T::StorageDeposit::hold(&source.sender(), &identifier, entry.encoded_size() as u32)?;
+			<Schemas<T>>::insert(&identifier, entry);
 
 			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
 
@@ -227,6 +262,126 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Replace the content of an existing schema and bump its version.
+		///
+		/// The schema keeps its identifier, creator and space. The caller
+		/// must be a delegate of the space the schema belongs to, and the
+		/// schema must not be archived. The deposit of the previous content
+		/// is released and the deposit of the new content is held from the
+		/// sender.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `schema_id`: The identifier of the schema to update.
+		/// * `tx_schema`: The new schema content.
+		/// * `authorization`: The authorization of the caller in the schema space.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update(tx_schema.len().saturated_into()))]
+		pub fn update(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			tx_schema: InputSchemaOf<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let author = source.subject();
+
+			ensure!(tx_schema.len() > 0, Error::<T>::EmptyTransaction);
+
+			let mut schema =
+				Self::ensure_schema_authorization(&schema_id, &authorization, &author)?;
+
+			let digest = <T as frame_system::Config>::Hashing::hash(&tx_schema[..]);
+			ensure!(schema.digest != digest, Error::<T>::SchemaUnchanged);
+
+			schema.schema = tx_schema;
+			schema.digest = digest;
+
+			// The deposit follows the size of the new content.
+			T::StorageDeposit::release(&schema_id);
+			T::StorageDeposit::hold(&source.sender(), &schema_id, schema.encoded_size() as u32)?;
+			<Schemas<T>>::insert(&schema_id, schema);
+
+			let version = <SchemaVersion<T>>::mutate(&schema_id, |version| {
+				*version = version.saturating_add(1);
+				*version
+			});
+
+			Self::update_activity(&schema_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::Updated { identifier: schema_id, version, author });
+
+			Ok(())
+		}
+
+		/// Archive an existing schema.
+		///
+		/// An archived schema remains readable but can no longer be updated
+		/// and is no longer valid for new use. The caller must be a delegate
+		/// of the space the schema belongs to.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `schema_id`: The identifier of the schema to archive.
+		/// * `authorization`: The authorization of the caller in the schema space.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::archive())]
+		pub fn archive(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let author = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			Self::ensure_schema_authorization(&schema_id, &authorization, &author)?;
+
+			<Archived<T>>::insert(&schema_id, ());
+
+			Self::update_activity(&schema_id, CallTypeOf::Archive).map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::Archived { identifier: schema_id, author });
+
+			Ok(())
+		}
+
+		/// Archive an existing schema without a space authorization, for
+		/// governance to retire schemas.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction, must be root.
+		/// * `schema_id`: The identifier of the schema to archive.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_archive())]
+		pub fn force_archive(origin: OriginFor<T>, schema_id: SchemaIdOf) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(<Schemas<T>>::contains_key(&schema_id), Error::<T>::SchemaNotFound);
+			ensure!(!<Archived<T>>::contains_key(&schema_id), Error::<T>::SchemaArchived);
+
+			<Archived<T>>::insert(&schema_id, ());
+
+			Self::update_activity(&schema_id, CallTypeOf::Archive).map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::ForceArchived { identifier: schema_id });
+
+			Ok(())
+		}
 	}
 }
 
@@ -243,9 +398,49 @@ impl<T: Config> Pallet<T> {
 	/// A Result<(), Error<T>>
 	pub fn is_valid(tx_ident: &SchemaIdOf) -> Result<(), Error<T>> {
 		ensure!(<Schemas<T>>::contains_key(tx_ident), Error::<T>::SchemaNotFound);
+		ensure!(!<Archived<T>>::contains_key(tx_ident), Error::<T>::SchemaArchived);
 		Ok(())
 	}
 
+	/// The details of a schema, including its version and archival status.
+	///
+	/// Arguments:
+	///
+	/// * `schema_id`: The identifier of the schema.
+	///
+	/// Returns:
+	///
+	/// The schema details, or `None` if the schema does not exist.
+	pub fn get_schema(schema_id: &SchemaIdOf) -> Option<SchemaDetailsOf<T>> {
+		<Schemas<T>>::get(schema_id).map(|entry| SchemaDetails {
+			entry,
+			version: <SchemaVersion<T>>::get(schema_id),
+			archived: <Archived<T>>::contains_key(schema_id),
+		})
+	}
+
+	/// Checks that a schema exists, is not archived and that `author` holds
+	/// `authorization` in the space of the schema.
+	///
+	/// Returns:
+	///
+	/// The schema entry on success.
+	fn ensure_schema_authorization(
+		schema_id: &SchemaIdOf,
+		authorization: &AuthorizationIdOf,
+		author: &SchemaCreatorOf<T>,
+	) -> Result<SchemaEntryOf<T>, DispatchError> {
+		let schema = <Schemas<T>>::get(schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+		ensure!(!<Archived<T>>::contains_key(schema_id), Error::<T>::SchemaArchived);
+
+		let space_id =
+			pallet_chain_space::Pallet::<T>::ensure_authorization_origin(authorization, author)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+		ensure!(schema.space == space_id, Error::<T>::UnauthorizedOperation);
+
+		Ok(schema)
+	}
+
 	/// Updates the global timeline with a new activity event for a schema.
 	///
 	/// An `EventEntryOf` struct is created, encapsulating the type of action
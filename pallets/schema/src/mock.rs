@@ -90,6 +90,8 @@ impl pallet_schema::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type IdentifierNamespace = ();
+	type StorageDeposit = ();
 }
 
 parameter_types! {
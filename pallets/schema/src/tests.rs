@@ -23,7 +23,10 @@ use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
 use frame_support::{assert_noop, assert_ok, BoundedVec};
 use frame_system::RawOrigin;
 use sp_core::H256;
-use sp_runtime::{traits::Hash, AccountId32};
+use sp_runtime::{
+	traits::{BadOrigin, Hash},
+	AccountId32,
+};
 use sp_std::prelude::*;
 const DEFAULT_SCHEMA_HASH_SEED: u64 = 1u64;
 const ALTERNATIVE_SCHEMA_HASH_SEED: u64 = 2u64;
@@ -314,3 +317,190 @@ fn test_schema_lookup() {
 		}
 	});
 }
+
+/// Creates and approves a space owned by `creator`, anchors `raw_schema` in it
+/// and returns the schema and authorization identifiers.
+fn create_space_and_schema(
+	author: AccountId,
+	creator: SubjectId,
+	raw_space: &[u8],
+	raw_schema: &[u8],
+) -> (SchemaIdOf, AuthorizationIdOf) {
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema.to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_digest);
+
+	assert_ok!(Space::create(DoubleOrigin(author.clone(), creator.clone()).into(), space_digest));
+	assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, 3u64));
+	assert_ok!(Schema::create(
+		DoubleOrigin(author, creator).into(),
+		schema,
+		authorization_id.clone()
+	));
+
+	(schema_id, authorization_id)
+}
+
+// This test checks that updating a schema replaces its content and bumps its
+// version, and that an identical update is rejected.
+#[test]
+fn check_successful_schema_update() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let new_schema: InputSchemaOf<Test> = BoundedVec::try_from([4u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+
+	new_test_ext().execute_with(|| {
+		let (schema_id, authorization_id) =
+			create_space_and_schema(author.clone(), creator.clone(), &[2u8; 256], &[3u8; 256]);
+		assert_eq!(Schema::get_schema(&schema_id).map(|details| details.version), Some(0));
+
+		assert_ok!(Schema::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			new_schema.clone(),
+			authorization_id.clone()
+		));
+
+		let details =
+			Schema::get_schema(&schema_id).expect("Schema Identifier should be present on chain.");
+		assert_eq!(details.version, 1);
+		assert!(!details.archived);
+		assert_eq!(details.entry.schema, new_schema);
+		assert_eq!(
+			details.entry.digest,
+			<Test as frame_system::Config>::Hashing::hash(&new_schema[..])
+		);
+		assert_eq!(details.entry.creator, creator);
+
+		assert_noop!(
+			Schema::update(
+				DoubleOrigin(author, creator).into(),
+				schema_id,
+				new_schema,
+				authorization_id
+			),
+			Error::<Test>::SchemaUnchanged
+		);
+	});
+}
+
+// This test checks that an archived schema is reported as archived, is no
+// longer valid and can neither be updated nor archived again.
+#[test]
+fn check_successful_schema_archival() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let new_schema: InputSchemaOf<Test> = BoundedVec::try_from([4u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+
+	new_test_ext().execute_with(|| {
+		let (schema_id, authorization_id) =
+			create_space_and_schema(author.clone(), creator.clone(), &[2u8; 256], &[3u8; 256]);
+		assert_ok!(Schema::is_valid(&schema_id));
+
+		assert_ok!(Schema::archive(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			authorization_id.clone()
+		));
+
+		assert_eq!(Schema::get_schema(&schema_id).map(|details| details.archived), Some(true));
+		assert_eq!(Schema::is_valid(&schema_id), Err(Error::<Test>::SchemaArchived));
+		assert_noop!(
+			Schema::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema_id.clone(),
+				new_schema,
+				authorization_id.clone()
+			),
+			Error::<Test>::SchemaArchived
+		);
+		assert_noop!(
+			Schema::archive(DoubleOrigin(author, creator).into(), schema_id, authorization_id),
+			Error::<Test>::SchemaArchived
+		);
+	});
+}
+
+// This test checks that root can archive a schema without an authorization,
+// and only once.
+#[test]
+fn check_successful_schema_force_archival() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+
+	new_test_ext().execute_with(|| {
+		let (schema_id, authorization_id) =
+			create_space_and_schema(author.clone(), creator.clone(), &[2u8; 256], &[3u8; 256]);
+
+		assert_noop!(
+			Schema::force_archive(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema_id.clone()
+			),
+			BadOrigin
+		);
+		assert_ok!(Schema::force_archive(RawOrigin::Root.into(), schema_id.clone()));
+		System::assert_last_event(Event::ForceArchived { identifier: schema_id.clone() }.into());
+
+		assert_eq!(Schema::is_valid(&schema_id), Err(Error::<Test>::SchemaArchived));
+		assert_noop!(
+			Schema::force_archive(RawOrigin::Root.into(), schema_id.clone()),
+			Error::<Test>::SchemaArchived
+		);
+		assert_noop!(
+			Schema::archive(DoubleOrigin(author, creator).into(), schema_id, authorization_id),
+			Error::<Test>::SchemaArchived
+		);
+	});
+}
+
+// This test checks that an authorization from another space cannot be used to
+// update or archive a schema.
+#[test]
+fn check_schema_update_from_another_space_fails() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let new_schema: InputSchemaOf<Test> = BoundedVec::try_from([4u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+
+	new_test_ext().execute_with(|| {
+		let (schema_id, _) =
+			create_space_and_schema(author.clone(), creator.clone(), &[2u8; 256], &[3u8; 256]);
+		let (_, other_authorization_id) =
+			create_space_and_schema(author.clone(), creator.clone(), &[5u8; 256], &[6u8; 256]);
+
+		assert_noop!(
+			Schema::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema_id.clone(),
+				new_schema,
+				other_authorization_id.clone()
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_noop!(
+			Schema::archive(
+				DoubleOrigin(author, creator).into(),
+				schema_id,
+				other_authorization_id
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+	});
+}
@@ -31,3 +31,14 @@ pub struct SchemaEntry<InputSchemaOf, SchemaHashOf, SchemaCreatorOf, SpaceIdOf>
 	/// Identifier of the space asscoaiated with the schema
 	pub space: SpaceIdOf,
 }
+
+/// The details of a schema, as returned to clients.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SchemaDetails<SchemaEntryOf> {
+	/// The schema entry.
+	pub entry: SchemaEntryOf,
+	/// Number of updates applied to the schema since its creation.
+	pub version: u32,
+	/// Whether the schema has been archived.
+	pub archived: bool,
+}
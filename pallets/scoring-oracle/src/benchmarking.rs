@@ -0,0 +1,97 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Register `count` oracles, and return them in registration order.
+fn add_oracles<T: Config>(count: u32) -> Vec<T::AccountId> {
+	let oracles = (0..count).map(|i| account("oracle", i, SEED)).collect::<Vec<T::AccountId>>();
+	let mut sorted = oracles.clone();
+	sorted.sort();
+	Oracles::<T>::put(BoundedVec::truncate_from(sorted));
+	oracles
+}
+
+benchmarks! {
+	register_oracle {
+		add_oracles::<T>(T::MaxOracles::get() - 1);
+		let oracle: T::AccountId = account("oracle", T::MaxOracles::get(), SEED);
+		let origin = T::OracleOrigin::try_successful_origin()
+			.map_err(|_| frame_benchmarking::BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, oracle.clone())
+	verify {
+		assert!(Pallet::<T>::is_oracle(&oracle));
+		assert_last_event::<T>(Event::OracleRegistered { oracle }.into());
+	}
+
+	deregister_oracle {
+		let oracles = add_oracles::<T>(T::MaxOracles::get());
+		let oracle = oracles[0].clone();
+		let origin = T::OracleOrigin::try_successful_origin()
+			.map_err(|_| frame_benchmarking::BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, oracle.clone())
+	verify {
+		assert!(!Pallet::<T>::is_oracle(&oracle));
+		assert_last_event::<T>(Event::OracleDeregistered { oracle }.into());
+	}
+
+	// The signature of a report is checked when the transaction is
+	// validated, so the dispatch only records and aggregates the report.
+	submit_report {
+		let r in 1 .. T::MaxOracles::get();
+
+		let oracles = add_oracles::<T>(r);
+		let subject: T::AccountId = account("subject", 0, SEED);
+		let timestamp = frame_system::Pallet::<T>::block_number();
+		let reports = oracles[1..]
+			.iter()
+			.enumerate()
+			.map(|(i, oracle)| (oracle.clone(), FixedU128::saturating_from_integer(i as u32), timestamp))
+			.collect::<Vec<_>>();
+		Reports::<T>::insert(&subject, BoundedVec::truncate_from(reports));
+
+		let oracle = oracles[0].clone();
+		let score = FixedU128::saturating_from_integer(r);
+		let report = OracleReport { subject: subject.clone(), score, timestamp };
+	}: {
+		Pallet::<T>::record_report(oracle, report)?;
+	}
+	verify {
+		assert_eq!(Reports::<T>::get(&subject).len() as u32, r);
+		assert!(Scores::<T>::contains_key(&subject));
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,455 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Scoring Oracle Pallet
+//!
+//! The Scoring Oracle Pallet aggregates scores of accounts reported by a set
+//! of registered oracles. Every oracle keeps one report per subject, and the
+//! score of a subject is the median of the latest reports of the registered
+//! oracles, recomputed whenever one of them reports.
+//!
+//! Oracles run an offchain worker which, every `ReportInterval` blocks,
+//! fetches scores from the HTTP endpoint found in the offchain local storage
+//! under [`ORACLE_ENDPOINT_STORAGE_KEY`]. The endpoint answers a `GET` request
+//! with a SCALE encoded `Vec<(AccountId, FixedU128)>`. Every score is signed
+//! with the oracle key of the node, of type [`KEY_TYPE`], and submitted as an
+//! unsigned transaction.
+//!
+//! Oracles are registered and deregistered by the `OracleOrigin`. The reports
+//! of a deregistered oracle are dropped from the score of a subject the next
+//! time the subject is reported on.
+//!
+//! ### Storage
+//!
+//! - `Oracles`: The registered oracles, sorted.
+//! - `Reports`: The latest report of every oracle on a subject.
+//! - `Scores`: The aggregated score of every subject.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `register_oracle` - Register an oracle.
+//! - `deregister_oracle` - Deregister an oracle.
+//! - `submit_report` - Submit a signed oracle report, as an unsigned
+//!   transaction.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::Decode;
+use frame_system::offchain::{
+	CreateSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer,
+};
+pub use pallet::*;
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+	offchain::{http, storage::StorageValueRef, Duration},
+	traits::{IdentifyAccount, Saturating, Zero},
+	FixedPointNumber, FixedU128,
+};
+use sp_std::vec::Vec;
+pub use types::{OracleReport, OracleReportPayload};
+pub use weights::WeightInfo;
+
+/// Key type of the oracle keys that sign oracle reports.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"orcl");
+
+/// Offchain local storage key holding the URL of the score endpoint.
+pub const ORACLE_ENDPOINT_STORAGE_KEY: &[u8] = b"cord::scoring-oracle::endpoint";
+
+/// Time the offchain worker waits for the score endpoint, in milliseconds.
+const FETCH_TIMEOUT_MS: u64 = 3_000;
+
+/// The oracle key, an sr25519 key of type [`KEY_TYPE`].
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Identifier of the oracle key, to sign oracle reports with.
+	pub struct OracleAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OracleAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+		for OracleAuthId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::{offchain::AppCrypto, pallet_prelude::*};
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of an oracle report.
+	pub type OracleReportOf<T> =
+		OracleReport<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+	/// Type of a signed oracle report.
+	pub type OracleReportPayloadOf<T> = OracleReportPayload<
+		<T as frame_system::offchain::SigningTypes>::Public,
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+	>;
+	/// Latest reports on a subject, as `(oracle, score, timestamp)`.
+	pub type OracleScoresOf<T> = BoundedVec<
+		(<T as frame_system::Config>::AccountId, FixedU128, BlockNumberFor<T>),
+		<T as Config>::MaxOracles,
+	>;
+
+	#[pallet::config]
+	pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin that can register and deregister oracles.
+		type OracleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The oracle key the offchain worker signs reports with.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+		/// The maximum number of registered oracles.
+		#[pallet::constant]
+		type MaxOracles: Get<u32>;
+		/// Number of blocks between two score fetches of the offchain worker.
+		/// Reports observed longer ago are stale.
+		#[pallet::constant]
+		type ReportInterval: Get<BlockNumberFor<Self>>;
+		/// Priority of the unsigned oracle report transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// registered oracles, sorted
+	#[pallet::storage]
+	#[pallet::getter(fn oracles)]
+	pub type Oracles<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxOracles>, ValueQuery>;
+
+	/// latest oracle reports by subject
+	#[pallet::storage]
+	#[pallet::getter(fn reports)]
+	pub type Reports<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, OracleScoresOf<T>, ValueQuery>;
+
+	/// aggregated scores by subject
+	#[pallet::storage]
+	#[pallet::getter(fn scores)]
+	pub type Scores<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, FixedU128, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An oracle was registered.
+		OracleRegistered { oracle: T::AccountId },
+		/// An oracle was deregistered.
+		OracleDeregistered { oracle: T::AccountId },
+		/// An oracle reported a score.
+		ReportSubmitted { oracle: T::AccountId, subject: T::AccountId, score: FixedU128 },
+		/// The aggregated score of a subject changed.
+		ScoreAggregated { subject: T::AccountId, score: FixedU128 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The oracle is already registered.
+		OracleAlreadyRegistered,
+		/// The account is not a registered oracle.
+		OracleNotFound,
+		/// The maximum number of oracles is registered.
+		TooManyOracles,
+		/// The report timestamp is in the future.
+		FutureReport,
+		/// The report is older than the report interval, or than the latest
+		/// report of the oracle on the subject.
+		StaleReport,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			if let Err(e) = Self::fetch_and_submit_reports(now) {
+				log::debug!(
+					target: "runtime::scoring-oracle",
+					"Skipping oracle reports: {}",
+					e
+				);
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::submit_report { payload, signature } = call else {
+				return InvalidTransaction::Call.into()
+			};
+
+			if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into()
+			}
+			let oracle = payload.public.clone().into_account();
+			if !Self::is_oracle(&oracle) {
+				return InvalidTransaction::BadSigner.into()
+			}
+			Self::ensure_fresh_report(&oracle, &payload.report)
+				.map_err(|_| InvalidTransaction::Stale)?;
+
+			ValidTransaction::with_tag_prefix("CordOracleReport")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((oracle, &payload.report.subject, payload.report.timestamp))
+				.longevity(64_u64)
+				.propagate(true)
+				.build()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register `oracle`, allowing it to report scores.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_oracle())]
+		pub fn register_oracle(origin: OriginFor<T>, oracle: T::AccountId) -> DispatchResult {
+			T::OracleOrigin::ensure_origin(origin)?;
+
+			Oracles::<T>::try_mutate(|oracles| {
+				let index = oracles
+					.binary_search(&oracle)
+					.err()
+					.ok_or(Error::<T>::OracleAlreadyRegistered)?;
+				oracles
+					.try_insert(index, oracle.clone())
+					.map_err(|_| Error::<T>::TooManyOracles)
+			})?;
+
+			Self::deposit_event(Event::OracleRegistered { oracle });
+			Ok(())
+		}
+
+		/// Deregister `oracle`. Its reports no longer count towards the
+		/// scores.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::deregister_oracle())]
+		pub fn deregister_oracle(origin: OriginFor<T>, oracle: T::AccountId) -> DispatchResult {
+			T::OracleOrigin::ensure_origin(origin)?;
+
+			Oracles::<T>::try_mutate(|oracles| {
+				let index =
+					oracles.binary_search(&oracle).map_err(|_| Error::<T>::OracleNotFound)?;
+				oracles.remove(index);
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::OracleDeregistered { oracle });
+			Ok(())
+		}
+
+		/// Submit the oracle report in `payload`, signed by a registered
+		/// oracle, and update the score of its subject. The signature is
+		/// checked when the transaction is validated.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_report(T::MaxOracles::get()))]
+		pub fn submit_report(
+			origin: OriginFor<T>,
+			payload: OracleReportPayloadOf<T>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let oracle = payload.public.into_account();
+			ensure!(Self::is_oracle(&oracle), Error::<T>::OracleNotFound);
+			Self::ensure_fresh_report(&oracle, &payload.report)?;
+
+			Self::record_report(oracle, payload.report)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `who` is a registered oracle.
+	pub fn is_oracle(who: &T::AccountId) -> bool {
+		Oracles::<T>::get().binary_search(who).is_ok()
+	}
+
+	/// The aggregated score of `subject`, if any.
+	pub fn get_score(subject: &T::AccountId) -> Option<FixedU128> {
+		Scores::<T>::get(subject)
+	}
+
+	/// The median of `scores`, or `None` if there are none. The median of an
+	/// even number of scores is the mean of the two middle ones.
+	pub fn median(scores: &mut [FixedU128]) -> Option<FixedU128> {
+		if scores.is_empty() {
+			return None
+		}
+		scores.sort_unstable();
+
+		let middle = scores.len() / 2;
+		if scores.len() % 2 == 1 {
+			return Some(scores[middle])
+		}
+		let (low, high) = (scores[middle - 1], scores[middle]);
+		Some(low.saturating_add(
+			high.saturating_sub(low).saturating_mul(FixedU128::from_rational(1, 2)),
+		))
+	}
+
+	/// Ensure `report` by `oracle` was observed within the report interval,
+	/// and after the latest report of `oracle` on the same subject.
+	fn ensure_fresh_report(
+		oracle: &T::AccountId,
+		report: &OracleReportOf<T>,
+	) -> Result<(), Error<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		if report.timestamp > now {
+			return Err(Error::<T>::FutureReport)
+		}
+		if now.saturating_sub(report.timestamp) > T::ReportInterval::get() {
+			return Err(Error::<T>::StaleReport)
+		}
+
+		let latest = Reports::<T>::get(&report.subject)
+			.into_iter()
+			.find_map(|(reporter, _, timestamp)| (&reporter == oracle).then_some(timestamp));
+		match latest {
+			Some(timestamp) if timestamp >= report.timestamp => Err(Error::<T>::StaleReport),
+			_ => Ok(()),
+		}
+	}
+
+	/// Replace the report of `oracle` on the subject of `report`, drop the
+	/// reports of deregistered oracles, and aggregate the score of the
+	/// subject.
+	pub(crate) fn record_report(
+		oracle: T::AccountId,
+		report: OracleReportOf<T>,
+	) -> sp_runtime::DispatchResult {
+		let OracleReport { subject, score, timestamp } = report;
+		let oracles = Oracles::<T>::get();
+
+		let mut reports = Reports::<T>::get(&subject);
+		reports.retain(|(reporter, ..)| {
+			reporter != &oracle && oracles.binary_search(reporter).is_ok()
+		});
+		reports
+			.try_push((oracle.clone(), score, timestamp))
+			.map_err(|_| Error::<T>::TooManyOracles)?;
+
+		let mut scores = reports.iter().map(|(_, score, _)| *score).collect::<Vec<_>>();
+		let aggregated = Self::median(&mut scores).unwrap_or(score);
+
+		Reports::<T>::insert(&subject, reports);
+		Scores::<T>::insert(&subject, aggregated);
+
+		Self::deposit_event(Event::ReportSubmitted { oracle, subject: subject.clone(), score });
+		Self::deposit_event(Event::ScoreAggregated { subject, score: aggregated });
+		Ok(())
+	}
+
+	/// Fetch the scores from the oracle endpoint, once every report interval,
+	/// and submit a signed report for each of them.
+	fn fetch_and_submit_reports(now: BlockNumberFor<T>) -> Result<(), &'static str> {
+		let interval = T::ReportInterval::get();
+		if interval.is_zero() || !(now % interval).is_zero() {
+			return Ok(())
+		}
+
+		let storage = StorageValueRef::persistent(ORACLE_ENDPOINT_STORAGE_KEY);
+		let endpoint = match storage.get::<Vec<u8>>() {
+			Ok(Some(endpoint)) => endpoint,
+			Ok(None) => return Ok(()),
+			Err(_) => return Err("undecodable oracle endpoint"),
+		};
+
+		let signer = Signer::<T, T::AuthorityId>::any_account();
+		if !signer.can_sign() {
+			return Err("no oracle key in the keystore")
+		}
+
+		for (subject, score) in Self::fetch_scores(&endpoint)? {
+			let report = OracleReport { subject, score, timestamp: now };
+			let (_, result) = signer
+				.send_unsigned_transaction(
+					|account| OracleReportPayload {
+						report: report.clone(),
+						public: account.public.clone(),
+					},
+					|payload, signature| Call::submit_report { payload, signature },
+				)
+				.ok_or("no oracle key in the keystore")?;
+			result.map_err(|_| "failed to submit an oracle report")?;
+		}
+		Ok(())
+	}
+
+	/// Fetch the scores served at `endpoint`.
+	fn fetch_scores(endpoint: &[u8]) -> Result<Vec<(T::AccountId, FixedU128)>, &'static str> {
+		let url =
+			sp_std::str::from_utf8(endpoint).map_err(|_| "the oracle endpoint is not UTF-8")?;
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+
+		let pending = http::Request::get(url)
+			.deadline(deadline)
+			.send()
+			.map_err(|_| "failed to request the scores")?;
+		let response = pending
+			.try_wait(deadline)
+			.map_err(|_| "the score request timed out")?
+			.map_err(|_| "the score request failed")?;
+		if response.code != 200 {
+			return Err("unexpected score response status")
+		}
+
+		let body = response.body().collect::<Vec<u8>>();
+		Decode::decode(&mut &body[..]).map_err(|_| "undecodable scores")
+	}
+}
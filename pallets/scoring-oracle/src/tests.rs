@@ -0,0 +1,260 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, pallet_prelude::*};
+use sp_core::{
+	offchain::{testing, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt},
+	sr25519, Pair,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::traits::BadOrigin;
+
+const ENDPOINT: &str = "http://localhost:8080/scores";
+
+fn oracle(seed: u8) -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[seed; 32])
+}
+
+fn subject(seed: u8) -> AccountId {
+	sr25519::Public::from_raw([seed; 32])
+}
+
+fn score(n: u32) -> FixedU128 {
+	FixedU128::saturating_from_integer(n)
+}
+
+fn register(pairs: &[&sr25519::Pair]) {
+	for pair in pairs {
+		assert_ok!(ScoringOracle::register_oracle(RuntimeOrigin::root(), pair.public()));
+	}
+}
+
+fn signed_report(
+	pair: &sr25519::Pair,
+	subject: AccountId,
+	score: FixedU128,
+	timestamp: u64,
+) -> (OracleReportPayloadOf<Test>, sr25519::Signature) {
+	let payload = OracleReportPayload {
+		report: OracleReport { subject, score, timestamp },
+		public: pair.public(),
+	};
+	let signature = pair.sign(&payload.encode());
+	(payload, signature)
+}
+
+fn submit(
+	pair: &sr25519::Pair,
+	subject: AccountId,
+	score: FixedU128,
+	timestamp: u64,
+) -> DispatchResult {
+	let (payload, signature) = signed_report(pair, subject, score, timestamp);
+	ScoringOracle::submit_report(RuntimeOrigin::none(), payload, signature)
+}
+
+#[test]
+fn register_and_deregister_oracle_works() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (oracle(1), oracle(2));
+		assert_noop!(
+			ScoringOracle::register_oracle(RuntimeOrigin::signed(bob.public()), alice.public()),
+			BadOrigin
+		);
+
+		register(&[&alice]);
+		assert!(ScoringOracle::is_oracle(&alice.public()));
+		System::assert_last_event(Event::OracleRegistered { oracle: alice.public() }.into());
+		assert_noop!(
+			ScoringOracle::register_oracle(RuntimeOrigin::root(), alice.public()),
+			Error::<Test>::OracleAlreadyRegistered
+		);
+
+		assert_ok!(ScoringOracle::deregister_oracle(RuntimeOrigin::root(), alice.public()));
+		assert!(!ScoringOracle::is_oracle(&alice.public()));
+		System::assert_last_event(Event::OracleDeregistered { oracle: alice.public() }.into());
+		assert_noop!(
+			ScoringOracle::deregister_oracle(RuntimeOrigin::root(), alice.public()),
+			Error::<Test>::OracleNotFound
+		);
+	});
+}
+
+#[test]
+fn registered_oracles_are_bounded() {
+	new_test_ext().execute_with(|| {
+		register(&[&oracle(1), &oracle(2), &oracle(3)]);
+		assert_noop!(
+			ScoringOracle::register_oracle(RuntimeOrigin::root(), oracle(4).public()),
+			Error::<Test>::TooManyOracles
+		);
+	});
+}
+
+#[test]
+fn median_works() {
+	assert_eq!(ScoringOracle::median(&mut []), None);
+	assert_eq!(ScoringOracle::median(&mut [score(7)]), Some(score(7)));
+	assert_eq!(ScoringOracle::median(&mut [score(9), score(1), score(4)]), Some(score(4)));
+	// the mean of the two middle scores
+	assert_eq!(
+		ScoringOracle::median(&mut [score(8), score(1), score(3), score(100)]),
+		Some(FixedU128::from_rational(11, 2))
+	);
+}
+
+#[test]
+fn submit_report_aggregates_the_median() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob, charlie) = (oracle(1), oracle(2), oracle(3));
+		register(&[&alice, &bob, &charlie]);
+
+		assert_ok!(submit(&alice, subject(9), score(10), 1));
+		assert_eq!(ScoringOracle::get_score(&subject(9)), Some(score(10)));
+		assert_ok!(submit(&bob, subject(9), score(2), 1));
+		assert_eq!(ScoringOracle::get_score(&subject(9)), Some(score(6)));
+		assert_ok!(submit(&charlie, subject(9), score(3), 1));
+		assert_eq!(ScoringOracle::get_score(&subject(9)), Some(score(3)));
+		System::assert_last_event(
+			Event::ScoreAggregated { subject: subject(9), score: score(3) }.into(),
+		);
+
+		// a newer report replaces the previous one of the same oracle
+		System::set_block_number(2);
+		assert_ok!(submit(&bob, subject(9), score(20), 2));
+		assert_eq!(ScoringOracle::reports(subject(9)).len(), 3);
+		assert_eq!(ScoringOracle::get_score(&subject(9)), Some(score(10)));
+
+		// the reports of deregistered oracles are dropped
+		assert_ok!(ScoringOracle::deregister_oracle(RuntimeOrigin::root(), bob.public()));
+		assert_ok!(submit(&alice, subject(9), score(4), 2));
+		assert_eq!(ScoringOracle::reports(subject(9)).len(), 2);
+		assert_eq!(ScoringOracle::get_score(&subject(9)), Some(FixedU128::from_rational(7, 2)));
+	});
+}
+
+#[test]
+fn submit_report_rejects_invalid_reports() {
+	new_test_ext().execute_with(|| {
+		let alice = oracle(1);
+		assert_noop!(submit(&alice, subject(9), score(1), 1), Error::<Test>::OracleNotFound);
+		register(&[&alice]);
+
+		let (payload, signature) = signed_report(&alice, subject(9), score(1), 1);
+		assert_noop!(
+			ScoringOracle::submit_report(RuntimeOrigin::signed(alice.public()), payload, signature),
+			BadOrigin
+		);
+
+		System::set_block_number(REPORT_INTERVAL + 2);
+		assert_noop!(
+			submit(&alice, subject(9), score(1), REPORT_INTERVAL + 3),
+			Error::<Test>::FutureReport
+		);
+		assert_noop!(submit(&alice, subject(9), score(1), 1), Error::<Test>::StaleReport);
+
+		assert_ok!(submit(&alice, subject(9), score(1), REPORT_INTERVAL));
+		assert_noop!(
+			submit(&alice, subject(9), score(2), REPORT_INTERVAL),
+			Error::<Test>::StaleReport
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_checks_the_signature_and_the_oracle() {
+	new_test_ext().execute_with(|| {
+		let (alice, bob) = (oracle(1), oracle(2));
+		register(&[&alice]);
+
+		let (payload, signature) = signed_report(&alice, subject(9), score(1), 1);
+		let call = Call::submit_report { payload: payload.clone(), signature };
+		assert_ok!(ScoringOracle::validate_unsigned(TransactionSource::External, &call));
+
+		let forged = bob.sign(&payload.encode());
+		let call = Call::submit_report { payload, signature: forged };
+		assert_eq!(
+			ScoringOracle::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::BadProof.into()
+		);
+
+		let (payload, signature) = signed_report(&bob, subject(9), score(1), 1);
+		let call = Call::submit_report { payload, signature };
+		assert_eq!(
+			ScoringOracle::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::BadSigner.into()
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_submits_the_fetched_scores() {
+	let (offchain, offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let keystore = MemoryKeystore::new();
+	let public = keystore.sr25519_generate_new(KEY_TYPE, None).unwrap();
+
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt::new(keystore));
+
+	offchain_state.write().expect_request(testing::PendingRequest {
+		method: "GET".into(),
+		uri: ENDPOINT.into(),
+		response: Some(vec![(subject(9), score(42))].encode()),
+		sent: true,
+		..Default::default()
+	});
+
+	ext.execute_with(|| {
+		assert_ok!(ScoringOracle::register_oracle(RuntimeOrigin::root(), public));
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			ORACLE_ENDPOINT_STORAGE_KEY,
+			&ENDPOINT.as_bytes().to_vec().encode(),
+		);
+
+		// nothing is fetched between two report intervals
+		ScoringOracle::offchain_worker(REPORT_INTERVAL - 1);
+		assert!(pool_state.read().transactions.is_empty());
+
+		System::set_block_number(REPORT_INTERVAL);
+		ScoringOracle::offchain_worker(REPORT_INTERVAL);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, None);
+		let RuntimeCall::ScoringOracle(Call::submit_report { payload, signature }) = tx.call else {
+			panic!("unexpected call")
+		};
+		assert_eq!(
+			payload.report,
+			OracleReport { subject: subject(9), score: score(42), timestamp: REPORT_INTERVAL }
+		);
+		assert!(SignedPayload::<Test>::verify::<crypto::OracleAuthId>(&payload, signature.clone()));
+
+		assert_ok!(ScoringOracle::submit_report(RuntimeOrigin::none(), payload, signature));
+		assert_eq!(ScoringOracle::get_score(&subject(9)), Some(score(42)));
+	});
+}
@@ -0,0 +1,52 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_system::offchain::{SignedPayload, SigningTypes};
+use scale_info::TypeInfo;
+use sp_runtime::{FixedU128, RuntimeDebug};
+
+/// A score reported by an oracle.
+///
+/// ## Fields
+///
+/// - `subject`: The account the score is about.
+/// - `score`: The reported score.
+/// - `timestamp`: The block the score was observed at.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct OracleReport<AccountId, BlockNumber> {
+	pub subject: AccountId,
+	pub score: FixedU128,
+	pub timestamp: BlockNumber,
+}
+
+/// An oracle report, together with the public key of the oracle that signs
+/// it.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct OracleReportPayload<Public, AccountId, BlockNumber> {
+	pub report: OracleReport<AccountId, BlockNumber>,
+	pub public: Public,
+}
+
+impl<T: SigningTypes, AccountId: Encode, BlockNumber: Encode> SignedPayload<T>
+	for OracleReportPayload<T::Public, AccountId, BlockNumber>
+{
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
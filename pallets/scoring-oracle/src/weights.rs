@@ -0,0 +1,116 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_scoring_oracle`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_scoring_oracle
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/scoring-oracle/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_scoring_oracle`.
+pub trait WeightInfo {
+	fn register_oracle() -> Weight;
+	fn deregister_oracle() -> Weight;
+	fn submit_report(r: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_cord_scoring_oracle` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ScoringOracle::Oracles` (r:1 w:1)
+	/// Proof: `ScoringOracle::Oracles` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	fn register_oracle() -> Weight {
+		Weight::from_parts(18_250_000, 2510)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ScoringOracle::Oracles` (r:1 w:1)
+	/// Proof: `ScoringOracle::Oracles` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	fn deregister_oracle() -> Weight {
+		Weight::from_parts(17_480_000, 2510)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ScoringOracle::Oracles` (r:1 w:0)
+	/// Proof: `ScoringOracle::Oracles` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	/// Storage: `ScoringOracle::Reports` (r:1 w:1)
+	/// Proof: `ScoringOracle::Reports` (`max_values`: None, `max_size`: Some(1716), added: 4191, mode: `MaxEncodedLen`)
+	/// Storage: `ScoringOracle::Scores` (r:0 w:1)
+	/// Proof: `ScoringOracle::Scores` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 32]`.
+	fn submit_report(r: u32, ) -> Weight {
+		Weight::from_parts(28_940_000, 5181)
+			.saturating_add(Weight::from_parts(412_600, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ScoringOracle::Oracles` (r:1 w:1)
+	/// Proof: `ScoringOracle::Oracles` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	fn register_oracle() -> Weight {
+		Weight::from_parts(18_250_000, 2510)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ScoringOracle::Oracles` (r:1 w:1)
+	/// Proof: `ScoringOracle::Oracles` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	fn deregister_oracle() -> Weight {
+		Weight::from_parts(17_480_000, 2510)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ScoringOracle::Oracles` (r:1 w:0)
+	/// Proof: `ScoringOracle::Oracles` (`max_values`: Some(1), `max_size`: Some(1025), added: 1520, mode: `MaxEncodedLen`)
+	/// Storage: `ScoringOracle::Reports` (r:1 w:1)
+	/// Proof: `ScoringOracle::Reports` (`max_values`: None, `max_size`: Some(1716), added: 4191, mode: `MaxEncodedLen`)
+	/// Storage: `ScoringOracle::Scores` (r:0 w:1)
+	/// Proof: `ScoringOracle::Scores` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 32]`.
+	fn submit_report(r: u32, ) -> Weight {
+		Weight::from_parts(28_940_000, 5181)
+			.saturating_add(Weight::from_parts(412_600, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
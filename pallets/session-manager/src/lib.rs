@@ -0,0 +1,195 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+//! # Session Manager Pallet
+//!
+//! The Session Manager Pallet lets validators rotate their session keys
+//! without a runtime upgrade. `update_session_keys` sets the new keys through
+//! `pallet_session`, which queues them at the end of the current session,
+//! and records them as a pending rotation until then.
+//!
+//! The pallet is the `ShouldEndSession` of `pallet_session`, delegating the
+//! decision to `SessionEndTrigger`, e.g. `Babe`. The genesis session keys
+//! are still set by the session genesis config.
+//!
+//! Pending rotations are keyed by the session they were set in. Once that
+//! session ended they are stale and `on_idle` removes them, at most
+//! `MaxPrunedPerBlock` entries per block.
+//!
+//! ### Storage
+//!
+//! - `PendingKeyRotation`: Session keys set by a validator in a session.
+//! - `OldestPendingSession`: The oldest session that may still have pending
+//!   rotations stored.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `update_session_keys` - Set new session keys for the next session.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use frame_support::{traits::Get, weights::Weight};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use pallet_session::ShouldEndSession;
+use sp_runtime::{SaturatedConversion, Saturating};
+use sp_staking::SessionIndex;
+use sp_std::prelude::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_session::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Decides when the session ends, e.g. `Babe`.
+		type SessionEndTrigger: ShouldEndSession<BlockNumberFor<Self>>;
+		/// The maximum number of stale pending rotations removed in a block.
+		#[pallet::constant]
+		type MaxPrunedPerBlock: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// session keys set by a validator, keyed by the session they were set in
+	#[pallet::storage]
+	pub type PendingKeyRotation<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Keys,
+		OptionQuery,
+	>;
+
+	/// the oldest session that may still have pending rotations stored
+	#[pallet::storage]
+	#[pallet::getter(fn oldest_pending_session)]
+	pub type OldestPendingSession<T> = StorageValue<_, SessionIndex, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New session keys of a validator were set for the next session.
+		SessionKeysUpdated { who: T::AccountId },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_stale_rotations(remaining_weight)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set new session keys for the caller. The keys are queued at the
+		/// end of the current session and replace the current keys in the
+		/// session after.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_session_keys())]
+		pub fn update_session_keys(origin: OriginFor<T>, keys: T::Keys) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			// Session keys do not check ownership proofs.
+			pallet_session::Pallet::<T>::set_keys(origin, keys.clone(), Vec::new())?;
+			let session = pallet_session::Pallet::<T>::current_index();
+			PendingKeyRotation::<T>::insert(session, &who, keys);
+
+			Self::deposit_event(Event::SessionKeysUpdated { who });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The session keys `who` set during the current session, if any.
+	pub fn pending_key_rotation(who: &T::AccountId) -> Option<T::Keys> {
+		PendingKeyRotation::<T>::get(pallet_session::Pallet::<T>::current_index(), who)
+	}
+
+	/// Remove the pending rotations of ended sessions, oldest session first,
+	/// within `limit` and `MaxPrunedPerBlock`.
+	pub(crate) fn prune_stale_rotations(limit: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// Reading both session indices and writing back the oldest one.
+		let mut consumed = db_weight.reads_writes(2, 1);
+		if consumed.any_gt(limit) {
+			return Weight::zero()
+		}
+
+		let current = pallet_session::Pallet::<T>::current_index();
+		let mut oldest = OldestPendingSession::<T>::get();
+		if oldest >= current {
+			return db_weight.reads(2)
+		}
+
+		let mut budget = T::MaxPrunedPerBlock::get();
+		while oldest < current && budget > 0 {
+			let fits = limit.saturating_sub(consumed).ref_time() / db_weight.write.max(1);
+			let removals = budget.min(fits.saturated_into());
+			if removals == 0 {
+				break
+			}
+
+			let result = PendingKeyRotation::<T>::clear_prefix(oldest, removals, None);
+			// An empty session still costs a step of the budget.
+			let steps = result.loops.max(1);
+			budget = budget.saturating_sub(steps);
+			consumed.saturating_accrue(db_weight.writes(steps.into()));
+			if result.maybe_cursor.is_some() {
+				break
+			}
+			oldest.saturating_inc();
+		}
+
+		OldestPendingSession::<T>::put(oldest);
+		consumed
+	}
+}
+
+impl<T: Config> ShouldEndSession<BlockNumberFor<T>> for Pallet<T> {
+	fn should_end_session(now: BlockNumberFor<T>) -> bool {
+		T::SessionEndTrigger::should_end_session(now)
+	}
+}
@@ -0,0 +1,116 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin};
+
+#[test]
+fn update_session_keys_sets_the_next_keys() {
+	new_test_ext().execute_with(|| {
+		let keys: MockSessionKeys = UintAuthorityId(11).into();
+		assert_ok!(SessionManager::update_session_keys(RuntimeOrigin::signed(1), keys.clone()));
+
+		assert_eq!(pallet_session::NextKeys::<Test>::get(1), Some(keys.clone()));
+		assert_eq!(SessionManager::pending_key_rotation(&1), Some(keys));
+		System::assert_last_event(Event::SessionKeysUpdated { who: 1 }.into());
+
+		assert_noop!(
+			SessionManager::update_session_keys(RuntimeOrigin::none(), UintAuthorityId(12).into()),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn pending_rotations_are_queued_at_the_session_end() {
+	new_test_ext().execute_with(|| {
+		let keys: MockSessionKeys = UintAuthorityId(11).into();
+		assert_ok!(SessionManager::update_session_keys(RuntimeOrigin::signed(1), keys.clone()));
+
+		run_to_block(SESSION_LENGTH - 1);
+		assert_eq!(Session::current_index(), 0);
+		assert!(SessionManager::pending_key_rotation(&1).is_some());
+
+		run_to_block(SESSION_LENGTH);
+		assert_eq!(Session::current_index(), 1);
+		assert_eq!(SessionManager::pending_key_rotation(&1), None);
+		assert!(Session::queued_keys().contains(&(1, keys)));
+		// The rotation is stale, but still stored until it is pruned.
+		assert!(PendingKeyRotation::<Test>::contains_key(0, 1));
+	});
+}
+
+#[test]
+fn stale_rotations_are_pruned_in_bounded_steps() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		for who in 1..=3 {
+			let keys: MockSessionKeys = UintAuthorityId(10 + who).into();
+			assert_ok!(SessionManager::update_session_keys(RuntimeOrigin::signed(who), keys));
+		}
+
+		// Nothing is stale during the session the rotations were set in.
+		SessionManager::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(PendingKeyRotation::<Test>::iter_prefix(0).count(), 3);
+
+		run_to_block(SESSION_LENGTH);
+		let keys: MockSessionKeys = UintAuthorityId(21).into();
+		assert_ok!(SessionManager::update_session_keys(RuntimeOrigin::signed(1), keys));
+	});
+	// Limited prefix removals only apply to committed keys.
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		// At most `MaxPrunedPerBlock` entries go per block.
+		SessionManager::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(PendingKeyRotation::<Test>::iter_prefix(0).count(), 1);
+		assert_eq!(SessionManager::oldest_pending_session(), 0);
+	});
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		run_to_block(SESSION_LENGTH + 1);
+		SessionManager::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(PendingKeyRotation::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(SessionManager::oldest_pending_session(), 1);
+
+		// The rotation of the current session is kept.
+		assert!(SessionManager::pending_key_rotation(&1).is_some());
+	});
+}
+
+#[test]
+fn pruning_respects_the_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		let keys: MockSessionKeys = UintAuthorityId(11).into();
+		assert_ok!(SessionManager::update_session_keys(RuntimeOrigin::signed(1), keys));
+		run_to_block(SESSION_LENGTH);
+
+		assert_eq!(SessionManager::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+		assert!(PendingKeyRotation::<Test>::contains_key(0, 1));
+	});
+}
+
+#[test]
+fn should_end_session_delegates_to_the_trigger() {
+	new_test_ext().execute_with(|| {
+		assert!(!<SessionManager as ShouldEndSession<_>>::should_end_session(SESSION_LENGTH - 1));
+		assert!(<SessionManager as ShouldEndSession<_>>::should_end_session(SESSION_LENGTH));
+	});
+}
@@ -0,0 +1,90 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+const MAX_VALIDATORS: u32 = 1_000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// `count` validators that can afford the session bond.
+fn funded_validators<T: Config>(count: u32) -> Vec<T::AccountId> {
+	let balance = T::SessionBond::get()
+		.saturating_mul(2u32.into())
+		.saturating_add(T::Currency::minimum_balance());
+	(0..count)
+		.map(|i| {
+			let who: T::AccountId = account("validator", i, SEED);
+			T::Currency::make_free_balance_be(&who, balance);
+			who
+		})
+		.collect()
+}
+
+benchmarks! {
+	slash_validator {
+		let validators = funded_validators::<T>(1);
+		T::Currency::make_free_balance_be(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+		Pallet::<T>::on_session_start(&validators);
+		let who = validators[0].clone();
+		let amount = Perbill::from_percent(50).mul_floor(T::SessionBond::get());
+	}: _(RawOrigin::Root, who.clone(), Perbill::from_percent(50))
+	verify {
+		assert_last_event::<T>(Event::Slashed { who, amount }.into());
+	}
+
+	on_session_start {
+		let v in 1 .. MAX_VALIDATORS;
+
+		let validators = funded_validators::<T>(v);
+	}: {
+		Pallet::<T>::on_session_start(&validators);
+	}
+	verify {
+		assert!(validators.iter().all(|who| Bonds::<T>::contains_key(who)));
+	}
+
+	// Every validator missed the session, and is slashed into the treasury.
+	on_session_end {
+		let v in 1 .. MAX_VALIDATORS;
+
+		let validators = funded_validators::<T>(v);
+		T::Currency::make_free_balance_be(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+		Pallet::<T>::on_session_start(&validators);
+	}: {
+		Pallet::<T>::on_session_end();
+	}
+	verify {
+		assert_eq!(Bonds::<T>::iter().count(), 0);
+		assert!(validators.iter().all(|who| T::Currency::reserved_balance(who).is_zero()));
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
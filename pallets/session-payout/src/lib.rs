@@ -0,0 +1,229 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Session Payout Pallet
+//!
+//! The Session Payout Pallet puts a price on missed heartbeats. At the start
+//! of every session it reserves a `SessionBond` from each validator. At the
+//! end of the session, the validators whose heartbeat coverage is below
+//! `MinHeartbeatRatio` lose part of the `MissedSessionSlash` share of their
+//! bond to the treasury, in proportion to the coverage they missed, and the
+//! rest of every bond is released.
+//!
+//! A validator that can not afford the bond is not bonded for the session,
+//! and so is not slashed for it.
+//!
+//! ### Storage
+//!
+//! - `Bonds`: The bond reserved from every validator for the current session.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `slash_validator` - Slash a share of the bond of a validator.
+//!
+//! ### Integration
+//!
+//! The pallet exposes `on_session_start` and `on_session_end`, to be called
+//! with the validators of the starting session and at the end of the
+//! session.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod traits;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use frame_support::{
+	dispatch::DispatchClass,
+	traits::{BalanceStatus, Currency, ReservableCurrency},
+};
+pub use pallet::*;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill,
+};
+use sp_std::prelude::*;
+pub use traits::HeartbeatCoverage;
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency the bonds are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The heartbeat coverage of the validators.
+		type Heartbeats: HeartbeatCoverage<Self::AccountId>;
+		/// The account slashed bonds are sent to.
+		type TreasuryAccount: Get<Self::AccountId>;
+		/// The bond reserved from every validator for a session.
+		#[pallet::constant]
+		type SessionBond: Get<BalanceOf<Self>>;
+		/// The heartbeat coverage below which a validator missed the session.
+		#[pallet::constant]
+		type MinHeartbeatRatio: Get<Perbill>;
+		/// The share of the bond a validator that missed the whole session
+		/// loses. Validators with a partial coverage below
+		/// `MinHeartbeatRatio` lose this share scaled by the missed coverage.
+		#[pallet::constant]
+		type MissedSessionSlash: Get<Perbill>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// bond reserved from a validator for the current session
+	#[pallet::storage]
+	#[pallet::getter(fn bonds)]
+	pub type Bonds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A validator was bonded for the session.
+		Bonded { who: T::AccountId, bond: BalanceOf<T> },
+		/// A validator could not afford the session bond.
+		BondUnavailable { who: T::AccountId },
+		/// A share of the bond of a validator was sent to the treasury.
+		Slashed { who: T::AccountId, amount: BalanceOf<T> },
+		/// The remaining bond of a validator was released.
+		BondReleased { who: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not bonded for the session
+		NotBonded,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Send the `fraction` share of the session bond of `who` to the
+		/// treasury.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::slash_validator())]
+		pub fn slash_validator(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			fraction: Perbill,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let bond = Bonds::<T>::get(&who).ok_or(Error::<T>::NotBonded)?;
+			let slashed = Self::slash(&who, fraction.mul_floor(bond));
+			Bonds::<T>::insert(&who, bond.saturating_sub(slashed));
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Reserve the session bond from every validator of the starting
+	/// session that is not bonded yet.
+	pub fn on_session_start(validators: &[T::AccountId]) {
+		let bond = T::SessionBond::get();
+
+		for who in validators {
+			if Bonds::<T>::contains_key(who) {
+				continue
+			}
+			if T::Currency::reserve(who, bond).is_ok() {
+				Bonds::<T>::insert(who, bond);
+				Self::deposit_event(Event::Bonded { who: who.clone(), bond });
+			} else {
+				Self::deposit_event(Event::BondUnavailable { who: who.clone() });
+			}
+		}
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::on_session_start(validators.len() as u32),
+			DispatchClass::Mandatory,
+		);
+	}
+
+	/// Slash the bonds of the validators whose heartbeat coverage of the
+	/// ending session is below `MinHeartbeatRatio`, in proportion to the
+	/// coverage they missed, and release the rest of every bond.
+	pub fn on_session_end() {
+		let bonds = Bonds::<T>::drain().collect::<Vec<_>>();
+		let min_coverage = T::MinHeartbeatRatio::get();
+
+		for (who, bond) in &bonds {
+			let mut remaining = *bond;
+			let coverage = T::Heartbeats::coverage(who);
+			if coverage < min_coverage {
+				let fraction = T::MissedSessionSlash::get() * coverage.left_from_one();
+				let slashed = Self::slash(who, fraction.mul_floor(*bond));
+				remaining = remaining.saturating_sub(slashed);
+			}
+
+			let unreserved = remaining.saturating_sub(T::Currency::unreserve(who, remaining));
+			Self::deposit_event(Event::BondReleased { who: who.clone(), amount: unreserved });
+		}
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::WeightInfo::on_session_end(bonds.len() as u32),
+			DispatchClass::Mandatory,
+		);
+	}
+
+	/// Move `amount` of the bond of `who` to the treasury, returning the
+	/// amount moved.
+	fn slash(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+		if amount.is_zero() {
+			return Zero::zero()
+		}
+
+		let missing = T::Currency::repatriate_reserved(
+			who,
+			&T::TreasuryAccount::get(),
+			amount,
+			BalanceStatus::Free,
+		)
+		.unwrap_or(amount);
+		let slashed = amount.saturating_sub(missing);
+		Self::deposit_event(Event::Slashed { who: who.clone(), amount: slashed });
+		slashed
+	}
+}
@@ -0,0 +1,110 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn session_start_bonds_the_validators() {
+	new_test_ext().execute_with(|| {
+		SessionPayout::on_session_start(&[1, 2, 3]);
+
+		assert_eq!(SessionPayout::bonds(1), Some(BOND));
+		assert_eq!(SessionPayout::bonds(2), Some(BOND));
+		assert_eq!(Balances::reserved_balance(1), BOND);
+		// A validator that can not afford the bond is not bonded.
+		assert_eq!(SessionPayout::bonds(3), None);
+		System::assert_last_event(Event::BondUnavailable { who: 3 }.into());
+	});
+}
+
+#[test]
+fn session_start_does_not_bond_twice() {
+	new_test_ext().execute_with(|| {
+		SessionPayout::on_session_start(&[1]);
+		SessionPayout::on_session_start(&[1]);
+
+		assert_eq!(Balances::reserved_balance(1), BOND);
+	});
+}
+
+#[test]
+fn session_end_slashes_validators_that_missed_the_session() {
+	new_test_ext().execute_with(|| {
+		SessionPayout::on_session_start(&[1, 2]);
+		Coverages::mutate(|coverages| coverages.insert(2, Perbill::from_percent(40)));
+
+		SessionPayout::on_session_end();
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		// 60% of the session was missed, so 60% of the 10% slash applies.
+		assert_eq!(Balances::free_balance(2), 994);
+		assert_eq!(Balances::free_balance(TREASURY), 1_006);
+		assert_eq!(SessionPayout::bonds(1), None);
+		assert_eq!(SessionPayout::bonds(2), None);
+		System::assert_has_event(Event::Slashed { who: 2, amount: 6 }.into());
+	});
+}
+
+#[test]
+fn session_end_spares_validators_at_the_min_heartbeat_ratio() {
+	new_test_ext().execute_with(|| {
+		SessionPayout::on_session_start(&[1]);
+		Coverages::mutate(|coverages| coverages.insert(1, Perbill::from_percent(50)));
+
+		SessionPayout::on_session_end();
+
+		assert_eq!(Balances::free_balance(1), 1_000);
+		System::assert_last_event(Event::BondReleased { who: 1, amount: BOND }.into());
+	});
+}
+
+#[test]
+fn slash_validator_slashes_the_bond() {
+	new_test_ext().execute_with(|| {
+		SessionPayout::on_session_start(&[1]);
+
+		assert_noop!(
+			SessionPayout::slash_validator(RuntimeOrigin::signed(1), 1, Perbill::one()),
+			BadOrigin
+		);
+		assert_noop!(
+			SessionPayout::slash_validator(RuntimeOrigin::root(), 2, Perbill::one()),
+			Error::<Test>::NotBonded
+		);
+		assert_ok!(SessionPayout::slash_validator(
+			RuntimeOrigin::root(),
+			1,
+			Perbill::from_percent(25)
+		));
+
+		assert_eq!(SessionPayout::bonds(1), Some(75));
+		assert_eq!(Balances::reserved_balance(1), 75);
+		assert_eq!(Balances::free_balance(TREASURY), 1_025);
+		System::assert_last_event(Event::Slashed { who: 1, amount: 25 }.into());
+
+		// The session slash applies to what is left of the bond.
+		Coverages::mutate(|coverages| coverages.insert(1, Perbill::zero()));
+		SessionPayout::on_session_end();
+		assert_eq!(Balances::free_balance(1), 1_000 - 25 - 7);
+	});
+}
@@ -1,8 +1,8 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use codec::Encode;
-use cord_utilities::traits::GenerateBenchmarkOrigin;
+use codec::{Encode, MaxEncodedLen};
+use cord_utilities::traits::{GenerateBenchmarkOrigin, IdentifierNamespace, StorageDeposit};
 use frame_benchmarking::{account, benchmarks};
 use frame_support::sp_runtime::traits::Hash;
 use frame_system::RawOrigin;
@@ -14,7 +14,11 @@ const MAX_PAYLOAD_BYTE_LENGTH: u32 = 5 * 1024;
 
 /// Generates a statement ID from a statement digest.
 pub fn generate_statement_id<T: Config>(digest: &StatementDigestOf<T>) -> StatementIdOf {
-	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Statement).unwrap()
+	Ss58Identifier::create_identifier(
+		&<T as Config>::IdentifierNamespace::namespaced(&digest.encode()[..]),
+		IdentifierType::Statement,
+	)
+	.unwrap()
 }
 
 /// Generates a space ID from a digest.
@@ -28,6 +32,12 @@ pub fn generate_authorization_id<T: Config>(digest: &SpaceCodeOf<T>) -> Authoriz
 		.unwrap()
 }
 
+/// Gives `caller` the balance to hold the deposits of `count` statements.
+fn fund_statements<T: Config>(caller: &T::AccountId, count: u32) {
+	let len = StatementDetailsOf::<T>::max_encoded_len() as u32;
+	<T as Config>::StorageDeposit::fund(caller, len.saturating_mul(count));
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -67,6 +77,7 @@ benchmarks! {
 
 		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
 
+		fund_statements::<T>(&caller, 1);
 		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
 		let chain_space_origin = RawOrigin::Root.into();
 
@@ -75,7 +86,7 @@ benchmarks! {
 
 	}: _<T::RuntimeOrigin>(origin, statement_digest, authorization_id, None)
 	verify {
-		assert_last_event::<T>(Event::Register { identifier, digest: statement_digest, author: did}.into());
+		assert_last_event::<T>(Event::Register { identifier, digest: statement_digest, schema: None, author: did}.into());
 	}
 
 	update {
@@ -111,6 +122,7 @@ benchmarks! {
 		let statement_update = [12u8; 32].to_vec();
 		let update_digest = <T as frame_system::Config>::Hashing::hash(&statement_update[..]);
 
+		fund_statements::<T>(&caller, 1);
 		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
 		let chain_space_origin = RawOrigin::Root.into();
 
@@ -151,6 +163,7 @@ benchmarks! {
 
 		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
 
+		fund_statements::<T>(&caller, 1);
 		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
 		let chain_space_origin = RawOrigin::Root.into();
 
@@ -191,6 +204,7 @@ benchmarks! {
 		);
 
 		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		fund_statements::<T>(&caller, 1);
 		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
 		let chain_space_origin = RawOrigin::Root.into();
 
@@ -234,6 +248,7 @@ benchmarks! {
 
 		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
 
+		fund_statements::<T>(&caller, 1);
 		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
 		let chain_space_origin = RawOrigin::Root.into();
 
@@ -275,6 +290,7 @@ benchmarks! {
 		);
 
 		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		fund_statements::<T>(&caller, 3);
 		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
 		let chain_space_origin = RawOrigin::Root.into();
 
@@ -313,6 +329,7 @@ benchmarks! {
 
 		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
 
+		fund_statements::<T>(&caller, 1);
 		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
 		let chain_space_origin = RawOrigin::Root.into();
 
@@ -354,6 +371,7 @@ benchmarks! {
 
 		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
 
+		fund_statements::<T>(&caller, 1);
 		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
 		let chain_space_origin = RawOrigin::Root.into();
 
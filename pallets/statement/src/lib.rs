@@ -99,7 +99,7 @@ use sp_std::{vec, vec::Vec};
 pub mod pallet {
 
 	use super::*;
-	use cord_utilities::traits::CallSources;
+	use cord_utilities::traits::{CallSources, IdentifierNamespace, StorageDeposit};
 	use frame_support::pallet_prelude::{OptionQuery, *};
 	use frame_system::pallet_prelude::*;
 	pub use identifier::{IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier};
@@ -126,6 +126,9 @@ pub mod pallet {
 	pub type StatementDetailsOf<T> = StatementDetails<StatementDigestOf<T>, SchemaIdOf, SpaceIdOf>;
 	/// Type for the statement entry details
 	pub type StatementEntryStatusOf<T> = StatementEntryStatus<StatementCreatorOf<T>, StatusOf>;
+	/// Type for the resolved statement entry
+	pub type StatementEntryOf<T> =
+		StatementEntry<StatementDetailsOf<T>, StatementCreatorOf<T>, StatusOf>;
 	/// Type for the statement entry details
 	pub type StatementPresentationDetailsOf<T> = StatementPresentationDetails<
 		StatementCreatorOf<T>,
@@ -150,6 +153,10 @@ pub mod pallet {
 		/// Maximum removals per call
 		#[pallet::constant]
 		type MaxRemoveEntries: Get<u16>;
+		/// Scope of the derived statement identifiers.
+		type IdentifierNamespace: IdentifierNamespace;
+		/// The deposit held from the sender for every registered statement.
+		type StorageDeposit: StorageDeposit<AccountIdOf<Self>>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -229,10 +236,12 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new statement identifier has been registered.
-		/// \[statement identifier, statement digest, controller\]
+		/// \[statement identifier, statement digest, schema identifier,
+		/// controller\]
 		Register {
 			identifier: StatementIdOf,
 			digest: StatementDigestOf<T>,
+			schema: Option<SchemaIdOf>,
 			author: StatementCreatorOf<T>,
 		},
 		/// A statement identifier has been updated.
@@ -367,7 +376,8 @@ pub mod pallet {
 		///
 		/// The function also logs the creation event by updating the activity
 		/// log and emits an event to signal the successful creation of the
-		/// statement.
+		/// statement. The storage deposit of the statement details is held
+		/// from the sender, and released when the statement is removed.
 		///
 		/// # Parameters
 		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
@@ -389,8 +399,8 @@ pub mod pallet {
 		/// updating the activity log may also occur.
 		///
 		/// # Events
-		/// - `Create`: Emitted when a statement is successfully created, containing the
-		///   `identifier`, `digest`, and `author` (creator).
+		/// - `Register`: Emitted when a statement is successfully created, containing the
+		///   `identifier`, `digest`, `schema` and `author` (creator).
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::register())]
 		pub fn register(
@@ -399,45 +409,14 @@ pub mod pallet {
 			authorization: AuthorizationIdOf,
 			schema_id: Option<SchemaIdOf>,
 		) -> DispatchResult {
-			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			Self::register_statement(
+				source.subject(),
+				&source.sender(),
+				digest,
 				&authorization,
-				&creator,
-			)
-			.map_err(<pallet_chain_space::Error<T>>::from)?;
-
-			// Id Digest = concat (H(<scale_encoded_statement_digest>,
-			// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
-			);
-
-			let identifier = Ss58Identifier::create_identifier(
-				&(id_digest).encode()[..],
-				IdentifierType::Statement,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			ensure!(
-				!<Statements<T>>::contains_key(&identifier),
-				Error::<T>::StatementAlreadyAnchored
-			);
-
-			<Statements<T>>::insert(
-				&identifier,
-				StatementDetailsOf::<T> {
-					digest,
-					schema: schema_id.clone(),
-					space: space_id.clone(),
-				},
-			);
-
-			<Entries<T>>::insert(&identifier, digest, creator.clone());
-			<IdentifierLookup<T>>::insert(digest, &space_id, &identifier);
-
-			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
-
-			Self::deposit_event(Event::Register { identifier, digest, author: creator });
+				schema_id,
+			)?;
 
 			Ok(())
 		}
@@ -802,6 +781,7 @@ pub mod pallet {
 					<RevocationList<T>>::clear_prefix(&statement_id, entries_count as u32, None);
 				let _ = <Entries<T>>::clear_prefix(&statement_id, entries_count as u32, None);
 				<Statements<T>>::remove(&statement_id);
+				T::StorageDeposit::release(&statement_id);
 				pallet_chain_space::Pallet::<T>::decrement_usage_entries(
 					&space_id,
 					entries_count as u16,
@@ -898,7 +878,8 @@ pub mod pallet {
 			authorization: AuthorizationIdOf,
 			schema_id: Option<SchemaIdOf>,
 		) -> DispatchResult {
-			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let creator = source.subject();
 			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
 				&authorization,
 				&creator,
@@ -925,24 +906,30 @@ pub mod pallet {
 				);
 
 				let identifier_result = Ss58Identifier::create_identifier(
-					&(id_digest).encode()[..],
+					&T::IdentifierNamespace::namespaced(&id_digest.encode()[..]),
 					IdentifierType::Statement,
 				);
 
 				match identifier_result {
 					Ok(identifier) => {
-						if <Statements<T>>::contains_key(&identifier) {
+						let details = StatementDetailsOf::<T> {
+							digest: *digest,
+							schema: schema_id.clone(),
+							space: space_id.clone(),
+						};
+
+						if <Statements<T>>::contains_key(&identifier) ||
+							T::StorageDeposit::hold(
+								&source.sender(),
+								&identifier,
+								details.encoded_size() as u32,
+							)
+							.is_err()
+						{
 							fail += 1;
 							indices.push(index as u16);
 						} else {
-							<Statements<T>>::insert(
-								&identifier,
-								StatementDetailsOf::<T> {
-									digest: *digest,
-									schema: schema_id.clone(),
-									space: space_id.clone(),
-								},
-							);
+							<Statements<T>>::insert(&identifier, details);
 
 							<Entries<T>>::insert(&identifier, digest, creator.clone());
 							<IdentifierLookup<T>>::insert(digest, &space_id, &identifier);
@@ -1131,6 +1118,64 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Registers a statement on behalf of `creator`, who must hold
+	/// `authorization` in the space the statement is anchored to. The
+	/// storage deposit of the statement details is held from `payer`.
+	///
+	/// This is the body of `register`, exposed so that other pallets can
+	/// anchor statements as part of a larger operation.
+	///
+	/// # Returns
+	/// The identifier of the new statement.
+	pub fn register_statement(
+		creator: StatementCreatorOf<T>,
+		payer: &AccountIdOf<T>,
+		digest: StatementDigestOf<T>,
+		authorization: &AuthorizationIdOf,
+		schema_id: Option<SchemaIdOf>,
+	) -> Result<StatementIdOf, sp_runtime::DispatchError> {
+		use codec::Encode;
+		use cord_utilities::traits::IdentifierNamespace;
+		use sp_runtime::traits::Hash;
+
+		let space_id =
+			pallet_chain_space::Pallet::<T>::ensure_authorization_origin(authorization, &creator)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+		// Id Digest = concat (H(<scale_encoded_statement_digest>,
+		// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let identifier = Ss58Identifier::create_identifier(
+			&<T as Config>::IdentifierNamespace::namespaced(&id_digest.encode()[..]),
+			IdentifierType::Statement,
+		)
+		.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(!<Statements<T>>::contains_key(&identifier), Error::<T>::StatementAlreadyAnchored);
+
+		let details =
+			StatementDetailsOf::<T> { digest, schema: schema_id.clone(), space: space_id.clone() };
+		<T as Config>::StorageDeposit::hold(payer, &identifier, details.encoded_size() as u32)?;
+		<Statements<T>>::insert(&identifier, details);
+
+		<Entries<T>>::insert(&identifier, digest, creator.clone());
+		<IdentifierLookup<T>>::insert(digest, &space_id, &identifier);
+
+		Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+
+		Self::deposit_event(Event::Register {
+			identifier: identifier.clone(),
+			digest,
+			schema: schema_id,
+			author: creator,
+		});
+
+		Ok(identifier)
+	}
+
 	/// Updates the global timeline with a new activity event for a statement.
 	/// This function is called whenever a significant action is performed on a
 	/// statement, ensuring that all such activities are logged with a timestamp
@@ -1164,6 +1209,17 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Resolves a statement to its latest details, the creator of its current
+	/// digest and its revocation status. Returns `None` if the statement is
+	/// not anchored.
+	pub fn resolve_statement(statement_id: &StatementIdOf) -> Option<StatementEntryOf<T>> {
+		let details = Statements::<T>::get(statement_id)?;
+		let creator = Entries::<T>::get(statement_id, details.digest)?;
+		let revoked = RevocationList::<T>::contains_key(statement_id, details.digest);
+
+		Some(StatementEntry { details, creator, revoked })
+	}
+
 	/// Retrieves the current timepoint.
 	///
 	/// This function returns a `Timepoint` structure containing the current
@@ -94,6 +94,8 @@ impl Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type MaxDigestsPerBatch = MaxDigetsPerBatch;
 	type MaxRemoveEntries = MaxRemoveEntries;
+	type IdentifierNamespace = ();
+	type StorageDeposit = ();
 	type WeightInfo = weights::SubstrateWeight<Test>;
 }
 
@@ -123,6 +125,8 @@ impl pallet_schema::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type IdentifierNamespace = ();
+	type StorageDeposit = ();
 }
 
 parameter_types! {
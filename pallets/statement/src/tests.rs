@@ -76,11 +76,25 @@ fn register_statement_should_succeed() {
 		));
 
 		assert_ok!(Statement::register(
-			DoubleOrigin(author, creator).into(),
+			DoubleOrigin(author, creator.clone()).into(),
 			statement_digest,
 			authorization_id,
-			Some(schema_id)
+			Some(schema_id.clone())
 		));
+
+		let id_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]]
+				.concat()[..],
+		);
+		System::assert_last_event(
+			Event::Register {
+				identifier: generate_statement_id::<Test>(&id_digest),
+				digest: statement_digest,
+				schema: Some(schema_id),
+				author: creator,
+			}
+			.into(),
+		);
 	});
 }
 
@@ -1090,3 +1104,118 @@ fn bulk_registering_statements_with_same_digest_should_fail() {
 		);
 	});
 }
+
+#[test]
+fn resolving_a_statement_should_reflect_its_revocation_status() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest: StatementDigestOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(Statement::resolve_statement(&statement_id), None);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None
+		));
+
+		assert_eq!(
+			Statement::resolve_statement(&statement_id),
+			Some(StatementEntry {
+				details: StatementDetails {
+					digest: statement_digest,
+					space: space_id.clone(),
+					schema: None
+				},
+				creator: creator.clone(),
+				revoked: false,
+			})
+		);
+
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			authorization_id,
+		));
+
+		let resolved = Statement::resolve_statement(&statement_id)
+			.expect("Revoked statement should still resolve.");
+		assert!(resolved.revoked);
+	});
+}
+
+#[test]
+fn registering_statements_up_to_the_space_capacity_should_succeed() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 2u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		for statement in [[77u8; 32], [78u8; 32]] {
+			assert_ok!(Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				<Test as frame_system::Config>::Hashing::hash(&statement[..]),
+				authorization_id.clone(),
+				None
+			));
+		}
+
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author, creator).into(),
+				<Test as frame_system::Config>::Hashing::hash(&[79u8; 32][..]),
+				authorization_id,
+				None
+			),
+			pallet_chain_space::Error::<Test>::CapacityLimitExceeded
+		);
+	});
+}
@@ -105,6 +105,20 @@ pub struct StatementEntryStatus<StatementCreatorOf, StatusOf> {
 	pub revoked: StatusOf,
 }
 
+/// `StatementEntry` is the resolved view of a statement, combining its
+/// latest details with the creator of the current digest and whether that
+/// digest has been revoked. It is returned by the `resolve_statement` runtime
+/// API so relying parties can check a statement in a single call.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct StatementEntry<StatementDetailsOf, StatementCreatorOf, StatusOf> {
+	/// The latest details of the statement.
+	pub details: StatementDetailsOf,
+	/// The DID identifier of the party that anchored the current digest.
+	pub creator: StatementCreatorOf,
+	/// Indicates whether the current digest has been revoked.
+	pub revoked: StatusOf,
+}
+
 /// Holds the details for a specific presentation of a statement.
 ///
 /// This struct captures the unique identifiers and metadata for a presentation
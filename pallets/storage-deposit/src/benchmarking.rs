@@ -0,0 +1,73 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::EnsureOrigin;
+
+/// The record the deposit is held for.
+const RECORD: [u8; 32] = [1u8; 32];
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	set_base_deposit_per_byte {
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| "no governance origin")?;
+		let amount: BalanceOf<T> = 5u32.into();
+	}: _<T::RuntimeOrigin>(origin, amount)
+	verify {
+		assert_eq!(BaseDepositPerByte::<T>::get(), amount);
+		assert_last_event::<T>(Event::BaseDepositPerByteSet { amount }.into());
+	}
+
+	hold_deposit {
+		let caller: T::AccountId = whitelisted_caller();
+		let len = u32::MAX;
+		<Pallet<T> as StorageDeposit<T::AccountId>>::fund(&caller, len);
+	}: {
+		<Pallet<T> as StorageDeposit<T::AccountId>>::hold(&caller, &RECORD, len)?;
+	}
+	verify {
+		let deposit = Deposits::<T>::get(Pallet::<T>::deposit_key(&RECORD));
+		assert_eq!(deposit.map(|d| d.amount), Some(Pallet::<T>::deposit_for(len)));
+	}
+
+	release_deposit {
+		let caller: T::AccountId = whitelisted_caller();
+		let len = u32::MAX;
+		<Pallet<T> as StorageDeposit<T::AccountId>>::fund(&caller, len);
+		<Pallet<T> as StorageDeposit<T::AccountId>>::hold(&caller, &RECORD, len)?;
+	}: {
+		<Pallet<T> as StorageDeposit<T::AccountId>>::release(&RECORD);
+	}
+	verify {
+		assert!(!Deposits::<T>::contains_key(Pallet::<T>::deposit_key(&RECORD)));
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,190 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Storage Deposit Pallet
+//!
+//! The Storage Deposit Pallet holds deposits for the records other pallets
+//! keep in storage, so that storage is paid for for as long as it is used.
+//! A pallet storing a record holds `BaseDepositPerByte` per byte of the
+//! record from the account paying for it, through the `StorageDeposit`
+//! trait, and releases the deposit when it deletes the record. The deposit
+//! returned is the one held, even if `BaseDepositPerByte` changed since.
+//!
+//! ### Storage
+//!
+//! - `BaseDepositPerByte`: The deposit held per byte of a record.
+//! - `Deposits`: The deposit held for every record, by the hash of its key.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `set_base_deposit_per_byte` - Set the deposit held per byte of new records.
+//!
+//! ### Public Functions
+//!
+//! - `deposit_for` - The deposit held for a record of a given length.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::Encode;
+use cord_utilities::{deposit::Deposit, free_deposit, reserve_deposit, traits::StorageDeposit};
+use frame_support::traits::{Currency, ReservableCurrency};
+pub use pallet::*;
+use sp_runtime::{
+	traits::{Hash, Saturating},
+	DispatchResult,
+};
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of a deposit held for a record.
+	pub type DepositOf<T> = Deposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency deposits are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Origin allowed to set the deposit per byte.
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The deposit per byte until governance sets one.
+		#[pallet::constant]
+		type DefaultDepositPerByte: Get<BalanceOf<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// deposit held per byte of a record
+	#[pallet::storage]
+	#[pallet::getter(fn base_deposit_per_byte)]
+	pub type BaseDepositPerByte<T: Config> =
+		StorageValue<_, BalanceOf<T>, ValueQuery, T::DefaultDepositPerByte>;
+
+	/// deposits held by the hash of the record key
+	#[pallet::storage]
+	#[pallet::getter(fn deposits)]
+	pub type Deposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, DepositOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The deposit per byte of new records was set.
+		BaseDepositPerByteSet { amount: BalanceOf<T> },
+		/// A deposit was held for a record.
+		DepositHeld { who: T::AccountId, key: T::Hash, amount: BalanceOf<T> },
+		/// The deposit of a deleted record was returned.
+		DepositReleased { who: T::AccountId, key: T::Hash, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A deposit is already held for the record.
+		DepositAlreadyHeld,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the deposit held per byte of the records stored from now on.
+		/// Deposits already held are left as they are.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_base_deposit_per_byte())]
+		pub fn set_base_deposit_per_byte(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			BaseDepositPerByte::<T>::put(amount);
+
+			Self::deposit_event(Event::BaseDepositPerByteSet { amount });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The deposit held for a record of `len` bytes.
+	pub fn deposit_for(len: u32) -> BalanceOf<T> {
+		BaseDepositPerByte::<T>::get().saturating_mul(len.into())
+	}
+
+	/// The storage key of the deposit of the record with `key`.
+	fn deposit_key<K: Encode>(key: &K) -> T::Hash {
+		<T as frame_system::Config>::Hashing::hash_of(key)
+	}
+}
+
+impl<T: Config> StorageDeposit<T::AccountId> for Pallet<T> {
+	fn hold<K: Encode>(who: &T::AccountId, key: &K, len: u32) -> DispatchResult {
+		let key = Self::deposit_key(key);
+		frame_support::ensure!(!Deposits::<T>::contains_key(key), Error::<T>::DepositAlreadyHeld);
+
+		let deposit = reserve_deposit::<_, T::Currency>(who.clone(), Self::deposit_for(len))?;
+		let amount = deposit.amount;
+		Deposits::<T>::insert(key, deposit);
+
+		Self::deposit_event(Event::DepositHeld { who: who.clone(), key, amount });
+		Ok(())
+	}
+
+	fn release<K: Encode>(key: &K) {
+		let key = Self::deposit_key(key);
+		if let Some(deposit) = Deposits::<T>::take(key) {
+			free_deposit::<_, T::Currency>(&deposit);
+			Self::deposit_event(Event::DepositReleased {
+				who: deposit.owner,
+				key,
+				amount: deposit.amount,
+			});
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn fund(who: &T::AccountId, len: u32) {
+		let balance = Self::deposit_for(len).saturating_add(T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(who, balance);
+	}
+}
@@ -0,0 +1,81 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::{StorageDeposit, *};
+use cord_utilities::traits::StorageDeposit as _;
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
+use sp_runtime::traits::BadOrigin;
+
+const RECORD: [u8; 32] = [1u8; 32];
+
+#[test]
+fn deposit_for_follows_the_base_deposit_per_byte() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(StorageDeposit::base_deposit_per_byte(), DEPOSIT_PER_BYTE);
+		assert_eq!(StorageDeposit::deposit_for(0), 0);
+		assert_eq!(StorageDeposit::deposit_for(10), 20);
+
+		assert_noop!(
+			StorageDeposit::set_base_deposit_per_byte(RuntimeOrigin::signed(ALICE), 3),
+			BadOrigin
+		);
+		assert_ok!(StorageDeposit::set_base_deposit_per_byte(RuntimeOrigin::root(), 3));
+		assert_eq!(StorageDeposit::deposit_for(10), 30);
+		System::assert_last_event(Event::BaseDepositPerByteSet { amount: 3 }.into());
+	});
+}
+
+#[test]
+fn hold_reserves_the_deposit_of_the_record() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(StorageDeposit::hold(&ALICE, &RECORD, 100));
+		assert_eq!(Balances::reserved_balance(ALICE), 200);
+
+		let key = StorageDeposit::deposit_key(&RECORD);
+		assert_eq!(StorageDeposit::deposits(key), Some(Deposit { owner: ALICE, amount: 200 }));
+		System::assert_last_event(Event::DepositHeld { who: ALICE, key, amount: 200 }.into());
+
+		assert_noop!(StorageDeposit::hold(&BOB, &RECORD, 100), Error::<Test>::DepositAlreadyHeld);
+		assert_noop!(
+			StorageDeposit::hold(&BOB, &[2u8; 32], 1_000),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn release_returns_the_deposit_held() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(StorageDeposit::hold(&ALICE, &RECORD, 100));
+
+		// A new rate does not change the deposits already held.
+		assert_ok!(StorageDeposit::set_base_deposit_per_byte(RuntimeOrigin::root(), 5));
+		StorageDeposit::release(&RECORD);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+
+		let key = StorageDeposit::deposit_key(&RECORD);
+		assert_eq!(StorageDeposit::deposits(key), None);
+		System::assert_last_event(Event::DepositReleased { who: ALICE, key, amount: 200 }.into());
+
+		// Releasing a record without a deposit does nothing.
+		StorageDeposit::release(&RECORD);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+	});
+}
@@ -0,0 +1,88 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Get, Hooks};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Make `who` the sudo key.
+fn set_sudo_key<T: Config>(who: &T::AccountId) {
+	pallet_sudo::Pallet::<T>::set_key(RawOrigin::Root.into(), T::Lookup::unlookup(who.clone()))
+		.expect("root can set the sudo key");
+}
+
+benchmarks! {
+	propose_rotation {
+		let caller: T::AccountId = whitelisted_caller();
+		set_sudo_key::<T>(&caller);
+		let new: T::AccountId = account("new", 0, SEED);
+		let at = frame_system::Pallet::<T>::block_number().saturating_add(T::RotationDelay::get());
+	}: _(RawOrigin::Signed(caller), T::Lookup::unlookup(new.clone()))
+	verify {
+		assert_eq!(RotationQueue::<T>::get(), Some(at));
+		assert_last_event::<T>(Event::RotationProposed { new, at }.into());
+	}
+
+	cancel_rotation {
+		let caller: T::AccountId = whitelisted_caller();
+		set_sudo_key::<T>(&caller);
+		let new: T::AccountId = account("new", 0, SEED);
+		Pallet::<T>::propose_rotation(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Lookup::unlookup(new.clone()),
+		)?;
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert!(!NewSudoKey::<T>::exists());
+		assert_last_event::<T>(Event::RotationCancelled { new }.into());
+	}
+
+	on_initialize_rotation {
+		let caller: T::AccountId = whitelisted_caller();
+		set_sudo_key::<T>(&caller);
+		let new: T::AccountId = account("new", 0, SEED);
+		Pallet::<T>::propose_rotation(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Lookup::unlookup(new.clone()),
+		)?;
+		let at = RotationQueue::<T>::get().expect("a rotation is pending");
+	}: {
+		Pallet::<T>::on_initialize(at);
+	}
+	verify {
+		assert_eq!(pallet_sudo::Pallet::<T>::key(), Some(new.clone()));
+		assert_last_event::<T>(Event::RotationCompleted { old: Some(caller), new }.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,189 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Sudo Key Rotation Pallet
+//!
+//! The Sudo Key Rotation Pallet hands the sudo key over to a new account
+//! only after a delay. The current sudo key proposes the new key, which
+//! becomes the sudo key `RotationDelay` blocks later unless the current
+//! key cancels the rotation in the meantime.
+//!
+//! ### Storage
+//!
+//! - `NewSudoKey`: The proposed sudo key.
+//! - `RotationQueue`: The block the proposed key becomes the sudo key at.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `propose_rotation` - Propose a new sudo key.
+//! - `cancel_rotation` - Cancel the pending rotation.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+use sp_runtime::traits::StaticLookup;
+pub use weights::WeightInfo;
+
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::Saturating;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_sudo::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Number of blocks between a rotation proposal and the new key
+		/// becoming the sudo key.
+		#[pallet::constant]
+		type RotationDelay: Get<BlockNumberFor<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// proposed sudo key
+	#[pallet::storage]
+	#[pallet::getter(fn new_sudo_key)]
+	pub type NewSudoKey<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// block the proposed sudo key becomes the sudo key at
+	#[pallet::storage]
+	#[pallet::getter(fn rotation_queue)]
+	pub type RotationQueue<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new sudo key was proposed and becomes the sudo key at `at`.
+		RotationProposed { new: T::AccountId, at: BlockNumberFor<T> },
+		/// The pending rotation was cancelled.
+		RotationCancelled { new: T::AccountId },
+		/// The proposed key became the sudo key.
+		RotationCompleted { old: Option<T::AccountId>, new: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The caller is not the current sudo key.
+		RequireSudo,
+		/// A rotation is already pending.
+		RotationPending,
+		/// There is no pending rotation.
+		NoRotationPending,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			match RotationQueue::<T>::get() {
+				Some(at) if at <= n => {
+					Self::complete_rotation();
+					<T as pallet::Config>::WeightInfo::on_initialize_rotation()
+				},
+				_ => T::DbWeight::get().reads(1),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose `new` as the sudo key, to take over after
+		/// `RotationDelay` blocks. Only the current sudo key can propose a
+		/// rotation, and only one rotation can be pending.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_rotation())]
+		pub fn propose_rotation(origin: OriginFor<T>, new: AccountIdLookupOf<T>) -> DispatchResult {
+			Self::ensure_sudo(origin)?;
+			let new = T::Lookup::lookup(new)?;
+			ensure!(!NewSudoKey::<T>::exists(), Error::<T>::RotationPending);
+
+			let at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::RotationDelay::get());
+			NewSudoKey::<T>::put(&new);
+			RotationQueue::<T>::put(at);
+
+			Self::deposit_event(Event::RotationProposed { new, at });
+			Ok(())
+		}
+
+		/// Cancel the pending rotation. Only the current sudo key can cancel
+		/// it.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_rotation())]
+		pub fn cancel_rotation(origin: OriginFor<T>) -> DispatchResult {
+			Self::ensure_sudo(origin)?;
+			let new = NewSudoKey::<T>::take().ok_or(Error::<T>::NoRotationPending)?;
+			RotationQueue::<T>::kill();
+
+			Self::deposit_event(Event::RotationCancelled { new });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Ensure `origin` is signed by the current sudo key.
+	fn ensure_sudo(origin: T::RuntimeOrigin) -> Result<T::AccountId, sp_runtime::DispatchError> {
+		let who = frame_system::ensure_signed(origin)?;
+		frame_support::ensure!(
+			pallet_sudo::Pallet::<T>::key().as_ref() == Some(&who),
+			Error::<T>::RequireSudo
+		);
+		Ok(who)
+	}
+
+	/// Make the proposed key the sudo key. The key is set through
+	/// `pallet_sudo`, which records the change with its `KeyChanged` event.
+	fn complete_rotation() {
+		RotationQueue::<T>::kill();
+		let Some(new) = NewSudoKey::<T>::take() else { return };
+		let old = pallet_sudo::Pallet::<T>::key();
+
+		if pallet_sudo::Pallet::<T>::set_key(
+			frame_system::RawOrigin::Root.into(),
+			T::Lookup::unlookup(new.clone()),
+		)
+		.is_ok()
+		{
+			Self::deposit_event(Event::RotationCompleted { old, new });
+		}
+	}
+}
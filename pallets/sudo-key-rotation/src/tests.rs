@@ -0,0 +1,83 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn propose_rotation_is_restricted_to_the_sudo_key() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(SudoKeyRotation::propose_rotation(RuntimeOrigin::root(), BOB), BadOrigin);
+		assert_noop!(
+			SudoKeyRotation::propose_rotation(RuntimeOrigin::signed(BOB), BOB),
+			Error::<Test>::RequireSudo
+		);
+
+		assert_ok!(SudoKeyRotation::propose_rotation(RuntimeOrigin::signed(ALICE), BOB));
+		assert_eq!(SudoKeyRotation::new_sudo_key(), Some(BOB));
+		assert_eq!(SudoKeyRotation::rotation_queue(), Some(1 + ROTATION_DELAY));
+		System::assert_last_event(Event::RotationProposed { new: BOB, at: 11 }.into());
+
+		assert_noop!(
+			SudoKeyRotation::propose_rotation(RuntimeOrigin::signed(ALICE), ALICE),
+			Error::<Test>::RotationPending
+		);
+	});
+}
+
+#[test]
+fn rotation_completes_after_the_delay() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SudoKeyRotation::propose_rotation(RuntimeOrigin::signed(ALICE), BOB));
+
+		SudoKeyRotation::on_initialize(ROTATION_DELAY);
+		assert_eq!(Sudo::key(), Some(ALICE));
+		assert_eq!(SudoKeyRotation::new_sudo_key(), Some(BOB));
+
+		System::set_block_number(1 + ROTATION_DELAY);
+		SudoKeyRotation::on_initialize(1 + ROTATION_DELAY);
+		assert_eq!(Sudo::key(), Some(BOB));
+		assert_eq!(SudoKeyRotation::new_sudo_key(), None);
+		assert_eq!(SudoKeyRotation::rotation_queue(), None);
+		System::assert_last_event(Event::RotationCompleted { old: Some(ALICE), new: BOB }.into());
+	});
+}
+
+#[test]
+fn cancel_rotation_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SudoKeyRotation::cancel_rotation(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NoRotationPending
+		);
+
+		assert_ok!(SudoKeyRotation::propose_rotation(RuntimeOrigin::signed(ALICE), BOB));
+		assert_noop!(
+			SudoKeyRotation::cancel_rotation(RuntimeOrigin::signed(BOB)),
+			Error::<Test>::RequireSudo
+		);
+
+		assert_ok!(SudoKeyRotation::cancel_rotation(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(Event::RotationCancelled { new: BOB }.into());
+
+		SudoKeyRotation::on_initialize(1 + ROTATION_DELAY);
+		assert_eq!(Sudo::key(), Some(ALICE));
+	});
+}
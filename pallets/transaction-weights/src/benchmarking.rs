@@ -0,0 +1,62 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::Hooks;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::Zero;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Fill the history, spreading the blocks over all buckets.
+fn fill_history<T: Config>() {
+	for n in 0..T::HistoryDepth::get() {
+		Pallet::<T>::record(WeightBucket((n % (WeightBucket::MAX as u32 + 1)) as u8));
+	}
+}
+
+benchmarks! {
+	on_finalize {
+		fill_history::<T>();
+	}: {
+		Pallet::<T>::on_finalize(BlockNumberFor::<T>::zero());
+	}
+	verify {
+		assert_eq!(RecentBuckets::<T>::get().len() as u32, T::HistoryDepth::get());
+	}
+
+	reset_histogram {
+		fill_history::<T>();
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(Pallet::<T>::weight_histogram().is_empty());
+		assert_last_event::<T>(Event::HistogramReset.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,196 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Transaction Weights Pallet
+//!
+//! The Transaction Weights Pallet keeps a histogram of the weight consumed
+//! by the most recent blocks, so that the weight usage of a live network
+//! can be profiled without scraping node logs.
+//!
+//! At the end of every block the consumed weight is sorted into one of ten
+//! buckets, each covering a tenth of the maximum block weight. Only the last
+//! `HistoryDepth` blocks are counted.
+//!
+//! ### Storage
+//!
+//! - `RecentBuckets`: The buckets of the last `HistoryDepth` blocks, oldest
+//!   first.
+//! - `Histogram`: The number of recent blocks in each bucket.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `reset_histogram` - Drop all recorded blocks.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, weights::Weight};
+pub use pallet::*;
+use scale_info::TypeInfo;
+use sp_runtime::{Percent, RuntimeDebug};
+use sp_std::vec::Vec;
+pub use weights::WeightInfo;
+
+/// A tenth of the maximum block weight. Bucket `n` holds the blocks that
+/// consumed at least `n * 10%` and less than `(n + 1) * 10%` of it, the last
+/// bucket also holds the full blocks.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	Copy,
+	MaxEncodedLen,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	TypeInfo,
+)]
+pub struct WeightBucket(pub u8);
+
+impl WeightBucket {
+	/// The index of the last bucket.
+	pub const MAX: u8 = 9;
+
+	/// The bucket of `consumed`, measured against `max_block`. Only the
+	/// `ref_time` dimension is taken into account.
+	pub fn of(consumed: Weight, max_block: Weight) -> Self {
+		let percent = Percent::from_rational(consumed.ref_time(), max_block.ref_time());
+		Self((percent.deconstruct() / 10).min(Self::MAX))
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin allowed to reset the histogram.
+		type HistogramOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The number of recent blocks the histogram covers.
+		#[pallet::constant]
+		type HistoryDepth: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// buckets of the most recent blocks, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn recent_buckets)]
+	pub type RecentBuckets<T: Config> =
+		StorageValue<_, BoundedVec<WeightBucket, T::HistoryDepth>, ValueQuery>;
+
+	/// number of recent blocks per bucket
+	#[pallet::storage]
+	#[pallet::getter(fn histogram)]
+	pub type Histogram<T> = StorageMap<_, Twox64Concat, WeightBucket, u64, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// All recorded blocks were dropped from the histogram.
+		HistogramReset,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			T::WeightInfo::on_finalize()
+		}
+
+		// `frame_system` resets the consumed weight before `on_initialize`
+		// runs, so a block is sampled at its own end instead.
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			let consumed = frame_system::Pallet::<T>::block_weight().total();
+			let max_block = <T as frame_system::Config>::BlockWeights::get().max_block;
+			Self::record(WeightBucket::of(consumed, max_block));
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Drop all recorded blocks, starting a new histogram from the next
+		/// block on.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reset_histogram())]
+		pub fn reset_histogram(origin: OriginFor<T>) -> DispatchResult {
+			T::HistogramOrigin::ensure_origin(origin)?;
+
+			RecentBuckets::<T>::kill();
+			// There are at most `WeightBucket::MAX + 1` entries.
+			let _ = Histogram::<T>::clear(u32::MAX, None);
+
+			Self::deposit_event(Event::HistogramReset);
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The number of recent blocks per bucket, in bucket order. Empty
+	/// buckets are left out.
+	pub fn weight_histogram() -> Vec<(WeightBucket, u64)> {
+		let mut histogram: Vec<_> = Histogram::<T>::iter().collect();
+		histogram.sort();
+		histogram
+	}
+
+	/// Count a block in `bucket`, dropping the oldest block once the history
+	/// is full.
+	fn record(bucket: WeightBucket) {
+		let inserted = RecentBuckets::<T>::mutate(|recent| {
+			recent.force_insert_keep_right(recent.len(), bucket)
+		});
+
+		match inserted {
+			Ok(Some(evicted)) => Histogram::<T>::mutate_exists(evicted, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0)
+			}),
+			Ok(None) => {},
+			// The history depth is zero.
+			Err(_) => return,
+		}
+
+		Histogram::<T>::mutate(bucket, |count| *count = count.saturating_add(1));
+	}
+}
@@ -0,0 +1,102 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchClass, traits::Hooks};
+use sp_runtime::traits::BadOrigin;
+
+/// Run block `n` with `ref_time` of consumed weight through the pallet.
+fn run_block(n: u64, ref_time: u64) {
+	System::initialize(&n, &System::parent_hash(), &Default::default());
+	System::register_extra_weight_unchecked(Weight::from_parts(ref_time, 0), DispatchClass::Normal);
+	TransactionWeights::on_finalize(n);
+}
+
+#[test]
+fn weight_bucket_covers_a_tenth_of_the_block() {
+	let max_block = Weight::from_parts(MAX_BLOCK_REF_TIME, 0);
+	let bucket = |ref_time| WeightBucket::of(Weight::from_parts(ref_time, 0), max_block);
+
+	assert_eq!(bucket(0), WeightBucket(0));
+	assert_eq!(bucket(99_999), WeightBucket(0));
+	assert_eq!(bucket(100_000), WeightBucket(1));
+	assert_eq!(bucket(950_000), WeightBucket(9));
+	assert_eq!(bucket(MAX_BLOCK_REF_TIME), WeightBucket(9));
+	assert_eq!(bucket(2 * MAX_BLOCK_REF_TIME), WeightBucket(9));
+}
+
+#[test]
+fn blocks_are_counted_in_their_bucket() {
+	new_test_ext().execute_with(|| {
+		assert!(TransactionWeights::weight_histogram().is_empty());
+
+		run_block(1, 0);
+		run_block(2, 350_000);
+		run_block(3, 390_000);
+		run_block(4, MAX_BLOCK_REF_TIME);
+
+		assert_eq!(
+			TransactionWeights::weight_histogram(),
+			vec![(WeightBucket(0), 1), (WeightBucket(3), 2), (WeightBucket(9), 1)]
+		);
+		assert_eq!(TransactionWeights::recent_buckets().len(), 4);
+	});
+}
+
+#[test]
+fn only_the_most_recent_blocks_are_counted() {
+	new_test_ext().execute_with(|| {
+		for n in 1..=256 {
+			run_block(n, 0);
+		}
+		assert_eq!(TransactionWeights::weight_histogram(), vec![(WeightBucket(0), 256)]);
+
+		// Each new block pushes out the oldest one.
+		run_block(257, 500_000);
+		run_block(258, 500_000);
+		assert_eq!(
+			TransactionWeights::weight_histogram(),
+			vec![(WeightBucket(0), 254), (WeightBucket(5), 2)]
+		);
+
+		for n in 259..=512 {
+			run_block(n, 500_000);
+		}
+		assert_eq!(TransactionWeights::weight_histogram(), vec![(WeightBucket(5), 256)]);
+		assert!(!Histogram::<Test>::contains_key(WeightBucket(0)));
+	});
+}
+
+#[test]
+fn reset_histogram_works() {
+	new_test_ext().execute_with(|| {
+		run_block(1, 350_000);
+		run_block(2, 700_000);
+
+		assert_noop!(TransactionWeights::reset_histogram(RuntimeOrigin::signed(1)), BadOrigin);
+
+		assert_ok!(TransactionWeights::reset_histogram(RuntimeOrigin::root()));
+		assert!(TransactionWeights::weight_histogram().is_empty());
+		assert!(TransactionWeights::recent_buckets().is_empty());
+		System::assert_last_event(Event::HistogramReset.into());
+
+		run_block(3, 0);
+		assert_eq!(TransactionWeights::weight_histogram(), vec![(WeightBucket(0), 1)]);
+	});
+}
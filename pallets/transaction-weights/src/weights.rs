@@ -0,0 +1,101 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_transaction_weights`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_transaction_weights
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/transaction-weights/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_transaction_weights`.
+pub trait WeightInfo {
+	fn on_finalize() -> Weight;
+	fn reset_histogram() -> Weight;
+}
+
+/// Weights for `pallet_cord_transaction_weights` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `System::BlockWeight` (r:1 w:0)
+	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionWeights::RecentBuckets` (r:1 w:1)
+	/// Proof: `TransactionWeights::RecentBuckets` (`max_values`: Some(1), `max_size`: Some(259), added: 754, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionWeights::Histogram` (r:2 w:2)
+	/// Proof: `TransactionWeights::Histogram` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn on_finalize() -> Weight {
+		Weight::from_parts(15_480_000, 6474)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TransactionWeights::RecentBuckets` (r:0 w:1)
+	/// Proof: `TransactionWeights::RecentBuckets` (`max_values`: Some(1), `max_size`: Some(259), added: 754, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionWeights::Histogram` (r:10 w:10)
+	/// Proof: `TransactionWeights::Histogram` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn reset_histogram() -> Weight {
+		Weight::from_parts(43_710_000, 25410)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `System::BlockWeight` (r:1 w:0)
+	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionWeights::RecentBuckets` (r:1 w:1)
+	/// Proof: `TransactionWeights::RecentBuckets` (`max_values`: Some(1), `max_size`: Some(259), added: 754, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionWeights::Histogram` (r:2 w:2)
+	/// Proof: `TransactionWeights::Histogram` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn on_finalize() -> Weight {
+		Weight::from_parts(15_480_000, 6474)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TransactionWeights::RecentBuckets` (r:0 w:1)
+	/// Proof: `TransactionWeights::RecentBuckets` (`max_values`: Some(1), `max_size`: Some(259), added: 754, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionWeights::Histogram` (r:10 w:10)
+	/// Proof: `TransactionWeights::Histogram` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn reset_histogram() -> Weight {
+		Weight::from_parts(43_710_000, 25410)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+}
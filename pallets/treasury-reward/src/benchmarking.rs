@@ -0,0 +1,64 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::Hooks;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::Saturating;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	mint_inflation {
+		// Large enough for a full annual rate to mint above the existential
+		// deposit in a single block.
+		let holder: T::AccountId = account("holder", 0, SEED);
+		let issuance = T::Currency::minimum_balance()
+			.saturating_mul(T::BlocksPerYear::get().saturating_mul(2).into());
+		T::Currency::set_balance(&holder, issuance);
+		InflationRate::<T>::put(Perquintill::one());
+		let treasury = T::TreasuryAccount::get();
+		let before = T::Currency::balance(&treasury);
+	}: {
+		Pallet::<T>::on_initialize(BlockNumberFor::<T>::zero());
+	}
+	verify {
+		assert!(T::Currency::balance(&treasury) > before);
+	}
+
+	set_inflation_rate {
+		let rate = Perquintill::from_percent(1);
+	}: _(RawOrigin::Root, rate)
+	verify {
+		assert_last_event::<T>(Event::InflationRateSet { rate }.into());
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
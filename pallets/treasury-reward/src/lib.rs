@@ -0,0 +1,179 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Treasury Reward Pallet
+//!
+//! The Treasury Reward Pallet mints inflation into the treasury account at
+//! the start of every block. The inflation rate is annual; every block mints
+//! `1 / BlocksPerYear` of the rate applied to the current total issuance.
+//! As every block mints on the issuance grown by the blocks before it, the
+//! issuance grows by slightly more than the rate over a year, e.g. by 10.52%
+//! for a rate of 10%.
+//!
+//! ### Storage
+//!
+//! - `InflationRate`: The annual inflation rate. It starts at zero and is set
+//!   by `InflationOrigin`.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `set_inflation_rate` - Set the annual inflation rate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+use frame_support::{
+	traits::{
+		fungible::{Inspect, Mutate},
+		Get,
+	},
+	weights::Weight,
+};
+pub use pallet::*;
+use sp_runtime::{traits::Zero, Perquintill};
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency the inflation is minted in.
+		type Currency: Mutate<Self::AccountId>;
+		/// The account receiving the inflation.
+		type TreasuryAccount: Get<Self::AccountId>;
+		/// Origin allowed to set the inflation rate.
+		type InflationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The number of blocks the annual inflation is spread over.
+		#[pallet::constant]
+		type BlocksPerYear: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// annual inflation rate of the total issuance
+	#[pallet::storage]
+	#[pallet::getter(fn inflation_rate)]
+	pub type InflationRate<T> = StorageValue<_, Perquintill, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub inflation_rate: Perquintill,
+		#[serde(skip)]
+		pub _config: sp_std::marker::PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			InflationRate::<T>::put(self.inflation_rate);
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The inflation rate was updated.
+		InflationRateSet { rate: Perquintill },
+		/// Inflation was minted into the treasury account.
+		InflationMinted { amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The rate is already set to the given value
+		InflationRateUnchanged,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// Minting here charges the weight for the rate the block mints
+			// with; a rate set later in the block applies from the next one.
+			Self::mint_inflation()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the annual inflation rate minted into the treasury account,
+		/// from the next block on.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_inflation_rate())]
+		pub fn set_inflation_rate(origin: OriginFor<T>, rate: Perquintill) -> DispatchResult {
+			T::InflationOrigin::ensure_origin(origin)?;
+
+			ensure!(InflationRate::<T>::get() != rate, Error::<T>::InflationRateUnchanged);
+			InflationRate::<T>::put(rate);
+
+			Self::deposit_event(Event::InflationRateSet { rate });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The inflation minted in a block at the annual `rate`.
+	pub fn block_inflation(rate: Perquintill) -> BalanceOf<T> {
+		let blocks_per_year = T::BlocksPerYear::get().max(1);
+		rate.mul_floor(T::Currency::total_issuance()) / blocks_per_year.into()
+	}
+
+	/// Mint the inflation of this block into the treasury account.
+	fn mint_inflation() -> Weight {
+		let rate = InflationRate::<T>::get();
+		if rate.is_zero() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let amount = Self::block_inflation(rate);
+		// Fails only while the minted amount is below the existential deposit
+		// of a not yet created treasury account.
+		if !amount.is_zero() && T::Currency::mint_into(&T::TreasuryAccount::get(), amount).is_ok() {
+			Self::deposit_event(Event::InflationMinted { amount });
+		}
+		T::WeightInfo::mint_inflation()
+	}
+}
@@ -0,0 +1,99 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn inflation_starts_at_zero() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TreasuryReward::inflation_rate(), Perquintill::zero());
+
+		TreasuryReward::on_initialize(1);
+		assert_eq!(Balances::free_balance(TREASURY), 0);
+		assert_eq!(Balances::total_issuance(), 1_000_000);
+	});
+}
+
+#[test]
+fn set_inflation_rate_works() {
+	new_test_ext().execute_with(|| {
+		let rate = Perquintill::from_percent(1);
+		assert_noop!(TreasuryReward::set_inflation_rate(RuntimeOrigin::signed(1), rate), BadOrigin);
+
+		assert_ok!(TreasuryReward::set_inflation_rate(RuntimeOrigin::root(), rate));
+		assert_eq!(TreasuryReward::inflation_rate(), rate);
+		System::assert_last_event(Event::InflationRateSet { rate }.into());
+
+		assert_noop!(
+			TreasuryReward::set_inflation_rate(RuntimeOrigin::root(), rate),
+			Error::<Test>::InflationRateUnchanged
+		);
+	});
+}
+
+#[test]
+fn on_initialize_mints_annual_rate_per_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TreasuryReward::set_inflation_rate(
+			RuntimeOrigin::root(),
+			Perquintill::from_percent(10)
+		));
+
+		// 10% of the issuance spread over `BLOCKS_PER_YEAR` blocks.
+		TreasuryReward::on_initialize(1);
+		assert_eq!(Balances::free_balance(TREASURY), 1_000);
+		assert_eq!(Balances::total_issuance(), 1_001_000);
+		System::assert_last_event(Event::InflationMinted { amount: 1_000 }.into());
+
+		// The minted amount follows the grown issuance.
+		TreasuryReward::on_initialize(2);
+		assert_eq!(Balances::free_balance(TREASURY), 2_001);
+	});
+}
+
+#[test]
+fn rate_changes_apply_from_the_next_block() {
+	new_test_ext().execute_with(|| {
+		TreasuryReward::on_initialize(1);
+		assert_ok!(TreasuryReward::set_inflation_rate(
+			RuntimeOrigin::root(),
+			Perquintill::from_percent(10)
+		));
+		assert_eq!(Balances::free_balance(TREASURY), 0);
+
+		TreasuryReward::on_initialize(2);
+		assert_eq!(Balances::free_balance(TREASURY), 1_000);
+	});
+}
+
+#[test]
+fn amounts_below_existential_deposit_are_skipped() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TreasuryReward::set_inflation_rate(
+			RuntimeOrigin::root(),
+			Perquintill::from_parts(1)
+		));
+
+		TreasuryReward::on_initialize(1);
+		assert_eq!(Balances::free_balance(TREASURY), 0);
+		assert_eq!(Balances::total_issuance(), 1_000_000);
+	});
+}
@@ -0,0 +1,92 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// An account holding `total` on top of the existential deposit.
+fn funded_account<T: Config>(total: BalanceOf<T>) -> T::AccountId {
+	let who: T::AccountId = account("beneficiary", 0, SEED);
+	T::Currency::make_free_balance_be(&who, total.saturating_add(T::Currency::minimum_balance()));
+	who
+}
+
+/// A schedule of `total` with `count` milestones of equal fractions, one
+/// every block from the cliff at block 1.
+fn schedule<T: Config>(total: BalanceOf<T>, count: u32) -> VestingScheduleOf<T> {
+	let fraction = Perbill::from_rational(1, count);
+	let mut milestones: Vec<(BlockNumberFor<T>, Perbill)> =
+		(1..=count).map(|block| (block.into(), fraction)).collect();
+	// The last milestone takes the rounding of the others.
+	let rest =
+		Perbill::from_parts(Perbill::one().deconstruct() - fraction.deconstruct() * (count - 1));
+	if let Some(last) = milestones.last_mut() {
+		last.1 = rest;
+	}
+	VestingSchedule {
+		cliff_block: 1u32.into(),
+		total,
+		milestones: BoundedVec::truncate_from(milestones),
+	}
+}
+
+benchmarks! {
+	add_vesting_schedule {
+		let m in 1 .. T::MaxMilestones::get();
+
+		let total = T::Currency::minimum_balance().saturating_mul(1_000u32.into());
+		let who = funded_account::<T>(total);
+		let vesting = schedule::<T>(total, m);
+	}: _(RawOrigin::Root, who.clone(), vesting)
+	verify {
+		assert!(Vesting::<T>::contains_key(&who));
+		assert_last_event::<T>(Event::VestingScheduleAdded { who, total }.into());
+	}
+
+	claim_vested {
+		let m in 1 .. T::MaxMilestones::get();
+
+		let total = T::Currency::minimum_balance().saturating_mul(1_000u32.into());
+		let who = funded_account::<T>(total);
+		Pallet::<T>::add_vesting_schedule(RawOrigin::Root.into(), who.clone(), schedule::<T>(total, m))?;
+		frame_system::Pallet::<T>::set_block_number(m.into());
+	}: _(RawOrigin::Signed(who.clone()))
+	verify {
+		assert!(!Vesting::<T>::contains_key(&who));
+		assert_last_event::<T>(
+			Event::VestingClaimed { who, amount: total, remaining: Zero::zero() }.into()
+		);
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,223 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Vesting Pallet
+//!
+//! The Vesting Pallet locks a token allocation of an account and releases
+//! it in milestones. Every milestone releases a fraction of the allocation
+//! once its block is reached, and no milestone releases anything before the
+//! cliff block of the schedule. The fractions of a schedule add up to the
+//! whole allocation, which is fully released by its last milestone.
+//!
+//! Schedules are added by root, against a balance the account already holds.
+//! The account claims the released balance, which lowers the lock to the
+//! balance still vesting. An account has at most one schedule.
+//!
+//! ### Storage
+//!
+//! - `Vesting`: The vesting schedule of every account and its progress.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `add_vesting_schedule` - Lock the allocation of an account under a
+//!   schedule.
+//! - `claim_vested` - Release the balance of the milestones reached.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+pub use types::{VestingInfo, VestingSchedule};
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill,
+};
+
+/// Identifier of the balance lock held by vesting schedules.
+const VESTING_ID: LockIdentifier = *b"cordvest";
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Milestones of a vesting schedule.
+	pub type MilestonesOf<T> =
+		BoundedVec<(BlockNumberFor<T>, Perbill), <T as Config>::MaxMilestones>;
+	/// Type of a vesting schedule.
+	pub type VestingScheduleOf<T> =
+		VestingSchedule<BlockNumberFor<T>, BalanceOf<T>, MilestonesOf<T>>;
+	/// Type of the vesting schedule of an account and its progress.
+	pub type VestingInfoOf<T> = VestingInfo<VestingScheduleOf<T>, BalanceOf<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency vesting allocations are locked in.
+		type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+		/// The maximum number of milestones of a schedule.
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// vesting schedules by account
+	#[pallet::storage]
+	#[pallet::getter(fn vesting)]
+	pub type Vesting<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, VestingInfoOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The allocation of an account was locked under a schedule.
+		VestingScheduleAdded { who: T::AccountId, total: BalanceOf<T> },
+		/// Vested balance was released, `remaining` is still locked.
+		VestingClaimed { who: T::AccountId, amount: BalanceOf<T>, remaining: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account already has a vesting schedule.
+		ScheduleExists,
+		/// The total is zero, or the milestones are not in ascending order
+		/// or do not add up to the whole total.
+		InvalidSchedule,
+		/// The free balance of the account is below the total.
+		InsufficientBalance,
+		/// The account has no vesting schedule.
+		NotVesting,
+		/// No balance was released since the last claim.
+		NothingToClaim,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock the `total` of `schedule` in the free balance of `who`, to
+		/// be released by the milestones of the schedule.
+		///
+		/// The dispatch origin must be root.
+		#[pallet::call_index(0)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::add_vesting_schedule(schedule.milestones.len() as u32)
+		)]
+		pub fn add_vesting_schedule(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!Vesting::<T>::contains_key(&who), Error::<T>::ScheduleExists);
+			ensure!(Self::is_valid(&schedule), Error::<T>::InvalidSchedule);
+			ensure!(
+				T::Currency::free_balance(&who) >= schedule.total,
+				Error::<T>::InsufficientBalance
+			);
+
+			let total = schedule.total;
+			T::Currency::set_lock(VESTING_ID, &who, total, WithdrawReasons::all());
+			Vesting::<T>::insert(&who, VestingInfo { schedule, released: Zero::zero() });
+
+			Self::deposit_event(Event::VestingScheduleAdded { who, total });
+			Ok(())
+		}
+
+		/// Release the balance of the milestones of the schedule of the
+		/// caller reached so far. The schedule is removed once fully
+		/// released.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_vested(T::MaxMilestones::get()))]
+		pub fn claim_vested(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut info = Vesting::<T>::get(&who).ok_or(Error::<T>::NotVesting)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let amount = Self::vested(&info.schedule, now).saturating_sub(info.released);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+			info.released = info.released.saturating_add(amount);
+			let remaining = info.schedule.total.saturating_sub(info.released);
+			if remaining.is_zero() {
+				T::Currency::remove_lock(VESTING_ID, &who);
+				Vesting::<T>::remove(&who);
+			} else {
+				T::Currency::set_lock(VESTING_ID, &who, remaining, WithdrawReasons::all());
+				Vesting::<T>::insert(&who, info);
+			}
+
+			Self::deposit_event(Event::VestingClaimed { who, amount, remaining });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The balance of `schedule` released by the milestones reached at
+	/// block `now`.
+	pub fn vested(schedule: &VestingScheduleOf<T>, now: BlockNumberFor<T>) -> BalanceOf<T> {
+		if now < schedule.cliff_block {
+			return Zero::zero()
+		}
+		let parts = schedule
+			.milestones
+			.iter()
+			.filter(|(block, _)| *block <= now)
+			.fold(0u32, |parts, (_, fraction)| parts.saturating_add(fraction.deconstruct()));
+		Perbill::from_parts(parts).mul_floor(schedule.total)
+	}
+
+	/// Whether `schedule` has a total and ascending milestones adding up to
+	/// the whole total.
+	fn is_valid(schedule: &VestingScheduleOf<T>) -> bool {
+		let ascending = schedule.milestones.windows(2).all(|pair| pair[0].0 < pair[1].0);
+		let parts = schedule
+			.milestones
+			.iter()
+			.try_fold(0u32, |parts, (_, fraction)| parts.checked_add(fraction.deconstruct()));
+		!schedule.total.is_zero() && ascending && parts == Some(Perbill::one().deconstruct())
+	}
+}
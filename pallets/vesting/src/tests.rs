@@ -0,0 +1,168 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::traits::BadOrigin;
+
+fn schedule(
+	cliff_block: u64,
+	total: Balance,
+	milestones: &[(u64, u32)],
+) -> VestingScheduleOf<Test> {
+	VestingSchedule {
+		cliff_block,
+		total,
+		milestones: BoundedVec::truncate_from(
+			milestones
+				.iter()
+				.map(|(block, percent)| (*block, Perbill::from_percent(*percent)))
+				.collect(),
+		),
+	}
+}
+
+/// The balance of `who` locked by its vesting schedule.
+fn locked(who: AccountId) -> Balance {
+	pallet_balances::Locks::<Test>::get(who).iter().map(|lock| lock.amount).sum()
+}
+
+#[test]
+fn add_vesting_schedule_locks_the_total() {
+	new_test_ext().execute_with(|| {
+		let vesting = schedule(10, 600, &[(10, 50), (20, 50)]);
+		assert_noop!(
+			Vesting::add_vesting_schedule(RuntimeOrigin::signed(1), 1, vesting.clone()),
+			BadOrigin
+		);
+
+		assert_ok!(Vesting::add_vesting_schedule(RuntimeOrigin::root(), 1, vesting.clone()));
+		assert_eq!(Vesting::vesting(1), Some(VestingInfo { schedule: vesting, released: 0 }));
+		assert_eq!(locked(1), 600);
+		System::assert_last_event(Event::VestingScheduleAdded { who: 1, total: 600 }.into());
+	});
+}
+
+#[test]
+fn add_vesting_schedule_checks_the_schedule() {
+	new_test_ext().execute_with(|| {
+		for invalid in [
+			schedule(10, 0, &[(10, 100)]),
+			schedule(10, 600, &[]),
+			schedule(10, 600, &[(10, 50), (20, 40)]),
+			schedule(10, 600, &[(10, 50), (20, 60)]),
+			schedule(10, 600, &[(20, 50), (10, 50)]),
+			schedule(10, 600, &[(10, 50), (10, 50)]),
+		] {
+			assert_noop!(
+				Vesting::add_vesting_schedule(RuntimeOrigin::root(), 1, invalid),
+				Error::<Test>::InvalidSchedule
+			);
+		}
+		assert_noop!(
+			Vesting::add_vesting_schedule(
+				RuntimeOrigin::root(),
+				1,
+				schedule(10, 1_001, &[(10, 100)])
+			),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Vesting::add_vesting_schedule(
+			RuntimeOrigin::root(),
+			1,
+			schedule(10, 600, &[(10, 100)])
+		));
+		assert_noop!(
+			Vesting::add_vesting_schedule(
+				RuntimeOrigin::root(),
+				1,
+				schedule(10, 300, &[(10, 100)])
+			),
+			Error::<Test>::ScheduleExists
+		);
+	});
+}
+
+#[test]
+fn nothing_is_released_before_the_cliff() {
+	new_test_ext().execute_with(|| {
+		// The first milestone is reached before the cliff.
+		let vesting = schedule(10, 600, &[(5, 25), (20, 75)]);
+		assert_ok!(Vesting::add_vesting_schedule(RuntimeOrigin::root(), 1, vesting));
+
+		System::set_block_number(9);
+		assert_noop!(
+			Vesting::claim_vested(RuntimeOrigin::signed(1)),
+			Error::<Test>::NothingToClaim
+		);
+
+		System::set_block_number(10);
+		assert_ok!(Vesting::claim_vested(RuntimeOrigin::signed(1)));
+		assert_eq!(locked(1), 450);
+		System::assert_last_event(
+			Event::VestingClaimed { who: 1, amount: 150, remaining: 450 }.into(),
+		);
+	});
+}
+
+#[test]
+fn claim_vested_releases_the_milestones_reached() {
+	new_test_ext().execute_with(|| {
+		let vesting = schedule(10, 600, &[(10, 10), (20, 20), (30, 30), (40, 40)]);
+		assert_ok!(Vesting::add_vesting_schedule(RuntimeOrigin::root(), 1, vesting));
+
+		// Two milestones are released at once.
+		System::set_block_number(25);
+		assert_ok!(Vesting::claim_vested(RuntimeOrigin::signed(1)));
+		assert_eq!(Vesting::vesting(1).map(|info| info.released), Some(180));
+		assert_eq!(locked(1), 420);
+		assert_noop!(
+			Vesting::claim_vested(RuntimeOrigin::signed(1)),
+			Error::<Test>::NothingToClaim
+		);
+
+		System::set_block_number(30);
+		assert_ok!(Vesting::claim_vested(RuntimeOrigin::signed(1)));
+		System::assert_last_event(
+			Event::VestingClaimed { who: 1, amount: 180, remaining: 240 }.into(),
+		);
+	});
+}
+
+#[test]
+fn claiming_the_last_milestone_removes_the_schedule() {
+	new_test_ext().execute_with(|| {
+		let vesting = schedule(10, 601, &[(10, 33), (20, 67)]);
+		assert_ok!(Vesting::add_vesting_schedule(RuntimeOrigin::root(), 1, vesting));
+
+		System::set_block_number(10);
+		assert_ok!(Vesting::claim_vested(RuntimeOrigin::signed(1)));
+		System::set_block_number(20);
+		assert_ok!(Vesting::claim_vested(RuntimeOrigin::signed(1)));
+
+		// The rounding of the earlier milestones is released by the last one.
+		System::assert_last_event(
+			Event::VestingClaimed { who: 1, amount: 403, remaining: 0 }.into(),
+		);
+		assert_eq!(Vesting::vesting(1), None);
+		assert_eq!(locked(1), 0);
+		assert_noop!(Vesting::claim_vested(RuntimeOrigin::signed(1)), Error::<Test>::NotVesting);
+	});
+}
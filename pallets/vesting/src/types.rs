@@ -0,0 +1,49 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// A schedule releasing a locked balance in milestones, none of them before
+/// a cliff.
+///
+/// ## Fields
+///
+/// - `cliff_block`: The block before which nothing is released.
+/// - `total`: The balance locked by the schedule.
+/// - `milestones`: The blocks at which a fraction of `total` is released, in
+///   ascending order. The fractions add up to the whole of `total`.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct VestingSchedule<BlockNumber, Balance, Milestones> {
+	pub cliff_block: BlockNumber,
+	pub total: Balance,
+	pub milestones: Milestones,
+}
+
+/// The vesting schedule of an account and its progress.
+///
+/// ## Fields
+///
+/// - `schedule`: The vesting schedule.
+/// - `released`: The balance of the schedule released so far.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct VestingInfo<Schedule, Balance> {
+	pub schedule: Schedule,
+	pub released: Balance,
+}
@@ -0,0 +1,128 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::Hash;
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// A voter that can afford ten times the minimum voting balance.
+fn funded_voter<T: Config>() -> T::AccountId {
+	let voter: T::AccountId = account("voter", 0, SEED);
+	let balance = T::MinimumVotingBalance::get()
+		.saturating_mul(10u32.into())
+		.saturating_add(T::Currency::minimum_balance());
+	T::Currency::make_free_balance_be(&voter, balance);
+	voter
+}
+
+fn start<T: Config>(voting_period: BlockNumberFor<T>) -> ReferendumIndex {
+	let origin =
+		T::StartOrigin::try_successful_origin().expect("StartOrigin has a successful origin");
+	let ref_index = ReferendumCount::<T>::get();
+	Pallet::<T>::start_referendum(origin, T::Hashing::hash_of(&ref_index), voting_period)
+		.expect("Should start the referendum");
+	ref_index
+}
+
+fn locked_vote<T: Config>() -> AccountVote<BalanceOf<T>> {
+	AccountVote::Standard {
+		vote: Vote { aye: true, conviction: Conviction::Locked6x },
+		balance: T::MinimumVotingBalance::get(),
+	}
+}
+
+/// `count` referendums `voter` voted in.
+fn vote_in_referendums<T: Config>(voter: &T::AccountId, count: u32) {
+	for _ in 0..count {
+		let ref_index = start::<T>(10u32.into());
+		Pallet::<T>::vote_weighted(
+			RawOrigin::Signed(voter.clone()).into(),
+			ref_index,
+			locked_vote::<T>(),
+		)
+		.expect("Should vote");
+	}
+}
+
+benchmarks! {
+	start_referendum {
+		let origin =
+			T::StartOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let proposal_hash = T::Hashing::hash_of(&0u32);
+		let voting_period: BlockNumberFor<T> = 10u32.into();
+		let end = frame_system::Pallet::<T>::block_number() + voting_period;
+	}: _<T::RuntimeOrigin>(origin, proposal_hash, voting_period)
+	verify {
+		assert_last_event::<T>(Event::ReferendumStarted { ref_index: 0, proposal_hash, end }.into());
+	}
+
+	vote_weighted {
+		let r in 0 .. T::MaxVotes::get() - 1;
+
+		let voter = funded_voter::<T>();
+		vote_in_referendums::<T>(&voter, r);
+		let ref_index = start::<T>(10u32.into());
+		// Replacing a previous vote is the worst case.
+		Pallet::<T>::vote_weighted(RawOrigin::Signed(voter.clone()).into(), ref_index, locked_vote::<T>())?;
+		let vote = locked_vote::<T>();
+		let weight = Conviction::Locked6x.votes(T::MinimumVotingBalance::get());
+	}: _(RawOrigin::Signed(voter.clone()), ref_index, vote)
+	verify {
+		assert_last_event::<T>(Event::WeightedVoteCast { who: voter, ref_index, vote, weight }.into());
+	}
+
+	close_referendum {
+		let voter = funded_voter::<T>();
+		let ref_index = start::<T>(1u32.into());
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+	}: _(RawOrigin::Signed(voter), ref_index)
+	verify {
+		assert_last_event::<T>(Event::ReferendumClosed { ref_index, approved: false }.into());
+	}
+
+	unlock {
+		let r in 1 .. T::MaxVotes::get();
+
+		let voter = funded_voter::<T>();
+		vote_in_referendums::<T>(&voter, r);
+		let until = frame_system::Pallet::<T>::block_number()
+			+ 10u32.into()
+			+ T::VoteLockingPeriod::get() * Conviction::Locked6x.lock_periods().into();
+		frame_system::Pallet::<T>::set_block_number(until);
+	}: _(RawOrigin::Signed(voter.clone()), voter.clone())
+	verify {
+		assert!(Locks::<T>::get(&voter).is_empty());
+		assert_last_event::<T>(Event::Unlocked { who: voter }.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -0,0 +1,344 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Weighted Democracy Pallet
+//!
+//! The Weighted Democracy Pallet runs referendums in which the weight of a
+//! vote grows with the balance behind it. A voter picks a conviction, and
+//! the weight of their vote is their balance times the multiplier of the
+//! conviction. The balance stays locked past the end of the referendum for a
+//! number of `VoteLockingPeriod`s that doubles with every conviction step.
+//!
+//! Votes below `MinimumVotingBalance` are rejected, so that dust accounts
+//! cannot crowd the tally. A referendum is approved when, once voting ended,
+//! the weight of the ayes exceeds the weight of the nays.
+//!
+//! | Conviction | Multiplier | Locking periods |
+//! |------------|------------|-----------------|
+//! | `None`     | 0.1x       | 0               |
+//! | `Locked1x` | 1x         | 1               |
+//! | `Locked2x` | 2x         | 2               |
+//! | `Locked3x` | 3x         | 4               |
+//! | `Locked4x` | 4x         | 8               |
+//! | `Locked5x` | 5x         | 16              |
+//! | `Locked6x` | 6x         | 32              |
+//!
+//! ### Storage
+//!
+//! - `ReferendumCount`: The number of referendums started so far.
+//! - `ReferendumInfoOf`: The state of every referendum.
+//! - `VotingOf`: The vote of an account in a referendum.
+//! - `Locks`: The balance of an account locked by each of its votes.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `start_referendum` - Put a proposal to the vote.
+//! - `vote_weighted` - Vote in a referendum with a weighted balance.
+//! - `close_referendum` - Record the outcome of a referendum once voting ended.
+//! - `unlock` - Release the balance of an account whose locks expired.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod types;
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub use pallet::*;
+pub use types::{
+	AccountVote, Conviction, ReferendumIndex, ReferendumInfo, ReferendumStatus, Tally, Vote,
+	VoteLock,
+};
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons};
+use sp_runtime::traits::{Saturating, Zero};
+
+/// Identifier of the balance lock held by votes.
+const VOTE_LOCK_ID: LockIdentifier = *b"cordwdem";
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of the state of a referendum.
+	pub type ReferendumOf<T> =
+		ReferendumInfo<<T as frame_system::Config>::Hash, BlockNumberFor<T>, BalanceOf<T>>;
+	/// Type of the locks of an account.
+	pub type VoteLocksOf<T> =
+		BoundedVec<VoteLock<BalanceOf<T>, BlockNumberFor<T>>, <T as Config>::MaxVotes>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency votes lock.
+		type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+		/// The origin allowed to start referendums.
+		type StartOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The smallest balance a vote can be cast with.
+		#[pallet::constant]
+		type MinimumVotingBalance: Get<BalanceOf<Self>>;
+		/// The blocks one locking period lasts.
+		#[pallet::constant]
+		type VoteLockingPeriod: Get<BlockNumberFor<Self>>;
+		/// The maximum number of unexpired votes of an account.
+		#[pallet::constant]
+		type MaxVotes: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// number of referendums started so far
+	#[pallet::storage]
+	#[pallet::getter(fn referendum_count)]
+	pub type ReferendumCount<T> = StorageValue<_, ReferendumIndex, ValueQuery>;
+
+	/// state of referendums by index
+	#[pallet::storage]
+	#[pallet::getter(fn referendum_info)]
+	pub type ReferendumInfoOf<T: Config> =
+		StorageMap<_, Twox64Concat, ReferendumIndex, ReferendumOf<T>, OptionQuery>;
+
+	/// votes of accounts by referendum
+	#[pallet::storage]
+	#[pallet::getter(fn voting_of)]
+	pub type VotingOf<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ReferendumIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		AccountVote<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// balance of accounts locked by their votes
+	#[pallet::storage]
+	#[pallet::getter(fn locks)]
+	pub type Locks<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, VoteLocksOf<T>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A referendum was started.
+		ReferendumStarted {
+			ref_index: ReferendumIndex,
+			proposal_hash: T::Hash,
+			end: BlockNumberFor<T>,
+		},
+		/// A vote was cast, `weight` is its weight in the tally.
+		WeightedVoteCast {
+			who: T::AccountId,
+			ref_index: ReferendumIndex,
+			vote: AccountVote<BalanceOf<T>>,
+			weight: BalanceOf<T>,
+		},
+		/// A referendum was closed.
+		ReferendumClosed { ref_index: ReferendumIndex, approved: bool },
+		/// The expired locks of an account were released.
+		Unlocked { who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The voting period is zero.
+		InvalidVotingPeriod,
+		/// No referendum has the index.
+		ReferendumNotFound,
+		/// Voting on the referendum ended.
+		ReferendumNotOngoing,
+		/// Voting on the referendum has not ended yet.
+		VotingNotEnded,
+		/// The vote balance is below `MinimumVotingBalance`.
+		BalanceTooLow,
+		/// The free balance of the voter is below the vote balance.
+		InsufficientFunds,
+		/// The voter has `MaxVotes` unexpired votes.
+		TooManyVotes,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Put the proposal with `proposal_hash` to the vote for
+		/// `voting_period` blocks.
+		///
+		/// The dispatch origin must be `StartOrigin`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::start_referendum())]
+		pub fn start_referendum(
+			origin: OriginFor<T>,
+			proposal_hash: T::Hash,
+			voting_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::StartOrigin::ensure_origin(origin)?;
+			ensure!(!voting_period.is_zero(), Error::<T>::InvalidVotingPeriod);
+
+			let ref_index = ReferendumCount::<T>::get();
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(voting_period);
+			ReferendumInfoOf::<T>::insert(
+				ref_index,
+				ReferendumInfo::Ongoing(ReferendumStatus {
+					proposal_hash,
+					end,
+					tally: Tally::default(),
+				}),
+			);
+			ReferendumCount::<T>::put(ref_index.saturating_add(1));
+
+			Self::deposit_event(Event::ReferendumStarted { ref_index, proposal_hash, end });
+			Ok(())
+		}
+
+		/// Vote in referendum `ref_index`. The weight of the vote is its
+		/// balance times the multiplier of its conviction, and the balance is
+		/// locked until the conviction lock expires. A new vote of the caller
+		/// replaces their previous vote in the referendum.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::vote_weighted(T::MaxVotes::get()))]
+		pub fn vote_weighted(
+			origin: OriginFor<T>,
+			ref_index: ReferendumIndex,
+			vote: AccountVote<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let balance = vote.balance();
+			ensure!(balance >= T::MinimumVotingBalance::get(), Error::<T>::BalanceTooLow);
+			ensure!(balance <= T::Currency::free_balance(&who), Error::<T>::InsufficientFunds);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut status = match ReferendumInfoOf::<T>::get(ref_index) {
+				Some(ReferendumInfo::Ongoing(status)) if now < status.end => status,
+				Some(_) => return Err(Error::<T>::ReferendumNotOngoing.into()),
+				None => return Err(Error::<T>::ReferendumNotFound.into()),
+			};
+
+			let until = status.end.saturating_add(
+				T::VoteLockingPeriod::get().saturating_mul(vote.lock_periods().into()),
+			);
+			let mut locks = Locks::<T>::get(&who);
+			locks.retain(|lock| lock.ref_index != ref_index && lock.until > now);
+			locks
+				.try_push(VoteLock { ref_index, amount: balance, until })
+				.map_err(|_| Error::<T>::TooManyVotes)?;
+
+			if let Some(previous) = VotingOf::<T>::get(ref_index, &who) {
+				status.tally.remove(previous.weights());
+			}
+			let (ayes, nays) = vote.weights();
+			status.tally.add((ayes, nays));
+
+			ReferendumInfoOf::<T>::insert(ref_index, ReferendumInfo::Ongoing(status));
+			VotingOf::<T>::insert(ref_index, &who, vote);
+			Self::update_lock(&who, locks);
+
+			Self::deposit_event(Event::WeightedVoteCast {
+				who,
+				ref_index,
+				vote,
+				weight: ayes.saturating_add(nays),
+			});
+			Ok(())
+		}
+
+		/// Close referendum `ref_index` once voting on it ended. It is
+		/// approved when the weight of the ayes exceeds the weight of the
+		/// nays.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::close_referendum())]
+		pub fn close_referendum(
+			origin: OriginFor<T>,
+			ref_index: ReferendumIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let status = match ReferendumInfoOf::<T>::get(ref_index) {
+				Some(ReferendumInfo::Ongoing(status)) => status,
+				Some(_) => return Err(Error::<T>::ReferendumNotOngoing.into()),
+				None => return Err(Error::<T>::ReferendumNotFound.into()),
+			};
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= status.end,
+				Error::<T>::VotingNotEnded
+			);
+
+			let approved = status.tally.ayes > status.tally.nays;
+			ReferendumInfoOf::<T>::insert(
+				ref_index,
+				ReferendumInfo::Finished { approved, end: status.end, tally: status.tally },
+			);
+
+			Self::deposit_event(Event::ReferendumClosed { ref_index, approved });
+			Ok(())
+		}
+
+		/// Release the balance of `target` held by votes whose lock expired.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unlock(T::MaxVotes::get()))]
+		pub fn unlock(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut locks = Locks::<T>::get(&target);
+			locks.retain(|lock| lock.until > now);
+			Self::update_lock(&target, locks);
+
+			Self::deposit_event(Event::Unlocked { who: target });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Store the locks of `who` and lock the largest of their amounts.
+	fn update_lock(who: &T::AccountId, locks: VoteLocksOf<T>) {
+		let amount =
+			locks.iter().fold(Zero::zero(), |max: BalanceOf<T>, lock| max.max(lock.amount));
+		if amount.is_zero() {
+			T::Currency::remove_lock(VOTE_LOCK_ID, who);
+			Locks::<T>::remove(who);
+		} else {
+			T::Currency::set_lock(
+				VOTE_LOCK_ID,
+				who,
+				amount,
+				WithdrawReasons::except(WithdrawReasons::RESERVE),
+			);
+			Locks::<T>::insert(who, locks);
+		}
+	}
+}
@@ -0,0 +1,289 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::traits::BadOrigin;
+
+const VOTING_PERIOD: u64 = 10;
+
+fn start() -> ReferendumIndex {
+	let ref_index = WeightedDemocracy::referendum_count();
+	assert_ok!(WeightedDemocracy::start_referendum(
+		RuntimeOrigin::root(),
+		H256::repeat_byte(ref_index as u8),
+		VOTING_PERIOD
+	));
+	ref_index
+}
+
+fn standard(aye: bool, conviction: Conviction, balance: Balance) -> AccountVote<Balance> {
+	AccountVote::Standard { vote: Vote { aye, conviction }, balance }
+}
+
+fn tally(ref_index: ReferendumIndex) -> Tally<Balance> {
+	match WeightedDemocracy::referendum_info(ref_index) {
+		Some(ReferendumInfo::Ongoing(status)) => status.tally,
+		info => panic!("referendum {ref_index} is not ongoing: {info:?}"),
+	}
+}
+
+/// The balance of `who` locked by the referendum votes.
+fn locked(who: AccountId) -> Balance {
+	pallet_balances::Locks::<Test>::get(who)
+		.iter()
+		.map(|lock| lock.amount)
+		.max()
+		.unwrap_or(0)
+}
+
+#[test]
+fn start_referendum_opens_the_voting() {
+	new_test_ext().execute_with(|| {
+		let proposal_hash = H256::repeat_byte(7);
+		assert_ok!(WeightedDemocracy::start_referendum(
+			RuntimeOrigin::root(),
+			proposal_hash,
+			VOTING_PERIOD
+		));
+
+		assert_eq!(WeightedDemocracy::referendum_count(), 1);
+		assert_eq!(
+			WeightedDemocracy::referendum_info(0),
+			Some(ReferendumInfo::Ongoing(ReferendumStatus {
+				proposal_hash,
+				end: 11,
+				tally: Tally::default()
+			}))
+		);
+		System::assert_last_event(
+			Event::ReferendumStarted { ref_index: 0, proposal_hash, end: 11 }.into(),
+		);
+	});
+}
+
+#[test]
+fn start_referendum_checks_origin_and_period() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			WeightedDemocracy::start_referendum(RuntimeOrigin::signed(1), H256::zero(), 10),
+			BadOrigin
+		);
+		assert_noop!(
+			WeightedDemocracy::start_referendum(RuntimeOrigin::root(), H256::zero(), 0),
+			Error::<Test>::InvalidVotingPeriod
+		);
+	});
+}
+
+#[test]
+fn vote_weight_is_the_balance_times_the_conviction_multiplier() {
+	new_test_ext().execute_with(|| {
+		let ref_index = start();
+		let vote = standard(true, Conviction::Locked3x, 100);
+		assert_ok!(WeightedDemocracy::vote_weighted(RuntimeOrigin::signed(1), ref_index, vote));
+
+		assert_eq!(tally(ref_index), Tally { ayes: 300, nays: 0 });
+		assert_eq!(WeightedDemocracy::voting_of(ref_index, 1), Some(vote));
+		assert_eq!(locked(1), 100);
+		System::assert_last_event(
+			Event::WeightedVoteCast { who: 1, ref_index, vote, weight: 300 }.into(),
+		);
+
+		// Without conviction, a vote counts a tenth of its balance.
+		let vote = standard(false, Conviction::None, 500);
+		assert_ok!(WeightedDemocracy::vote_weighted(RuntimeOrigin::signed(2), ref_index, vote));
+		assert_eq!(tally(ref_index), Tally { ayes: 300, nays: 50 });
+	});
+}
+
+#[test]
+fn split_votes_count_without_conviction() {
+	new_test_ext().execute_with(|| {
+		let ref_index = start();
+		let vote = AccountVote::Split { aye: 100, nay: 60 };
+		assert_ok!(WeightedDemocracy::vote_weighted(RuntimeOrigin::signed(1), ref_index, vote));
+
+		assert_eq!(tally(ref_index), Tally { ayes: 10, nays: 6 });
+		assert_eq!(locked(1), 160);
+		System::assert_last_event(
+			Event::WeightedVoteCast { who: 1, ref_index, vote, weight: 16 }.into(),
+		);
+	});
+}
+
+#[test]
+fn vote_checks_the_voting_balance() {
+	new_test_ext().execute_with(|| {
+		let ref_index = start();
+		assert_noop!(
+			WeightedDemocracy::vote_weighted(
+				RuntimeOrigin::signed(1),
+				ref_index,
+				standard(true, Conviction::Locked1x, MINIMUM_VOTING_BALANCE - 1)
+			),
+			Error::<Test>::BalanceTooLow
+		);
+		assert_noop!(
+			WeightedDemocracy::vote_weighted(
+				RuntimeOrigin::signed(1),
+				ref_index,
+				AccountVote::Split { aye: 20, nay: 20 }
+			),
+			Error::<Test>::BalanceTooLow
+		);
+		assert_noop!(
+			WeightedDemocracy::vote_weighted(
+				RuntimeOrigin::signed(1),
+				ref_index,
+				standard(true, Conviction::Locked1x, 1_001)
+			),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn new_vote_replaces_the_previous_vote() {
+	new_test_ext().execute_with(|| {
+		let ref_index = start();
+		assert_ok!(WeightedDemocracy::vote_weighted(
+			RuntimeOrigin::signed(1),
+			ref_index,
+			standard(true, Conviction::Locked1x, 100)
+		));
+		assert_ok!(WeightedDemocracy::vote_weighted(
+			RuntimeOrigin::signed(1),
+			ref_index,
+			standard(false, Conviction::Locked2x, 200)
+		));
+
+		assert_eq!(tally(ref_index), Tally { ayes: 0, nays: 400 });
+		assert_eq!(WeightedDemocracy::locks(1).len(), 1);
+		assert_eq!(locked(1), 200);
+	});
+}
+
+#[test]
+fn vote_requires_an_ongoing_referendum() {
+	new_test_ext().execute_with(|| {
+		let vote = standard(true, Conviction::Locked1x, 100);
+		assert_noop!(
+			WeightedDemocracy::vote_weighted(RuntimeOrigin::signed(1), 0, vote),
+			Error::<Test>::ReferendumNotFound
+		);
+
+		let ref_index = start();
+		System::set_block_number(1 + VOTING_PERIOD);
+		assert_noop!(
+			WeightedDemocracy::vote_weighted(RuntimeOrigin::signed(1), ref_index, vote),
+			Error::<Test>::ReferendumNotOngoing
+		);
+	});
+}
+
+#[test]
+fn close_referendum_records_the_outcome_once_voting_ended() {
+	new_test_ext().execute_with(|| {
+		let approved = start();
+		let rejected = start();
+		assert_ok!(WeightedDemocracy::vote_weighted(
+			RuntimeOrigin::signed(1),
+			approved,
+			standard(true, Conviction::Locked1x, 100)
+		));
+		// More balance, but less weight.
+		assert_ok!(WeightedDemocracy::vote_weighted(
+			RuntimeOrigin::signed(2),
+			approved,
+			standard(false, Conviction::None, 900)
+		));
+		assert_noop!(
+			WeightedDemocracy::close_referendum(RuntimeOrigin::signed(1), approved),
+			Error::<Test>::VotingNotEnded
+		);
+
+		System::set_block_number(1 + VOTING_PERIOD);
+		assert_ok!(WeightedDemocracy::close_referendum(RuntimeOrigin::signed(1), approved));
+		System::assert_last_event(
+			Event::ReferendumClosed { ref_index: approved, approved: true }.into(),
+		);
+		// A tie is rejected.
+		assert_ok!(WeightedDemocracy::close_referendum(RuntimeOrigin::signed(1), rejected));
+		assert_eq!(
+			WeightedDemocracy::referendum_info(rejected),
+			Some(ReferendumInfo::Finished {
+				approved: false,
+				end: 1 + VOTING_PERIOD,
+				tally: Tally::default()
+			})
+		);
+		assert_noop!(
+			WeightedDemocracy::close_referendum(RuntimeOrigin::signed(1), approved),
+			Error::<Test>::ReferendumNotOngoing
+		);
+	});
+}
+
+#[test]
+fn unlock_releases_expired_locks() {
+	new_test_ext().execute_with(|| {
+		let ref_index = start();
+		assert_ok!(WeightedDemocracy::vote_weighted(
+			RuntimeOrigin::signed(1),
+			ref_index,
+			standard(true, Conviction::Locked2x, 100)
+		));
+		let until = 1 + VOTING_PERIOD + 2 * VOTE_LOCKING_PERIOD;
+		assert_eq!(WeightedDemocracy::locks(1)[0].until, until);
+
+		System::set_block_number(until - 1);
+		assert_ok!(WeightedDemocracy::unlock(RuntimeOrigin::signed(2), 1));
+		assert_eq!(locked(1), 100);
+
+		System::set_block_number(until);
+		assert_ok!(WeightedDemocracy::unlock(RuntimeOrigin::signed(2), 1));
+		assert_eq!(locked(1), 0);
+		assert!(WeightedDemocracy::locks(1).is_empty());
+		System::assert_last_event(Event::Unlocked { who: 1 }.into());
+	});
+}
+
+#[test]
+fn vote_is_bounded_by_max_votes() {
+	new_test_ext().execute_with(|| {
+		let vote = standard(true, Conviction::None, 100);
+		for _ in 0..MAX_VOTES {
+			let ref_index = start();
+			assert_ok!(WeightedDemocracy::vote_weighted(RuntimeOrigin::signed(1), ref_index, vote));
+		}
+		let ref_index = start();
+		assert_noop!(
+			WeightedDemocracy::vote_weighted(RuntimeOrigin::signed(1), ref_index, vote),
+			Error::<Test>::TooManyVotes
+		);
+
+		// Locks that expired no longer count.
+		System::set_block_number(1 + VOTING_PERIOD);
+		let ref_index = start();
+		assert_ok!(WeightedDemocracy::vote_weighted(RuntimeOrigin::signed(1), ref_index, vote));
+		assert_eq!(WeightedDemocracy::locks(1).len(), 1);
+	});
+}
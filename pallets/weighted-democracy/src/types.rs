@@ -0,0 +1,202 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Saturating, Zero},
+	RuntimeDebug,
+};
+
+/// Index of a referendum.
+pub type ReferendumIndex = u32;
+
+/// How long a voter locks their balance beyond the end of a referendum, in
+/// exchange for more weight.
+#[derive(
+	Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, Default, TypeInfo,
+)]
+pub enum Conviction {
+	/// 0.1x the balance, locked until the end of the referendum.
+	#[default]
+	None,
+	/// 1x the balance, locked for one locking period.
+	Locked1x,
+	/// 2x the balance, locked for 2 locking periods.
+	Locked2x,
+	/// 3x the balance, locked for 4 locking periods.
+	Locked3x,
+	/// 4x the balance, locked for 8 locking periods.
+	Locked4x,
+	/// 5x the balance, locked for 16 locking periods.
+	Locked5x,
+	/// 6x the balance, locked for 32 locking periods.
+	Locked6x,
+}
+
+impl Conviction {
+	/// The number of locking periods the balance stays locked after the
+	/// referendum ended.
+	pub fn lock_periods(self) -> u32 {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 4,
+			Conviction::Locked4x => 8,
+			Conviction::Locked5x => 16,
+			Conviction::Locked6x => 32,
+		}
+	}
+
+	/// The weight of `balance` voted with this conviction.
+	pub fn votes<Balance: AtLeast32BitUnsigned>(self, balance: Balance) -> Balance {
+		match self {
+			Conviction::None => balance / 10u8.into(),
+			Conviction::Locked1x => balance,
+			Conviction::Locked2x => balance.saturating_mul(2u8.into()),
+			Conviction::Locked3x => balance.saturating_mul(3u8.into()),
+			Conviction::Locked4x => balance.saturating_mul(4u8.into()),
+			Conviction::Locked5x => balance.saturating_mul(5u8.into()),
+			Conviction::Locked6x => balance.saturating_mul(6u8.into()),
+		}
+	}
+}
+
+/// A vote for or against a referendum.
+///
+/// ## Fields
+///
+/// - `aye`: Whether the vote approves the referendum.
+/// - `conviction`: The conviction the vote is cast with.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct Vote {
+	pub aye: bool,
+	pub conviction: Conviction,
+}
+
+/// The balance an account votes with.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum AccountVote<Balance> {
+	/// The whole balance votes one way, with a conviction.
+	Standard { vote: Vote, balance: Balance },
+	/// The balance is split between both sides, without conviction.
+	Split { aye: Balance, nay: Balance },
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> AccountVote<Balance> {
+	/// The balance locked by the vote.
+	pub fn balance(&self) -> Balance {
+		match self {
+			AccountVote::Standard { balance, .. } => *balance,
+			AccountVote::Split { aye, nay } => aye.saturating_add(*nay),
+		}
+	}
+
+	/// The weight the vote adds to the ayes and to the nays.
+	pub fn weights(&self) -> (Balance, Balance) {
+		match self {
+			AccountVote::Standard { vote, balance } => {
+				let weight = vote.conviction.votes(*balance);
+				if vote.aye {
+					(weight, Zero::zero())
+				} else {
+					(Zero::zero(), weight)
+				}
+			},
+			AccountVote::Split { aye, nay } => {
+				(Conviction::None.votes(*aye), Conviction::None.votes(*nay))
+			},
+		}
+	}
+
+	/// The number of locking periods the balance stays locked after the
+	/// referendum ended.
+	pub fn lock_periods(&self) -> u32 {
+		match self {
+			AccountVote::Standard { vote, .. } => vote.conviction.lock_periods(),
+			AccountVote::Split { .. } => 0,
+		}
+	}
+}
+
+/// The weighted votes of a referendum.
+///
+/// ## Fields
+///
+/// - `ayes`: The weight of the votes in favour.
+/// - `nays`: The weight of the votes against.
+#[derive(
+	Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, Default, TypeInfo,
+)]
+pub struct Tally<Balance> {
+	pub ayes: Balance,
+	pub nays: Balance,
+}
+
+impl<Balance: Saturating + Copy> Tally<Balance> {
+	/// Add the weights of a vote.
+	pub fn add(&mut self, (ayes, nays): (Balance, Balance)) {
+		self.ayes = self.ayes.saturating_add(ayes);
+		self.nays = self.nays.saturating_add(nays);
+	}
+
+	/// Remove the weights of a vote.
+	pub fn remove(&mut self, (ayes, nays): (Balance, Balance)) {
+		self.ayes = self.ayes.saturating_sub(ayes);
+		self.nays = self.nays.saturating_sub(nays);
+	}
+}
+
+/// A referendum that accepts votes.
+///
+/// ## Fields
+///
+/// - `proposal_hash`: The hash of the proposal put to the vote.
+/// - `end`: The block voting ends in.
+/// - `tally`: The weighted votes so far.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct ReferendumStatus<Hash, BlockNumber, Balance> {
+	pub proposal_hash: Hash,
+	pub end: BlockNumber,
+	pub tally: Tally<Balance>,
+}
+
+/// The state of a referendum.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum ReferendumInfo<Hash, BlockNumber, Balance> {
+	/// Voting on the referendum is open.
+	Ongoing(ReferendumStatus<Hash, BlockNumber, Balance>),
+	/// The referendum was closed, approved when the ayes outweighed the nays
+	/// of its final tally.
+	Finished { approved: bool, end: BlockNumber, tally: Tally<Balance> },
+}
+
+/// Balance of an account locked by one of its votes.
+///
+/// ## Fields
+///
+/// - `ref_index`: The referendum the vote was cast in.
+/// - `amount`: The balance locked by the vote.
+/// - `until`: The block the lock expires in.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct VoteLock<Balance, BlockNumber> {
+	pub ref_index: ReferendumIndex,
+	pub amount: Balance,
+	pub until: BlockNumber,
+}
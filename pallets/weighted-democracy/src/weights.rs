@@ -0,0 +1,163 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_weighted_democracy`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_weighted_democracy
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/weighted-democracy/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cord_weighted_democracy`.
+pub trait WeightInfo {
+	fn start_referendum() -> Weight;
+	fn vote_weighted(r: u32, ) -> Weight;
+	fn close_referendum() -> Weight;
+	fn unlock(r: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_cord_weighted_democracy` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `WeightedDemocracy::ReferendumCount` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::ReferendumCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `WeightedDemocracy::ReferendumInfoOf` (r:0 w:1)
+	/// Proof: `WeightedDemocracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn start_referendum() -> Weight {
+		Weight::from_parts(9_480_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `WeightedDemocracy::ReferendumInfoOf` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `WeightedDemocracy::Locks` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::Locks` (`max_values`: None, `max_size`: Some(2449), added: 4924, mode: `MaxEncodedLen`)
+	/// Storage: `WeightedDemocracy::VotingOf` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::VotingOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 99]`.
+	fn vote_weighted(r: u32, ) -> Weight {
+		Weight::from_parts(43_025_000, 5914)
+			.saturating_add(Weight::from_parts(98_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `WeightedDemocracy::ReferendumInfoOf` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn close_referendum() -> Weight {
+		Weight::from_parts(11_690_000, 3546)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `WeightedDemocracy::Locks` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::Locks` (`max_values`: None, `max_size`: Some(2449), added: 4924, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 100]`.
+	fn unlock(r: u32, ) -> Weight {
+		Weight::from_parts(31_950_000, 5914)
+			.saturating_add(Weight::from_parts(87_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `WeightedDemocracy::ReferendumCount` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::ReferendumCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `WeightedDemocracy::ReferendumInfoOf` (r:0 w:1)
+	/// Proof: `WeightedDemocracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn start_referendum() -> Weight {
+		Weight::from_parts(9_480_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `WeightedDemocracy::ReferendumInfoOf` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `WeightedDemocracy::Locks` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::Locks` (`max_values`: None, `max_size`: Some(2449), added: 4924, mode: `MaxEncodedLen`)
+	/// Storage: `WeightedDemocracy::VotingOf` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::VotingOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 99]`.
+	fn vote_weighted(r: u32, ) -> Weight {
+		Weight::from_parts(43_025_000, 5914)
+			.saturating_add(Weight::from_parts(98_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `WeightedDemocracy::ReferendumInfoOf` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn close_referendum() -> Weight {
+		Weight::from_parts(11_690_000, 3546)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `WeightedDemocracy::Locks` (r:1 w:1)
+	/// Proof: `WeightedDemocracy::Locks` (`max_values`: None, `max_size`: Some(2449), added: 4924, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 100]`.
+	fn unlock(r: u32, ) -> Weight {
+		Weight::from_parts(31_950_000, 5914)
+			.saturating_add(Weight::from_parts(87_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
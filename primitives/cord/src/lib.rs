@@ -22,6 +22,7 @@
 use sp_runtime::{
 	generic,
 	traits::{BlakeTwo256, IdentifyAccount, Verify},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
 	MultiSignature, OpaqueExtrinsic,
 };
 use sp_std::vec::Vec;
@@ -96,6 +97,61 @@ pub type StatusOf = bool;
 /// node identifier
 pub type NodeId = Vec<u8>;
 
+/// Reasons CORD specific transaction checks reject a transaction for,
+/// reported as `InvalidTransaction::Custom` with the code of the variant:
+///
+/// | Code | Variant             |
+/// |------|---------------------|
+/// | 1    | `NotAuthorized`     |
+/// | 2    | `RateLimitExceeded` |
+/// | 3    | `MembershipExpired` |
+/// | 4    | `SchemaNotActive`   |
+///
+/// Code 0 is left out, as it is commonly used for unspecified rejections.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CordTransactionError {
+	/// The signer is not a network member.
+	NotAuthorized = 1,
+	/// The signer already had as many extrinsics included in the block as
+	/// it may.
+	RateLimitExceeded = 2,
+	/// The network membership of the signer expired.
+	MembershipExpired = 3,
+	/// The schema the transaction refers to is not active.
+	SchemaNotActive = 4,
+}
+
+impl CordTransactionError {
+	/// The `InvalidTransaction::Custom` code of the error.
+	pub const fn code(self) -> u8 {
+		self as u8
+	}
+
+	/// The error reported with `code`, if it is a CORD code.
+	pub fn from_code(code: u8) -> Option<Self> {
+		match code {
+			1 => Some(Self::NotAuthorized),
+			2 => Some(Self::RateLimitExceeded),
+			3 => Some(Self::MembershipExpired),
+			4 => Some(Self::SchemaNotActive),
+			_ => None,
+		}
+	}
+}
+
+impl From<CordTransactionError> for InvalidTransaction {
+	fn from(error: CordTransactionError) -> Self {
+		InvalidTransaction::Custom(error.code())
+	}
+}
+
+impl From<CordTransactionError> for TransactionValidityError {
+	fn from(error: CordTransactionError) -> Self {
+		InvalidTransaction::from(error).into()
+	}
+}
+
 /// Authorship perio
 // pub const AUTHORSHIP_PERIOD: u32 = 5256000;
 pub const AUTHORSHIP_PERIOD: u32 = 20;
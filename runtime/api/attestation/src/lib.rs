@@ -0,0 +1,34 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+
+//! Runtime API definition for querying attestations.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait AttestationApi<ClaimHash, AttestationEntry> where
+		ClaimHash: Codec,
+		AttestationEntry: Codec,
+	{
+		/// The attestation of a claim, including the block it was made at
+		/// and whether it has been revoked.
+		fn get_attestation(claim_hash: ClaimHash) -> Option<AttestationEntry>;
+	}
+}
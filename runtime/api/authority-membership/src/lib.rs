@@ -0,0 +1,42 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for authority membership.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_staking::SessionIndex;
+use sp_std::vec::Vec;
+
+pub use authority_membership::AuthorityDiff;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(2)]
+	pub trait AuthorityManagerApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// The authority diff to be applied at the next session, if any.
+		fn pending_diff() -> Option<AuthorityDiff<AccountId>>;
+
+		/// The authority set of a session, if it is still in the authority
+		/// set history.
+		#[api_version(2)]
+		fn get_authority_set_at(session: SessionIndex) -> Option<Vec<AccountId>>;
+	}
+}
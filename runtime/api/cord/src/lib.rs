@@ -0,0 +1,191 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definitions for the identity and the pallets of a CORD chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// A pallet of the runtime, as listed by `CordMetadataApi`.
+#[derive(Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct PalletMeta {
+	/// Name of the pallet in the runtime, as UTF-8.
+	pub name: Vec<u8>,
+	/// Storage version of the pallet, 0 when none is recorded.
+	pub version: u32,
+	/// Prefix of the storage keys of the pallet, the twox128 hash of its
+	/// name.
+	pub storage_prefix: Vec<u8>,
+}
+
+/// An approved treasury spend proposal, as listed by `CordTreasuryApi`.
+#[derive(Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct TreasuryProposalInfo<AccountId, Balance> {
+	/// Index of the proposal in the treasury.
+	pub index: u32,
+	/// The account that made the proposal.
+	pub proposer: AccountId,
+	/// The account the treasury pays.
+	pub beneficiary: AccountId,
+	/// The amount the treasury pays.
+	pub value: Balance,
+	/// The deposit reserved from the proposer.
+	pub bond: Balance,
+}
+
+/// The number of treasury proposals `pending_proposals_count` scans at most.
+pub const MAX_TREASURY_PROPOSALS_COUNTED: u32 = 1_000;
+
+/// The on-chain anchor of a DID document, as resolved by `CordIdentityApi`.
+#[derive(Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct DidDocumentInfo<AccountId, Hash, BlockNumber> {
+	/// Hash of the DID document.
+	pub digest: Hash,
+	/// Whether the document can still be updated, `false` once it was
+	/// deactivated.
+	pub active: bool,
+	/// The account that anchored the document.
+	pub creator: AccountId,
+	/// The block the document was first anchored in.
+	pub created_at: BlockNumber,
+	/// The block of the last update or deactivation of the document.
+	pub updated_at: BlockNumber,
+}
+
+/// A state snapshot published on-chain, as returned by `CordSnapshotApi`.
+#[derive(Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct SnapshotInfo<Hash, BlockNumber> {
+	/// The block whose state the snapshot holds.
+	pub block: BlockNumber,
+	/// The state root of the block, to verify the snapshot against.
+	pub state_root: Hash,
+	/// The IPFS CID the snapshot can be fetched under.
+	pub ipfs_cid: Vec<u8>,
+}
+
+sp_api::decl_runtime_apis! {
+	#[api_version(3)]
+	pub trait CordRuntimeApi<AccountId, AccountIndex> where
+		AccountId: Codec,
+		AccountIndex: Codec,
+	{
+		/// The hash of the genesis block, as recorded by the runtime. Only
+		/// known once the first block has been imported.
+		fn genesis_hash() -> Block::Hash;
+
+		/// The CORD specific features the runtime was built with, as UTF-8
+		/// names.
+		fn features() -> Vec<Vec<u8>>;
+
+		/// The account an account index is assigned to, if any.
+		#[api_version(2)]
+		fn resolve_account_index(index: AccountIndex) -> Option<AccountId>;
+
+		/// The chain id schema and statement identifiers are scoped to.
+		#[api_version(3)]
+		fn chain_id() -> u32;
+	}
+
+	#[api_version(1)]
+	pub trait CordMetadataApi {
+		/// The pallets of the runtime, in the order of their index.
+		fn cord_pallet_list() -> Vec<PalletMeta>;
+	}
+
+	#[api_version(1)]
+	pub trait CordAuthorityApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Whether an account may author extrinsics, that is whether it holds
+		/// a network membership.
+		fn is_author(who: AccountId) -> bool;
+	}
+
+	#[api_version(1)]
+	pub trait CordSyncStateApi<BlockNumber> where
+		BlockNumber: Codec,
+	{
+		/// The number of blocks the block the API is called at is ahead of
+		/// `finalized`, the last block finalized by GRANDPA. The runtime does
+		/// not record finality, so the node passes the finalized number in.
+		fn finalization_lag(finalized: BlockNumber) -> u32;
+
+		/// The time, in milliseconds, the chain takes to produce `lag`
+		/// blocks at the target block time, an estimate of how long a lag of
+		/// `lag` blocks takes to be finalized.
+		fn estimated_time_to_finalize(lag: u32) -> u64;
+	}
+
+	#[api_version(1)]
+	pub trait CordTreasuryApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The free balance of the treasury account.
+		fn treasury_balance() -> Balance;
+
+		/// The spend proposals approved by the council and awaiting payout.
+		fn approved_proposals() -> Vec<TreasuryProposalInfo<AccountId, Balance>>;
+
+		/// The number of spend proposals the council did not approve yet,
+		/// capped at `MAX_TREASURY_PROPOSALS_COUNTED`.
+		fn pending_proposals_count() -> u32;
+	}
+
+	#[api_version(1)]
+	pub trait CordBlockTimeApi {
+		/// The time, in milliseconds, the runtime targets between two blocks.
+		fn target_block_time_ms() -> u64;
+
+		/// The average time, in milliseconds, between the last `window`
+		/// blocks up to the block the API is called at. The window is capped
+		/// to the blocks whose timestamps the runtime keeps; zero if it spans
+		/// none.
+		fn measured_block_time_ms(window: u32) -> u64;
+	}
+
+	#[api_version(1)]
+	pub trait CordIdentityApi<DidIdentifier, AccountId, BlockNumber> where
+		DidIdentifier: Codec,
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The document anchored by a DID, if any. Deactivated documents are
+		/// still returned.
+		fn resolve_did(
+			identifier: DidIdentifier,
+		) -> Option<DidDocumentInfo<AccountId, Block::Hash, BlockNumber>>;
+
+		/// Whether a DID anchored a document.
+		fn did_exists(identifier: DidIdentifier) -> bool;
+
+		/// The DIDs whose documents an account anchored.
+		fn list_dids_for_account(account: AccountId) -> Vec<DidIdentifier>;
+	}
+
+	#[api_version(1)]
+	pub trait CordSnapshotApi<BlockNumber> where
+		BlockNumber: Codec,
+	{
+		/// The latest state snapshot published on-chain, if any.
+		fn get_latest_snapshot() -> Option<SnapshotInfo<Block::Hash, BlockNumber>>;
+	}
+}
@@ -0,0 +1,37 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for resolving DID documents kept off-chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait OffchainResolverApi<DidIdentifier> where
+		DidIdentifier: Codec,
+	{
+		/// The off-chain document of the given DID, if the offchain worker has
+		/// fetched it and it still matches the anchored digest. The document
+		/// is read from offchain storage, so this is only answered when the
+		/// call runs with the offchain extensions enabled.
+		fn resolve_did_offchain(identifier: DidIdentifier) -> Option<Vec<u8>>;
+	}
+}
@@ -20,10 +20,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet_network_membership::RuntimeDispatchWeightInfo;
+pub use pallet_cord_transaction_weights::WeightBucket;
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait TransactionWeightApi {
 		fn query_weight_info(uxt: Block::Extrinsic ) -> RuntimeDispatchWeightInfo;
+
+		/// The number of recent blocks per weight bucket, in bucket order.
+		#[api_version(2)]
+		fn weight_histogram() -> Vec<(WeightBucket, u64)>;
 	}
 }
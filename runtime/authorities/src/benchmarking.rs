@@ -0,0 +1,156 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+//
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use codec::Decode;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_runtime::traits::TrailingZeroInput;
+
+const SEED: u32 = 0;
+const COUNCIL_SIZE: u32 = 3;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn network_member<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	pallet_network_membership::Pallet::<T>::nominate(RawOrigin::Root.into(), who.clone(), false)
+		.expect("the account is not a network member yet");
+	who
+}
+
+fn council<T: Config>() -> Vec<T::AccountId> {
+	// events are not deposited at genesis
+	frame_system::Pallet::<T>::set_block_number(1u32.into());
+	(0..COUNCIL_SIZE)
+		.map(|i| {
+			let member = network_member::<T>("council", i);
+			T::CouncilMembers::add(&member);
+			member
+		})
+		.collect()
+}
+
+/// A network member with registered session keys.
+fn candidate<T: Config>() -> T::AccountId {
+	let candidate = network_member::<T>("candidate", 0);
+	frame_system::Pallet::<T>::inc_providers(&candidate);
+	let keys = <T as pallet_session::Config>::Keys::decode(&mut TrailingZeroInput::zeroes())
+		.expect("session keys decode from zeroes");
+	pallet_session::Pallet::<T>::set_keys(
+		RawOrigin::Signed(candidate.clone()).into(),
+		keys,
+		Vec::new(),
+	)
+	.expect("the candidate can set session keys");
+	candidate
+}
+
+benchmarks! {
+	propose_add_authority {
+		let council = council::<T>();
+		let proposer = council[0].clone();
+		let who: T::AccountId = account("candidate", 0, SEED);
+		let proposal_id = Pallet::<T>::proposal_id(
+			&proposer,
+			&who,
+			frame_system::Pallet::<T>::block_number(),
+		);
+	}: _(RawOrigin::Signed(proposer), who.clone(), COUNCIL_SIZE)
+	verify {
+		assert!(Proposals::<T>::contains_key(proposal_id));
+		assert_last_event::<T>(Event::AuthorityProposed(proposal_id, who).into());
+	}
+
+	// The approval reaching the threshold adds the candidate.
+	approve_proposal {
+		let council = council::<T>();
+		let candidate = candidate::<T>();
+		let proposer = council[0].clone();
+		Pallet::<T>::propose_add_authority(
+			RawOrigin::Signed(proposer.clone()).into(),
+			candidate.clone(),
+			COUNCIL_SIZE,
+		)?;
+		let proposal_id = Pallet::<T>::proposal_id(
+			&proposer,
+			&candidate,
+			frame_system::Pallet::<T>::block_number(),
+		);
+		for approver in &council[1..] {
+			Pallet::<T>::approve_proposal(RawOrigin::Signed(approver.clone()).into(), proposal_id)?;
+		}
+	}: _(RawOrigin::Signed(proposer), proposal_id)
+	verify {
+		assert!(!Proposals::<T>::contains_key(proposal_id));
+		assert_last_event::<T>(Event::MemberAdded(candidate).into());
+	}
+
+	cancel_proposal {
+		let council = council::<T>();
+		let proposer = council[0].clone();
+		let who: T::AccountId = account("candidate", 0, SEED);
+		Pallet::<T>::propose_add_authority(
+			RawOrigin::Signed(proposer.clone()).into(),
+			who.clone(),
+			COUNCIL_SIZE,
+		)?;
+		let proposal_id = Pallet::<T>::proposal_id(
+			&proposer,
+			&who,
+			frame_system::Pallet::<T>::block_number(),
+		);
+	}: _(RawOrigin::Signed(proposer), proposal_id)
+	verify {
+		assert!(!Proposals::<T>::contains_key(proposal_id));
+		assert_last_event::<T>(Event::ProposalCancelled(proposal_id).into());
+	}
+
+	// A full history drops its oldest set when the set of the next session is
+	// recorded.
+	note_authority_set {
+		let a in 1 .. 100;
+
+		let depth = MaxAuthorityHistoryDepth::<T>::get();
+		let authorities: Vec<T::ValidatorId> = (0..a)
+			.filter_map(|i| T::ValidatorIdOf::convert(account("authority", i, SEED)))
+			.collect();
+		for session in 0..depth {
+			AuthoritySetHistory::<T>::insert(session, authorities.clone());
+		}
+		EarliestAuthoritySet::<T>::put(0);
+	}: {
+		Pallet::<T>::note_authority_set(depth, authorities.clone());
+	}
+	verify {
+		assert!(!AuthoritySetHistory::<T>::contains_key(0));
+		assert_eq!(AuthoritySetHistory::<T>::get(depth), Some(authorities));
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -25,7 +25,7 @@
 use super::pallet::*;
 use frame_support::{pallet_prelude::Weight, traits::Get};
 use pallet_offences::{traits::OnOffenceHandler, SlashStrategy};
-use sp_runtime::traits::Convert;
+use sp_runtime::{traits::Convert, Perbill};
 use sp_staking::{offence::OffenceDetails, SessionIndex};
 
 impl<T: Config>
@@ -43,6 +43,7 @@ where
 			T::AccountId,
 			pallet_session::historical::IdentificationTuple<T>,
 		>],
+		slash_fraction: &[Perbill],
 		strategy: SlashStrategy,
 		_slash_session: SessionIndex,
 	) -> Weight {
@@ -74,6 +75,16 @@ where
 					}
 				},
 		}
+
+		// Offenders slashed above the threshold also lose their authority
+		// membership at the next session.
+		let threshold = T::AutoRemoveThreshold::get();
+		for (offender, fraction) in offenders.iter().zip(slash_fraction) {
+			if *fraction > threshold {
+				Self::mark_for_pending_removal(offender.offender.0.clone());
+				add_db_reads_writes(1, 1);
+			}
+		}
 		consumed_weight
 	}
 }
@@ -21,15 +21,50 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod impls;
-
-use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, traits::EnsureOrigin};
+pub mod traits;
+pub mod types;
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	pallet_prelude::*,
+	traits::{EnsureOrigin, SortedMembers},
+};
+use frame_system::{
+	offchain::{SendTransactionTypes, SubmitTransaction},
+	pallet_prelude::BlockNumberFor,
+};
 pub use pallet::*;
-use sp_runtime::traits::Convert;
+use sp_core::H256;
+use sp_runtime::{
+	offchain::storage::StorageValueRef,
+	traits::{Convert, IdentifyAccount, Saturating, Verify},
+	PerThing, Perbill,
+};
 use sp_staking::{
 	offence::{Offence, OffenceError, ReportOffence},
 	SessionIndex,
 };
-use sp_std::{vec, vec::Vec};
+use sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec};
+
+pub use crate::{
+	traits::AuthorityScore,
+	types::{AuthorityDiff, AuthorityProposal, SignedAuthorityDiff},
+};
+
+/// Offchain local storage key watched for a signed authority diff.
+pub const AUTHORITY_DIFF_STORAGE_KEY: &[u8] = b"cord::authority-membership::diff";
+
+pub type SignedAuthorityDiffOf<T> = SignedAuthorityDiff<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::AuthoritySignature,
+>;
+
+pub type AuthorityProposalOf<T> = AuthorityProposal<
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	<T as Config>::MaxProposalApprovals,
+>;
 
 #[cfg(any(feature = "mock", test))]
 pub mod mock;
@@ -37,14 +72,15 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
 type Session<T> = pallet_session::Pallet<T>;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::traits::ValidatorRegistration;
 	use frame_system::pallet_prelude::*;
-	use network_membership::traits::IsMember;
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
@@ -56,11 +92,47 @@ pub mod pallet {
 		+ pallet_session::Config
 		+ pallet_network_membership::Config
 		+ pallet_session::historical::Config
+		+ SendTransactionTypes<Call<Self>>
 	{
 		/// The overreaching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Privileged origin that can add or remove validators.
 		type AuthorityMembershipOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Signature of an authority member over an authority diff.
+		type AuthoritySignature: Verify<Signer = Self::AuthoritySigner> + Parameter;
+		/// Signer of an authority diff.
+		type AuthoritySigner: IdentifyAccount<AccountId = Self::AccountId>;
+		/// Share of the authority members that must sign an authority diff.
+		#[pallet::constant]
+		type AuthorityDiffThreshold: Get<Perbill>;
+		/// Priority of the unsigned authority diff transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// Reliability scores of the session validators. Members scoring below
+		/// the threshold are removed at the end of the session.
+		type ValidatorScore: AuthorityScore<Self::ValidatorId>;
+		/// Slash fraction above which an offender is removed from the
+		/// authority membership at the next session.
+		#[pallet::constant]
+		type AutoRemoveThreshold: Get<Perbill>;
+		/// Council members allowed to propose and approve the addition of an
+		/// authority member.
+		type CouncilMembers: SortedMembers<Self::AccountId>;
+		/// Number of blocks a proposal stays open. The proposer can cancel it
+		/// during that time; it is pruned once the time has passed.
+		#[pallet::constant]
+		type ProposalTtl: Get<BlockNumberFor<Self>>;
+		/// Maximum number of open proposals of a single council member.
+		#[pallet::constant]
+		type MaxProposalsPerProposer: Get<u32>;
+		/// Maximum number of approvals recorded on a proposal, and so the
+		/// highest threshold a proposal can have.
+		#[pallet::constant]
+		type MaxProposalApprovals: Get<u32>;
+		/// Number of sessions whose authority set is kept until governance
+		/// sets `MaxAuthorityHistoryDepth`.
+		#[pallet::constant]
+		type DefaultAuthorityHistoryDepth: Get<u32>;
 	}
 
 	#[pallet::event]
@@ -83,6 +155,35 @@ pub mod pallet {
 		MemberRemoved(T::AccountId),
 		/// A member has been removed from the blacklist.
 		MemberWhiteList(T::AccountId),
+		/// An authority diff has been enqueued for the next session.
+		AuthorityDiffEnqueued(AuthorityDiff<T::AccountId>),
+		/// A pending authority diff has been applied.
+		AuthorityDiffApplied(AuthorityDiff<T::AccountId>),
+		/// A member will be removed from the authority set in 2 sessions as
+		/// its reliability score fell below the threshold.
+		MemberRemovedForLowScore(T::ValidatorId),
+		/// A member will be removed from the authority membership at the next
+		/// session as its slash fraction exceeded the threshold.
+		MemberQueuedForRemoval(T::AccountId),
+		/// A pending removal has been cancelled.
+		PendingRemovalCancelled(T::AccountId),
+		/// A member has been removed from the authority membership for an
+		/// offence.
+		MemberRemovedForOffence(T::AccountId),
+		/// A council member proposed adding an authority member.
+		/// [proposal_id, candidate]
+		AuthorityProposed(H256, T::AccountId),
+		/// A council member approved a proposal. [proposal_id, approver]
+		ProposalApproved(H256, T::AccountId),
+		/// A proposal reached its threshold and was executed. [proposal_id]
+		ProposalExecuted(H256),
+		/// A proposal was cancelled by its proposer. [proposal_id]
+		ProposalCancelled(H256),
+		/// A proposal was pruned after `ProposalTtl` blocks. [proposal_id]
+		ProposalExpired(H256),
+		/// The number of sessions whose authority set is kept was changed.
+		/// [depth]
+		AuthorityHistoryDepthSet(u32),
 	}
 
 	#[pallet::error]
@@ -104,6 +205,36 @@ pub mod pallet {
 		MemberNotBlackListed,
 		/// Not a network member
 		NetworkMembershipNotFound,
+		/// The authority diff changes nothing
+		EmptyAuthorityDiff,
+		/// The authority diff was signed for another session
+		StaleAuthorityDiff,
+		/// Not enough authority members signed the authority diff
+		InsufficientAuthorityDiffSignatures,
+		/// An authority diff is already pending
+		AuthorityDiffAlreadyPending,
+		/// The member is not queued for removal
+		RemovalNotPending,
+		/// The origin is not a council member
+		NotCouncilMember,
+		/// The threshold is zero or above the council size
+		InvalidProposalThreshold,
+		/// The same proposal was already created in this block
+		ProposalAlreadyExists,
+		/// There is no proposal with the given id
+		ProposalNotFound,
+		/// The council member already approved the proposal
+		ProposalAlreadyApproved,
+		/// Only the proposer can cancel a proposal
+		NotProposer,
+		/// The proposal can no longer be cancelled
+		ProposalTtlElapsed,
+		/// The council member has too many open proposals
+		TooManyProposals,
+		/// The proposal has recorded the maximum number of approvals
+		TooManyApprovals,
+		/// The authority set history must keep at least one session
+		ZeroAuthorityHistoryDepth,
 	}
 
 	/// list incoming authorities
@@ -126,6 +257,62 @@ pub mod pallet {
 	#[pallet::getter(fn blacklist)]
 	pub type BlackList<T: Config> = StorageValue<_, Vec<T::ValidatorId>, ValueQuery>;
 
+	/// Authority diff applied at the next session.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_diff)]
+	pub type PendingDiff<T: Config> = StorageValue<_, AuthorityDiff<T::AccountId>, OptionQuery>;
+
+	/// Offenders removed from the authority membership at the next session.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_removals)]
+	pub type PendingRemovals<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	/// Open council proposals to add an authority member.
+	#[pallet::storage]
+	#[pallet::getter(fn proposals)]
+	pub type Proposals<T: Config> =
+		StorageMap<_, Blake2_128Concat, H256, AuthorityProposalOf<T>, OptionQuery>;
+
+	/// Number of open proposals per proposer.
+	#[pallet::storage]
+	#[pallet::getter(fn open_proposals)]
+	pub type OpenProposals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Open proposals by the block at which they are pruned.
+	#[pallet::storage]
+	pub type ProposalExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		H256,
+		(),
+		OptionQuery,
+	>;
+
+	/// Authority sets of the most recent sessions, by session index.
+	#[pallet::storage]
+	#[pallet::getter(fn authority_set_at)]
+	pub type AuthoritySetHistory<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, Vec<T::ValidatorId>, OptionQuery>;
+
+	/// Oldest session in `AuthoritySetHistory`.
+	#[pallet::storage]
+	pub type EarliestAuthoritySet<T> = StorageValue<_, SessionIndex, OptionQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultAuthorityHistoryDepth<T: Config>() -> u32 {
+		T::DefaultAuthorityHistoryDepth::get()
+	}
+
+	/// Number of sessions whose authority set is kept in
+	/// `AuthoritySetHistory`.
+	#[pallet::storage]
+	#[pallet::getter(fn max_authority_history_depth)]
+	pub type MaxAuthorityHistoryDepth<T: Config> =
+		StorageValue<_, u32, ValueQuery, DefaultAuthorityHistoryDepth<T>>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -143,6 +330,47 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::prune_expired_proposals(now)
+		}
+
+		fn offchain_worker(_now: BlockNumberFor<T>) {
+			if let Err(e) = Self::submit_authority_diff() {
+				log::debug!(
+					target: "runtime::authorities",
+					"Skipping authority diff submission: {}",
+					e
+				);
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::enqueue_authority_diff { signed_diff } = call else {
+				return InvalidTransaction::Call.into()
+			};
+
+			if PendingDiff::<T>::exists() {
+				return InvalidTransaction::Stale.into()
+			}
+			Self::ensure_valid_authority_diff(signed_diff)
+				.map_err(|_| InvalidTransaction::BadProof)?;
+
+			ValidTransaction::with_tag_prefix("CordAuthorityDiff")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(signed_diff.session)
+				.longevity(64_u64)
+				.propagate(true)
+				.build()
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Add new authorities to the set.
@@ -152,28 +380,7 @@ pub mod pallet {
 		pub fn nominate(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResult {
 			T::AuthorityMembershipOrigin::ensure_origin(origin)?;
 
-			if !pallet_network_membership::Pallet::<T>::is_member(&candidate) {
-				return Err(Error::<T>::NetworkMembershipNotFound.into());
-			}
-
-			let member = T::ValidatorIdOf::convert(candidate.clone())
-				.ok_or(pallet_session::Error::<T>::NoAssociatedValidatorId)?;
-			if !pallet_session::Pallet::<T>::is_registered(&member) {
-				return Err(Error::<T>::SessionKeysNotAdded.into());
-			}
-
-			if Self::is_blacklisted(&member) {
-				return Err(Error::<T>::MemberBlackListed.into());
-			}
-
-			if Self::is_incoming(&member) {
-				return Err(Error::<T>::MemberAlreadyIncoming.into());
-			}
-			if Self::is_outgoing(&member) {
-				return Err(Error::<T>::MemberAlreadyOutgoing.into());
-			}
-
-			Self::add_authority_member(&member)?;
+			Self::add_candidate(&candidate)?;
 
 			Self::deposit_event(Event::MemberAdded(candidate));
 			Ok(())
@@ -262,10 +469,250 @@ pub mod pallet {
 			Self::deposit_event(Event::MemberGoOnline(who));
 			Ok(())
 		}
+
+		/// Enqueue an authority diff signed by the authority members.
+		/// The diff is applied at the next session.
+		#[pallet::call_index(5)]
+		#[pallet::weight({100_000})]
+		pub fn enqueue_authority_diff(
+			origin: OriginFor<T>,
+			signed_diff: SignedAuthorityDiffOf<T>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			ensure!(!PendingDiff::<T>::exists(), Error::<T>::AuthorityDiffAlreadyPending);
+			Self::ensure_valid_authority_diff(&signed_diff)?;
+
+			PendingDiff::<T>::put(&signed_diff.diff);
+
+			Self::deposit_event(Event::AuthorityDiffEnqueued(signed_diff.diff));
+			Ok(())
+		}
+
+		/// Keep an offender in the authority membership by cancelling its
+		/// queued removal.
+		#[pallet::call_index(6)]
+		#[pallet::weight({100_000})]
+		pub fn cancel_pending_removal(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AuthorityMembershipOrigin::ensure_origin(origin)?;
+
+			PendingRemovals::<T>::try_mutate(|pending| {
+				let pos =
+					pending.iter().position(|p| *p == who).ok_or(Error::<T>::RemovalNotPending)?;
+				pending.remove(pos);
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::PendingRemovalCancelled(who));
+			Ok(())
+		}
+
+		/// Propose adding an authority member. The candidate is added as
+		/// soon as `threshold` council members approved the proposal.
+		#[pallet::call_index(7)]
+		#[pallet::weight({100_000})]
+		pub fn propose_add_authority(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			threshold: u32,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(T::CouncilMembers::contains(&proposer), Error::<T>::NotCouncilMember);
+			ensure!(
+				threshold > 0 &&
+					threshold as usize <= T::CouncilMembers::count() &&
+					threshold <= T::MaxProposalApprovals::get(),
+				Error::<T>::InvalidProposalThreshold
+			);
+			ensure!(
+				OpenProposals::<T>::get(&proposer) < T::MaxProposalsPerProposer::get(),
+				Error::<T>::TooManyProposals
+			);
+
+			let created_at = frame_system::Pallet::<T>::block_number();
+			let proposal_id = Self::proposal_id(&proposer, &who, created_at);
+			ensure!(!Proposals::<T>::contains_key(proposal_id), Error::<T>::ProposalAlreadyExists);
+
+			OpenProposals::<T>::mutate(&proposer, |count| count.saturating_inc());
+			ProposalExpiries::<T>::insert(Self::proposal_expiry(created_at), proposal_id, ());
+			Proposals::<T>::insert(
+				proposal_id,
+				AuthorityProposal {
+					proposer,
+					candidate: who.clone(),
+					threshold,
+					approvals: BoundedVec::new(),
+					created_at,
+				},
+			);
+
+			Self::deposit_event(Event::AuthorityProposed(proposal_id, who));
+			Ok(())
+		}
+
+		/// Approve a proposal to add an authority member. The approval
+		/// reaching the threshold adds the candidate; it fails, leaving the
+		/// proposal open, while the candidate can not be added.
+		#[pallet::call_index(8)]
+		#[pallet::weight({100_000})]
+		pub fn approve_proposal(origin: OriginFor<T>, proposal_id: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::CouncilMembers::contains(&who), Error::<T>::NotCouncilMember);
+
+			let mut proposal =
+				Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(!proposal.approvals.contains(&who), Error::<T>::ProposalAlreadyApproved);
+			proposal
+				.approvals
+				.try_push(who.clone())
+				.map_err(|_| Error::<T>::TooManyApprovals)?;
+			Self::deposit_event(Event::ProposalApproved(proposal_id, who));
+
+			// approvals of members who left the council no longer count
+			let approvals =
+				proposal.approvals.iter().filter(|a| T::CouncilMembers::contains(a)).count();
+			if (approvals as u32) < proposal.threshold {
+				Proposals::<T>::insert(proposal_id, proposal);
+				return Ok(())
+			}
+
+			Self::remove_proposal(proposal_id, &proposal);
+			Self::add_candidate(&proposal.candidate)?;
+
+			Self::deposit_event(Event::ProposalExecuted(proposal_id));
+			Self::deposit_event(Event::MemberAdded(proposal.candidate));
+			Ok(())
+		}
+
+		/// Withdraw a proposal. Only the proposer can cancel it, and only
+		/// within `ProposalTtl` blocks of its creation.
+		#[pallet::call_index(9)]
+		#[pallet::weight({100_000})]
+		pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.proposer == who, Error::<T>::NotProposer);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <=
+					proposal.created_at.saturating_add(T::ProposalTtl::get()),
+				Error::<T>::ProposalTtlElapsed
+			);
+
+			Self::remove_proposal(proposal_id, &proposal);
+
+			Self::deposit_event(Event::ProposalCancelled(proposal_id));
+			Ok(())
+		}
+
+		/// Set the number of sessions whose authority set is kept. Sets
+		/// beyond the new depth are dropped at the next session.
+		#[pallet::call_index(10)]
+		#[pallet::weight({100_000})]
+		pub fn set_max_authority_history_depth(origin: OriginFor<T>, depth: u32) -> DispatchResult {
+			T::AuthorityMembershipOrigin::ensure_origin(origin)?;
+			ensure!(depth > 0, Error::<T>::ZeroAuthorityHistoryDepth);
+
+			MaxAuthorityHistoryDepth::<T>::put(depth);
+
+			Self::deposit_event(Event::AuthorityHistoryDepthSet(depth));
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The id of a proposal created by `proposer` for `candidate` at block
+	/// `created_at`.
+	pub fn proposal_id(
+		proposer: &T::AccountId,
+		candidate: &T::AccountId,
+		created_at: BlockNumberFor<T>,
+	) -> H256 {
+		use sp_runtime::traits::{BlakeTwo256, Hash};
+		BlakeTwo256::hash_of(&(b"cord::authority-proposal", proposer, candidate, created_at))
+	}
+
+	/// The block at which a proposal created at `created_at` is pruned.
+	fn proposal_expiry(created_at: BlockNumberFor<T>) -> BlockNumberFor<T> {
+		created_at.saturating_add(T::ProposalTtl::get()).saturating_add(1u32.into())
+	}
+
+	/// Remove a proposal along with its expiry and its count against the
+	/// proposer.
+	fn remove_proposal(proposal_id: H256, proposal: &AuthorityProposalOf<T>) {
+		Proposals::<T>::remove(proposal_id);
+		ProposalExpiries::<T>::remove(Self::proposal_expiry(proposal.created_at), proposal_id);
+		Self::release_proposal_slot(&proposal.proposer);
+	}
+
+	fn release_proposal_slot(proposer: &T::AccountId) {
+		OpenProposals::<T>::mutate_exists(proposer, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+		});
+	}
+
+	/// Prune the proposals whose `ProposalTtl` ended before block `now`.
+	///
+	/// The open proposals are bounded by the council size times
+	/// `MaxProposalsPerProposer`, and so are the proposals expiring at once.
+	fn prune_expired_proposals(now: BlockNumberFor<T>) -> Weight {
+		let mut pruned = 0u64;
+		for (proposal_id, ()) in ProposalExpiries::<T>::drain_prefix(now) {
+			if let Some(proposal) = Proposals::<T>::take(proposal_id) {
+				Self::release_proposal_slot(&proposal.proposer);
+				Self::deposit_event(Event::ProposalExpired(proposal_id));
+			}
+			pruned.saturating_inc();
+		}
+		T::DbWeight::get()
+			.reads_writes(pruned.saturating_mul(3).saturating_add(1), pruned.saturating_mul(3))
+	}
+
+	/// Record `authorities` as the set of session `index`, and drop the sets
+	/// older than `MaxAuthorityHistoryDepth` sessions.
+	fn note_authority_set(index: SessionIndex, authorities: Vec<T::ValidatorId>) {
+		AuthoritySetHistory::<T>::insert(index, authorities);
+
+		let depth = MaxAuthorityHistoryDepth::<T>::get();
+		let mut earliest = EarliestAuthoritySet::<T>::get().unwrap_or(index);
+		while earliest.saturating_add(depth) <= index {
+			AuthoritySetHistory::<T>::remove(earliest);
+			earliest.saturating_inc();
+		}
+		EarliestAuthoritySet::<T>::put(earliest);
+	}
+
+	/// Add a network member with registered session keys to the authority
+	/// membership.
+	fn add_candidate(candidate: &T::AccountId) -> DispatchResult {
+		use frame_support::traits::ValidatorRegistration;
+		use network_membership::traits::IsMember;
+
+		if !pallet_network_membership::Pallet::<T>::is_member(candidate) {
+			return Err(Error::<T>::NetworkMembershipNotFound.into());
+		}
+
+		let member = T::ValidatorIdOf::convert(candidate.clone())
+			.ok_or(pallet_session::Error::<T>::NoAssociatedValidatorId)?;
+		if !pallet_session::Pallet::<T>::is_registered(&member) {
+			return Err(Error::<T>::SessionKeysNotAdded.into());
+		}
+
+		if Self::is_blacklisted(&member) {
+			return Err(Error::<T>::MemberBlackListed.into());
+		}
+
+		if Self::is_incoming(&member) {
+			return Err(Error::<T>::MemberAlreadyIncoming.into());
+		}
+		if Self::is_outgoing(&member) {
+			return Err(Error::<T>::MemberAlreadyOutgoing.into());
+		}
+
+		Self::add_authority_member(&member)
+	}
+
 	fn add_authority_member(authority: &T::ValidatorId) -> DispatchResult {
 		ensure!(!<Members<T>>::get().contains(authority), Error::<T>::MemberAlreadyExists);
 		Members::<T>::mutate(|v| v.push(authority.clone()));
@@ -296,6 +743,15 @@ impl<T: Config> Pallet<T> {
 		BlackList::<T>::mutate(|v| v.push(authority.clone()));
 		OutgoingAuthorities::<T>::mutate(|v| v.push(authority));
 	}
+	// Queues an offender for removal from the authority membership.
+	fn mark_for_pending_removal(who: T::AccountId) {
+		PendingRemovals::<T>::mutate(|pending| {
+			if !pending.contains(&who) {
+				pending.push(who.clone());
+				Self::deposit_event(Event::MemberQueuedForRemoval(who));
+			}
+		});
+	}
 	/// check if authority is incoming
 	fn is_incoming(authority: &T::ValidatorId) -> bool {
 		IncomingAuthorities::<T>::get().contains(authority)
@@ -308,16 +764,137 @@ impl<T: Config> Pallet<T> {
 	fn is_blacklisted(authority: &T::ValidatorId) -> bool {
 		BlackList::<T>::get().contains(authority)
 	}
+
+	/// Check that an authority diff is signed, for the current session, by at
+	/// least `AuthorityDiffThreshold` of the authority members.
+	pub fn ensure_valid_authority_diff(
+		signed_diff: &SignedAuthorityDiffOf<T>,
+	) -> Result<(), Error<T>> {
+		ensure!(!signed_diff.diff.is_empty(), Error::<T>::EmptyAuthorityDiff);
+		ensure!(
+			signed_diff.session == Session::<T>::current_index(),
+			Error::<T>::StaleAuthorityDiff
+		);
+
+		let members = Members::<T>::get();
+		let payload = signed_diff.signing_payload();
+		let signers = signed_diff
+			.signatures
+			.iter()
+			.filter(|(signer, signature)| {
+				T::ValidatorIdOf::convert(signer.clone())
+					.map_or(false, |member| members.contains(&member)) &&
+					signature.verify(&payload[..], signer)
+			})
+			.map(|(signer, _)| signer.clone())
+			.collect::<BTreeSet<_>>();
+
+		let required = T::AuthorityDiffThreshold::get().mul_ceil(members.len() as u32).max(1);
+		ensure!(
+			signers.len() as u32 >= required,
+			Error::<T>::InsufficientAuthorityDiffSignatures
+		);
+		Ok(())
+	}
+
+	/// Submit the signed authority diff found in the offchain local storage
+	/// as an unsigned transaction.
+	fn submit_authority_diff() -> Result<(), &'static str> {
+		let mut storage = StorageValueRef::persistent(AUTHORITY_DIFF_STORAGE_KEY);
+		let signed_diff = match storage.get::<SignedAuthorityDiffOf<T>>() {
+			Ok(Some(signed_diff)) => signed_diff,
+			Ok(None) => return Ok(()),
+			Err(_) => {
+				storage.clear();
+				return Err("undecodable authority diff")
+			},
+		};
+
+		if PendingDiff::<T>::exists() {
+			return Err("an authority diff is already pending")
+		}
+		if Self::ensure_valid_authority_diff(&signed_diff).is_err() {
+			storage.clear();
+			return Err("invalid authority diff")
+		}
+
+		let call = Call::enqueue_authority_diff { signed_diff };
+		SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+			.map_err(|_| "failed to submit the authority diff")?;
+
+		storage.clear();
+		Ok(())
+	}
+
+	/// Apply a pending authority diff. Entries that can not be applied, e.g.
+	/// blacklisted or unknown members, are skipped.
+	fn apply_authority_diff(diff: AuthorityDiff<T::AccountId>) {
+		use frame_support::traits::ValidatorRegistration;
+
+		for candidate in &diff.remove {
+			let result = T::ValidatorIdOf::convert(candidate.clone())
+				.ok_or(Error::<T>::MemberNotFound.into())
+				.and_then(|member| Self::remove_authority_member(&member));
+			if let Err(e) = result {
+				log::warn!(target: "runtime::authorities", "Skipping authority removal: {:?}", e);
+			}
+		}
+
+		for candidate in &diff.add {
+			let result = T::ValidatorIdOf::convert(candidate.clone())
+				.ok_or(Error::<T>::SessionKeysNotAdded.into())
+				.and_then(|member| {
+					ensure!(
+						pallet_session::Pallet::<T>::is_registered(&member),
+						Error::<T>::SessionKeysNotAdded
+					);
+					ensure!(!Self::is_blacklisted(&member), Error::<T>::MemberBlackListed);
+					Self::add_authority_member(&member)
+				});
+			if let Err(e) = result {
+				log::warn!(target: "runtime::authorities", "Skipping authority addition: {:?}", e);
+			}
+		}
+
+		Self::deposit_event(Event::AuthorityDiffApplied(diff));
+	}
+
+	/// Remove the offenders queued for removal from the authority membership.
+	fn apply_pending_removals() {
+		for candidate in PendingRemovals::<T>::take() {
+			// never remove the last authority member
+			if Members::<T>::decode_len().unwrap_or_default() <= 1 {
+				break
+			}
+			let result = T::ValidatorIdOf::convert(candidate.clone())
+				.ok_or(Error::<T>::MemberNotFound.into())
+				.and_then(|member| Self::remove_authority_member(&member));
+			match result {
+				Ok(()) => Self::deposit_event(Event::MemberRemovedForOffence(candidate)),
+				Err(e) => log::warn!(
+					target: "runtime::authorities",
+					"Skipping removal of an offender: {:?}",
+					e
+				),
+			}
+		}
+	}
 }
 
 impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Pallet<T> {
-	fn new_session(_new_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
+	fn new_session(new_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
+		if let Some(diff) = PendingDiff::<T>::take() {
+			Self::apply_authority_diff(diff);
+		}
+		Self::apply_pending_removals();
+
 		let members_to_add = IncomingAuthorities::<T>::take();
 		let members_to_del = OutgoingAuthorities::<T>::take();
 
 		if members_to_add.is_empty() {
 			if members_to_del.is_empty() {
 				// when no change to the set of autorities, return None
+				Self::note_authority_set(new_index, Session::<T>::validators());
 				return None;
 			} else {
 				Self::deposit_event(Event::OutgoingAuthorities(members_to_del.clone()));
@@ -340,17 +917,35 @@ impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Pallet<T> {
 			}
 		});
 
+		Self::note_authority_set(new_index, authorities.clone());
 		Some(authorities)
 	}
 
 	/// Same as `new_session`, but it this should only be called at genesis.
-	fn new_session_genesis(_new_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
-		Some(Members::<T>::get().into_iter().collect())
+	fn new_session_genesis(new_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
+		let authorities = Members::<T>::get();
+		Self::note_authority_set(new_index, authorities.clone());
+		Some(authorities)
 	}
 
-	fn end_session(_: SessionIndex) {}
+	fn end_session(_: SessionIndex) {
+		let validators = Session::<T>::validators();
+		T::ValidatorScore::note_session_end(&validators);
+
+		for validator in validators.iter().filter(|v| T::ValidatorScore::is_below_threshold(v)) {
+			// never remove the last authority member
+			if Members::<T>::decode_len().unwrap_or_default() <= 1 {
+				break
+			}
+			if Self::remove_authority_member(validator).is_ok() {
+				Self::deposit_event(Event::MemberRemovedForLowScore(validator.clone()));
+			}
+		}
+	}
 
-	fn start_session(_start_index: SessionIndex) {}
+	fn start_session(_start_index: SessionIndex) {
+		T::ValidatorScore::note_session_start(&Session::<T>::validators());
+	}
 }
 
 // see substrate FullIdentification
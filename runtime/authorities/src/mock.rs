@@ -20,7 +20,7 @@ use super::*;
 use crate::{self as cord_authority_membership};
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
-	traits::{ConstU32, ConstU64},
+	traits::{ConstU32, ConstU64, SortedMembers},
 };
 use sp_state_machine::BasicExternalities;
 use std::collections::BTreeMap;
@@ -31,9 +31,9 @@ use pallet_session::ShouldEndSession;
 use sp_core::{crypto::key_types::DUMMY, H256};
 use sp_runtime::{
 	impl_opaque_keys,
-	testing::UintAuthorityId,
+	testing::{TestSignature, TestXt, UintAuthorityId},
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup, OpaqueKeys},
-	BuildStorage, KeyTypeId,
+	BuildStorage, KeyTypeId, Perbill,
 };
 use sp_staking::offence::OffenceDetails;
 
@@ -128,6 +128,7 @@ impl pallet_session::Config for Test {
 parameter_types! {
 	pub const MembershipPeriod: BlockNumberFor<Test> = 5;
 	pub const MaxMembersPerBlock: u32 = 5;
+	pub const MaxExtrinsicsPerBlock: u32 = 5;
 }
 
 impl pallet_network_membership::Config for Test {
@@ -135,6 +136,7 @@ impl pallet_network_membership::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MembershipPeriod = MembershipPeriod;
 	type MaxMembersPerBlock = MaxMembersPerBlock;
+	type MaxExtrinsicsPerBlock = MaxExtrinsicsPerBlock;
 	type WeightInfo = ();
 }
 
@@ -149,9 +151,35 @@ impl pallet_session::historical::Config for Test {
 	type FullIdentificationOf = FullIdentificationOfImpl;
 }
 
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+parameter_types! {
+	pub const AuthorityDiffThreshold: Perbill = Perbill::from_percent(50);
+	pub const AutoRemoveThreshold: Perbill = Perbill::from_percent(50);
+}
+
 impl cord_authority_membership::Config for Test {
 	type AuthorityMembershipOrigin = EnsureRoot<u64>;
 	type RuntimeEvent = RuntimeEvent;
+	type AuthoritySignature = TestSignature;
+	type AuthoritySigner = UintAuthorityId;
+	type AuthorityDiffThreshold = AuthorityDiffThreshold;
+	type UnsignedPriority = ConstU64<{ u64::MAX }>;
+	type ValidatorScore = MockValidatorScore;
+	type AutoRemoveThreshold = AutoRemoveThreshold;
+	type CouncilMembers = TestCouncil;
+	type ProposalTtl = ConstU64<10>;
+	type MaxProposalsPerProposer = ConstU32<2>;
+	type MaxProposalApprovals = ConstU32<3>;
+	type DefaultAuthorityHistoryDepth = ConstU32<3>;
 	// type WeightInfo = ();
 }
 
@@ -161,7 +189,34 @@ parameter_types! {
 	pub static Authorities: Vec<UintAuthorityId> =
 		vec![UintAuthorityId(1), UintAuthorityId(2), UintAuthorityId(3)];
 	pub static ValidatorAccounts: BTreeMap<u64, u64> = BTreeMap::new();
+	pub static LowScoreValidators: Vec<u64> = vec![];
+	pub static ScoredValidators: Vec<u64> = vec![];
+	pub static CouncilMembers: Vec<u64> = vec![20, 21, 22];
+}
+
+pub struct TestCouncil;
+impl SortedMembers<u64> for TestCouncil {
+	fn sorted_members() -> Vec<u64> {
+		let mut members = CouncilMembers::get();
+		members.sort();
+		members
+	}
 
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &u64) {
+		CouncilMembers::mutate(|members| members.push(*who));
+	}
+}
+
+pub struct MockValidatorScore;
+impl AuthorityScore<u64> for MockValidatorScore {
+	fn note_session_end(validators: &[u64]) {
+		ScoredValidators::set(validators.to_vec());
+	}
+
+	fn is_below_threshold(validator: &u64) -> bool {
+		LowScoreValidators::get().contains(validator)
+	}
 }
 
 pub fn authorities() -> Vec<UintAuthorityId> {
@@ -194,6 +249,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		.unwrap();
 	let v = NextValidators::get().iter().map(|&i| (i, i)).collect();
 	ValidatorAccounts::mutate(|m| *m = v);
+	CouncilMembers::set(vec![20, 21, 22]);
 	sp_io::TestExternalities::new(t)
 }
 
@@ -217,5 +273,17 @@ pub(crate) fn on_offence(
 	>],
 	slash_strategy: SlashStrategy,
 ) {
-	AuthorityMembership::on_offence(offenders, slash_strategy, 0);
+	on_offence_with_slash(offenders, Perbill::zero(), slash_strategy);
+}
+
+pub(crate) fn on_offence_with_slash(
+	offenders: &[OffenceDetails<
+		AccountId,
+		pallet_session::historical::IdentificationTuple<Test>,
+	>],
+	slash_fraction: Perbill,
+	slash_strategy: SlashStrategy,
+) {
+	let slash_fraction = vec![slash_fraction; offenders.len()];
+	AuthorityMembership::on_offence(offenders, &slash_fraction, slash_strategy, 0);
 }
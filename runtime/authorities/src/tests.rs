@@ -22,7 +22,11 @@ use crate::mock::{new_test_ext, Session, *};
 
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::RawOrigin;
-use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::BadOrigin,
+	Perbill,
+};
 use sp_staking::offence::OffenceDetails;
 
 const EMPTY: Vec<u64> = Vec::new();
@@ -370,3 +374,503 @@ fn test_offence_black_list_prevent_from_going_online() {
 		);
 	});
 }
+
+/// offenders slashed above the threshold leave the membership at the next
+/// session
+#[test]
+fn test_offence_above_threshold_queues_removal() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		on_offence_with_slash(
+			&[OffenceDetails { offender: (3, ()), reporters: vec![] }],
+			Perbill::from_percent(60),
+			pallet_offences::SlashStrategy::Disconnect,
+		);
+		System::assert_last_event(Event::MemberQueuedForRemoval(3).into());
+		on_offence_with_slash(
+			&[OffenceDetails { offender: (2, ()), reporters: vec![] }],
+			Perbill::from_percent(10),
+			pallet_offences::SlashStrategy::Disconnect,
+		);
+
+		// Verify state
+		assert_eq!(AuthorityMembership::pending_removals(), vec![3]);
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64, 3u64]);
+
+		// Member 3 leaves the membership at the session boundary
+		run_to_block(5);
+		assert_eq!(Session::current_index(), 1);
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64]);
+		assert_eq!(AuthorityMembership::pending_removals(), EMPTY);
+		System::assert_has_event(Event::MemberRemovedForOffence(3).into());
+
+		// Member 3 should not be allowed to go online
+		run_to_block(10);
+		assert_ok!(Session::set_keys(RuntimeOrigin::signed(3), UintAuthorityId(3).into(), vec![]));
+		assert_err!(
+			AuthorityMembership::go_online(RuntimeOrigin::signed(3)),
+			Error::<Test>::MemberNotFound
+		);
+	});
+}
+
+/// governance can keep an offender in the membership
+#[test]
+fn test_cancel_pending_removal() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		on_offence_with_slash(
+			&[OffenceDetails { offender: (3, ()), reporters: vec![] }],
+			Perbill::from_percent(60),
+			pallet_offences::SlashStrategy::Disconnect,
+		);
+		assert_eq!(AuthorityMembership::pending_removals(), vec![3]);
+
+		assert_noop!(
+			AuthorityMembership::cancel_pending_removal(RuntimeOrigin::signed(3), 3),
+			BadOrigin
+		);
+		assert_ok!(AuthorityMembership::cancel_pending_removal(RawOrigin::Root.into(), 3));
+		assert_eq!(AuthorityMembership::pending_removals(), EMPTY);
+		System::assert_last_event(Event::PendingRemovalCancelled(3).into());
+
+		assert_noop!(
+			AuthorityMembership::cancel_pending_removal(RawOrigin::Root.into(), 3),
+			Error::<Test>::RemovalNotPending
+		);
+
+		// Member 3 is disconnected but keeps its membership
+		run_to_block(5);
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64, 3u64]);
+	});
+}
+
+fn signed_diff(
+	session: SessionIndex,
+	add: Vec<u64>,
+	remove: Vec<u64>,
+	signers: &[u64],
+) -> SignedAuthorityDiff<u64, TestSignature> {
+	let mut signed_diff =
+		SignedAuthorityDiff { session, diff: AuthorityDiff { add, remove }, signatures: vec![] };
+	let payload = signed_diff.signing_payload();
+	signed_diff.signatures =
+		signers.iter().map(|signer| (*signer, TestSignature(*signer, payload.clone()))).collect();
+	signed_diff
+}
+
+#[test]
+fn test_enqueue_authority_diff() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let signed = signed_diff(0, vec![], vec![3], &[1, 2]);
+		assert_ok!(AuthorityMembership::enqueue_authority_diff(RuntimeOrigin::none(), signed));
+		assert_eq!(
+			AuthorityMembership::pending_diff(),
+			Some(AuthorityDiff { add: vec![], remove: vec![3] })
+		);
+		assert_noop!(
+			AuthorityMembership::enqueue_authority_diff(
+				RuntimeOrigin::none(),
+				signed_diff(0, vec![], vec![2], &[1, 2])
+			),
+			Error::<Test>::AuthorityDiffAlreadyPending
+		);
+
+		// The diff is applied when the next session is planned.
+		run_to_block(5);
+		assert_eq!(AuthorityMembership::pending_diff(), None);
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64]);
+
+		run_to_block(10);
+		assert_eq!(Session::current_index(), 2);
+		assert_eq!(Session::validators(), vec![1, 2]);
+	});
+}
+
+#[test]
+fn test_enqueue_authority_diff_requires_threshold_signatures() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		// A single member is below the threshold.
+		assert_noop!(
+			AuthorityMembership::enqueue_authority_diff(
+				RuntimeOrigin::none(),
+				signed_diff(0, vec![], vec![3], &[1])
+			),
+			Error::<Test>::InsufficientAuthorityDiffSignatures
+		);
+		// Non members and repeated signers are not counted.
+		assert_noop!(
+			AuthorityMembership::enqueue_authority_diff(
+				RuntimeOrigin::none(),
+				signed_diff(0, vec![], vec![3], &[1, 1, 12])
+			),
+			Error::<Test>::InsufficientAuthorityDiffSignatures
+		);
+		// Signatures over another payload are not counted.
+		let mut signed = signed_diff(0, vec![], vec![3], &[1, 2]);
+		signed.diff.remove = vec![2];
+		assert_noop!(
+			AuthorityMembership::enqueue_authority_diff(RuntimeOrigin::none(), signed),
+			Error::<Test>::InsufficientAuthorityDiffSignatures
+		);
+		assert_noop!(
+			AuthorityMembership::enqueue_authority_diff(
+				RuntimeOrigin::signed(1),
+				signed_diff(0, vec![], vec![3], &[1, 2])
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn test_enqueue_authority_diff_rejects_stale_or_empty_diff() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_noop!(
+			AuthorityMembership::enqueue_authority_diff(
+				RuntimeOrigin::none(),
+				signed_diff(1, vec![], vec![3], &[1, 2])
+			),
+			Error::<Test>::StaleAuthorityDiff
+		);
+		assert_noop!(
+			AuthorityMembership::enqueue_authority_diff(
+				RuntimeOrigin::none(),
+				signed_diff(0, vec![], vec![], &[1, 2])
+			),
+			Error::<Test>::EmptyAuthorityDiff
+		);
+	});
+}
+
+#[test]
+fn test_validate_unsigned_authority_diff() {
+	use frame_support::pallet_prelude::{InvalidTransaction, TransactionSource, ValidateUnsigned};
+
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let call = crate::Call::enqueue_authority_diff {
+			signed_diff: signed_diff(0, vec![], vec![3], &[1, 2]),
+		};
+		assert_ok!(AuthorityMembership::validate_unsigned(TransactionSource::Local, &call));
+
+		let invalid = crate::Call::enqueue_authority_diff {
+			signed_diff: signed_diff(0, vec![], vec![3], &[1]),
+		};
+		assert_err!(
+			AuthorityMembership::validate_unsigned(TransactionSource::Local, &invalid),
+			InvalidTransaction::BadProof
+		);
+
+		assert_ok!(AuthorityMembership::enqueue_authority_diff(
+			RuntimeOrigin::none(),
+			signed_diff(0, vec![], vec![3], &[1, 2])
+		));
+		assert_err!(
+			AuthorityMembership::validate_unsigned(TransactionSource::Local, &call),
+			InvalidTransaction::Stale
+		);
+	});
+}
+
+/// members scoring below the threshold are removed at the end of the session
+#[test]
+fn test_low_score_member_removed_at_session_end() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		LowScoreValidators::set(vec![3]);
+
+		run_to_block(5);
+		assert_eq!(Session::current_index(), 1);
+		assert_eq!(ScoredValidators::get(), vec![1, 2, 3]);
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64]);
+		System::assert_has_event(Event::MemberRemovedForLowScore(3).into());
+
+		// Member 3 should be out at session 2
+		run_to_block(10);
+		assert_eq!(Session::current_index(), 2);
+		assert_eq!(Session::validators(), vec![1, 2]);
+	});
+}
+
+/// low scores never empty the authority membership
+#[test]
+fn test_low_score_keeps_last_member() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		LowScoreValidators::set(vec![1, 2, 3]);
+
+		run_to_block(5);
+		assert_eq!(AuthorityMembership::member(), vec![3u64]);
+		assert_eq!(AuthorityMembership::outgoing(), EMPTY);
+
+		run_to_block(10);
+		assert_eq!(Session::validators(), vec![3]);
+	});
+}
+
+/// registers account 10 as a network member with session keys
+fn prepare_candidate() {
+	assert_ok!(NetworkMembership::nominate(RuntimeOrigin::root(), 10, true));
+	assert_ok!(Session::set_keys(RuntimeOrigin::signed(10), UintAuthorityId(10).into(), vec![]));
+}
+
+#[test]
+fn test_propose_add_authority() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_noop!(
+			AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(1), 10, 2),
+			Error::<Test>::NotCouncilMember
+		);
+		assert_noop!(
+			AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 0),
+			Error::<Test>::InvalidProposalThreshold
+		);
+		assert_noop!(
+			AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 4),
+			Error::<Test>::InvalidProposalThreshold
+		);
+
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 2));
+		let proposal_id = AuthorityMembership::proposal_id(&20, &10, 1);
+		assert_eq!(
+			AuthorityMembership::proposals(proposal_id),
+			Some(AuthorityProposal {
+				proposer: 20,
+				candidate: 10,
+				threshold: 2,
+				approvals: BoundedVec::new(),
+				created_at: 1,
+			})
+		);
+		System::assert_last_event(Event::AuthorityProposed(proposal_id, 10).into());
+
+		assert_noop!(
+			AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 3),
+			Error::<Test>::ProposalAlreadyExists
+		);
+	});
+}
+
+/// the candidate is added once the threshold of council approvals is met
+#[test]
+fn test_approve_proposal_adds_authority_at_threshold() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		prepare_candidate();
+
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 2));
+		let proposal_id = AuthorityMembership::proposal_id(&20, &10, 1);
+
+		assert_noop!(
+			AuthorityMembership::approve_proposal(RuntimeOrigin::signed(1), proposal_id),
+			Error::<Test>::NotCouncilMember
+		);
+		assert_noop!(
+			AuthorityMembership::approve_proposal(RuntimeOrigin::signed(21), H256::zero()),
+			Error::<Test>::ProposalNotFound
+		);
+
+		assert_ok!(AuthorityMembership::approve_proposal(RuntimeOrigin::signed(21), proposal_id));
+		assert_eq!(AuthorityMembership::proposals(proposal_id).unwrap().approvals, vec![21]);
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64, 3u64]);
+		assert_noop!(
+			AuthorityMembership::approve_proposal(RuntimeOrigin::signed(21), proposal_id),
+			Error::<Test>::ProposalAlreadyApproved
+		);
+
+		assert_ok!(AuthorityMembership::approve_proposal(RuntimeOrigin::signed(22), proposal_id));
+		assert_eq!(AuthorityMembership::proposals(proposal_id), None);
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64, 3u64, 10u64]);
+		assert_eq!(AuthorityMembership::incoming(), vec![10]);
+		System::assert_has_event(Event::ProposalExecuted(proposal_id).into());
+		System::assert_last_event(Event::MemberAdded(10).into());
+	});
+}
+
+/// approvals of members who left the council are not counted
+#[test]
+fn test_approve_proposal_ignores_former_council_members() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		prepare_candidate();
+
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 2));
+		let proposal_id = AuthorityMembership::proposal_id(&20, &10, 1);
+		assert_ok!(AuthorityMembership::approve_proposal(RuntimeOrigin::signed(20), proposal_id));
+
+		CouncilMembers::set(vec![21, 22]);
+		assert_ok!(AuthorityMembership::approve_proposal(RuntimeOrigin::signed(21), proposal_id));
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64, 3u64]);
+
+		assert_ok!(AuthorityMembership::approve_proposal(RuntimeOrigin::signed(22), proposal_id));
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64, 3u64, 10u64]);
+	});
+}
+
+/// the final approval fails while the candidate can not be added
+#[test]
+fn test_approve_proposal_keeps_proposal_if_candidate_is_not_ready() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(NetworkMembership::nominate(RuntimeOrigin::root(), 10, true));
+
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 1));
+		let proposal_id = AuthorityMembership::proposal_id(&20, &10, 1);
+		assert_noop!(
+			AuthorityMembership::approve_proposal(RuntimeOrigin::signed(21), proposal_id),
+			Error::<Test>::SessionKeysNotAdded
+		);
+
+		assert_ok!(Session::set_keys(
+			RuntimeOrigin::signed(10),
+			UintAuthorityId(10).into(),
+			vec![]
+		));
+		assert_ok!(AuthorityMembership::approve_proposal(RuntimeOrigin::signed(21), proposal_id));
+		assert_eq!(AuthorityMembership::member(), vec![1u64, 2u64, 3u64, 10u64]);
+	});
+}
+
+#[test]
+fn test_cancel_proposal() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 2));
+		let proposal_id = AuthorityMembership::proposal_id(&20, &10, 1);
+		assert_noop!(
+			AuthorityMembership::cancel_proposal(RuntimeOrigin::signed(21), proposal_id),
+			Error::<Test>::NotProposer
+		);
+
+		assert_ok!(AuthorityMembership::cancel_proposal(RuntimeOrigin::signed(20), proposal_id));
+		assert_eq!(AuthorityMembership::proposals(proposal_id), None);
+		System::assert_last_event(Event::ProposalCancelled(proposal_id).into());
+
+		// the proposer can cancel up to `ProposalTtl` blocks after creation
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 2));
+		let proposal_id = AuthorityMembership::proposal_id(&20, &10, 1);
+		System::set_block_number(12);
+		assert_noop!(
+			AuthorityMembership::cancel_proposal(RuntimeOrigin::signed(20), proposal_id),
+			Error::<Test>::ProposalTtlElapsed
+		);
+		System::set_block_number(11);
+		assert_ok!(AuthorityMembership::cancel_proposal(RuntimeOrigin::signed(20), proposal_id));
+	});
+}
+
+/// a council member can only hold `MaxProposalsPerProposer` open proposals
+#[test]
+fn test_open_proposals_are_limited_per_proposer() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 2));
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 11, 2));
+		assert_eq!(AuthorityMembership::open_proposals(20), 2);
+		assert_noop!(
+			AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 12, 2),
+			Error::<Test>::TooManyProposals
+		);
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(21), 12, 2));
+
+		let proposal_id = AuthorityMembership::proposal_id(&20, &10, 1);
+		assert_ok!(AuthorityMembership::cancel_proposal(RuntimeOrigin::signed(20), proposal_id));
+		assert_eq!(AuthorityMembership::open_proposals(20), 1);
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 12, 2));
+	});
+}
+
+/// proposals are pruned once `ProposalTtl` blocks have passed
+#[test]
+fn test_proposals_are_pruned_after_ttl() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(AuthorityMembership::propose_add_authority(RuntimeOrigin::signed(20), 10, 2));
+		let proposal_id = AuthorityMembership::proposal_id(&20, &10, 1);
+
+		run_to_block(11);
+		assert!(AuthorityMembership::proposals(proposal_id).is_some());
+
+		run_to_block(12);
+		assert_eq!(AuthorityMembership::proposals(proposal_id), None);
+		assert_eq!(AuthorityMembership::open_proposals(20), 0);
+		assert_eq!(ProposalExpiries::<Test>::iter().count(), 0);
+		System::assert_has_event(Event::ProposalExpired(proposal_id).into());
+		assert_noop!(
+			AuthorityMembership::approve_proposal(RuntimeOrigin::signed(21), proposal_id),
+			Error::<Test>::ProposalNotFound
+		);
+	});
+}
+
+#[test]
+fn authority_sets_are_recorded_at_each_session() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_eq!(AuthorityMembership::authority_set_at(0), Some(vec![1, 2, 3]));
+		assert_eq!(AuthorityMembership::authority_set_at(1), Some(vec![1, 2, 3]));
+		assert_eq!(AuthorityMembership::authority_set_at(2), None);
+
+		assert_ok!(NetworkMembership::nominate(RuntimeOrigin::root(), 10, true));
+		assert_ok!(Session::set_keys(
+			RuntimeOrigin::signed(10),
+			UintAuthorityId(10).into(),
+			vec![]
+		));
+		assert_ok!(AuthorityMembership::nominate(RuntimeOrigin::root(), 10));
+
+		// the set planned at block 5 for session 2 includes member 10
+		run_to_block(5);
+		assert_eq!(AuthorityMembership::authority_set_at(2), Some(vec![1, 2, 3, 10]));
+
+		// an unchanged set is recorded as well
+		run_to_block(10);
+		assert_eq!(AuthorityMembership::authority_set_at(3), Some(vec![1, 2, 3, 10]));
+	});
+}
+
+#[test]
+fn authority_set_history_is_capped_at_the_max_depth() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(AuthorityMembership::max_authority_history_depth(), 3);
+
+		// sessions 0 to 4 are recorded, the last three are kept
+		run_to_block(15);
+		assert_eq!(Session::current_index(), 3);
+		assert_eq!(AuthorityMembership::authority_set_at(1), None);
+		assert!(AuthorityMembership::authority_set_at(2).is_some());
+		assert!(AuthorityMembership::authority_set_at(4).is_some());
+
+		assert_noop!(
+			AuthorityMembership::set_max_authority_history_depth(RuntimeOrigin::signed(1), 1),
+			BadOrigin
+		);
+		assert_noop!(
+			AuthorityMembership::set_max_authority_history_depth(RuntimeOrigin::root(), 0),
+			Error::<Test>::ZeroAuthorityHistoryDepth
+		);
+		assert_ok!(AuthorityMembership::set_max_authority_history_depth(RuntimeOrigin::root(), 1));
+		assert_eq!(AuthorityMembership::max_authority_history_depth(), 1);
+
+		// the sets beyond the new depth are dropped at the next session
+		run_to_block(20);
+		assert_eq!(AuthorityMembership::authority_set_at(2), None);
+		assert_eq!(AuthorityMembership::authority_set_at(4), None);
+		assert!(AuthorityMembership::authority_set_at(5).is_some());
+	});
+}
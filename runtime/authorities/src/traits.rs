@@ -0,0 +1,35 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+/// Reliability scoring of the session validators.
+pub trait AuthorityScore<ValidatorId> {
+	/// Note the validators of the starting session.
+	fn note_session_start(_validators: &[ValidatorId]) {}
+	/// Update the scores of the validators of the ending session.
+	fn note_session_end(validators: &[ValidatorId]);
+	/// Whether the score of `validator` fell below the removal threshold.
+	fn is_below_threshold(validator: &ValidatorId) -> bool;
+}
+
+impl<ValidatorId> AuthorityScore<ValidatorId> for () {
+	fn note_session_end(_validators: &[ValidatorId]) {}
+
+	fn is_below_threshold(_validator: &ValidatorId) -> bool {
+		false
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::Get, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_staking::SessionIndex;
+use sp_std::vec::Vec;
+
+/// A change to the authority set, applied at the next session.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AuthorityDiff<AccountId> {
+	/// Accounts joining the authority set.
+	pub add: Vec<AccountId>,
+	/// Accounts leaving the authority set.
+	pub remove: Vec<AccountId>,
+}
+
+impl<AccountId> AuthorityDiff<AccountId> {
+	/// Whether the diff changes nothing.
+	pub fn is_empty(&self) -> bool {
+		self.add.is_empty() && self.remove.is_empty()
+	}
+}
+
+/// An authority diff signed by the current authority members.
+///
+/// Every signature is over the SCALE encoded `(session, diff)` tuple, so a
+/// payload is only valid during the session it was signed for.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SignedAuthorityDiff<AccountId, Signature> {
+	/// The session the diff was signed for.
+	pub session: SessionIndex,
+	/// The requested change.
+	pub diff: AuthorityDiff<AccountId>,
+	/// Signatures of authority members over the payload.
+	pub signatures: Vec<(AccountId, Signature)>,
+}
+
+impl<AccountId: Encode, Signature> SignedAuthorityDiff<AccountId, Signature> {
+	/// The payload authority members sign.
+	pub fn signing_payload(&self) -> Vec<u8> {
+		(self.session, &self.diff).encode()
+	}
+}
+
+/// A council proposal to add an authority member, executed once
+/// `threshold` council members approved it.
+#[derive(
+	CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxApprovals))]
+pub struct AuthorityProposal<AccountId, BlockNumber, MaxApprovals: Get<u32>>
+where
+	AccountId: Clone + Eq + core::fmt::Debug,
+	BlockNumber: Clone + Eq + core::fmt::Debug,
+{
+	/// The council member who created the proposal.
+	pub proposer: AccountId,
+	/// The account to add to the authority membership.
+	pub candidate: AccountId,
+	/// Number of council approvals required.
+	pub threshold: u32,
+	/// Council members who approved the proposal.
+	pub approvals: BoundedVec<AccountId, MaxApprovals>,
+	/// The block the proposal was created at.
+	pub created_at: BlockNumber,
+}
@@ -33,17 +33,17 @@ use frame_support::{
 	construct_runtime, derive_impl,
 	dispatch::DispatchClass,
 	genesis_builder_helper::{build_config, create_default_config},
-	parameter_types,
+	parameter_types, PalletId,
 	traits::{
-		fungible::HoldConsideration, ConstU32, Contains, EitherOfDiverse, KeyOwnerProofSystem,
-		LinearStoragePrice, PrivilegeCmp,
+		fungible::HoldConsideration, ConstU32, Contains, Currency, EitherOfDiverse,
+		KeyOwnerProofSystem, LinearStoragePrice, OnUnbalanced, OriginTrait, PrivilegeCmp,
 	},
 	weights::{
 		constants::{
 			BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_REF_TIME_PER_MILLIS,
 			WEIGHT_REF_TIME_PER_SECOND,
 		},
-		Weight,
+		ConstantMultiplier, Weight,
 	},
 };
 use frame_system::{
@@ -59,17 +59,19 @@ use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use pallet_identity::legacy::IdentityInfo;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use pallet_session::historical as pallet_session_historical;
+use pallet_transaction_payment::{CurrencyAdapter, FeeDetails};
+use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, Extrinsic as ExtrinsicT, NumberFor,
-		OpaqueKeys, SaturatedConversion, Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Extrinsic as ExtrinsicT,
+		NumberFor, OpaqueKeys, SaturatedConversion, Verify,
 	},
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, Perbill,
+	ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill,
 };
 use sp_staking::SessionIndex;
 use sp_std::{cmp::Ordering, prelude::*};
@@ -95,6 +97,9 @@ use cord_runtime_constants::{currency::*, time::*};
 
 // Weights used in the runtime.
 mod weights;
+pub use weights::{calibrate_block_execution_weight, REFERENCE_HARDWARE_SCORE};
+// Storage migrations run on runtime upgrade.
+pub mod migrations;
 // CORD Pallets
 pub use authority_membership;
 pub use pallet_network_membership;
@@ -134,6 +139,16 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	state_version: 1,
 };
 
+/// CORD specific features this runtime was built with.
+pub const CORD_FEATURES: &[&str] = &[
+	#[cfg(feature = "fast-runtime")]
+	"fast-runtime",
+	#[cfg(feature = "runtime-benchmarks")]
+	"runtime-benchmarks",
+	#[cfg(feature = "try-runtime")]
+	"try-runtime",
+];
+
 /// The BABE epoch configuration at genesis.
 pub const BABE_GENESIS_EPOCH_CONFIG: sp_consensus_babe::BabeEpochConfiguration =
 	sp_consensus_babe::BabeEpochConfiguration {
@@ -147,11 +162,11 @@ pub fn native_version() -> NativeVersion {
 	NativeVersion { runtime_version: VERSION, can_author_with: Default::default() }
 }
 
-/// We currently allow all calls.
+/// We allow all calls, except those of the pallets paused by `Pause`.
 pub struct BaseFilter;
 impl Contains<RuntimeCall> for BaseFilter {
-	fn contains(_c: &RuntimeCall) -> bool {
-		true
+	fn contains(c: &RuntimeCall) -> bool {
+		pallet_cord_pause::EnsureNotPaused::<Runtime>::contains(c)
 	}
 }
 
@@ -165,6 +180,11 @@ type EnsureRootOrCommitteeApproval = EitherOfDiverse<
 	pallet_collective::EnsureProportionMoreThan<AccountId, TechnicalCollective, 3, 5>,
 >;
 
+type TwoThirdsTechnicalCommittee = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+>;
+
 /// We assume that an on-initialize consumes 10% of the weight on average, hence
 /// a single extrinsic will not be allowed to consume more than
 /// `AvailableBlockRatio - 10%`.
@@ -366,9 +386,17 @@ impl pallet_timestamp::Config for Runtime {
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxBlockTimeWindow: u32 = HOURS;
+}
+
+impl pallet_cord_block_time::Config for Runtime {
+	type MaxWindow = MaxBlockTimeWindow;
+}
+
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
-	type EventHandler = ImOnline;
+	type EventHandler = (ImOnline, ReliabilityScore, EpochRewards);
 }
 
 impl_opaque_keys! {
@@ -393,7 +421,7 @@ impl pallet_session::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ValidatorId = AccountId;
 	type ValidatorIdOf = ValidatorIdOf;
-	type ShouldEndSession = Babe;
+	type ShouldEndSession = SessionManager;
 	type NextSessionRotation = Babe;
 	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, AuthorityMembership>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
@@ -401,6 +429,13 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
+impl pallet_cord_session_manager::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type SessionEndTrigger = Babe;
+	type MaxPrunedPerBlock = ConstU32<256>;
+	type WeightInfo = weights::pallet_cord_session_manager::WeightInfo<Runtime>;
+}
+
 pub struct FullIdentificationOf;
 impl sp_runtime::traits::Convert<AccountId, Option<()>> for FullIdentificationOf {
 	fn convert(_: AccountId) -> Option<()> {
@@ -473,6 +508,7 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type WeightInfo = weights::pallet_collective::WeightInfo<Runtime>;
 	type NetworkMembershipOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
+	type Currency = Balances;
 }
 
 impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
@@ -500,6 +536,7 @@ impl pallet_collective::Config<TechnicalCollective> for Runtime {
 	type WeightInfo = weights::pallet_collective::WeightInfo<Runtime>;
 	type NetworkMembershipOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
+	type Currency = Balances;
 }
 
 impl pallet_membership::Config<pallet_membership::Instance2> for Runtime {
@@ -518,7 +555,7 @@ impl pallet_membership::Config<pallet_membership::Instance2> for Runtime {
 impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
-	type OnOffenceHandler = AuthorityMembership;
+	type OnOffenceHandler = (AuthorityMembership, NetworkScoreSlashing);
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -584,6 +621,7 @@ where
 			.saturating_sub(1);
 		let extra: SignedExtra = (
 			pallet_network_membership::CheckNetworkMembership::<Runtime>::new(),
+			pallet_network_membership::RateLimiter::<Runtime>::new(),
 			frame_system::CheckNonZeroSender::<Runtime>::new(),
 			frame_system::CheckSpecVersion::<Runtime>::new(),
 			frame_system::CheckTxVersion::<Runtime>::new(),
@@ -594,6 +632,8 @@ where
 			)),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_cord_fee_proxy::FeeProxyExtension::<Runtime>::from(0),
+			pallet_cord_fee_rebate::RebateFee::<Runtime>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -648,9 +688,68 @@ impl pallet_multisig::Config for Runtime {
 parameter_types! {
 	pub const MaxProposalLength: u16 = 5;
 }
+parameter_types! {
+	pub const AuthorityDiffThreshold: Perbill = Perbill::from_percent(67);
+	pub const AuthorityDiffUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+	pub const AutoRemoveThreshold: Perbill = Perbill::from_percent(10);
+	pub const AuthorityProposalTtl: BlockNumber = 3 * DAYS;
+	pub const MaxAuthorityProposalsPerProposer: u32 = 4;
+	pub const DefaultAuthorityHistoryDepth: u32 = DAYS / EPOCH_DURATION_IN_BLOCKS;
+}
+
 impl authority_membership::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AuthorityMembershipOrigin = MoreThanHalfCouncil;
+	type AuthoritySignature = Signature;
+	type AuthoritySigner = <Signature as Verify>::Signer;
+	type AuthorityDiffThreshold = AuthorityDiffThreshold;
+	type UnsignedPriority = AuthorityDiffUnsignedPriority;
+	type ValidatorScore = AuthorityReliability;
+	type AutoRemoveThreshold = AutoRemoveThreshold;
+	type CouncilMembers = CouncilMembership;
+	type ProposalTtl = AuthorityProposalTtl;
+	type MaxProposalsPerProposer = MaxAuthorityProposalsPerProposer;
+	type MaxProposalApprovals = MaxMembers;
+	type DefaultAuthorityHistoryDepth = DefaultAuthorityHistoryDepth;
+}
+
+/// Applies the actions approved by a multisig authority to the
+/// `AuthorityMembership`.
+pub struct MultisigAuthorityManager;
+impl pallet_cord_multisig_authority::AuthorityManager<AccountId> for MultisigAuthorityManager {
+	fn add_authority(who: &AccountId) -> sp_runtime::DispatchResult {
+		AuthorityMembership::nominate(RuntimeOrigin::root(), who.clone())
+	}
+
+	fn remove_authority(who: &AccountId) -> sp_runtime::DispatchResult {
+		AuthorityMembership::remove(RuntimeOrigin::root(), who.clone())
+	}
+}
+
+parameter_types! {
+	pub const MaxAuthoritySigners: u32 = 100;
+}
+
+impl pallet_cord_multisig_authority::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityManager = MultisigAuthorityManager;
+	type MaxSigners = MaxAuthoritySigners;
+	type WeightInfo = weights::pallet_cord_multisig_authority::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub ClaimsPrefix: &'static [u8] = b"Pay WAYs to the CORD account:";
+	pub ClaimExpiry: BlockNumber = prod_or_fast!(180 * DAYS, 10 * MINUTES, "CORD_CLAIM_EXPIRY");
+	pub const MaxClaimsPerBlock: u32 = 1_000;
+}
+
+impl pallet_cord_claims::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Prefix = ClaimsPrefix;
+	type ClaimExpiry = ClaimExpiry;
+	type MaxClaimsPerBlock = MaxClaimsPerBlock;
+	type WeightInfo = weights::pallet_cord_claims::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -671,6 +770,7 @@ impl pallet_node_authorization::Config for Runtime {
 parameter_types! {
 	pub const MembershipPeriod: BlockNumber = YEAR;
 	pub const MaxMembersPerBlock: u32 = 1_000;
+	pub const MaxExtrinsicsPerBlock: u32 = 100;
 	pub const MaxEventsHistory: u32 = u32::MAX;
 }
 
@@ -679,6 +779,7 @@ impl pallet_network_membership::Config for Runtime {
 	type NetworkMembershipOrigin = MoreThanHalfCouncil;
 	type MembershipPeriod = MembershipPeriod;
 	type MaxMembersPerBlock = MaxMembersPerBlock;
+	type MaxExtrinsicsPerBlock = MaxExtrinsicsPerBlock;
 	type WeightInfo = weights::pallet_network_membership::WeightInfo<Runtime>;
 }
 
@@ -740,6 +841,45 @@ parameter_types! {
 	pub const MaxPrefixLength: u32 = 54;
 }
 
+parameter_types! {
+	pub const MaxDidDocumentLength: u32 = 5_120;
+}
+
+impl pallet_cord_did_document::Config for Runtime {
+	type EnsureOrigin = pallet_did::EnsureDidOrigin<DidIdentifier, AccountId>;
+	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
+	type RuntimeEvent = RuntimeEvent;
+	type DidIdentifier = DidIdentifier;
+	type MaxDocumentLength = MaxDidDocumentLength;
+	type WeightInfo = weights::pallet_cord_did_document::WeightInfo<Runtime>;
+}
+
+/// Digests of the DID documents anchored by `DidDocument`, which the
+/// offchain resolver checks fetched documents against. Deactivated documents
+/// are not resolved.
+pub struct DidDocumentDigests;
+impl pallet_cord_offchain_resolver::DocumentDigest<DidIdentifier, Hash> for DidDocumentDigests {
+	fn document_digest(identifier: &DidIdentifier) -> Option<Hash> {
+		DidDocument::resolve_did(identifier)
+			.filter(|document| document.status == pallet_cord_did_document::DidStatus::Active)
+			.map(|document| document.digest)
+	}
+}
+
+parameter_types! {
+	pub const MaxDocumentFetchesPerBlock: u32 = 8;
+}
+
+impl pallet_cord_offchain_resolver::Config for Runtime {
+	type EnsureOrigin = pallet_did::EnsureDidOrigin<DidIdentifier, AccountId>;
+	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
+	type RuntimeEvent = RuntimeEvent;
+	type DidIdentifier = DidIdentifier;
+	type DocumentDigest = DidDocumentDigests;
+	type MaxFetchesPerBlock = MaxDocumentFetchesPerBlock;
+	type WeightInfo = weights::pallet_cord_offchain_resolver::WeightInfo<Runtime>;
+}
+
 impl pallet_did_name::Config for Runtime {
 	type BanOrigin = EnsureRoot<AccountId>;
 	type EnsureOrigin = pallet_did::EnsureDidOrigin<DidIdentifier, AccountId>;
@@ -763,6 +903,8 @@ impl pallet_schema::Config for Runtime {
 	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type IdentifierNamespace = ChainNamespace;
+	type StorageDeposit = StorageDeposit;
 	type WeightInfo = weights::pallet_schema::WeightInfo<Runtime>;
 }
 
@@ -792,6 +934,8 @@ impl pallet_statement::Config for Runtime {
 	type WeightInfo = weights::pallet_statement::WeightInfo<Runtime>;
 	type MaxDigestsPerBatch = MaxDigestsPerBatch;
 	type MaxRemoveEntries = MaxRemoveEntries;
+	type IdentifierNamespace = ChainNamespace;
+	type StorageDeposit = StorageDeposit;
 }
 
 impl pallet_remark::Config for Runtime {
@@ -829,6 +973,732 @@ impl pallet_asset::Config for Runtime {
 	type WeightInfo = weights::pallet_asset::WeightInfo<Runtime>;
 }
 
+/// Heartbeat status of the session validators, as seen by `ImOnline`.
+pub struct ImOnlineHeartbeats;
+impl pallet_cord_reliability_score::HeartbeatStatus<AccountId> for ImOnlineHeartbeats {
+	fn is_online(who: &AccountId) -> bool {
+		Session::validators()
+			.iter()
+			.position(|validator| validator == who)
+			.map_or(false, |index| ImOnline::is_online(index as u32))
+	}
+}
+
+impl pallet_cord_session_payout::HeartbeatCoverage<AccountId> for ImOnlineHeartbeats {
+	fn coverage(who: &AccountId) -> Perbill {
+		if <Self as pallet_cord_reliability_score::HeartbeatStatus<AccountId>>::is_online(who) {
+			Perbill::one()
+		} else {
+			Perbill::zero()
+		}
+	}
+}
+
+/// Feeds the session validators to `ReliabilityScore` and reports the
+/// unreliable ones to `AuthorityMembership`. Sessions end with the BABE
+/// epoch, which also pays the `EpochRewards`. The validators are bonded by
+/// `SessionPayout` for every session.
+pub struct AuthorityReliability;
+impl authority_membership::AuthorityScore<AccountId> for AuthorityReliability {
+	fn note_session_start(validators: &[AccountId]) {
+		SessionPayout::on_session_start(validators);
+	}
+
+	fn note_session_end(validators: &[AccountId]) {
+		ReliabilityScore::note_session_end(validators);
+		EpochRewards::on_epoch_end();
+		SessionPayout::on_session_end();
+	}
+
+	fn is_below_threshold(validator: &AccountId) -> bool {
+		ReliabilityScore::is_below_threshold(validator)
+	}
+}
+
+parameter_types! {
+	pub const ReliabilitySmoothingFactor: Perbill = Perbill::from_percent(20);
+	pub MinReliabilityScore: FixedU128 = FixedU128::from_rational(1, 4);
+}
+
+impl pallet_cord_reliability_score::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type HeartbeatStatus = ImOnlineHeartbeats;
+	type SmoothingFactor = ReliabilitySmoothingFactor;
+	type MinReliabilityScore = MinReliabilityScore;
+	type ResetOrigin = MoreThanHalfCouncil;
+	type WeightInfo = weights::pallet_cord_reliability_score::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const SlashPerOffender: Perbill = Perbill::from_percent(10);
+}
+
+impl pallet_cord_network_score_slashing::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type SlashPerOffender = SlashPerOffender;
+	type MinScore = MinReliabilityScore;
+	type WeightInfo = weights::pallet_cord_network_score_slashing::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_epoch_rewards::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PoolOrigin = MoreThanHalfCouncil;
+	type WeightInfo = weights::pallet_cord_epoch_rewards::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+}
+
+impl pallet_cord_treasury_reward::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type TreasuryAccount = TreasuryAccount;
+	type InflationOrigin = EnsureRoot<AccountId>;
+	type BlocksPerYear = ConstU32<YEAR>;
+	type WeightInfo = weights::pallet_cord_treasury_reward::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const TreasuryProposalBond: Permill = Permill::from_percent(5);
+	pub const TreasuryProposalBondMinimum: Balance = 100 * WAY;
+	pub const TreasurySpendPeriod: BlockNumber = 7 * DAYS;
+	// The pot also collects fees and inflation, none of it is burnt.
+	pub const TreasuryBurn: Permill = Permill::zero();
+	pub const MaxTreasuryApprovals: u32 = 100;
+	pub const MaxTreasurySpend: Balance = Balance::MAX;
+	pub const TreasurySpendPayoutPeriod: BlockNumber = 30 * DAYS;
+}
+
+/// Creates the benchmark arguments of the native-only treasury spends.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TreasuryBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_treasury::ArgumentsFactory<(), AccountId> for TreasuryBenchmarkHelper {
+	fn create_asset_kind(_seed: u32) {}
+
+	fn create_beneficiary(seed: [u8; 32]) -> AccountId {
+		AccountId::from(seed)
+	}
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = MoreThanHalfCouncil;
+	type RejectOrigin = MoreThanHalfCouncil;
+	type RuntimeEvent = RuntimeEvent;
+	type OnSlash = ();
+	type ProposalBond = TreasuryProposalBond;
+	type ProposalBondMinimum = TreasuryProposalBondMinimum;
+	type ProposalBondMaximum = ();
+	type SpendPeriod = TreasurySpendPeriod;
+	type Burn = TreasuryBurn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = MaxTreasuryApprovals;
+	type SpendOrigin =
+		frame_system::EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, MaxTreasurySpend>;
+	type AssetKind = ();
+	type Beneficiary = AccountId;
+	type BeneficiaryLookup = sp_runtime::traits::IdentityLookup<AccountId>;
+	type Paymaster = frame_support::traits::tokens::pay::PayFromAccount<Balances, TreasuryAccount>;
+	type BalanceConverter = frame_support::traits::tokens::UnityAssetBalanceConversion;
+	type PayoutPeriod = TreasurySpendPayoutPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TreasuryBenchmarkHelper;
+}
+
+parameter_types! {
+	pub const SessionBond: Balance = 100 * WAY;
+	pub const MinHeartbeatRatio: Perbill = Perbill::from_percent(80);
+	pub const MissedSessionSlash: Perbill = Perbill::from_percent(1);
+}
+
+impl pallet_cord_session_payout::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Heartbeats = ImOnlineHeartbeats;
+	type TreasuryAccount = TreasuryAccount;
+	type SessionBond = SessionBond;
+	type MinHeartbeatRatio = MinHeartbeatRatio;
+	type MissedSessionSlash = MissedSessionSlash;
+	type WeightInfo = weights::pallet_cord_session_payout::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxIssuers: u32 = 1_000;
+}
+
+impl pallet_cord_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type IssuerOrigin = MoreThanHalfCouncil;
+	type MaxIssuers = MaxIssuers;
+	type WeightInfo = weights::pallet_cord_registry::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxDelegationDepth: u32 = 10;
+	pub const MaxDelegationChildren: u32 = 25;
+}
+
+impl pallet_cord_delegation::Config for Runtime {
+	type EnsureOrigin = pallet_did::EnsureDidOrigin<DidIdentifier, AccountId>;
+	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
+	type RuntimeEvent = RuntimeEvent;
+	type DelegateIdentifier = DidIdentifier;
+	type MaxDepth = MaxDelegationDepth;
+	type MaxChildren = MaxDelegationChildren;
+	type WeightInfo = weights::pallet_cord_delegation::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_credentials::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_cord_credentials::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_chain_namespace::Config for Runtime {
+	type WeightInfo = weights::pallet_cord_chain_namespace::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const WeightHistoryDepth: u32 = 256;
+}
+
+impl pallet_cord_transaction_weights::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type HistogramOrigin = MoreThanHalfCouncil;
+	type HistoryDepth = WeightHistoryDepth;
+	type WeightInfo = weights::pallet_cord_transaction_weights::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_fee_adjustment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AdjustmentOrigin = MoreThanHalfCouncil;
+	type WeightInfo = weights::pallet_cord_fee_adjustment::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const BaseFee: Balance = MILLIUNITS;
+	// A full block costs about one WAY at the default fee adjustment.
+	pub FeeCoefficient: FixedU128 = FixedU128::from_rational(1, 1_000_000_000_000);
+	pub const TransactionByteFee: Balance = NANOUNITS;
+	pub const OperationalFeeMultiplier: u8 = 5;
+}
+
+/// Converts weight to fee quadratically, `BaseFee + FeeCoefficient *
+/// ref_time^2`, so that heavy extrinsics become disproportionately
+/// expensive. The council scales the result through the fee multiplier set
+/// by `FeeAdjustment`.
+pub struct CordWeightToFee;
+impl frame_support::weights::WeightToFee for CordWeightToFee {
+	type Balance = Balance;
+
+	fn weight_to_fee(weight: &Weight) -> Balance {
+		let ref_time = Balance::from(weight.ref_time());
+		// `u64::MAX^2` still fits into a `u128`.
+		BaseFee::get().saturating_add(FeeCoefficient::get().saturating_mul_int(ref_time * ref_time))
+	}
+}
+
+type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
+
+/// Credits transaction fees and tips to the treasury account.
+pub struct DealWithFees;
+impl OnUnbalanced<NegativeImbalance> for DealWithFees {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+		Balances::resolve_creating(&TreasuryAccount::get(), amount);
+	}
+}
+
+impl pallet_transaction_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
+	type OperationalFeeMultiplier = OperationalFeeMultiplier;
+	type WeightToFee = CordWeightToFee;
+	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+	type FeeMultiplierUpdate = pallet_cord_fee_adjustment::AdjustedFeeMultiplier<Runtime>;
+}
+
+/// Every signer pays its own fee, except for the `FeeProxy::sponsor_call`
+/// extrinsics that a sponsor pays for.
+pub struct SignerPaysFee;
+impl pallet_cord_fee_rebate::FeePayer<AccountId, RuntimeCall> for SignerPaysFee {
+	fn pays_own_fee(_who: &AccountId, call: &RuntimeCall) -> bool {
+		!matches!(call, RuntimeCall::FeeProxy(pallet_cord_fee_proxy::Call::sponsor_call { .. }))
+	}
+}
+
+impl pallet_cord_fee_rebate::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RebateAccount = TreasuryAccount;
+	type Authors = NetworkMembership;
+	type FeePayer = SignerPaysFee;
+	type RebateOrigin = MoreThanHalfCouncil;
+	type WeightInfo = weights::pallet_cord_fee_rebate::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = FeeRebateBenchmarkHelper;
+}
+
+impl pallet_cord_fee_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type WeightInfo = weights::pallet_cord_fee_proxy::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = FeeProxyBenchmarkHelper;
+}
+
+/// Funds a sponsor for the fee proxy benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct FeeProxyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_cord_fee_proxy::BenchmarkHelper<AccountId> for FeeProxyBenchmarkHelper {
+	fn fund_sponsor(sponsor: &AccountId) {
+		Balances::make_free_balance_be(sponsor, 1_000 * WAY);
+	}
+}
+
+/// Nominates the network member benchmarked as extrinsic author.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct FeeRebateBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_cord_fee_rebate::BenchmarkHelper<AccountId> for FeeRebateBenchmarkHelper {
+	fn author() -> AccountId {
+		let author: AccountId = frame_benchmarking::account("author", 0, 0);
+		NetworkMembership::nominate(RuntimeOrigin::root(), author.clone(), false)
+			.expect("the author is not a network member yet");
+		author
+	}
+}
+
+parameter_types! {
+	pub const CreditExistentialDeposit: Balance = UNITS;
+}
+
+impl pallet_cord_credit::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CreditBalance = Balance;
+	type ExistentialDeposit = CreditExistentialDeposit;
+	type CreditOrigin = MoreThanHalfCouncil;
+	type WeightInfo = weights::pallet_cord_credit::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const GovernanceQuorum: Percent = Percent::from_percent(60);
+}
+
+/// Calls carrying out council governance proposals.
+pub struct CordProposalCall;
+impl
+	sp_runtime::traits::Convert<
+		pallet_cord_governance::CordProposalOf<Runtime>,
+		Option<RuntimeCall>,
+	> for CordProposalCall
+{
+	fn convert(proposal: pallet_cord_governance::CordProposalOf<Runtime>) -> Option<RuntimeCall> {
+		use pallet_cord_governance::CordProposal;
+
+		let call = match proposal {
+			CordProposal::AddAuthority(candidate) =>
+				RuntimeCall::AuthorityMembership(authority_membership::Call::nominate {
+					candidate,
+				}),
+			CordProposal::RemoveAuthority(candidate) =>
+				RuntimeCall::AuthorityMembership(authority_membership::Call::remove { candidate }),
+			CordProposal::UpdateInflationRate(rate) =>
+				RuntimeCall::TreasuryReward(pallet_cord_treasury_reward::Call::set_inflation_rate {
+					rate,
+				}),
+			CordProposal::SetNetworkMembership(member, true) =>
+				RuntimeCall::NetworkMembership(pallet_network_membership::Call::nominate {
+					member,
+					expires: true,
+				}),
+			CordProposal::SetNetworkMembership(member, false) =>
+				RuntimeCall::NetworkMembership(pallet_network_membership::Call::revoke { member }),
+			CordProposal::ArchiveSchema(schema_id) =>
+				RuntimeCall::Schema(pallet_schema::Call::force_archive { schema_id }),
+		};
+		Some(call)
+	}
+}
+
+impl pallet_cord_governance::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type SchemaId = Ss58Identifier;
+	type ProposalCall = CordProposalCall;
+	type Preimages = Preimage;
+	type CouncilMembers = CouncilMembership;
+	type QuorumPercent = GovernanceQuorum;
+	type MaxApprovals = MaxMembers;
+	type ProposalObserver = GovernanceRegistry;
+	type WeightInfo = weights::pallet_cord_governance::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_governance_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_cord_governance_registry::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MinimumVotingBalance: Balance = WAY;
+	pub VoteLockingPeriod: BlockNumber =
+		prod_or_fast!(7 * DAYS, MINUTES, "CORD_VOTE_LOCKING_PERIOD");
+	pub const MaxVotes: u32 = 100;
+}
+
+impl pallet_cord_weighted_democracy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type StartOrigin = MoreThanHalfCouncil;
+	type MinimumVotingBalance = MinimumVotingBalance;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type MaxVotes = MaxVotes;
+	type WeightInfo = weights::pallet_cord_weighted_democracy::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_composite_proof::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_cord_composite_proof::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxVestingMilestones: u32 = 10;
+}
+
+impl pallet_cord_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MaxMilestones = MaxVestingMilestones;
+	type WeightInfo = weights::pallet_cord_vesting::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxRegistrarDelegationDepth: u8 = 5;
+}
+
+impl pallet_cord_registry_delegation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistrarOrigin = pallet_cord_registry::EnsureTrustedIssuer<Runtime>;
+	type MaxDelegationDepth = MaxRegistrarDelegationDepth;
+	type WeightInfo = weights::pallet_cord_registry_delegation::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const DefaultDepositPerByte: Balance = deposit(0, 1);
+}
+
+impl pallet_cord_storage_deposit::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type GovernanceOrigin = MoreThanHalfCouncil;
+	type DefaultDepositPerByte = DefaultDepositPerByte;
+	type WeightInfo = weights::pallet_cord_storage_deposit::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MinCouncilBond: Balance = 1_000 * WAY;
+	pub const CouncilSlashFraction: Perbill = Perbill::from_percent(10);
+	pub UnbondingDelay: BlockNumber =
+		prod_or_fast!(28 * DAYS, 10 * MINUTES, "CORD_COUNCIL_UNBONDING_DELAY");
+}
+
+impl pallet_cord_council_staking::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MinCouncilBond = MinCouncilBond;
+	type CouncilSlashFraction = CouncilSlashFraction;
+	type UnbondingDelay = UnbondingDelay;
+	type WeightInfo = weights::pallet_cord_council_staking::WeightInfo<Runtime>;
+}
+
+/// Authorizes the calls of a batch whose author is a network member. The
+/// author of a DID origin is the account that submitted the DID call.
+pub struct NetworkMemberAuthorship;
+impl pallet_cord_batch::ExtrinsicAuthorship<RuntimeOrigin, RuntimeCall>
+	for NetworkMemberAuthorship
+{
+	fn is_authorized(origin: &RuntimeOrigin, _call: &RuntimeCall) -> bool {
+		match origin.caller() {
+			OriginCaller::system(frame_system::RawOrigin::Root) => true,
+			OriginCaller::system(frame_system::RawOrigin::Signed(who)) =>
+				NetworkMembership::is_member_inner(who),
+			OriginCaller::Did(pallet_did::DidRawOrigin { submitter, .. }) =>
+				NetworkMembership::is_member_inner(submitter),
+			_ => false,
+		}
+	}
+}
+
+parameter_types! {
+	pub const MaxBatchSize: u32 = 32;
+}
+
+impl pallet_cord_batch::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type ExtrinsicAuthorship = NetworkMemberAuthorship;
+	type MaxBatchSize = MaxBatchSize;
+	type WeightInfo = weights::pallet_cord_batch::WeightInfo<Runtime>;
+}
+
+/// The pallets that block production, or resuming paused pallets, depends
+/// on.
+pub struct UnpausablePallets;
+impl Contains<pallet_cord_pause::PalletIdOf<Runtime>> for UnpausablePallets {
+	fn contains(pallet_id: &pallet_cord_pause::PalletIdOf<Runtime>) -> bool {
+		const UNPAUSABLE: [&[u8]; 8] = [
+			b"System",
+			b"Babe",
+			b"Timestamp",
+			b"Grandpa",
+			b"ImOnline",
+			b"TechnicalCommittee",
+			b"TechnicalMembership",
+			b"Sudo",
+		];
+		UNPAUSABLE.contains(&pallet_id.as_slice())
+	}
+}
+
+parameter_types! {
+	pub const MaxPaused: u32 = 32;
+	pub const MaxPalletNameLen: u32 = 32;
+}
+
+impl pallet_cord_pause::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PauseOrigin = TwoThirdsTechnicalCommittee;
+	type UnpausablePallets = UnpausablePallets;
+	type MaxPaused = MaxPaused;
+	type MaxPalletNameLen = MaxPalletNameLen;
+	type WeightInfo = weights::pallet_cord_pause::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub DefaultSnapshotInterval: BlockNumber =
+		prod_or_fast!(DAYS, 10 * MINUTES, "CORD_SNAPSHOT_INTERVAL");
+}
+
+impl pallet_cord_periodic_snapshot::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OperatorOrigin = frame_system::EnsureSignedBy<TechnicalMembership, AccountId>;
+	type GovernanceOrigin = MoreThanHalfCouncil;
+	type DefaultSnapshotInterval = DefaultSnapshotInterval;
+	type WeightInfo = weights::pallet_cord_periodic_snapshot::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxPermissions: u32 = 64;
+}
+
+impl pallet_cord_access_control::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PermissionOrigin = EnsureRoot<AccountId>;
+	type MaxPermissions = MaxPermissions;
+	type WeightInfo = weights::pallet_cord_access_control::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_attestation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_cord_attestation::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxAnchorMetaLen: u32 = 1024;
+}
+
+impl pallet_cord_anchor::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type StorageDeposit = StorageDeposit;
+	type MaxMetaLen = MaxAnchorMetaLen;
+	type WeightInfo = weights::pallet_cord_anchor::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxOracles: u32 = 32;
+	pub OracleReportInterval: BlockNumber =
+		prod_or_fast!(HOURS, 2 * MINUTES, "CORD_ORACLE_REPORT_INTERVAL");
+	pub const OracleUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
+impl pallet_cord_scoring_oracle::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OracleOrigin = MoreThanHalfCouncil;
+	type AuthorityId = pallet_cord_scoring_oracle::crypto::OracleAuthId;
+	type MaxOracles = MaxOracles;
+	type ReportInterval = OracleReportInterval;
+	type UnsignedPriority = OracleUnsignedPriority;
+	type WeightInfo = weights::pallet_cord_scoring_oracle::WeightInfo<Runtime>;
+}
+
+/// Resolves bounty credentials against `Credential`: a credential verifies a
+/// bounty while its statement is anchored and its digest, the one the
+/// credential was issued with, is not revoked.
+pub struct BountyCredentials;
+impl pallet_cord_bounties::CredentialInspect<Hash, pallet_schema::SchemaIdOf>
+	for BountyCredentials
+{
+	fn is_active(credential: &Hash, schema: &pallet_schema::SchemaIdOf) -> bool {
+		Credential::credentials(credential)
+			.filter(|entry| &entry.schema == schema)
+			.and_then(|entry| {
+				Statement::resolve_statement(&entry.statement)
+					.map(|resolved| !resolved.revoked && resolved.details.digest == entry.digest)
+			})
+			.unwrap_or(false)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn active_credential() -> (Hash, pallet_schema::SchemaIdOf) {
+		insert_active_credential(frame_benchmarking::account("issuer", 0, 0))
+	}
+}
+
+/// Anchor an active credential held by `holder`, for benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+fn insert_active_credential(holder: AccountId) -> (Hash, pallet_schema::SchemaIdOf) {
+	use identifier::{IdentifierCreator, IdentifierType};
+
+	let digest = Hash::repeat_byte(1);
+	let schema = Ss58Identifier::create_identifier(&[1], IdentifierType::Schema)
+		.expect("identifier is valid");
+	let space = Ss58Identifier::create_identifier(&[1], IdentifierType::Space)
+		.expect("identifier is valid");
+	let statement = Ss58Identifier::create_identifier(&[1], IdentifierType::Statement)
+		.expect("identifier is valid");
+	let issuer: DidIdentifier = frame_benchmarking::account("issuer", 0, 0);
+
+	pallet_statement::Statements::<Runtime>::insert(
+		&statement,
+		pallet_statement::StatementDetails { digest, space, schema: Some(schema.clone()) },
+	);
+	pallet_statement::Entries::<Runtime>::insert(&statement, digest, issuer.clone());
+	let credential = Hash::repeat_byte(2);
+	pallet_cord_credentials::Credentials::<Runtime>::insert(
+		credential,
+		pallet_cord_credentials::CredentialEntry {
+			schema: schema.clone(),
+			statement,
+			digest,
+			issuer: issuer.clone(),
+			holder,
+			created_at: System::block_number(),
+		},
+	);
+	(credential, schema)
+}
+
+/// Resolves NFT credentials against `Credential`, with the activity rule of
+/// [`BountyCredentials`]. Revoking a credential revokes the statement digest
+/// it was issued with.
+pub struct NftCredentials;
+impl pallet_cord_credential_nfts::CredentialInspect<Hash, AccountId> for NftCredentials {
+	fn active_holder(credential: &Hash) -> Option<AccountId> {
+		let entry = Credential::credentials(credential)?;
+		Statement::resolve_statement(&entry.statement)
+			.filter(|resolved| !resolved.revoked && resolved.details.digest == entry.digest)
+			.map(|_| entry.holder)
+	}
+
+	fn revoke(credential: &Hash, who: &AccountId) -> sp_runtime::DispatchResult {
+		let entry = Credential::credentials(credential)
+			.ok_or(pallet_cord_credential_nfts::Error::<Runtime>::CredentialNotActive)?;
+		pallet_statement::RevocationList::<Runtime>::insert(
+			&entry.statement,
+			entry.digest,
+			pallet_statement::StatementEntryStatusOf::<Runtime> {
+				creator: who.clone(),
+				revoked: true,
+			},
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn active_credential(holder: &AccountId) -> Hash {
+		insert_active_credential(holder.clone()).0
+	}
+}
+
+/// Resolves disputed credentials like [`NftCredentials`], except that the
+/// revocation of an upheld dispute is recorded on behalf of the issuer.
+pub struct DisputedCredentials;
+impl pallet_cord_dispute::DisputedCredentials<Hash, AccountId> for DisputedCredentials {
+	fn active_parties(credential: &Hash) -> Option<(AccountId, AccountId)> {
+		let entry = Credential::credentials(credential)?;
+		Statement::resolve_statement(&entry.statement)
+			.filter(|resolved| !resolved.revoked && resolved.details.digest == entry.digest)
+			.map(|_| (entry.holder, entry.issuer))
+	}
+
+	fn revoke(credential: &Hash) -> sp_runtime::DispatchResult {
+		let entry = Credential::credentials(credential)
+			.ok_or(pallet_cord_dispute::Error::<Runtime>::CredentialNotActive)?;
+		pallet_statement::RevocationList::<Runtime>::insert(
+			&entry.statement,
+			entry.digest,
+			pallet_statement::StatementEntryStatusOf::<Runtime> {
+				creator: entry.issuer,
+				revoked: true,
+			},
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn active_credential(holder: &AccountId) -> Hash {
+		insert_active_credential(holder.clone()).0
+	}
+}
+
+parameter_types! {
+	pub const MaxBountyDescLen: u32 = 1024;
+}
+
+impl pallet_cord_bounties::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type SchemaId = pallet_schema::SchemaIdOf;
+	type Credentials = BountyCredentials;
+	type MaxDescLen = MaxBountyDescLen;
+	type WeightInfo = weights::pallet_cord_bounties::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_credential_nfts::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Credentials = NftCredentials;
+	type WeightInfo = weights::pallet_cord_credential_nfts::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_dispute::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Credentials = DisputedCredentials;
+	type ResolveOrigin = MoreThanHalfCouncil;
+	type WeightInfo = weights::pallet_cord_dispute::WeightInfo<Runtime>;
+}
+
+impl pallet_cord_asset_vc::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_cord_asset_vc::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub SudoRotationDelay: BlockNumber =
+		prod_or_fast!(2 * DAYS, 2 * MINUTES, "CORD_SUDO_ROTATION_DELAY");
+}
+
+impl pallet_cord_sudo_key_rotation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RotationDelay = SudoRotationDelay;
+	type WeightInfo = weights::pallet_cord_sudo_key_rotation::WeightInfo<Runtime>;
+}
+
 construct_runtime! (
 	pub enum Runtime
 	{
@@ -853,6 +1723,7 @@ construct_runtime! (
 		NodeAuthorization: pallet_node_authorization = 18,
 		RuntimeUpgrade: pallet_runtime_upgrade = 19,
 		Utility: pallet_utility = 31,
+		TransactionPayment: pallet_transaction_payment = 32,
 		Historical: pallet_session_historical = 33,
 		Multisig: pallet_multisig = 35,
 		Remark: pallet_remark = 37,
@@ -866,6 +1737,47 @@ construct_runtime! (
 		DidName: pallet_did_name = 106,
 		NetworkScore: pallet_network_score = 108,
 		Asset: pallet_asset = 109,
+		ReliabilityScore: pallet_cord_reliability_score = 110,
+		TreasuryReward: pallet_cord_treasury_reward = 111,
+		DidDocument: pallet_cord_did_document = 112,
+		Registry: pallet_cord_registry = 113,
+		Delegation: pallet_cord_delegation = 114,
+		Credential: pallet_cord_credentials = 115,
+		ChainNamespace: pallet_cord_chain_namespace = 116,
+		TransactionWeights: pallet_cord_transaction_weights = 117,
+		Credit: pallet_cord_credit = 118,
+		Governance: pallet_cord_governance = 119,
+		Attestation: pallet_cord_attestation = 120,
+		AssetVc: pallet_cord_asset_vc = 121,
+		SudoKeyRotation: pallet_cord_sudo_key_rotation = 122,
+		NetworkScoreSlashing: pallet_cord_network_score_slashing = 123,
+		EpochRewards: pallet_cord_epoch_rewards = 124,
+		SessionManager: pallet_cord_session_manager = 125,
+		MultisigAuthority: pallet_cord_multisig_authority = 126,
+		Claims: pallet_cord_claims = 127,
+		Anchor: pallet_cord_anchor = 128,
+		ScoringOracle: pallet_cord_scoring_oracle = 129,
+		Bounties: pallet_cord_bounties = 130,
+		OffchainResolver: pallet_cord_offchain_resolver = 131,
+		CredentialNfts: pallet_cord_credential_nfts = 132,
+		SessionPayout: pallet_cord_session_payout = 133,
+		Dispute: pallet_cord_dispute = 134,
+		GovernanceRegistry: pallet_cord_governance_registry = 135,
+		WeightedDemocracy: pallet_cord_weighted_democracy = 136,
+		CompositeProof: pallet_cord_composite_proof = 137,
+		Vesting: pallet_cord_vesting = 138,
+		RegistryDelegation: pallet_cord_registry_delegation = 139,
+		StorageDeposit: pallet_cord_storage_deposit = 140,
+		CouncilStaking: pallet_cord_council_staking = 141,
+		Batch: pallet_cord_batch = 142,
+		Pause: pallet_cord_pause = 143,
+		PeriodicSnapshot: pallet_cord_periodic_snapshot = 144,
+		AccessControl: pallet_cord_access_control = 145,
+		FeeRebate: pallet_cord_fee_rebate = 146,
+		FeeProxy: pallet_cord_fee_proxy = 147,
+		BlockTime: pallet_cord_block_time = 148,
+		Treasury: pallet_treasury = 149,
+		FeeAdjustment: pallet_cord_fee_adjustment = 150,
 		Sudo: pallet_sudo = 255,
 	}
 );
@@ -905,6 +1817,15 @@ impl pallet_did::DeriveDidCallAuthorizationVerificationKeyRelationship for Runti
 			RuntimeCall::DidName { .. } => {
 				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
 			},
+			RuntimeCall::DidDocument { .. } => {
+				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
+			},
+			RuntimeCall::Delegation { .. } => {
+				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
+			},
+			RuntimeCall::Credential { .. } => {
+				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
+			},
 			RuntimeCall::Schema { .. } => {
 				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
 			},
@@ -956,6 +1877,10 @@ impl pallet_did::DeriveDidCallAuthorizationVerificationKeyRelationship for Runti
 			RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) => {
 				single_key_relationship(&calls[..])
 			},
+			RuntimeCall::Batch(pallet_cord_batch::Call::batch_all { calls }) => {
+				let calls: Vec<RuntimeCall> = calls.iter().map(|call| (**call).clone()).collect();
+				single_key_relationship(&calls[..])
+			},
 			#[cfg(not(feature = "runtime-benchmarks"))]
 			_ => Err(pallet_did::RelationshipDeriveError::NotCallableByDid),
 			// By default, returns the authentication key
@@ -984,6 +1909,7 @@ pub type BlockId = generic::BlockId<Block>;
 /// The `SignedExtension` to the basic transaction logic.
 pub type SignedExtra = (
 	pallet_network_membership::CheckNetworkMembership<Runtime>,
+	pallet_network_membership::RateLimiter<Runtime>,
 	frame_system::CheckNonZeroSender<Runtime>,
 	frame_system::CheckSpecVersion<Runtime>,
 	frame_system::CheckTxVersion<Runtime>,
@@ -991,6 +1917,8 @@ pub type SignedExtra = (
 	frame_system::CheckMortality<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	pallet_cord_fee_proxy::FeeProxyExtension<Runtime>,
+	pallet_cord_fee_rebate::RebateFee<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -1006,6 +1934,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	migrations::Unreleased,
 >;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
@@ -1028,16 +1957,57 @@ mod benches {
 		[pallet_scheduler, Scheduler]
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_timestamp, Timestamp]
+		[pallet_treasury, Treasury]
 		[pallet_utility, Utility]
 		[pallet_schema, Schema]
 		[pallet_statement, Statement]
 		[pallet_chain_space, ChainSpace]
 		[pallet_did, Did]
+		[pallet_cord_did_document, DidDocument]
+		[pallet_cord_registry, Registry]
+		[pallet_cord_delegation, Delegation]
+		[pallet_cord_credentials, Credential]
+		[pallet_cord_chain_namespace, ChainNamespace]
+		[pallet_cord_transaction_weights, TransactionWeights]
+		[pallet_cord_fee_adjustment, FeeAdjustment]
+		[authority_membership, AuthorityMembership]
+		[pallet_cord_credit, Credit]
+		[pallet_cord_governance, Governance]
+		[pallet_cord_attestation, Attestation]
+		[pallet_cord_asset_vc, AssetVc]
+		[pallet_cord_sudo_key_rotation, SudoKeyRotation]
 		[pallet_did_name, DidName]
 		[pallet_network_membership, NetworkMembership]
 		[pallet_network_score, NetworkScore]
 		[pallet_sudo, Sudo]
 		[pallet_asset, Asset]
+		[pallet_cord_reliability_score, ReliabilityScore]
+		[pallet_cord_network_score_slashing, NetworkScoreSlashing]
+		[pallet_cord_epoch_rewards, EpochRewards]
+		[pallet_cord_session_manager, SessionManager]
+		[pallet_cord_multisig_authority, MultisigAuthority]
+		[pallet_cord_claims, Claims]
+		[pallet_cord_anchor, Anchor]
+		[pallet_cord_scoring_oracle, ScoringOracle]
+		[pallet_cord_bounties, Bounties]
+		[pallet_cord_offchain_resolver, OffchainResolver]
+		[pallet_cord_credential_nfts, CredentialNfts]
+		[pallet_cord_session_payout, SessionPayout]
+		[pallet_cord_dispute, Dispute]
+		[pallet_cord_governance_registry, GovernanceRegistry]
+		[pallet_cord_weighted_democracy, WeightedDemocracy]
+		[pallet_cord_composite_proof, CompositeProof]
+		[pallet_cord_vesting, Vesting]
+		[pallet_cord_registry_delegation, RegistryDelegation]
+		[pallet_cord_storage_deposit, StorageDeposit]
+		[pallet_cord_council_staking, CouncilStaking]
+		[pallet_cord_batch, Batch]
+		[pallet_cord_pause, Pause]
+		[pallet_cord_periodic_snapshot, PeriodicSnapshot]
+		[pallet_cord_access_control, AccessControl]
+		[pallet_cord_treasury_reward, TreasuryReward]
+		[pallet_cord_fee_rebate, FeeRebate]
+		[pallet_cord_fee_proxy, FeeProxy]
 	);
 }
 
@@ -1201,6 +2171,43 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
+		Block,
+		Balance,
+	> for Runtime {
+		fn query_info(uxt: <Block as BlockT>::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance> {
+			TransactionPayment::query_info(uxt, len)
+		}
+		fn query_fee_details(uxt: <Block as BlockT>::Extrinsic, len: u32) -> FeeDetails<Balance> {
+			TransactionPayment::query_fee_details(uxt, len)
+		}
+		fn query_weight_to_fee(weight: Weight) -> Balance {
+			TransactionPayment::weight_to_fee(weight)
+		}
+		fn query_length_to_fee(length: u32) -> Balance {
+			TransactionPayment::length_to_fee(length)
+		}
+	}
+
+	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<
+		Block,
+		Balance,
+		RuntimeCall,
+	> for Runtime {
+		fn query_call_info(call: RuntimeCall, len: u32) -> RuntimeDispatchInfo<Balance> {
+			TransactionPayment::query_call_info(call, len)
+		}
+		fn query_call_fee_details(call: RuntimeCall, len: u32) -> FeeDetails<Balance> {
+			TransactionPayment::query_call_fee_details(call, len)
+		}
+		fn query_weight_to_fee(weight: Weight) -> Balance {
+			TransactionPayment::weight_to_fee(weight)
+		}
+		fn query_length_to_fee(length: u32) -> Balance {
+			TransactionPayment::length_to_fee(length)
+		}
+	}
+
 	impl pallet_did_runtime_api::DidApi<
 		Block,
 		DidIdentifier,
@@ -1254,10 +2261,263 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl cord_authority_membership_runtime_api::AuthorityManagerApi<Block, AccountId> for Runtime {
+		fn pending_diff() -> Option<authority_membership::AuthorityDiff<AccountId>> {
+			AuthorityMembership::pending_diff()
+		}
+
+		fn get_authority_set_at(session: SessionIndex) -> Option<Vec<AccountId>> {
+			AuthorityMembership::authority_set_at(session)
+		}
+	}
+
+	impl cord_reliability_score_runtime_api::ReliabilityScoreApi<Block, AccountId> for Runtime {
+		fn get_score(who: AccountId) -> FixedU128 {
+			ReliabilityScore::get_score(&who)
+		}
+	}
+
+	impl cord_registry_delegation_runtime_api::RegistryDelegationApi<Block, AccountId> for Runtime {
+		fn resolve_authority_chain(delegate: AccountId) -> Vec<AccountId> {
+			RegistryDelegation::resolve_authority_chain(&delegate)
+		}
+	}
+
+	impl cord_did_document_runtime_api::DidDocumentApi<
+		Block,
+		DidIdentifier,
+		pallet_cord_did_document::DidDocumentOf<Runtime>,
+	> for Runtime {
+		fn resolve_did(
+			identifier: DidIdentifier,
+		) -> Option<pallet_cord_did_document::DidDocumentOf<Runtime>> {
+			DidDocument::resolve_did(&identifier)
+		}
+	}
+
+	impl cord_offchain_resolver_runtime_api::OffchainResolverApi<Block, DidIdentifier> for Runtime {
+		fn resolve_did_offchain(identifier: DidIdentifier) -> Option<Vec<u8>> {
+			OffchainResolver::resolve_did_offchain(&identifier)
+		}
+	}
+
+	impl cord_schema_runtime_api::SchemaApi<
+		Block,
+		pallet_schema::SchemaIdOf,
+		pallet_schema::SchemaDetailsOf<Runtime>,
+	> for Runtime {
+		fn get_schema(
+			schema_id: pallet_schema::SchemaIdOf,
+		) -> Option<pallet_schema::SchemaDetailsOf<Runtime>> {
+			Schema::get_schema(&schema_id)
+		}
+	}
+
+	impl cord_attestation_runtime_api::AttestationApi<
+		Block,
+		sp_core::H256,
+		pallet_cord_attestation::AttestationEntryOf<Runtime>,
+	> for Runtime {
+		fn get_attestation(
+			claim_hash: sp_core::H256,
+		) -> Option<pallet_cord_attestation::AttestationEntryOf<Runtime>> {
+			Attestation::get_attestation(&claim_hash)
+		}
+	}
+
+	impl cord_anchor_runtime_api::AnchorApi<
+		Block,
+		sp_core::H256,
+		pallet_cord_anchor::AnchorEntryOf<Runtime>,
+	> for Runtime {
+		fn get_anchor(data_hash: sp_core::H256) -> Option<pallet_cord_anchor::AnchorEntryOf<Runtime>> {
+			Anchor::get_anchor(&data_hash)
+		}
+	}
+
+	impl cord_asset_vc_runtime_api::AssetVcApi<
+		Block,
+		pallet_cord_asset_vc::AssetIdOf,
+		pallet_cord_asset_vc::CredentialIdOf<Runtime>,
+	> for Runtime {
+		fn get_credential_for_asset(
+			asset_id: pallet_cord_asset_vc::AssetIdOf,
+		) -> Option<pallet_cord_asset_vc::CredentialIdOf<Runtime>> {
+			AssetVc::get_credential_for_asset(&asset_id)
+		}
+	}
+
+	impl cord_statement_runtime_api::StatementApi<
+		Block,
+		pallet_statement::StatementIdOf,
+		pallet_statement::StatementEntryOf<Runtime>,
+	> for Runtime {
+		fn resolve_statement(
+			statement_id: pallet_statement::StatementIdOf,
+		) -> Option<pallet_statement::StatementEntryOf<Runtime>> {
+			Statement::resolve_statement(&statement_id)
+		}
+	}
+
+	impl cord_asset_runtime_api::AssetApi<
+		Block,
+		pallet_asset::AssetIdOf,
+		pallet_asset::AssetStatusOf,
+	> for Runtime {
+		fn query_asset_status(
+			asset_id: pallet_asset::AssetIdOf,
+		) -> Option<pallet_asset::AssetStatusOf> {
+			Asset::query_asset_status(&asset_id)
+		}
+	}
+
+	impl cord_runtime_api::CordRuntimeApi<Block, AccountId, AccountIndex> for Runtime {
+		fn genesis_hash() -> Hash {
+			System::block_hash(0)
+		}
+
+		fn features() -> Vec<Vec<u8>> {
+			CORD_FEATURES.iter().map(|feature| feature.as_bytes().to_vec()).collect()
+		}
+
+		fn resolve_account_index(index: AccountIndex) -> Option<AccountId> {
+			Indices::lookup_index(index)
+		}
+
+		fn chain_id() -> u32 {
+			ChainNamespace::current_chain_id()
+		}
+	}
+
+	impl cord_runtime_api::CordMetadataApi<Block> for Runtime {
+		fn cord_pallet_list() -> Vec<cord_runtime_api::PalletMeta> {
+			use frame_support::{
+				sp_io::hashing::twox_128,
+				storage::unhashed,
+				traits::{PalletsInfoAccess, STORAGE_VERSION_STORAGE_KEY_POSTFIX},
+			};
+
+			let mut infos = <AllPalletsWithSystem as PalletsInfoAccess>::infos();
+			infos.sort_by_key(|info| info.index);
+			infos
+				.into_iter()
+				.map(|info| {
+					let storage_prefix = twox_128(info.name.as_bytes());
+					let version_key =
+						[storage_prefix, twox_128(STORAGE_VERSION_STORAGE_KEY_POSTFIX)].concat();
+					cord_runtime_api::PalletMeta {
+						name: info.name.as_bytes().to_vec(),
+						version: unhashed::get_or_default::<u16>(&version_key).into(),
+						storage_prefix: storage_prefix.to_vec(),
+					}
+				})
+				.collect()
+		}
+	}
+
+	impl cord_runtime_api::CordAuthorityApi<Block, AccountId> for Runtime {
+		fn is_author(who: AccountId) -> bool {
+			NetworkMembership::is_member_inner(&who)
+		}
+	}
+
+	impl cord_runtime_api::CordSyncStateApi<Block, BlockNumber> for Runtime {
+		fn finalization_lag(finalized: BlockNumber) -> u32 {
+			System::block_number().saturating_sub(finalized)
+		}
+
+		fn estimated_time_to_finalize(lag: u32) -> u64 {
+			u64::from(lag).saturating_mul(MILLISECS_PER_BLOCK)
+		}
+	}
+
+	impl cord_runtime_api::CordBlockTimeApi<Block> for Runtime {
+		fn target_block_time_ms() -> u64 {
+			MILLISECS_PER_BLOCK
+		}
+
+		fn measured_block_time_ms(window: u32) -> u64 {
+			BlockTime::measured_block_time(window)
+		}
+	}
+
+	impl cord_runtime_api::CordIdentityApi<Block, DidIdentifier, AccountId, BlockNumber> for Runtime {
+		fn resolve_did(
+			identifier: DidIdentifier,
+		) -> Option<cord_runtime_api::DidDocumentInfo<AccountId, Hash, BlockNumber>> {
+			DidDocument::resolve_did(&identifier).map(|document| cord_runtime_api::DidDocumentInfo {
+				digest: document.digest,
+				active: document.status == pallet_cord_did_document::DidStatus::Active,
+				creator: document.creator,
+				created_at: document.created_at,
+				updated_at: document.updated_at,
+			})
+		}
+
+		fn did_exists(identifier: DidIdentifier) -> bool {
+			DidDocument::did_exists(&identifier)
+		}
+
+		fn list_dids_for_account(account: AccountId) -> Vec<DidIdentifier> {
+			DidDocument::dids_of_creator(&account)
+		}
+	}
+
+	impl cord_runtime_api::CordSnapshotApi<Block, BlockNumber> for Runtime {
+		fn get_latest_snapshot() -> Option<cord_runtime_api::SnapshotInfo<Hash, BlockNumber>> {
+			PeriodicSnapshot::get_latest_snapshot().map(|meta| cord_runtime_api::SnapshotInfo {
+				block: meta.block,
+				state_root: meta.state_root,
+				ipfs_cid: meta.ipfs_cid.into_inner(),
+			})
+		}
+	}
+
+	impl cord_runtime_api::CordTreasuryApi<Block, AccountId, Balance> for Runtime {
+		fn treasury_balance() -> Balance {
+			Balances::free_balance(TreasuryAccount::get())
+		}
+
+		fn approved_proposals() -> Vec<cord_runtime_api::TreasuryProposalInfo<AccountId, Balance>> {
+			// `Approvals` is bounded by `MaxTreasuryApprovals`.
+			pallet_treasury::Approvals::<Runtime>::get()
+				.into_iter()
+				.filter_map(|index| {
+					let proposal = pallet_treasury::Proposals::<Runtime>::get(index)?;
+					// The fields of `pallet_treasury::Proposal` are private.
+					let (proposer, value, beneficiary, bond) =
+						<(AccountId, Balance, AccountId, Balance)>::decode(
+							&mut &proposal.encode()[..],
+						)
+						.ok()?;
+					Some(cord_runtime_api::TreasuryProposalInfo {
+						index,
+						proposer,
+						beneficiary,
+						value,
+						bond,
+					})
+				})
+				.collect()
+		}
+
+		fn pending_proposals_count() -> u32 {
+			let approvals = pallet_treasury::Approvals::<Runtime>::get();
+			pallet_treasury::Proposals::<Runtime>::iter_keys()
+				.take(cord_runtime_api::MAX_TREASURY_PROPOSALS_COUNTED as usize)
+				.filter(|index| !approvals.contains(index))
+				.count() as u32
+		}
+	}
+
 	impl pallet_transaction_weight_runtime_api::TransactionWeightApi<Block> for Runtime {
 		fn query_weight_info(uxt: <Block as BlockT>::Extrinsic) -> RuntimeDispatchWeightInfo {
 			NetworkMembership::query_weight_info(uxt)
 		}
+
+		fn weight_histogram() -> Vec<(pallet_cord_transaction_weights::WeightBucket, u64)> {
+			TransactionWeights::weight_histogram()
+		}
 	}
 
 	impl sp_session::SessionKeys<Block> for Runtime {
@@ -0,0 +1,250 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations run by the runtime on upgrade.
+
+use crate::Runtime;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use {
+	codec::{Decode, Encode},
+	sp_runtime::TryRuntimeError,
+	sp_std::vec::Vec,
+};
+
+const LOG_TARGET: &str = "runtime::migrations";
+
+/// Migrations executed on the next runtime upgrade.
+///
+/// Wrap each entry in a `VersionedMigration` so that it turns into a no-op
+/// once applied and can be dropped from the tuple in a later release.
+pub type Unreleased =
+	(VersionedMigration<1, 2, v1::ExtrinsicAuthorsMigration, crate::NetworkMembership>,);
+
+/// Runs `Inner` only if the on-chain storage version of `Pallet` is `FROM`,
+/// then bumps the storage version to `TO`.
+///
+/// When the on-chain version does not match, e.g. because the migration
+/// already ran or the pallet is older than expected, `Inner` is skipped and
+/// the storage version is left untouched.
+pub struct VersionedMigration<const FROM: u16, const TO: u16, Inner, Pallet>(
+	PhantomData<(Inner, Pallet)>,
+);
+
+impl<const FROM: u16, const TO: u16, Inner, Pallet> OnRuntimeUpgrade
+	for VersionedMigration<FROM, TO, Inner, Pallet>
+where
+	Inner: OnRuntimeUpgrade,
+	Pallet: GetStorageVersion + PalletInfoAccess,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		let on_chain_version = Pallet::on_chain_storage_version();
+
+		if on_chain_version == FROM {
+			log::info!(
+				target: LOG_TARGET,
+				"{}: migrating storage from version {} to {}",
+				Pallet::name(),
+				FROM,
+				TO,
+			);
+			let weight = Inner::on_runtime_upgrade();
+			StorageVersion::new(TO).put::<Pallet>();
+			weight.saturating_add(db_weight.reads_writes(1, 1))
+		} else {
+			log::warn!(
+				target: LOG_TARGET,
+				"{}: skipping migration from version {} to {}, on-chain version is {:?}",
+				Pallet::name(),
+				FROM,
+				TO,
+				on_chain_version,
+			);
+			db_weight.reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		if Pallet::on_chain_storage_version() == FROM {
+			Ok((true, Inner::pre_upgrade()?).encode())
+		} else {
+			Ok((false, Vec::<u8>::new()).encode())
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let (should_run, inner_state): (bool, Vec<u8>) = Decode::decode(&mut &state[..])
+			.map_err(|_| TryRuntimeError::Other("failed to decode the pre-upgrade state"))?;
+
+		if should_run {
+			Inner::post_upgrade(inner_state)?;
+			frame_support::ensure!(
+				Pallet::on_chain_storage_version() == TO,
+				"storage version was not bumped by the migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Gives the extrinsic authors, the members of `pallet_network_membership`,
+/// their `AuthorMeta`. Storage version 1 to 2 of the pallet.
+pub mod v1 {
+	use super::*;
+	use crate::{AccountId, BlockNumber};
+	use codec::Decode;
+	use frame_support::{storage_alias, Blake2_128Concat};
+	use pallet_network_membership::{AuthorMeta, MemberData};
+
+	pub(crate) mod old {
+		use super::*;
+
+		/// The member data before `AuthorMeta` was added.
+		#[derive(Encode, Decode)]
+		pub struct MemberData {
+			pub expire_on: BlockNumber,
+		}
+
+		#[storage_alias]
+		pub type Members =
+			CountedStorageMap<NetworkMembership, Blake2_128Concat, AccountId, MemberData>;
+	}
+
+	#[storage_alias]
+	type Members =
+		CountedStorageMap<NetworkMembership, Blake2_128Concat, AccountId, MemberData<BlockNumber>>;
+
+	/// Converts every member entry to one carrying `AuthorMeta::default()`.
+	///
+	/// Run it through `VersionedMigration<1, 2, _, NetworkMembership>`, which
+	/// bumps the storage version.
+	pub struct ExtrinsicAuthorsMigration;
+
+	impl OnRuntimeUpgrade for ExtrinsicAuthorsMigration {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Members::translate::<old::MemberData, _>(|_, old| {
+				translated += 1;
+				Some(MemberData::new(old.expire_on))
+			});
+
+			log::info!(target: LOG_TARGET, "migrated {} extrinsic authors", translated);
+			<Runtime as frame_system::Config>::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let authors: Vec<(AccountId, BlockNumber)> =
+				old::Members::iter().map(|(who, data)| (who, data.expire_on)).collect();
+			Ok(authors.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let authors: Vec<(AccountId, BlockNumber)> = Decode::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("failed to decode the pre-upgrade state"))?;
+
+			frame_support::ensure!(
+				Members::count() as usize == authors.len(),
+				"the number of extrinsic authors changed"
+			);
+			for (who, expire_on) in authors {
+				let data = Members::get(&who).ok_or("an extrinsic author was lost")?;
+				frame_support::ensure!(
+					data.expire_on == expire_on && data.meta == AuthorMeta::default(),
+					"an extrinsic author was not migrated"
+				);
+			}
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{AccountId, NetworkMembership, Schema};
+	use network_membership::traits::MembersCount;
+	use pallet_network_membership::MemberData;
+
+	const MIGRATED_KEY: &[u8] = b":test:migrated";
+
+	struct MarkMigrated;
+
+	impl OnRuntimeUpgrade for MarkMigrated {
+		fn on_runtime_upgrade() -> Weight {
+			sp_io::storage::set(MIGRATED_KEY, &[1]);
+			Weight::zero()
+		}
+	}
+
+	type SchemaV1ToV2 = VersionedMigration<1, 2, MarkMigrated, Schema>;
+
+	#[test]
+	fn migration_is_skipped_for_a_stale_storage_version() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			StorageVersion::new(0).put::<Schema>();
+
+			SchemaV1ToV2::on_runtime_upgrade();
+
+			assert_eq!(sp_io::storage::get(MIGRATED_KEY), None);
+			assert_eq!(Schema::on_chain_storage_version(), StorageVersion::new(0));
+		});
+	}
+
+	#[test]
+	fn migration_runs_once_and_bumps_the_storage_version() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			StorageVersion::new(1).put::<Schema>();
+
+			SchemaV1ToV2::on_runtime_upgrade();
+
+			assert!(sp_io::storage::get(MIGRATED_KEY).is_some());
+			assert_eq!(Schema::on_chain_storage_version(), StorageVersion::new(2));
+
+			// A second upgrade finds version 2 and leaves storage alone.
+			sp_io::storage::clear(MIGRATED_KEY);
+			SchemaV1ToV2::on_runtime_upgrade();
+
+			assert_eq!(sp_io::storage::get(MIGRATED_KEY), None);
+			assert_eq!(Schema::on_chain_storage_version(), StorageVersion::new(2));
+		});
+	}
+
+	#[test]
+	fn extrinsic_authors_get_the_default_author_meta() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			StorageVersion::new(1).put::<NetworkMembership>();
+			let author = AccountId::new([1u8; 32]);
+			v1::old::Members::insert(&author, v1::old::MemberData { expire_on: 5 });
+
+			Unreleased::on_runtime_upgrade();
+
+			assert_eq!(NetworkMembership::members(&author), Some(MemberData::new(5)));
+			assert_eq!(NetworkMembership::members_count(), 1);
+			assert_eq!(NetworkMembership::on_chain_storage_version(), StorageVersion::new(2));
+		});
+	}
+}
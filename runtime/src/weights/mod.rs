@@ -18,11 +18,52 @@
 
 //! A list of the different weight modules for our runtime.
 
+use frame_support::weights::{constants::BlockExecutionWeight, Weight};
+
 pub mod frame_system;
 pub mod pallet_asset;
 pub mod pallet_balances;
 pub mod pallet_chain_space;
 pub mod pallet_collective;
+pub mod pallet_cord_access_control;
+pub mod pallet_cord_anchor;
+pub mod pallet_cord_asset_vc;
+pub mod pallet_cord_attestation;
+pub mod pallet_cord_batch;
+pub mod pallet_cord_bounties;
+pub mod pallet_cord_chain_namespace;
+pub mod pallet_cord_claims;
+pub mod pallet_cord_composite_proof;
+pub mod pallet_cord_council_staking;
+pub mod pallet_cord_credential_nfts;
+pub mod pallet_cord_credentials;
+pub mod pallet_cord_credit;
+pub mod pallet_cord_delegation;
+pub mod pallet_cord_did_document;
+pub mod pallet_cord_dispute;
+pub mod pallet_cord_epoch_rewards;
+pub mod pallet_cord_fee_adjustment;
+pub mod pallet_cord_fee_proxy;
+pub mod pallet_cord_fee_rebate;
+pub mod pallet_cord_governance;
+pub mod pallet_cord_governance_registry;
+pub mod pallet_cord_multisig_authority;
+pub mod pallet_cord_network_score_slashing;
+pub mod pallet_cord_offchain_resolver;
+pub mod pallet_cord_pause;
+pub mod pallet_cord_periodic_snapshot;
+pub mod pallet_cord_registry;
+pub mod pallet_cord_registry_delegation;
+pub mod pallet_cord_reliability_score;
+pub mod pallet_cord_scoring_oracle;
+pub mod pallet_cord_session_manager;
+pub mod pallet_cord_session_payout;
+pub mod pallet_cord_storage_deposit;
+pub mod pallet_cord_sudo_key_rotation;
+pub mod pallet_cord_transaction_weights;
+pub mod pallet_cord_treasury_reward;
+pub mod pallet_cord_vesting;
+pub mod pallet_cord_weighted_democracy;
 pub mod pallet_did;
 pub mod pallet_did_name;
 pub mod pallet_identity;
@@ -41,3 +82,61 @@ pub mod pallet_statement;
 pub mod pallet_sudo;
 pub mod pallet_timestamp;
 pub mod pallet_utility;
+
+/// CPU score of the reference hardware, in KiB/s of BLAKE2-256 hashing as
+/// measured by the node hardware benchmarks.
+pub const REFERENCE_HARDWARE_SCORE: u64 = 802_068;
+
+/// Largest deviation, in percent, of a calibrated block execution weight
+/// from `BlockExecutionWeight`.
+pub const MAX_CALIBRATION_DEVIATION: u64 = 50;
+
+/// Scale `BlockExecutionWeight` to hardware with a CPU score of
+/// `hardware_score`, relative to [`REFERENCE_HARDWARE_SCORE`]. An empty block
+/// executes in less time on hardware scoring higher than the reference.
+///
+/// Panics if the calibrated weight deviates from `BlockExecutionWeight` by
+/// more than [`MAX_CALIBRATION_DEVIATION`] percent.
+pub fn calibrate_block_execution_weight(hardware_score: u64) -> Weight {
+	let reference = BlockExecutionWeight::get();
+	let ref_time = u128::from(reference.ref_time()) * u128::from(REFERENCE_HARDWARE_SCORE) /
+		u128::from(hardware_score.max(1));
+	let calibrated =
+		Weight::from_parts(u64::try_from(ref_time).unwrap_or(u64::MAX), reference.proof_size());
+
+	let deviation = calibrated.ref_time().abs_diff(reference.ref_time());
+	assert!(
+		u128::from(deviation) * 100 <=
+			u128::from(reference.ref_time()) * u128::from(MAX_CALIBRATION_DEVIATION),
+		"calibrated block execution weight {} deviates from {} by more than {}%",
+		calibrated.ref_time(),
+		reference.ref_time(),
+		MAX_CALIBRATION_DEVIATION,
+	);
+	calibrated
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn calibration_scales_with_the_hardware_score() {
+		let reference = BlockExecutionWeight::get();
+		assert_eq!(calibrate_block_execution_weight(REFERENCE_HARDWARE_SCORE), reference);
+		assert_eq!(
+			calibrate_block_execution_weight(REFERENCE_HARDWARE_SCORE * 2).ref_time(),
+			reference.ref_time() / 2
+		);
+		assert_eq!(
+			calibrate_block_execution_weight(REFERENCE_HARDWARE_SCORE * 3 / 2).ref_time(),
+			reference.ref_time() * 2 / 3
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "by more than 50%")]
+	fn calibration_panics_on_a_large_deviation() {
+		calibrate_block_execution_weight(REFERENCE_HARDWARE_SCORE / 2);
+	}
+}
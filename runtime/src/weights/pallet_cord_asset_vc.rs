@@ -0,0 +1,78 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_asset_vc`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_asset_vc
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --header=./HEADER-GPL3
+// --output=./runtime/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_cord_asset_vc`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_cord_asset_vc::WeightInfo for WeightInfo<T> {
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::VCAssets` (r:1 w:0)
+	/// Proof: `Asset::VCAssets` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `Credential::Credentials` (r:1 w:0)
+	/// Proof: `Credential::Credentials` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Statement::RevocationList` (r:1 w:0)
+	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
+	/// Storage: `AssetVc::AssetCredentialLink` (r:1 w:1)
+	/// Proof: `AssetVc::AssetCredentialLink` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn link_asset_to_credential() -> Weight {
+		Weight::from_parts(32_710_000, 0)
+			.saturating_add(Weight::from_parts(0, 6659))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::VCAssets` (r:1 w:0)
+	/// Proof: `Asset::VCAssets` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `AssetVc::AssetCredentialLink` (r:1 w:1)
+	/// Proof: `AssetVc::AssetCredentialLink` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn unlink_asset() -> Weight {
+		Weight::from_parts(22_930_000, 0)
+			.saturating_add(Weight::from_parts(0, 6659))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
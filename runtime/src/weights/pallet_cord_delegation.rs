@@ -0,0 +1,73 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_delegation`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_delegation
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --header=./HEADER-GPL3
+// --output=./runtime/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_cord_delegation`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_cord_delegation::WeightInfo for WeightInfo<T> {
+	/// Storage: `Delegation::DelegationHierarchy` (r:2 w:2)
+	/// Proof: `Delegation::DelegationHierarchy` (`max_values`: None, `max_size`: Some(943), added: 3418, mode: `MaxEncodedLen`)
+	/// Storage: `Delegation::Delegates` (r:1 w:1)
+	/// Proof: `Delegation::Delegates` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn create_delegation() -> Weight {
+		Weight::from_parts(28_040_000, 0)
+			.saturating_add(Weight::from_parts(0, 7826))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Delegation::DelegationHierarchy` (r:10 w:10)
+	/// Proof: `Delegation::DelegationHierarchy` (`max_values`: None, `max_size`: Some(943), added: 3418, mode: `MaxEncodedLen`)
+	/// Storage: `Delegation::Delegates` (r:10 w:10)
+	/// Proof: `Delegation::Delegates` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 10]`.
+	fn revoke_delegation(r: u32, ) -> Weight {
+		Weight::from_parts(14_981_226, 0)
+			.saturating_add(Weight::from_parts(0, 1488))
+			.saturating_add(Weight::from_parts(9_902_615, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 3418).saturating_mul(r.into()))
+	}
+}
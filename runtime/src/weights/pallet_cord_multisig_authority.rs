@@ -0,0 +1,84 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_multisig_authority`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_multisig_authority
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --header=./HEADER-GPL3
+// --output=./runtime/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_cord_multisig_authority`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_cord_multisig_authority::WeightInfo for WeightInfo<T> {
+	/// Storage: `MultisigAuthority::MultisigAuthorities` (r:1 w:1)
+	/// Proof: `MultisigAuthority::MultisigAuthorities` (`max_values`: None, `max_size`: Some(3452), added: 5927, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn create_multisig_authority(s: u32, ) -> Weight {
+		Weight::from_parts(18_710_000, 0)
+			.saturating_add(Weight::from_parts(0, 6917))
+			.saturating_add(Weight::from_parts(96_213, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `MultisigAuthority::MultisigAuthorities` (r:1 w:0)
+	/// Proof: `MultisigAuthority::MultisigAuthorities` (`max_values`: None, `max_size`: Some(3452), added: 5927, mode: `MaxEncodedLen`)
+	/// Storage: `MultisigAuthority::PendingActions` (r:1 w:1)
+	/// Proof: `MultisigAuthority::PendingActions` (`max_values`: None, `max_size`: Some(3303), added: 5778, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn propose_authority_action(s: u32, ) -> Weight {
+		Weight::from_parts(22_050_000, 0)
+			.saturating_add(Weight::from_parts(0, 6917))
+			.saturating_add(Weight::from_parts(61_847, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `MultisigAuthority::PendingActions` (r:1 w:1)
+	/// Proof: `MultisigAuthority::PendingActions` (`max_values`: None, `max_size`: Some(3303), added: 5778, mode: `MaxEncodedLen`)
+	/// Storage: `MultisigAuthority::MultisigAuthorities` (r:1 w:0)
+	/// Proof: `MultisigAuthority::MultisigAuthorities` (`max_values`: None, `max_size`: Some(3452), added: 5927, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn sign_authority_action(s: u32, ) -> Weight {
+		Weight::from_parts(24_610_000, 0)
+			.saturating_add(Weight::from_parts(0, 6917))
+			.saturating_add(Weight::from_parts(118_402, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,72 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_cord_transaction_weights`
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, NOT THE OUTPUT OF A BENCHMARK
+//! RUN. Regenerate them with the command below before relying on them on a
+//! live chain.
+
+// Command to generate the weights:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_cord_transaction_weights
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --header=./HEADER-GPL3
+// --output=./runtime/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_cord_transaction_weights`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_cord_transaction_weights::WeightInfo for WeightInfo<T> {
+	/// Storage: `System::BlockWeight` (r:1 w:0)
+	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionWeights::RecentBuckets` (r:1 w:1)
+	/// Proof: `TransactionWeights::RecentBuckets` (`max_values`: Some(1), `max_size`: Some(259), added: 754, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionWeights::Histogram` (r:2 w:2)
+	/// Proof: `TransactionWeights::Histogram` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn on_finalize() -> Weight {
+		Weight::from_parts(15_480_000, 0)
+			.saturating_add(Weight::from_parts(0, 6474))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `TransactionWeights::RecentBuckets` (r:0 w:1)
+	/// Proof: `TransactionWeights::RecentBuckets` (`max_values`: Some(1), `max_size`: Some(259), added: 754, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionWeights::Histogram` (r:10 w:10)
+	/// Proof: `TransactionWeights::Histogram` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn reset_histogram() -> Weight {
+		Weight::from_parts(43_710_000, 0)
+			.saturating_add(Weight::from_parts(0, 25410))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+}
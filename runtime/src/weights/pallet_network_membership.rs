@@ -23,6 +23,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `smohan-dev-host`, CPU: `AMD EPYC 7B12`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! The weights of `renew_membership`, `is_member`, `set_rate_limit` and `rate_limiter_validate` are hand-written
+//! placeholders until the pallet is benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -97,4 +100,43 @@ impl<T: frame_system::Config> pallet_network_membership::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `NetworkMembership::Members` (r:1 w:1)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::MembershipsExpiresOn` (r:2 w:2)
+	/// Proof: `NetworkMembership::MembershipsExpiresOn` (`max_values`: None, `max_size`: Some(32022), added: 34497, mode: `MaxEncodedLen`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn renew_membership() -> Weight {
+		Weight::from_parts(20_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 69984))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `NetworkMembership::Members` (r:1 w:0)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 1000]`.
+	/// Hand-written placeholder, not a benchmark result.
+	fn is_member(m: u32, ) -> Weight {
+		Weight::from_parts(4_655_902, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			.saturating_add(Weight::from_parts(318, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `NetworkMembership::RateLimits` (r:0 w:1)
+	/// Proof: `NetworkMembership::RateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn set_rate_limit() -> Weight {
+		Weight::from_parts(8_490_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `NetworkMembership::ExtrinsicCount` (r:1 w:0)
+	/// Proof: `NetworkMembership::ExtrinsicCount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::RateLimits` (r:1 w:0)
+	/// Proof: `NetworkMembership::RateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Hand-written placeholder, not a benchmark result.
+	fn rate_limiter_validate() -> Weight {
+		Weight::from_parts(7_290_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
 }
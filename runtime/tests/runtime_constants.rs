@@ -0,0 +1,156 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Pins the runtime `parameter_types` to literal values.
+//!
+//! Most of these constants end up in storage layouts, weight proofs or
+//! on-chain economics, so changing one should be a deliberate decision that
+//! also touches this file rather than a side effect of editing an expression.
+
+use cord_runtime::*;
+use cord_runtime_constants::{currency::*, time::*};
+use frame_support::{
+	dispatch::DispatchClass,
+	traits::Get,
+	weights::{Weight, WeightToFee},
+};
+use sp_consensus_babe::AllowedSlots;
+use sp_runtime::{FixedU128, Perbill, Percent};
+
+#[test]
+fn time_constants_are_pinned() {
+	assert_eq!(MILLISECS_PER_BLOCK, 3_000);
+	assert_eq!(MINUTES, 20);
+	assert_eq!(HOURS, 1_200);
+	assert_eq!(DAYS, 28_800);
+	assert_eq!(YEAR, 10_519_200);
+	assert_eq!(EPOCH_DURATION_IN_BLOCKS, 40);
+	assert_eq!(EPOCH_DURATION_IN_SLOTS, 40);
+	assert_eq!(PRIMARY_PROBABILITY, (1, 4));
+}
+
+#[test]
+fn currency_constants_are_pinned() {
+	assert_eq!(WAY, 1_000_000_000_000);
+	assert_eq!(UNITS, 10_000_000_000);
+	assert_eq!(MILLIUNITS, 100_000_000);
+	assert_eq!(EXISTENTIAL_DEPOSIT, 1_000_000_000_000);
+	assert_eq!(deposit(1, 0), 1_000_000_000_000);
+	assert_eq!(deposit(0, 1), 10_000_000_000);
+}
+
+#[test]
+fn system_parameters_are_pinned() {
+	assert_eq!(BlockHashCount::get(), 2400);
+	assert_eq!(SS58Prefix::get(), 29);
+	assert_eq!(&*Version::get().spec_name, "cord");
+	assert_eq!(AVERAGE_ON_INITIALIZE_RATIO, Perbill::from_percent(10));
+	assert_eq!(NORMAL_DISPATCH_RATIO, Perbill::from_percent(50));
+
+	assert_eq!(
+		RuntimeBlockWeights::get().max_block,
+		Weight::from_parts(1_000_000_000_000, u64::MAX)
+	);
+
+	let length = RuntimeBlockLength::get();
+	assert_eq!(*length.max.get(DispatchClass::Normal), 2_621_440);
+	assert_eq!(*length.max.get(DispatchClass::Operational), 5_242_880);
+	assert_eq!(*length.max.get(DispatchClass::Mandatory), 5_242_880);
+}
+
+#[test]
+fn consensus_parameters_are_pinned() {
+	assert_eq!(BABE_GENESIS_EPOCH_CONFIG.c, (1, 4));
+	assert_eq!(BABE_GENESIS_EPOCH_CONFIG.allowed_slots, AllowedSlots::PrimaryAndSecondaryVRFSlots);
+	assert_eq!(EpochDuration::get(), 40);
+	assert_eq!(ExpectedBlockTime::get(), 3_000);
+	assert_eq!(MaxAuthorities::get(), 1_000);
+	assert_eq!(SessionsPerEra::get(), 6);
+	assert_eq!(BondingDuration::get(), 28);
+	assert_eq!(MaxSetIdSessionEntries::get(), 168);
+}
+
+#[test]
+fn balances_parameters_are_pinned() {
+	assert_eq!(ExistentialDeposit::get(), 1_000_000_000_000);
+	assert_eq!(MaxLocks::get(), 50);
+	assert_eq!(MaxReserves::get(), 50);
+	assert_eq!(CreditExistentialDeposit::get(), 10_000_000_000);
+}
+
+#[test]
+fn utility_parameters_are_pinned() {
+	assert_eq!(MaxScheduledPerBlock::get(), 50);
+	assert_eq!(PreimageMaxSize::get(), 4_194_304);
+	assert_eq!(MaxSignatories::get(), 100);
+	assert_eq!(DepositBase::get(), 1_880_000_000_000);
+	assert_eq!(DepositFactor::get(), 320_000_000_000);
+}
+
+#[test]
+fn governance_parameters_are_pinned() {
+	assert_eq!(MaxProposals::get(), 100);
+	assert_eq!(MaxMembers::get(), 50);
+	assert_eq!(MaxProposalLength::get(), 5);
+	assert_eq!(GovernanceQuorum::get(), Percent::from_percent(60));
+}
+
+#[test]
+fn membership_parameters_are_pinned() {
+	assert_eq!(AuthorityDiffThreshold::get(), Perbill::from_percent(67));
+	assert_eq!(AutoRemoveThreshold::get(), Perbill::from_percent(10));
+	assert_eq!(AuthorityProposalTtl::get(), 86_400);
+	assert_eq!(MaxWellKnownNodes::get(), 1_000);
+	assert_eq!(MembershipPeriod::get(), 10_519_200);
+	assert_eq!(MaxMembersPerBlock::get(), 1_000);
+	assert_eq!(MaxExtrinsicsPerBlock::get(), 100);
+}
+
+#[test]
+fn registry_parameters_are_pinned() {
+	assert_eq!(MaxEncodedSchemaLength::get(), 15_360);
+	assert_eq!(MaxSpaceDelegates::get(), 10_000);
+	assert_eq!(MaxIssuers::get(), 1_000);
+	assert_eq!(MaxEncodedValueLength::get(), 1_024);
+	assert_eq!(MaxDelegationDepth::get(), 10);
+	assert_eq!(MaxDelegationChildren::get(), 25);
+	assert_eq!(WeightHistoryDepth::get(), 256);
+}
+
+#[test]
+fn fee_parameters_are_pinned() {
+	assert_eq!(BaseFee::get(), 100_000_000);
+	assert_eq!(FeeCoefficient::get(), FixedU128::from_rational(1, 1_000_000_000_000));
+}
+
+#[test]
+fn weight_to_fee_is_quadratic() {
+	let fee = |ref_time| CordWeightToFee::weight_to_fee(&Weight::from_parts(ref_time, 0));
+
+	assert_eq!(fee(0), BaseFee::get());
+	assert_eq!(fee(1_000_000), BaseFee::get() + 1);
+	assert_eq!(fee(2_000_000), BaseFee::get() + 4);
+	assert_eq!(fee(u64::MAX), u128::from(u64::MAX).pow(2) / 1_000_000_000_000 + BaseFee::get());
+}
+
+#[test]
+fn reliability_parameters_are_pinned() {
+	assert_eq!(ReliabilitySmoothingFactor::get(), Perbill::from_percent(20));
+	assert_eq!(MinReliabilityScore::get(), FixedU128::from_rational(1, 4));
+	assert_eq!(SlashPerOffender::get(), Perbill::from_percent(10));
+}
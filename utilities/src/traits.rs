@@ -18,8 +18,10 @@
 // You should have received a copy of the GNU General Public License
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
+use codec::Encode;
 use frame_support::traits::{Currency, ReservableCurrency};
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
+use sp_std::vec::Vec;
 
 use crate::{deposit::Deposit, free_deposit};
 
@@ -56,6 +58,50 @@ impl<S: Clone, P: Clone> CallSources<S, P> for (S, P) {
 	}
 }
 
+/// Scope of the identifiers derived on a chain.
+///
+/// Pallets pass the raw digest of a new identifier through the namespace
+/// before encoding it, so identifiers created on different chains do not
+/// collide.
+pub trait IdentifierNamespace {
+	/// The raw identifier digest, scoped to the chain.
+	fn namespaced(raw_id: &[u8]) -> Vec<u8>;
+}
+
+/// Identifiers are not scoped.
+impl IdentifierNamespace for () {
+	fn namespaced(raw_id: &[u8]) -> Vec<u8> {
+		raw_id.to_vec()
+	}
+}
+
+/// Deposits held for the records a pallet keeps in storage.
+///
+/// A pallet holds the deposit of a record from the account paying for it
+/// when it stores the record, and releases the deposit when it deletes the
+/// record. `key` identifies the record among the records of all pallets.
+pub trait StorageDeposit<AccountId> {
+	/// Hold the deposit of a record of `len` bytes from `who`.
+	fn hold<K: Encode>(who: &AccountId, key: &K, len: u32) -> DispatchResult;
+
+	/// Return the deposit of a record to the account it was held from, if a
+	/// deposit is held for the record.
+	fn release<K: Encode>(key: &K);
+
+	/// Give `who` the balance to hold the deposits of `len` bytes of records.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn fund(_who: &AccountId, _len: u32) {}
+}
+
+/// Records are stored without deposits.
+impl<AccountId> StorageDeposit<AccountId> for () {
+	fn hold<K: Encode>(_who: &AccountId, _key: &K, _len: u32) -> DispatchResult {
+		Ok(())
+	}
+
+	fn release<K: Encode>(_key: &K) {}
+}
+
 /// A trait that allows version migrators to access the underlying pallet's
 /// context, e.g., its Config trait.
 ///